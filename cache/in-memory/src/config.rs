@@ -19,6 +19,10 @@ bitflags! {
         const USER_CURRENT = 1 << 8;
         const USER = 1 << 9;
         const VOICE_STATE = 1 << 10;
+        const MEMBER_LIST = 1 << 11;
+        const RELATIONSHIP = 1 << 12;
+        const USER_GUILD_SETTINGS = 1 << 13;
+        const THREAD_MEMBER = 1 << 14;
     }
 }
 
@@ -79,6 +83,10 @@ mod tests {
         assert_eq!(1 << 8, ResourceType::USER_CURRENT.bits());
         assert_eq!(1 << 9, ResourceType::USER.bits());
         assert_eq!(1 << 10, ResourceType::VOICE_STATE.bits());
+        assert_eq!(1 << 11, ResourceType::MEMBER_LIST.bits());
+        assert_eq!(1 << 12, ResourceType::RELATIONSHIP.bits());
+        assert_eq!(1 << 13, ResourceType::USER_GUILD_SETTINGS.bits());
+        assert_eq!(1 << 14, ResourceType::THREAD_MEMBER.bits());
     }
 
     #[test]
@@ -54,6 +54,7 @@ pub mod model;
 mod builder;
 mod config;
 mod stats;
+mod unread;
 mod updates;
 
 pub use self::{
@@ -69,17 +70,19 @@ use std::{
     borrow::Cow,
     collections::{BTreeSet, HashSet, VecDeque},
     hash::Hash,
+    ops::Range,
     sync::{Arc, Mutex},
 };
 use twilight_model::{
     channel::{Group, GuildChannel, PrivateChannel},
-    gateway::payload::ReadState,
+    gateway::payload::{MemberListGroup, MemberListId, ReadState},
     gateway::presence::{Presence, UserOrId},
-    guild::{Emoji, Guild, Member, PartialMember, Role},
+    guild::{Emoji, Guild, Member, PartialMember, Permissions, Role},
     id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
-    user::{CurrentUser, User},
+    user::{CurrentUser, Relationship, User, UserGuildSettings},
     voice::VoiceState,
 };
+use twilight_util::permission_calculator::PermissionCalculator;
 
 #[derive(Debug)]
 struct GuildItem<T> {
@@ -138,6 +141,8 @@ fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, Arc<V>>, k: K, v: V)
 struct InMemoryCacheRef {
     config: Arc<Config>,
     ack_token: Mutex<Option<String>>,
+    /// Mapping of channels and the active threads created in them.
+    channel_threads: DashMap<ChannelId, HashSet<ChannelId>>,
     channels_guild: DashMap<ChannelId, GuildItem<GuildChannel>>,
     channels_private: DashMap<ChannelId, Arc<PrivateChannel>>,
     // So long as the lock isn't held across await or panic points this is fine.
@@ -151,12 +156,17 @@ struct InMemoryCacheRef {
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
     members: DashMap<(GuildId, UserId), Arc<CachedMember>>,
+    member_lists: DashMap<(GuildId, MemberListId), CachedMemberList>,
     messages: DashMap<ChannelId, VecDeque<Arc<CachedMessage>>>,
     presences: DashMap<(Option<GuildId>, UserId), Arc<CachedPresence>>,
     read_state: DashMap<ChannelId, ReadState>,
+    relationships: DashMap<UserId, Arc<Relationship>>,
     roles: DashMap<RoleId, GuildItem<Role>>,
+    /// Mapping of threads and the users that are members of them.
+    thread_members: DashMap<ChannelId, HashSet<UserId>>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, (Arc<User>, BTreeSet<GuildId>)>,
+    user_guild_settings: DashMap<Option<GuildId>, Arc<UserGuildSettings>>,
     /// Mapping of channels and the users currently connected.
     voice_state_channels: DashMap<ChannelId, HashSet<(GuildId, UserId)>>,
     /// Mapping of guilds and users currently connected to its voice channels.
@@ -377,6 +387,24 @@ impl InMemoryCache {
             .map(|r| r.value().clone())
     }
 
+    /// Gets the set of active threads created in a channel.
+    ///
+    /// Threads themselves are retrieved with [`guild_channel`], and are also
+    /// part of their guild's [`guild_channels`].
+    ///
+    /// This is a O(m) operation, where m is the amount of threads in the
+    /// channel. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`guild_channel`]: Self::guild_channel
+    /// [`guild_channels`]: Self::guild_channels
+    pub fn channel_threads(&self, channel_id: ChannelId) -> Option<HashSet<ChannelId>> {
+        self.0
+            .channel_threads
+            .get(&channel_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets the set of emojis in a guild.
     ///
     /// This is a O(m) operation, where m is the amount of emojis in the guild.
@@ -457,6 +485,39 @@ impl InMemoryCache {
         )
     }
 
+    /// Gets the entries of a guild member list within a range of indices.
+    ///
+    /// Group headers and members are returned in display order. Indices that
+    /// haven't been synced yet or that were invalidated are skipped.
+    ///
+    /// This is an O(n) operation, where n is the length of the range. This
+    /// requires the lazy guild subscription of the member list's channel.
+    pub fn member_list(
+        &self,
+        guild_id: GuildId,
+        list_id: MemberListId,
+        range: Range<usize>,
+    ) -> Option<Vec<CachedMemberListItem>> {
+        self.0
+            .member_lists
+            .get(&(guild_id, list_id))
+            .map(|list| list.range(range).cloned().collect())
+    }
+
+    /// Gets the groups of a guild member list, including their member counts.
+    ///
+    /// This is an O(n) operation, where n is the number of groups.
+    pub fn member_list_groups(
+        &self,
+        guild_id: GuildId,
+        list_id: MemberListId,
+    ) -> Option<Vec<MemberListGroup>> {
+        self.0
+            .member_lists
+            .get(&(guild_id, list_id))
+            .map(|list| list.groups.clone())
+    }
+
     /// Gets a message by channel ID and message ID.
     ///
     /// This is an O(n) operation. This requires one or both of the
@@ -477,6 +538,45 @@ impl InMemoryCache {
             .map(Arc::clone)
     }
 
+    /// Calculates the permissions of a member in a guild channel.
+    ///
+    /// The permissions are calculated from the guild's owner, the member's
+    /// roles, and the channel's permission overwrites. Threads don't have
+    /// permission overwrites of their own, so the overwrites of their parent
+    /// channel are used. Returns `None` if the channel, the parent of a
+    /// thread, its guild, the member, or the guild's `@everyone` role aren't
+    /// cached. Roles of the member that aren't cached are ignored.
+    ///
+    /// This is an O(n) operation, where n is the number of roles of the
+    /// member. This requires the [`GUILDS`] and [`GUILD_MEMBERS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn permissions_in(&self, channel_id: ChannelId, user_id: UserId) -> Option<Permissions> {
+        let mut channel = self.guild_channel(channel_id)?;
+
+        if let GuildChannel::Thread(thread) = channel.as_ref() {
+            channel = self.guild_channel(thread.parent_id?)?;
+        }
+
+        let guild_id = channel.guild_id()?;
+        let guild = self.guild(guild_id)?;
+        let member = self.member(guild_id, user_id)?;
+        let everyone_role = self.role(RoleId(guild_id.0))?;
+
+        let member_roles = member
+            .roles
+            .iter()
+            .filter_map(|role_id| Some((*role_id, self.role(*role_id)?.permissions)))
+            .collect::<Vec<_>>();
+
+        let calculator =
+            PermissionCalculator::new(guild_id, user_id, everyone_role.permissions, &member_roles)
+                .owner_id(guild.owner_id);
+
+        Some(calculator.in_channel(channel.permission_overwrites()))
+    }
+
     /// Gets a presence by, optionally, guild ID, and user ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_PRESENCES`] intent.
@@ -525,6 +625,39 @@ impl InMemoryCache {
             .map(|state| state.value().clone())
     }
 
+    /// Gets the current user's relationship with another user by their ID.
+    ///
+    /// This is an O(1) operation.
+    pub fn relationship(&self, user_id: UserId) -> Option<Arc<Relationship>> {
+        self.0
+            .relationships
+            .get(&user_id)
+            .map(|r| Arc::clone(r.value()))
+    }
+
+    /// Gets all of the current user's relationships, such as friends, blocked
+    /// users and pending friend requests.
+    ///
+    /// Returns `None` if the cache doesn't handle the [`RELATIONSHIP`]
+    /// resource type.
+    ///
+    /// This is an O(n) operation.
+    ///
+    /// [`RELATIONSHIP`]: ResourceType::RELATIONSHIP
+    pub fn relationships(&self) -> Option<Vec<Arc<Relationship>>> {
+        if !self.wants(ResourceType::RELATIONSHIP) {
+            return None;
+        }
+
+        Some(
+            self.0
+                .relationships
+                .iter()
+                .map(|r| Arc::clone(r.value()))
+                .collect(),
+        )
+    }
+
     /// Gets a role by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -547,6 +680,24 @@ impl InMemoryCache {
             .map(|item| item.value().clone())
     }
 
+    /// Gets the set of users that are members of a thread.
+    ///
+    /// Only members that were added or removed while the thread was cached
+    /// and the current user are known, since Discord doesn't send the full
+    /// list of members with threads.
+    ///
+    /// This is a O(m) operation, where m is the amount of members in the
+    /// thread. This requires the [`GUILD_MEMBERS`] intent for members other
+    /// than the current user.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn thread_members(&self, thread_id: ChannelId) -> Option<HashSet<UserId>> {
+        self.0
+            .thread_members
+            .get(&thread_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -556,6 +707,17 @@ impl InMemoryCache {
         self.0.users.get(&user_id).map(|r| Arc::clone(&r.0))
     }
 
+    /// Gets the current user's notification settings for a guild by ID, or
+    /// for private channels if `None` is provided.
+    ///
+    /// This is an O(1) operation.
+    pub fn user_guild_settings(&self, guild_id: Option<GuildId>) -> Option<Arc<UserGuildSettings>> {
+        self.0
+            .user_guild_settings
+            .get(&guild_id)
+            .map(|r| Arc::clone(r.value()))
+    }
+
     /// Gets the voice states within a voice channel.
     ///
     /// This requires both the [`GUILDS`] and [`GUILD_VOICE_STATES`] intents.
@@ -591,6 +753,7 @@ impl InMemoryCache {
     ///
     /// This is equal to creating a new empty cache.
     pub fn clear(&self) {
+        self.0.channel_threads.clear();
         self.0.channels_guild.clear();
         self.0.channels_private.clear();
         self.0
@@ -607,11 +770,15 @@ impl InMemoryCache {
         self.0.guild_presences.clear();
         self.0.guild_roles.clear();
         self.0.members.clear();
+        self.0.member_lists.clear();
         self.0.messages.clear();
         self.0.presences.clear();
+        self.0.relationships.clear();
         self.0.roles.clear();
+        self.0.thread_members.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
+        self.0.user_guild_settings.clear();
         self.0.voice_state_channels.clear();
         self.0.voice_state_guilds.clear();
         self.0.voice_states.clear();
@@ -659,6 +826,27 @@ impl InMemoryCache {
             GuildChannel::Stage(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
+            GuildChannel::Thread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+
+                // Thread updates don't contain the member of the current
+                // user, so keep the one that was cached.
+                if c.member.is_none() {
+                    if let Some(item) = self.0.channels_guild.get(&c.id) {
+                        if let GuildChannel::Thread(cached) = item.data.as_ref() {
+                            c.member = cached.member.clone();
+                        }
+                    }
+                }
+
+                if let Some(parent_id) = c.parent_id {
+                    self.0
+                        .channel_threads
+                        .entry(parent_id)
+                        .or_default()
+                        .insert(c.id);
+                }
+            }
         }
 
         let id = channel.id();
@@ -743,6 +931,7 @@ impl InMemoryCache {
         if self.wants(ResourceType::CHANNEL) {
             self.0.guild_channels.insert(guild.id, HashSet::new());
             self.cache_guild_channels(guild.id, guild.channels);
+            self.cache_guild_channels(guild.id, guild.threads);
         }
 
         if self.wants(ResourceType::EMOJI) {
@@ -862,7 +1051,7 @@ impl InMemoryCache {
         let cached = Arc::new(CachedMember {
             deaf: member.deaf,
             guild_id,
-            joined_at: member.joined_at.to_owned(),
+            joined_at: member.joined_at,
             mute: member.mute,
             nick: member.nick.to_owned(),
             pending: false,
@@ -923,6 +1112,14 @@ impl InMemoryCache {
         self.0.read_state.insert(read_state.id, read_state);
     }
 
+    fn cache_relationship(&self, relationship: Relationship) -> Arc<Relationship> {
+        upsert_item(&self.0.relationships, relationship.id, relationship)
+    }
+
+    fn cache_user_guild_settings(&self, settings: UserGuildSettings) -> Arc<UserGuildSettings> {
+        upsert_item(&self.0.user_guild_settings, settings.guild_id, settings)
+    }
+
     fn cache_roles(&self, guild_id: GuildId, roles: impl IntoIterator<Item = Role>) {
         for role in roles {
             self.cache_role(guild_id, role);
@@ -1058,7 +1255,8 @@ impl InMemoryCache {
     /// Delete a guild channel from the cache.
     ///
     /// The guild channel data itself and the channel entry in its guild's list
-    /// of channels will be deleted.
+    /// of channels will be deleted. Threads created in the channel are deleted
+    /// along with it.
     fn delete_guild_channel(&self, channel_id: ChannelId) -> Option<Arc<GuildChannel>> {
         let GuildItem { data, guild_id } = self.0.channels_guild.remove(&channel_id)?.1;
 
@@ -1066,6 +1264,20 @@ impl InMemoryCache {
             guild_channels.remove(&channel_id);
         }
 
+        if let Some(parent_id) = data.parent_id() {
+            if let Some(mut threads) = self.0.channel_threads.get_mut(&parent_id) {
+                threads.remove(&channel_id);
+            }
+        }
+
+        self.0.thread_members.remove(&channel_id);
+
+        if let Some((_, threads)) = self.0.channel_threads.remove(&channel_id) {
+            for thread_id in threads {
+                self.delete_guild_channel(thread_id);
+            }
+        }
+
         Some(data)
     }
 
@@ -1098,7 +1310,12 @@ mod tests {
     use crate::InMemoryCache;
     use std::borrow::Cow;
     use twilight_model::{
-        channel::{ChannelType, GuildChannel, TextChannel},
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            ChannelType, GuildChannel, TextChannel, ThreadChannel,
+        },
+        datetime::Timestamp,
         gateway::payload::{GuildEmojisUpdate, MemberRemove, RoleDelete},
         guild::{
             DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
@@ -1139,6 +1356,56 @@ mod tests {
         }
     }
 
+    fn guild(id: GuildId, channels: Vec<GuildChannel>) -> Guild {
+        Guild {
+            id,
+            afk_channel_id: None,
+            afk_timeout: 300,
+            application_id: None,
+            banner: None,
+            channels,
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: None,
+            discovery_splash: None,
+            emojis: Vec::new(),
+            explicit_content_filter: ExplicitContentFilter::AllMembers,
+            features: vec![],
+            icon: None,
+            joined_at: Some("".to_owned()),
+            large: false,
+            max_members: Some(50),
+            max_presences: Some(100),
+            member_count: Some(25),
+            members: Vec::new(),
+            mfa_level: MfaLevel::Elevated,
+            name: "this is a guild".to_owned(),
+            nsfw: false,
+            owner: Some(false),
+            owner_id: UserId(456),
+            permissions: Some(Permissions::SEND_MESSAGES),
+            preferred_locale: "en-GB".to_owned(),
+            premium_subscription_count: Some(0),
+            premium_tier: PremiumTier::None,
+            presences: Vec::new(),
+            region: "us-east".to_owned(),
+            roles: Vec::new(),
+            splash: None,
+            system_channel_id: None,
+            system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
+            rules_channel_id: None,
+            threads: Vec::new(),
+            unavailable: false,
+            verification_level: VerificationLevel::VeryHigh,
+            voice_states: Vec::new(),
+            vanity_url_code: None,
+            widget_channel_id: None,
+            widget_enabled: None,
+            max_video_channel_users: None,
+            approximate_member_count: None,
+            approximate_presence_count: None,
+        }
+    }
+
     fn member(id: UserId, guild_id: GuildId) -> Member {
         Member {
             deaf: false,
@@ -1272,6 +1539,7 @@ mod tests {
             system_channel_id: None,
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
             rules_channel_id: None,
+            threads: Vec::new(),
             unavailable: false,
             verification_level: VerificationLevel::VeryHigh,
             voice_states: Vec::new(),
@@ -1300,6 +1568,130 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_permissions_in() {
+        let guild_id = GuildId(123);
+        let channel = GuildChannel::Text(TextChannel {
+            id: ChannelId(111),
+            guild_id: Some(guild_id),
+            kind: ChannelType::GuildText,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: "announcements".to_owned(),
+            nsfw: false,
+            permission_overwrites: vec![
+                PermissionOverwrite {
+                    allow: Permissions::empty(),
+                    deny: Permissions::SEND_MESSAGES,
+                    kind: PermissionOverwriteType::Role(RoleId(guild_id.0)),
+                },
+                PermissionOverwrite {
+                    allow: Permissions::SEND_MESSAGES,
+                    deny: Permissions::empty(),
+                    kind: PermissionOverwriteType::Role(RoleId(1)),
+                },
+            ],
+            parent_id: None,
+            position: 1,
+            rate_limit_per_user: None,
+            topic: None,
+        });
+
+        let mut everyone = role(RoleId(guild_id.0));
+        everyone.permissions = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let mut moderator = member(UserId(2), guild_id);
+        moderator.roles.push(RoleId(1));
+
+        let mut guild = guild(guild_id, vec![channel]);
+        guild.roles = vec![everyone, role(RoleId(1))];
+        guild.members = vec![
+            moderator,
+            member(UserId(3), guild_id),
+            member(UserId(456), guild_id),
+        ];
+
+        let cache = InMemoryCache::new();
+        cache.cache_guild(guild);
+
+        assert_eq!(
+            Some(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+            cache.permissions_in(ChannelId(111), UserId(2))
+        );
+        assert_eq!(
+            Some(Permissions::VIEW_CHANNEL),
+            cache.permissions_in(ChannelId(111), UserId(3))
+        );
+        assert_eq!(
+            Some(Permissions::all()),
+            cache.permissions_in(ChannelId(111), UserId(456))
+        );
+        assert!(cache.permissions_in(ChannelId(111), UserId(4)).is_none());
+        assert!(cache.permissions_in(ChannelId(112), UserId(2)).is_none());
+    }
+
+    #[test]
+    fn test_permissions_in_thread() {
+        let guild_id = GuildId(123);
+        let parent = GuildChannel::Text(TextChannel {
+            id: ChannelId(111),
+            guild_id: Some(guild_id),
+            kind: ChannelType::GuildText,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: "staff".to_owned(),
+            nsfw: false,
+            permission_overwrites: vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::VIEW_CHANNEL,
+                kind: PermissionOverwriteType::Role(RoleId(guild_id.0)),
+            }],
+            parent_id: None,
+            position: 1,
+            rate_limit_per_user: None,
+            topic: None,
+        });
+        let thread = GuildChannel::Thread(ThreadChannel {
+            guild_id: Some(guild_id),
+            id: ChannelId(112),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(456)),
+            parent_id: Some(ChannelId(111)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                archive_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                    .expect("valid timestamp"),
+                invitable: None,
+                locked: false,
+            },
+        });
+
+        let mut everyone = role(RoleId(guild_id.0));
+        everyone.permissions = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+
+        let mut guild = guild(guild_id, vec![parent]);
+        guild.roles = vec![everyone];
+        guild.members = vec![member(UserId(3), guild_id)];
+
+        let cache = InMemoryCache::new();
+        cache.cache_guild(guild);
+        cache.cache_guild_channel(guild_id, thread);
+
+        let in_parent = cache.permissions_in(ChannelId(111), UserId(3)).unwrap();
+        assert!(!in_parent.contains(Permissions::VIEW_CHANNEL));
+        assert_eq!(
+            Some(in_parent),
+            cache.permissions_in(ChannelId(112), UserId(3))
+        );
+    }
+
     #[test]
     fn test_syntax_update() {
         let cache = InMemoryCache::new();
@@ -1,6 +1,7 @@
 use serde::Serialize;
 use std::sync::Arc;
 use twilight_model::{
+    datetime::Timestamp,
     guild::{Member, PartialMember},
     id::{GuildId, RoleId},
     user::User,
@@ -10,7 +11,7 @@ use twilight_model::{
 pub struct CachedMember {
     pub deaf: bool,
     pub guild_id: GuildId,
-    pub joined_at: Option<String>,
+    pub joined_at: Option<Timestamp>,
     pub mute: bool,
     pub nick: Option<String>,
     pub pending: bool,
@@ -0,0 +1,255 @@
+use serde::Serialize;
+use std::ops::Range;
+use twilight_model::{
+    gateway::payload::{MemberListGroup, MemberListItem, MemberListUpdate, MemberListUpdateOp},
+    id::UserId,
+};
+
+/// Entry of a cached member list.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub enum CachedMemberListItem {
+    /// Header of a group, such as a hoisted role or the online and offline
+    /// sections.
+    Group(MemberListGroup),
+    /// Member listed under the closest preceding group.
+    Member(UserId),
+}
+
+impl From<&MemberListItem> for CachedMemberListItem {
+    fn from(item: &MemberListItem) -> Self {
+        match item {
+            MemberListItem::Group(group) => Self::Group(group.clone()),
+            MemberListItem::Member(member) => Self::Member(member.user.id),
+        }
+    }
+}
+
+/// Ordered and indexed state of a guild's member list.
+///
+/// Indices match the indices used by Discord in member list update operations.
+/// Entries that haven't been synced yet or that were invalidated are `None`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct CachedMemberList {
+    pub groups: Vec<MemberListGroup>,
+    pub items: Vec<Option<CachedMemberListItem>>,
+    pub member_count: u32,
+    pub online_count: u32,
+}
+
+impl CachedMemberList {
+    /// Apply a member list update, including all of its operations in order.
+    pub(crate) fn update(&mut self, update: &MemberListUpdate) {
+        self.groups = update.groups.clone();
+        self.member_count = update.member_count;
+        self.online_count = update.online_count;
+
+        for op in &update.ops {
+            self.apply(op);
+        }
+    }
+
+    /// Apply a single member list operation.
+    pub(crate) fn apply(&mut self, op: &MemberListUpdateOp) {
+        match op {
+            MemberListUpdateOp::Sync { range, items } => {
+                let range = Self::op_range(range);
+                self.fill(range.clone(), None);
+
+                for (idx, item) in items.iter().enumerate() {
+                    self.set(range.start + idx, Some(item.into()));
+                }
+            }
+            MemberListUpdateOp::Invalidate { range } => {
+                self.fill(Self::op_range(range), None);
+            }
+            MemberListUpdateOp::Update { item, index } => {
+                self.set(*index as usize, Some(item.into()));
+            }
+            MemberListUpdateOp::Delete { index } => {
+                let index = *index as usize;
+
+                if index < self.items.len() {
+                    self.items.remove(index);
+                }
+            }
+            MemberListUpdateOp::Insert { item, index } => {
+                let index = *index as usize;
+
+                if index > self.items.len() {
+                    self.items.resize(index, None);
+                }
+
+                self.items.insert(index, Some(item.into()));
+            }
+            MemberListUpdateOp::Unknown => {
+                tracing::warn!("member list update unknown type");
+            }
+        }
+    }
+
+    /// Iterate over the known entries within a range of indices, in display
+    /// order.
+    pub fn range(&self, range: Range<usize>) -> impl Iterator<Item = &CachedMemberListItem> {
+        let end = range.end.min(self.items.len());
+        let start = range.start.min(end);
+
+        self.items[start..end].iter().flatten()
+    }
+
+    /// Convert an inclusive range sent by Discord into an index range.
+    fn op_range(range: &[u32]) -> Range<usize> {
+        match range {
+            [start, end, ..] => *start as usize..*end as usize + 1,
+            [start] => *start as usize..*start as usize + 1,
+            [] => 0..0,
+        }
+    }
+
+    fn fill(&mut self, range: Range<usize>, value: Option<CachedMemberListItem>) {
+        let end = range.end.min(self.items.len());
+
+        for idx in range.start.min(end)..end {
+            self.items[idx] = value.clone();
+        }
+    }
+
+    fn set(&mut self, index: usize, value: Option<CachedMemberListItem>) {
+        if index >= self.items.len() {
+            self.items.resize(index + 1, None);
+        }
+
+        self.items[index] = value;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{CachedMemberList, CachedMemberListItem};
+    use twilight_model::{
+        datetime::Timestamp,
+        gateway::{
+            payload::{
+                GroupId, MemberListGroup, MemberListItem, MemberListMember, MemberListUpdateOp,
+                PartialUser,
+            },
+            presence::{ClientStatus, Presence, Status, UserOrId},
+        },
+        id::{GuildId, UserId},
+    };
+
+    fn group(id: GroupId, count: u32) -> MemberListItem {
+        MemberListItem::Group(MemberListGroup { id, count })
+    }
+
+    pub(crate) fn member(id: u64) -> MemberListItem {
+        MemberListItem::Member(Box::new(MemberListMember {
+            user: PartialUser {
+                avatar: None,
+                discriminator: "0001".to_owned(),
+                id: UserId(id),
+                username: "test".to_owned(),
+            },
+            roles: Vec::new(),
+            presence: Presence {
+                activities: Vec::new(),
+                client_status: ClientStatus {
+                    desktop: None,
+                    mobile: None,
+                    web: None,
+                },
+                guild_id: Some(GuildId(1)),
+                status: Status::Online,
+                user: UserOrId::UserId { id: UserId(id) },
+            },
+            mute: false,
+            joined_at: Timestamp::parse("2020-01-01T00:00:00.000000+00:00")
+                .expect("valid timestamp"),
+            hoisted_role: None,
+            deaf: false,
+        }))
+    }
+
+    fn user_ids(list: &CachedMemberList) -> Vec<Option<u64>> {
+        list.items
+            .iter()
+            .map(|item| match item {
+                Some(CachedMemberListItem::Member(id)) => Some(id.0),
+                Some(CachedMemberListItem::Group(_)) => Some(0),
+                None => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sync_insert_delete_update() {
+        let mut list = CachedMemberList::default();
+        list.apply(&MemberListUpdateOp::Sync {
+            range: vec![0, 99],
+            items: vec![group(GroupId::Online, 2), member(1), member(2)],
+        });
+        assert_eq!(vec![Some(0), Some(1), Some(2)], user_ids(&list));
+
+        list.apply(&MemberListUpdateOp::Insert {
+            index: 2,
+            item: member(3),
+        });
+        assert_eq!(vec![Some(0), Some(1), Some(3), Some(2)], user_ids(&list));
+
+        list.apply(&MemberListUpdateOp::Delete { index: 1 });
+        assert_eq!(vec![Some(0), Some(3), Some(2)], user_ids(&list));
+
+        list.apply(&MemberListUpdateOp::Update {
+            index: 2,
+            item: member(4),
+        });
+        assert_eq!(vec![Some(0), Some(3), Some(4)], user_ids(&list));
+    }
+
+    #[test]
+    fn test_invalidate_and_range() {
+        let mut list = CachedMemberList::default();
+        list.apply(&MemberListUpdateOp::Sync {
+            range: vec![0, 3],
+            items: vec![group(GroupId::Online, 3), member(1), member(2), member(3)],
+        });
+        list.apply(&MemberListUpdateOp::Sync {
+            range: vec![5, 6],
+            items: vec![group(GroupId::Offline, 1), member(4)],
+        });
+        assert_eq!(
+            vec![Some(0), Some(1), Some(2), Some(3), None, Some(0), Some(4)],
+            user_ids(&list)
+        );
+
+        list.apply(&MemberListUpdateOp::Invalidate { range: vec![0, 3] });
+        assert_eq!(
+            vec![None, None, None, None, None, Some(0), Some(4)],
+            user_ids(&list)
+        );
+
+        let items = list.range(0..100).cloned().collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                CachedMemberListItem::Group(MemberListGroup {
+                    id: GroupId::Offline,
+                    count: 1,
+                }),
+                CachedMemberListItem::Member(UserId(4)),
+            ],
+            items
+        );
+        assert_eq!(0, list.range(10..20).count());
+    }
+
+    #[test]
+    fn test_insert_past_end() {
+        let mut list = CachedMemberList::default();
+        list.apply(&MemberListUpdateOp::Insert {
+            index: 2,
+            item: member(1),
+        });
+        list.apply(&MemberListUpdateOp::Delete { index: 10 });
+
+        assert_eq!(vec![None, None, Some(1)], user_ids(&list));
+    }
+}
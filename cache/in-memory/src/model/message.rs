@@ -8,6 +8,7 @@ use twilight_model::{
         },
         Attachment, ChannelMention,
     },
+    datetime::Timestamp,
     guild::PartialMember,
     id::{ChannelId, GuildId, MessageId, RoleId, UserId, WebhookId},
 };
@@ -21,7 +22,7 @@ pub struct CachedMessage {
     pub author: UserId,
     pub channel_id: ChannelId,
     pub content: String,
-    pub edited_timestamp: Option<String>,
+    pub edited_timestamp: Option<Timestamp>,
     pub embeds: Vec<Embed>,
     pub flags: Option<MessageFlags>,
     pub guild_id: Option<GuildId>,
@@ -35,7 +36,7 @@ pub struct CachedMessage {
     pub reactions: Vec<MessageReaction>,
     pub reference: Option<MessageReference>,
     pub stickers: Vec<Sticker>,
-    pub timestamp: String,
+    pub timestamp: Timestamp,
     pub tts: bool,
     pub webhook_id: Option<WebhookId>,
 }
@@ -3,13 +3,21 @@
 mod emoji;
 mod guild;
 mod member;
+pub(crate) mod member_list;
 mod message;
 mod presence;
+mod unread;
 mod voice_state;
 
 pub use self::{
-    emoji::CachedEmoji, guild::CachedGuild, member::CachedMember, message::CachedMessage,
-    presence::CachedPresence, voice_state::CachedVoiceState,
+    emoji::CachedEmoji,
+    guild::CachedGuild,
+    member::CachedMember,
+    member_list::{CachedMemberList, CachedMemberListItem},
+    message::CachedMessage,
+    presence::CachedPresence,
+    unread::GuildUnread,
+    voice_state::CachedVoiceState,
 };
 
 #[cfg(tests)]
@@ -19,13 +19,13 @@ impl PartialEq<Presence> for CachedPresence {
             &self.activities,
             &self.client_status,
             self.guild_id,
-            self.status,
+            &self.status,
             self.user_id,
         ) == (
             &other.activities,
             &other.client_status,
             other.guild_id,
-            other.status,
+            &other.status,
             presence_user_id(&other.user),
         )
     }
@@ -37,7 +37,7 @@ impl From<&'_ Presence> for CachedPresence {
             activities: presence.activities.clone(),
             client_status: presence.client_status.clone(),
             guild_id: presence.guild_id,
-            status: presence.status,
+            status: presence.status.clone(),
             user_id: presence_user_id(&presence.user),
         }
     }
@@ -0,0 +1,17 @@
+use serde::Serialize;
+use twilight_model::id::{ChannelId, GuildId};
+
+/// Unread state of a guild, rolled up from the read states of its channels.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct GuildUnread {
+    pub guild_id: GuildId,
+    /// Total number of unread mentions across the guild's channels, including
+    /// muted channels.
+    pub mention_count: usize,
+    /// Whether the guild has an unread channel that isn't muted.
+    ///
+    /// This is always `false` if the guild itself is muted.
+    pub unread: bool,
+    /// Channels with unread messages, including muted channels.
+    pub unread_channels: Vec<ChannelId>,
+}
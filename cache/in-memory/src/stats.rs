@@ -34,6 +34,15 @@ impl<'a> InMemoryCacheStats<'a> {
         Some(channel.len())
     }
 
+    /// Number of active threads created in a given channel in the cache.
+    ///
+    /// Returns `None` if no threads of the channel have been cached.
+    pub fn channel_threads(&self, channel_id: ChannelId) -> Option<usize> {
+        let channel = self.0 .0.channel_threads.get(&channel_id)?;
+
+        Some(channel.len())
+    }
+
     /// Number of voice states in a given channel in the cache.
     ///
     /// Returns `None` if the channel hasn't yet been cached or there are no
@@ -134,6 +143,15 @@ impl<'a> InMemoryCacheStats<'a> {
         self.0 .0.roles.len()
     }
 
+    /// Number of members in a given thread in the cache.
+    ///
+    /// Returns `None` if the members of the thread haven't been cached.
+    pub fn thread_members(&self, thread_id: ChannelId) -> Option<usize> {
+        let thread = self.0 .0.thread_members.get(&thread_id)?;
+
+        Some(thread.len())
+    }
+
     /// Number of unavailable_guilds in the cache.
     pub fn unavailable_guilds(&self) -> usize {
         self.0 .0.unavailable_guilds.len()
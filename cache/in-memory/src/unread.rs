@@ -0,0 +1,456 @@
+//! Unread and mention tracking built on the cached read states.
+//!
+//! A channel is unread when the ID of its last message is newer than the ID of
+//! the last message acknowledged in its read state. Channels without a read
+//! state are unread as soon as they have a message.
+
+use super::{model::GuildUnread, InMemoryCache};
+use std::sync::Arc;
+use twilight_model::{
+    channel::{GuildChannel, Message},
+    gateway::payload::ReadState,
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
+
+impl InMemoryCache {
+    /// Whether a channel has messages newer than the last acknowledged
+    /// message.
+    ///
+    /// Returns `false` if the channel isn't cached or has no messages.
+    pub fn is_unread(&self, channel_id: ChannelId) -> bool {
+        let last_message_id = match self.last_message_id(channel_id) {
+            Some(last_message_id) => last_message_id,
+            None => return false,
+        };
+
+        self.0
+            .read_state
+            .get(&channel_id)
+            .map_or(true, |state| state.last_message_id < last_message_id)
+    }
+
+    /// Number of unread messages in a channel that mention the current user.
+    ///
+    /// Mentions include direct mentions, mentions of the current user's roles
+    /// and `@everyone` unless the guild's settings suppress them. Every
+    /// message in a private channel counts as a mention.
+    pub fn mention_count(&self, channel_id: ChannelId) -> usize {
+        self.0
+            .read_state
+            .get(&channel_id)
+            .and_then(|state| state.mention_count)
+            .unwrap_or_default()
+    }
+
+    /// Gets the IDs of all cached channels with unread messages.
+    ///
+    /// This is an O(n) operation.
+    pub fn unread_channels(&self) -> Vec<ChannelId> {
+        self.0
+            .channels_guild
+            .iter()
+            .map(|r| *r.key())
+            .chain(self.0.channels_private.iter().map(|r| *r.key()))
+            .chain(self.0.groups.iter().map(|r| *r.key()))
+            .filter(|channel_id| self.is_unread(*channel_id))
+            .collect()
+    }
+
+    /// Gets the unread state of a guild, rolled up from its channels.
+    ///
+    /// Channels that are muted, either directly or through their category, are
+    /// listed as unread but don't make the guild unread.
+    ///
+    /// This is an O(n) operation on the guild's channels. This requires the
+    /// [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_unread(&self, guild_id: GuildId) -> Option<GuildUnread> {
+        let channel_ids = self.0.guild_channels.get(&guild_id)?;
+        let settings = self.user_guild_settings(Some(guild_id));
+        let guild_muted = settings.as_ref().map_or(false, |s| s.muted);
+
+        let mut unread = GuildUnread {
+            guild_id,
+            mention_count: 0,
+            unread: false,
+            unread_channels: Vec::new(),
+        };
+
+        for channel_id in channel_ids.iter() {
+            unread.mention_count += self.mention_count(*channel_id);
+
+            if !self.is_unread(*channel_id) {
+                continue;
+            }
+
+            unread.unread_channels.push(*channel_id);
+
+            let channel_muted = settings.as_ref().map_or(false, |s| {
+                s.channel_muted(*channel_id, self.channel_parent_id(*channel_id))
+            });
+
+            if !guild_muted && !channel_muted {
+                unread.unread = true;
+            }
+        }
+
+        Some(unread)
+    }
+
+    /// Update the read state of a channel with a newly created message.
+    ///
+    /// Messages sent by the current user acknowledge the channel, while
+    /// messages that mention the current user increase the mention count.
+    pub(crate) fn track_unread(&self, message: &Message) {
+        let current_user_id = match self.current_user() {
+            Some(current_user) => current_user.id,
+            None => return,
+        };
+
+        let mut state = self
+            .0
+            .read_state
+            .entry(message.channel_id)
+            .or_insert_with(|| ReadState {
+                id: message.channel_id,
+                ..ReadState::default()
+            });
+
+        if message.author.id == current_user_id {
+            state.last_message_id = message.id;
+            state.mention_count = Some(0);
+
+            return;
+        }
+
+        if message.id > state.last_message_id && self.is_mention(message, current_user_id) {
+            *state.mention_count.get_or_insert(0) += 1;
+        }
+    }
+
+    /// Update the last message ID of a cached channel.
+    pub(crate) fn set_last_message_id(&self, channel_id: ChannelId, message_id: MessageId) {
+        if let Some(mut item) = self.0.channels_guild.get_mut(&channel_id) {
+            match Arc::make_mut(&mut item.data) {
+                GuildChannel::Text(channel) => {
+                    channel.last_message_id.replace(message_id);
+                }
+                GuildChannel::Thread(thread) => {
+                    thread.last_message_id.replace(message_id);
+                }
+                _ => {}
+            }
+        } else if let Some(mut channel) = self.0.channels_private.get_mut(&channel_id) {
+            Arc::make_mut(&mut channel)
+                .last_message_id
+                .replace(message_id);
+        } else if let Some(mut group) = self.0.groups.get_mut(&channel_id) {
+            Arc::make_mut(&mut group)
+                .last_message_id
+                .replace(message_id);
+        }
+    }
+
+    fn is_mention(&self, message: &Message, current_user_id: UserId) -> bool {
+        let guild_id = match message.guild_id {
+            Some(guild_id) => guild_id,
+            None => return true,
+        };
+
+        if message.mentions.iter().any(|m| m.id == current_user_id) {
+            return true;
+        }
+
+        let settings = self.user_guild_settings(Some(guild_id));
+
+        if message.mention_everyone && !settings.as_ref().map_or(false, |s| s.suppress_everyone) {
+            return true;
+        }
+
+        if message.mention_roles.is_empty() || settings.map_or(false, |s| s.suppress_roles) {
+            return false;
+        }
+
+        self.member(guild_id, current_user_id)
+            .map_or(false, |member| {
+                member
+                    .roles
+                    .iter()
+                    .any(|role_id| message.mention_roles.contains(role_id))
+            })
+    }
+
+    fn last_message_id(&self, channel_id: ChannelId) -> Option<MessageId> {
+        if let Some(item) = self.0.channels_guild.get(&channel_id) {
+            return match item.data.as_ref() {
+                GuildChannel::Text(channel) => channel.last_message_id,
+                GuildChannel::Thread(thread) => thread.last_message_id,
+                _ => None,
+            };
+        }
+
+        if let Some(channel) = self.0.channels_private.get(&channel_id) {
+            return channel.last_message_id;
+        }
+
+        self.0
+            .groups
+            .get(&channel_id)
+            .and_then(|group| group.last_message_id)
+    }
+
+    fn channel_parent_id(&self, channel_id: ChannelId) -> Option<ChannelId> {
+        self.0.channels_guild.get(&channel_id)?.data.parent_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::InMemoryCache;
+    use twilight_model::{
+        channel::{
+            message::{Mention, MessageType},
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            Channel, ChannelType, GuildChannel, Message, TextChannel, ThreadChannel,
+        },
+        datetime::Timestamp,
+        gateway::payload::{
+            ChannelCreate, MessageAck, MessageCreate, ThreadCreate, UserGuildSettingsUpdate,
+        },
+        id::{ChannelId, GuildId, MessageId, RoleId, UserId},
+        user::{CurrentUser, MessageNotificationLevel, User, UserFlags, UserGuildSettings},
+    };
+
+    fn text_channel(id: u64, last_message_id: Option<u64>) -> ChannelCreate {
+        ChannelCreate(Channel::Guild(GuildChannel::Text(TextChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(id),
+            kind: ChannelType::GuildText,
+            last_message_id: last_message_id.map(MessageId),
+            last_pin_timestamp: None,
+            name: "test".to_owned(),
+            nsfw: false,
+            parent_id: None,
+            permission_overwrites: Vec::new(),
+            position: 0,
+            rate_limit_per_user: None,
+            topic: None,
+        })))
+    }
+
+    fn thread(id: u64, parent_id: u64) -> ThreadCreate {
+        ThreadCreate(Channel::Guild(GuildChannel::Thread(ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(id),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(20)),
+            parent_id: Some(ChannelId(parent_id)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                archive_timestamp: Timestamp::from_secs(1_632_072_645).expect("valid timestamp"),
+                invitable: None,
+                locked: false,
+            },
+        })))
+    }
+
+    fn message(id: u64, channel_id: u64, author_id: u64) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(author_id),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id: ChannelId(channel_id),
+            content: String::new(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: Some(GuildId(1)),
+            id: MessageId(id),
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            nonce: None,
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            stickers: Vec::new(),
+            referenced_message: None,
+            timestamp: Timestamp::from_secs(1_632_072_645).expect("valid timestamp"),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    fn mention(id: u64) -> Mention {
+        Mention {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            id: UserId(id),
+            member: None,
+            name: "test".to_owned(),
+            public_flags: UserFlags::empty(),
+        }
+    }
+
+    fn cache() -> InMemoryCache {
+        let cache = InMemoryCache::new();
+        cache.cache_current_user(CurrentUser {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(10),
+            locale: None,
+            mfa_enabled: false,
+            name: "me".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            verified: None,
+        });
+        cache.update(&text_channel(2, None));
+        cache.update(&text_channel(3, None));
+
+        cache
+    }
+
+    #[test]
+    fn test_unread_and_ack() {
+        let cache = cache();
+        assert!(!cache.is_unread(ChannelId(2)));
+
+        cache.update(&MessageCreate(message(100, 2, 20)));
+        assert!(cache.is_unread(ChannelId(2)));
+        assert!(!cache.is_unread(ChannelId(3)));
+        assert_eq!(vec![ChannelId(2)], cache.unread_channels());
+
+        cache.update(&MessageAck {
+            channel_id: ChannelId(2),
+            message_id: MessageId(100),
+        });
+        assert!(!cache.is_unread(ChannelId(2)));
+
+        // Sending a message acknowledges the channel.
+        cache.update(&MessageCreate(message(101, 3, 20)));
+        cache.update(&MessageCreate(message(102, 3, 10)));
+        assert!(!cache.is_unread(ChannelId(3)));
+    }
+
+    #[test]
+    fn test_older_ack() {
+        let cache = cache();
+
+        let mut mentioned = message(101, 2, 20);
+        mentioned.mentions.push(mention(10));
+        cache.update(&MessageCreate(message(100, 2, 20)));
+        cache.update(&MessageCreate(mentioned));
+        cache.update(&MessageAck {
+            channel_id: ChannelId(2),
+            message_id: MessageId(101),
+        });
+        assert!(!cache.is_unread(ChannelId(2)));
+
+        let mut mentioned = message(102, 2, 20);
+        mentioned.mentions.push(mention(10));
+        cache.update(&MessageCreate(mentioned));
+        assert!(cache.is_unread(ChannelId(2)));
+        assert_eq!(1, cache.mention_count(ChannelId(2)));
+
+        // Acking an older message doesn't read the newer one.
+        cache.update(&MessageAck {
+            channel_id: ChannelId(2),
+            message_id: MessageId(100),
+        });
+        assert!(cache.is_unread(ChannelId(2)));
+        assert_eq!(1, cache.mention_count(ChannelId(2)));
+    }
+
+    #[test]
+    fn test_unread_thread() {
+        let cache = cache();
+        cache.update(&thread(4, 2));
+        assert!(!cache.is_unread(ChannelId(4)));
+
+        cache.update(&MessageCreate(message(100, 4, 20)));
+        assert!(cache.is_unread(ChannelId(4)));
+        assert!(!cache.is_unread(ChannelId(2)));
+        assert_eq!(vec![ChannelId(4)], cache.unread_channels());
+    }
+
+    #[test]
+    fn test_mentions() {
+        let cache = cache();
+
+        let mut direct = message(100, 2, 20);
+        direct.mentions.push(mention(10));
+        cache.update(&MessageCreate(direct));
+
+        let mut everyone = message(101, 2, 20);
+        everyone.mention_everyone = true;
+        cache.update(&MessageCreate(everyone.clone()));
+
+        let mut role = message(102, 2, 20);
+        role.mention_roles.push(RoleId(5));
+        cache.update(&MessageCreate(role));
+
+        cache.update(&MessageCreate(message(103, 2, 20)));
+        assert_eq!(2, cache.mention_count(ChannelId(2)));
+
+        cache.update(&UserGuildSettingsUpdate(UserGuildSettings {
+            channel_overrides: Vec::new(),
+            guild_id: Some(GuildId(1)),
+            hide_muted_channels: false,
+            message_notifications: MessageNotificationLevel::AllMessages,
+            mobile_push: false,
+            mute_config: None,
+            muted: true,
+            suppress_everyone: true,
+            suppress_roles: false,
+            version: 1,
+        }));
+        everyone.id = MessageId(104);
+        cache.update(&MessageCreate(everyone));
+        assert_eq!(2, cache.mention_count(ChannelId(2)));
+
+        let unread = cache
+            .guild_unread(GuildId(1))
+            .expect("guild channels cached");
+        assert_eq!(2, unread.mention_count);
+        assert_eq!(vec![ChannelId(2)], unread.unread_channels);
+        assert!(!unread.unread);
+
+        cache.update(&MessageAck {
+            channel_id: ChannelId(2),
+            message_id: MessageId(104),
+        });
+        assert_eq!(0, cache.mention_count(ChannelId(2)));
+    }
+}
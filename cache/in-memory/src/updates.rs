@@ -6,8 +6,8 @@ use twilight_model::{
     channel::{message::MessageReaction, Channel, GuildChannel, ReactionType},
     gateway::{event::Event, payload::*, presence::Presence},
     guild::GuildStatus,
-    id::GuildId,
-    user::User,
+    id::{ChannelId, GuildId},
+    user::{Relationship, User},
 };
 
 pub trait UpdateCache {
@@ -58,6 +58,8 @@ impl UpdateCache for Event {
             ReactionRemoveAll(v) => c.update(v),
             ReactionRemoveEmoji(v) => c.update(v),
             Ready(v) => c.update(v.deref()),
+            RelationshipAdd(v) => c.update(v.deref()),
+            RelationshipRemove(v) => c.update(v),
             Resumed => {}
             RoleCreate(v) => c.update(v),
             RoleDelete(v) => c.update(v),
@@ -69,8 +71,16 @@ impl UpdateCache for Event {
             ShardReconnecting(_) => {}
             ShardPayload(_) => {}
             ShardResuming(_) => {}
+            ThreadCreate(v) => c.update(v),
+            ThreadDelete(v) => c.update(v),
+            ThreadListSync(v) => c.update(v),
+            ThreadMemberUpdate(v) => c.update(v),
+            ThreadMembersUpdate(v) => c.update(v),
+            ThreadUpdate(v) => c.update(v),
             TypingStart(v) => c.update(v.deref()),
             UnavailableGuild(v) => c.update(v),
+            Unknown { .. } => {}
+            UserGuildSettingsUpdate(v) => c.update(v.deref()),
             UserUpdate(v) => c.update(v),
             VoiceServerUpdate(v) => c.update(v),
             VoiceStateUpdate(v) => c.update(v.deref()),
@@ -208,6 +218,13 @@ impl UpdateCache for GuildDelete {
         cache.0.guilds.remove(&id);
 
         if cache.wants(ResourceType::CHANNEL) {
+            if let Some(channel_ids) = cache.0.guild_channels.get(&id) {
+                for channel_id in channel_ids.iter() {
+                    cache.0.channel_threads.remove(channel_id);
+                    cache.0.thread_members.remove(channel_id);
+                }
+            }
+
             remove_ids(&cache.0.guild_channels, &cache.0.channels_guild, id);
         }
 
@@ -239,6 +256,13 @@ impl UpdateCache for GuildDelete {
                 }
             }
         }
+
+        if cache.wants(ResourceType::MEMBER_LIST) {
+            cache
+                .0
+                .member_lists
+                .retain(|(guild_id, _), _| *guild_id != id);
+        }
     }
 }
 
@@ -296,8 +320,16 @@ impl UpdateCache for GuildUpdate {
 impl UpdateCache for MessageAck {
     fn update(&self, cache: &InMemoryCache) {
         let mut read_state = cache.0.read_state.entry(self.channel_id).or_default();
-        // TODO: Should we update `mention_count` here?
+        read_state.id = self.channel_id;
+
+        // Acks of older messages, such as ones arriving out of order, don't
+        // mark newer messages as read.
+        if self.message_id <= read_state.last_message_id {
+            return;
+        }
+
         read_state.last_message_id = self.message_id;
+        read_state.mention_count = Some(0);
     }
 }
 
@@ -343,7 +375,7 @@ fn cache_member_list_update_member(
 
             cached_member.roles = member.roles.clone();
             cached_member.mute = member.mute;
-            cached_member.joined_at = Some(member.joined_at.clone());
+            cached_member.joined_at = Some(member.joined_at);
             cached_member.deaf = member.deaf;
             cache.cache_presence(Some(guild_id), member.presence.clone());
         }
@@ -371,7 +403,7 @@ fn cache_member_list_update_member(
             k.insert(Arc::new(CachedMember {
                 deaf: member.deaf,
                 guild_id,
-                joined_at: Some(member.joined_at.clone()),
+                joined_at: Some(member.joined_at),
                 mute: member.mute,
                 nick: None,
                 pending: false,
@@ -392,6 +424,15 @@ fn cache_member_list_update_member(
 
 impl UpdateCache for MemberListUpdate {
     fn update(&self, cache: &InMemoryCache) {
+        if cache.wants(ResourceType::MEMBER_LIST) {
+            cache
+                .0
+                .member_lists
+                .entry((self.guild_id, self.id))
+                .or_default()
+                .update(self);
+        }
+
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
@@ -482,13 +523,16 @@ impl UpdateCache for MemberUpdate {
         member.mute = self.mute.unwrap_or(member.mute);
         member.nick = self.nick.clone();
         member.roles = self.roles.clone();
-        member.joined_at.replace(self.joined_at.clone());
+        member.joined_at.replace(self.joined_at);
         member.pending = self.pending;
     }
 }
 
 impl UpdateCache for MessageCreate {
     fn update(&self, cache: &InMemoryCache) {
+        cache.set_last_message_id(self.channel_id, self.id);
+        cache.track_unread(&self.0);
+
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
@@ -559,7 +603,7 @@ impl UpdateCache for MessageUpdate {
             }
 
             if let Some(edited_timestamp) = &self.edited_timestamp {
-                msg.edited_timestamp.replace(edited_timestamp.clone());
+                msg.edited_timestamp.replace(*edited_timestamp);
             }
 
             if let Some(embeds) = &self.embeds {
@@ -583,7 +627,7 @@ impl UpdateCache for MessageUpdate {
             }
 
             if let Some(timestamp) = &self.timestamp {
-                msg.timestamp = timestamp.clone();
+                msg.timestamp = *timestamp;
             }
 
             if let Some(tts) = self.tts {
@@ -603,7 +647,7 @@ impl UpdateCache for PresenceUpdate {
             activities: self.activities.clone(),
             client_status: self.client_status.clone(),
             guild_id: self.guild_id,
-            status: self.status,
+            status: self.status.clone(),
             user: self.user.clone(),
         };
 
@@ -788,6 +832,35 @@ impl UpdateCache for Ready {
             system: None,
             verified: self.user.verified,
         });
+        if cache.wants(ResourceType::USER_GUILD_SETTINGS) {
+            for settings in &self.user_guild_settings.entries {
+                cache.cache_user_guild_settings(settings.clone());
+            }
+        }
+
+        if cache.wants(ResourceType::RELATIONSHIP) {
+            for relationship in &self.relationships {
+                let user = match self.users.iter().find(|u| u.id == relationship.user_id) {
+                    Some(user) => user.clone(),
+                    None => {
+                        tracing::warn!(
+                            "ready is missing the user of relationship {}",
+                            relationship.id
+                        );
+
+                        continue;
+                    }
+                };
+
+                cache.cache_relationship(Relationship {
+                    id: relationship.id,
+                    kind: relationship.kind,
+                    nickname: relationship.nickname.clone(),
+                    user,
+                });
+            }
+        }
+
         for (idx, guild_members) in self.merged_members.iter().enumerate() {
             for guild_member in guild_members {
                 let guild_id = match &self.guilds[idx] {
@@ -804,6 +877,26 @@ impl UpdateCache for Ready {
     }
 }
 
+impl UpdateCache for RelationshipAdd {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::RELATIONSHIP) {
+            return;
+        }
+
+        cache.cache_relationship(self.0.clone());
+    }
+}
+
+impl UpdateCache for RelationshipRemove {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::RELATIONSHIP) {
+            return;
+        }
+
+        cache.0.relationships.remove(&self.id);
+    }
+}
+
 impl UpdateCache for RoleCreate {
     fn update(&self, cache: &InMemoryCache) {
         if !cache.wants(ResourceType::ROLE) {
@@ -843,6 +936,161 @@ impl UpdateCache for RoleUpdate {
     }
 }
 
+impl UpdateCache for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Channel::Guild(c) = &self.0 {
+            if let Some(gid) = c.guild_id() {
+                cache.cache_guild_channel(gid, c.clone());
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        cache.delete_guild_channel(self.id);
+    }
+}
+
+impl UpdateCache for ThreadListSync {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        // Threads of the synced channels that aren't listed are no longer
+        // active.
+        let parent_ids = if self.channel_ids.is_empty() {
+            cache
+                .0
+                .channel_threads
+                .iter()
+                .map(|item| *item.key())
+                .filter(|parent_id| {
+                    cache
+                        .0
+                        .channels_guild
+                        .get(parent_id)
+                        .map_or(false, |item| item.guild_id == self.guild_id)
+                })
+                .collect()
+        } else {
+            self.channel_ids.clone()
+        };
+
+        let stale_ids = parent_ids
+            .iter()
+            .filter_map(|parent_id| cache.0.channel_threads.get(parent_id))
+            .flat_map(|threads| threads.value().iter().copied().collect::<Vec<_>>())
+            .filter(|thread_id| !self.threads.iter().any(|thread| thread.id() == *thread_id))
+            .collect::<Vec<ChannelId>>();
+
+        for thread_id in stale_ids {
+            cache.delete_guild_channel(thread_id);
+        }
+
+        for thread in &self.threads {
+            if let Channel::Guild(GuildChannel::Thread(thread)) = thread {
+                let mut thread = thread.clone();
+
+                if let Some(member) = self
+                    .members
+                    .iter()
+                    .find(|member| member.id == Some(thread.id))
+                {
+                    thread.member.replace(member.clone());
+                }
+
+                cache.cache_guild_channel(self.guild_id, GuildChannel::Thread(thread));
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        let thread_id = match self.id {
+            Some(thread_id) => thread_id,
+            None => return,
+        };
+
+        if let Some(mut item) = cache.0.channels_guild.get_mut(&thread_id) {
+            if let GuildChannel::Thread(thread) = Arc::make_mut(&mut item.data) {
+                thread.member.replace(self.0.clone());
+            }
+        }
+
+        if !cache.wants(ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        if let Some(user_id) = self.user_id {
+            cache
+                .0
+                .thread_members
+                .entry(thread_id)
+                .or_default()
+                .insert(user_id);
+        }
+    }
+}
+
+impl UpdateCache for ThreadMembersUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if cache.wants(ResourceType::CHANNEL) {
+            if let Some(mut item) = cache.0.channels_guild.get_mut(&self.id) {
+                if let GuildChannel::Thread(thread) = Arc::make_mut(&mut item.data) {
+                    thread.member_count = self.member_count;
+                }
+            }
+        }
+
+        if !cache.wants(ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        let mut members = cache.0.thread_members.entry(self.id).or_default();
+
+        for user_id in self
+            .added_members
+            .iter()
+            .filter_map(|member| member.user_id)
+        {
+            members.insert(user_id);
+        }
+
+        for user_id in &self.removed_member_ids {
+            members.remove(user_id);
+        }
+    }
+}
+
+impl UpdateCache for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Channel::Guild(c) = &self.0 {
+            if let Some(gid) = c.guild_id() {
+                cache.cache_guild_channel(gid, c.clone());
+            }
+        }
+    }
+}
+
 impl UpdateCache for TypingStart {}
 
 impl UpdateCache for UnavailableGuild {
@@ -856,6 +1104,16 @@ impl UpdateCache for UnavailableGuild {
     }
 }
 
+impl UpdateCache for UserGuildSettingsUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::USER_GUILD_SETTINGS) {
+            return;
+        }
+
+        cache.cache_user_guild_settings(self.0.clone());
+    }
+}
+
 impl UpdateCache for UserUpdate {
     fn update(&self, cache: &InMemoryCache) {
         if !cache.wants(ResourceType::USER_CURRENT) {
@@ -889,19 +1147,21 @@ impl UpdateCache for WebhooksUpdate {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::ResourceType;
+    use crate::{config::ResourceType, model::CachedMemberListItem};
     use twilight_model::{
         channel::{
             message::{MessageFlags, MessageType},
-            ChannelType, GuildChannel, Message, Reaction, TextChannel,
+            thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+            ChannelType, GuildChannel, Message, Reaction, TextChannel, ThreadChannel,
         },
+        datetime::Timestamp,
         gateway::payload::{reaction_remove_emoji::PartialEmoji, ChannelDelete},
         guild::{
             DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, Member, MfaLevel,
             PartialGuild, PartialMember, PremiumTier, SystemChannelFlags, VerificationLevel,
         },
         id::{ChannelId, GuildId, MessageId, UserId},
-        user::User,
+        user::{MessageNotificationLevel, RelationshipType, User, UserGuildSettings},
         voice::VoiceState,
     };
 
@@ -926,6 +1186,41 @@ mod tests {
         (guild_id, channel_id, channel)
     }
 
+    fn guild_thread(id: u64, parent_id: ChannelId) -> GuildChannel {
+        GuildChannel::Thread(ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(id),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(3)),
+            parent_id: Some(parent_id),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                archive_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                    .expect("valid timestamp"),
+                invitable: None,
+                locked: false,
+            },
+        })
+    }
+
+    fn thread_member(thread_id: u64, user_id: u64) -> ThreadMember {
+        ThreadMember {
+            flags: 0,
+            id: Some(ChannelId(thread_id)),
+            join_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                .expect("valid timestamp"),
+            user_id: Some(UserId(user_id)),
+        }
+    }
+
     fn cache_with_message_and_reactions() -> InMemoryCache {
         let cache = InMemoryCache::new();
 
@@ -974,7 +1269,7 @@ mod tests {
             reference: None,
             stickers: Vec::new(),
             referenced_message: None,
-            timestamp: String::new(),
+            timestamp: Timestamp::from_secs(1_632_072_645).expect("valid timestamp"),
             tts: false,
             webhook_id: None,
         };
@@ -1100,6 +1395,7 @@ mod tests {
             splash: None,
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: None,
             verification_level: VerificationLevel::VeryHigh,
@@ -1322,7 +1618,7 @@ mod tests {
             reference: None,
             stickers: Vec::new(),
             referenced_message: None,
-            timestamp: String::new(),
+            timestamp: Timestamp::from_secs(1_632_072_645).expect("valid timestamp"),
             tts: false,
             webhook_id: None,
         };
@@ -1442,4 +1738,249 @@ mod tests {
 
         assert_eq!(msg.reactions.len(), 0);
     }
+
+    #[test]
+    fn test_member_list_update() {
+        use crate::model::member_list::tests::member;
+
+        let online = MemberListGroup {
+            id: GroupId::Online,
+            count: 2,
+        };
+
+        let cache = InMemoryCache::new();
+        cache.update(&MemberListUpdate {
+            ops: vec![MemberListUpdateOp::Sync {
+                range: vec![0, 99],
+                items: vec![MemberListItem::Group(online.clone()), member(2), member(3)],
+            }],
+            online_count: 2,
+            member_count: 2,
+            id: MemberListId::Everyone,
+            guild_id: GuildId(1),
+            groups: vec![online.clone()],
+        });
+        cache.update(&MemberListUpdate {
+            ops: vec![
+                MemberListUpdateOp::Delete { index: 1 },
+                MemberListUpdateOp::Insert {
+                    index: 2,
+                    item: member(4),
+                },
+            ],
+            online_count: 2,
+            member_count: 2,
+            id: MemberListId::Everyone,
+            guild_id: GuildId(1),
+            groups: vec![online.clone()],
+        });
+
+        assert_eq!(
+            Some(vec![
+                CachedMemberListItem::Group(online.clone()),
+                CachedMemberListItem::Member(UserId(3)),
+                CachedMemberListItem::Member(UserId(4)),
+            ]),
+            cache.member_list(GuildId(1), MemberListId::Everyone, 0..100)
+        );
+        assert_eq!(
+            Some(vec![online]),
+            cache.member_list_groups(GuildId(1), MemberListId::Everyone)
+        );
+        assert!(cache.member(GuildId(1), UserId(4)).is_some());
+        assert!(cache
+            .member_list(GuildId(2), MemberListId::Everyone, 0..100)
+            .is_none());
+    }
+
+    #[test]
+    fn test_relationships() {
+        fn user(id: u64) -> User {
+            User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(id),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            }
+        }
+
+        let cache = InMemoryCache::new();
+        cache.update(&RelationshipAdd(Relationship {
+            id: UserId(1),
+            kind: RelationshipType::PendingIncoming,
+            nickname: None,
+            user: user(1),
+        }));
+        cache.update(&RelationshipAdd(Relationship {
+            id: UserId(2),
+            kind: RelationshipType::Blocked,
+            nickname: None,
+            user: user(2),
+        }));
+        assert_eq!(
+            Some(RelationshipType::PendingIncoming),
+            cache.relationship(UserId(1)).map(|r| r.kind)
+        );
+
+        cache.update(&RelationshipAdd(Relationship {
+            id: UserId(1),
+            kind: RelationshipType::Friend,
+            nickname: Some("friend".to_owned()),
+            user: user(1),
+        }));
+        assert_eq!(
+            Some(RelationshipType::Friend),
+            cache.relationship(UserId(1)).map(|r| r.kind)
+        );
+
+        cache.update(&RelationshipRemove {
+            id: UserId(2),
+            kind: RelationshipType::Blocked,
+        });
+        assert!(cache.relationship(UserId(2)).is_none());
+        assert_eq!(Some(1), cache.relationships().map(|r| r.len()));
+
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::empty())
+            .build();
+        assert!(cache.relationships().is_none());
+    }
+
+    #[test]
+    fn test_user_guild_settings_update() {
+        let settings = UserGuildSettings {
+            channel_overrides: Vec::new(),
+            guild_id: Some(GuildId(1)),
+            hide_muted_channels: false,
+            message_notifications: MessageNotificationLevel::OnlyMentions,
+            mobile_push: true,
+            mute_config: None,
+            muted: false,
+            suppress_everyone: false,
+            suppress_roles: false,
+            version: 1,
+        };
+
+        let cache = InMemoryCache::new();
+        cache.update(&UserGuildSettingsUpdate(settings.clone()));
+        assert_eq!(
+            Some(false),
+            cache.user_guild_settings(Some(GuildId(1))).map(|s| s.muted)
+        );
+
+        cache.update(&UserGuildSettingsUpdate(UserGuildSettings {
+            muted: true,
+            version: 2,
+            ..settings
+        }));
+        assert_eq!(
+            Some(true),
+            cache.user_guild_settings(Some(GuildId(1))).map(|s| s.muted)
+        );
+        assert!(cache.user_guild_settings(None).is_none());
+    }
+
+    #[test]
+    fn test_threads() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = guild_channel_text();
+        cache.update(&ChannelCreate(Channel::Guild(channel.clone())));
+
+        cache.update(&ThreadCreate(Channel::Guild(guild_thread(10, channel_id))));
+        cache.update(&ThreadCreate(Channel::Guild(guild_thread(11, channel_id))));
+        assert_eq!(3, cache.guild_channels(guild_id).unwrap().len());
+        assert_eq!(vec![ChannelId(10), ChannelId(11)], {
+            let mut ids = cache
+                .channel_threads(channel_id)
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        });
+
+        // Updates don't contain the current user's member, which is kept.
+        cache.update(&ThreadMemberUpdate(thread_member(10, 3)));
+        cache.update(&ThreadUpdate(Channel::Guild(guild_thread(10, channel_id))));
+        assert!(matches!(
+            cache.guild_channel(ChannelId(10)).as_deref(),
+            Some(GuildChannel::Thread(ThreadChannel {
+                member: Some(_),
+                ..
+            }))
+        ));
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: vec![thread_member(10, 4), thread_member(10, 5)],
+            guild_id,
+            id: ChannelId(10),
+            member_count: 3,
+            removed_member_ids: vec![UserId(5)],
+        });
+        let members = cache.thread_members(ChannelId(10)).unwrap();
+        assert!(members.contains(&UserId(3)) && members.contains(&UserId(4)));
+        assert!(!members.contains(&UserId(5)));
+        assert_eq!(Some(2), cache.stats().thread_members(ChannelId(10)));
+        assert!(matches!(
+            cache.guild_channel(ChannelId(10)).as_deref(),
+            Some(GuildChannel::Thread(ThreadChannel {
+                member_count: 3,
+                ..
+            }))
+        ));
+
+        cache.update(&ThreadDelete {
+            guild_id,
+            id: ChannelId(11),
+            kind: ChannelType::GuildPublicThread,
+            parent_id: channel_id,
+        });
+        assert!(cache.guild_channel(ChannelId(11)).is_none());
+        assert_eq!(Some(1), cache.stats().channel_threads(channel_id));
+
+        // Deleting the parent channel deletes its threads.
+        cache.update(&ChannelDelete(Channel::Guild(channel)));
+        assert!(cache.guild_channel(ChannelId(10)).is_none());
+        assert!(cache.channel_threads(channel_id).is_none());
+        assert!(cache.thread_members(ChannelId(10)).is_none());
+        assert!(cache.guild_channels(guild_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_thread_list_sync() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, channel) = guild_channel_text();
+        cache.update(&ChannelCreate(Channel::Guild(channel)));
+        cache.update(&ThreadCreate(Channel::Guild(guild_thread(10, channel_id))));
+
+        cache.update(&ThreadListSync {
+            channel_ids: Vec::new(),
+            guild_id,
+            members: vec![thread_member(11, 3)],
+            threads: vec![Channel::Guild(guild_thread(11, channel_id))],
+        });
+
+        // The thread that wasn't listed is no longer active.
+        assert!(cache.guild_channel(ChannelId(10)).is_none());
+        assert!(matches!(
+            cache.guild_channel(ChannelId(11)).as_deref(),
+            Some(GuildChannel::Thread(ThreadChannel {
+                member: Some(_),
+                ..
+            }))
+        ));
+        assert_eq!(
+            Some(vec![ChannelId(11)].into_iter().collect()),
+            cache.channel_threads(channel_id)
+        );
+    }
 }
@@ -3,11 +3,19 @@ use super::{
     r#impl::{Cluster, ClusterStartError},
     scheme::ShardScheme,
 };
-use crate::shard::{LargeThresholdError, ResumeSession, ShardBuilder};
+use crate::shard::{
+    Compression, Encoding, LargeThresholdError, ResumeSession, SessionStore, ShardBuilder,
+};
 use std::{collections::HashMap, sync::Arc};
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client;
-use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents};
+use twilight_model::gateway::{
+    payload::{
+        identify::{IdentifyClientState, IdentifyProperties},
+        update_status::UpdateStatusInfo,
+    },
+    Intents,
+};
 
 /// Builder to configure and construct a [`Cluster`].
 ///
@@ -87,6 +95,47 @@ impl ClusterBuilder {
         Cluster::new_with_config(self.0).await
     }
 
+    /// Set the capabilities bitfield to send when identifying.
+    ///
+    /// Refer to the shard's [`ShardBuilder::capabilities`] for more
+    /// information.
+    pub fn capabilities(mut self, capabilities: u32) -> Self {
+        self.1 = self.1.capabilities(capabilities);
+
+        self
+    }
+
+    /// Set the client state to send when identifying.
+    ///
+    /// Refer to the shard's [`ShardBuilder::client_state`] for more
+    /// information.
+    pub fn client_state(mut self, client_state: IdentifyClientState) -> Self {
+        self.1 = self.1.client_state(client_state);
+
+        self
+    }
+
+    /// Set the transport compression of the payloads received from the
+    /// gateway.
+    ///
+    /// Refer to the shard's [`ShardBuilder::compression`] for more
+    /// information.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.1 = self.1.compression(compression);
+
+        self
+    }
+
+    /// Set the encoding of the payloads sent to and received from the
+    /// gateway.
+    ///
+    /// Refer to the shard's [`ShardBuilder::encoding`] for more information.
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.1 = self.1.encoding(encoding);
+
+        self
+    }
+
     /// Set the URL that will be used to connect to the gateway.
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.1 = self.1.gateway_url(gateway_url);
@@ -107,6 +156,27 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set whether to request zlib compression of individual payloads when
+    /// identifying.
+    ///
+    /// Refer to the shard's [`ShardBuilder::identify_compress`] for more
+    /// information.
+    pub fn identify_compress(mut self, identify_compress: bool) -> Self {
+        self.1 = self.1.identify_compress(identify_compress);
+
+        self
+    }
+
+    /// Set the client properties to send when identifying.
+    ///
+    /// Refer to the shard's [`ShardBuilder::identify_properties`] for more
+    /// information.
+    pub fn identify_properties(mut self, identify_properties: IdentifyProperties) -> Self {
+        self.1 = self.1.identify_properties(identify_properties);
+
+        self
+    }
+
     /// Set the "large threshold" of shards.
     ///
     /// Refer to the shard's [`ShardBuilder::large_threshold`] for more
@@ -201,6 +271,21 @@ impl ClusterBuilder {
         self.0.resume_sessions = resume_sessions;
         self
     }
+
+    /// Set the store to load each shard's session from when starting.
+    ///
+    /// Sessions are saved to it when bringing the cluster down via
+    /// [`Cluster::down_and_save`]. Sessions set via [`resume_sessions`] take
+    /// precedence over stored ones.
+    ///
+    /// Refer to [`ShardBuilder::session_store`] for the default value.
+    ///
+    /// [`resume_sessions`]: Self::resume_sessions
+    pub fn session_store(mut self, session_store: Arc<Box<dyn SessionStore>>) -> Self {
+        self.1 = self.1.session_store(session_store);
+
+        self
+    }
 }
 
 impl<T: Into<String>> From<(T, Intents)> for ClusterBuilder {
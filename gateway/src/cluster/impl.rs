@@ -1,6 +1,8 @@
 use super::{builder::ClusterBuilder, config::Config, scheme::ShardScheme};
 use crate::{
-    shard::{raw_message::Message, Information, ResumeSession, Shard},
+    shard::{
+        raw_message::Message, Information, ResumeSession, SaveSessionError, Shard, StoredSession,
+    },
     EventTypeFlags, Intents,
 };
 use futures_util::{
@@ -15,7 +17,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 use twilight_http::Client as HttpClient;
-use twilight_model::gateway::event::Event;
+use twilight_model::gateway::{event::Event, payload::GuildSubscribe};
 
 /// Sending a command to a shard failed.
 #[derive(Debug)]
@@ -222,6 +224,7 @@ struct ClusterRef {
     config: Config,
     shard_from: u64,
     shard_to: u64,
+    shard_total: u64,
     shards: Mutex<HashMap<u64, Shard>>,
 }
 
@@ -292,6 +295,7 @@ impl Cluster {
             config,
             shard_from: scheme.from().expect("shard scheme is not auto"),
             shard_to: scheme.to().expect("shard scheme is not auto"),
+            shard_total: total,
             shards: Mutex::new(shards),
         })))
     }
@@ -387,6 +391,41 @@ impl Cluster {
             .collect()
     }
 
+    /// Bring down the cluster in a resumable way and save each shard's session
+    /// to the configured [`SessionStore`].
+    ///
+    /// The returned map is keyed by the shard's ID to the saved session. If a
+    /// shard had no session to save, then it is not included in the map.
+    ///
+    /// All shards are brought down, even if saving the session of one fails.
+    ///
+    /// [`SessionStore`]: crate::shard::SessionStore
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`Shard::shutdown_and_save`] that occurred.
+    pub async fn down_and_save(&self) -> Result<HashMap<u64, StoredSession>, SaveSessionError> {
+        let mut error = None;
+        let mut sessions = HashMap::new();
+
+        for shard in self.shards() {
+            match shard.shutdown_and_save().await {
+                Ok(Some(session)) => {
+                    sessions.insert(session.shard[0], session);
+                }
+                Ok(None) => {}
+                Err(source) => {
+                    error.get_or_insert(source);
+                }
+            }
+        }
+
+        match error {
+            Some(source) => Err(source),
+            None => Ok(sessions),
+        }
+    }
+
     /// Return a Shard by its ID.
     pub fn shard(&self, id: u64) -> Option<Shard> {
         self.0
@@ -473,6 +512,38 @@ impl Cluster {
             })
     }
 
+    /// Subscribe to a guild's typing, activities, threads, and member list
+    /// ranges on the shard that the guild belongs to.
+    ///
+    /// Refer to [`Shard::subscribe_guild`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClusterCommandErrorType::Sending`] error type if the shard
+    /// exists, but sending it failed.
+    ///
+    /// Returns a [`ClusterCommandErrorType::ShardNonexistent`] error type if
+    /// the shard that the guild belongs to does not exist in the cluster.
+    pub async fn subscribe_guild(
+        &self,
+        subscribe: GuildSubscribe,
+    ) -> Result<(), ClusterCommandError> {
+        let id = (subscribe.d.guild_id.0 >> 22) % self.0.shard_total;
+
+        let shard = self.shard(id).ok_or(ClusterCommandError {
+            kind: ClusterCommandErrorType::ShardNonexistent { id },
+            source: None,
+        })?;
+
+        shard
+            .subscribe_guild(subscribe)
+            .await
+            .map_err(|source| ClusterCommandError {
+                kind: ClusterCommandErrorType::Sending,
+                source: Some(Box::new(source)),
+            })
+    }
+
     /// Send a raw command to the specified shard.
     ///
     /// # Errors
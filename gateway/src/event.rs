@@ -89,6 +89,10 @@ bitflags! {
         const REACTION_REMOVE_EMOJI = 1 << 48;
         /// Session is initialized.
         const READY = 1 << 28;
+        /// Relationship with another user has been added or changed.
+        const RELATIONSHIP_ADD = 1 << 52;
+        /// Relationship with another user has been removed.
+        const RELATIONSHIP_REMOVE = 1 << 53;
         /// Session is resumed.
         const RESUMED = 1 << 29;
         /// Role has been created in a guild.
@@ -111,10 +115,30 @@ bitflags! {
         const SHARD_RECONNECTING = 1 << 37;
         /// Shard is resuming a session with the gateway.
         const SHARD_RESUMING = 1 << 38;
+        /// Thread has been created, or the current user has been added to a
+        /// private thread.
+        const THREAD_CREATE = 1 << 56;
+        /// Thread has been deleted.
+        const THREAD_DELETE = 1 << 57;
+        /// Active threads of a guild or some of its channels have been synced.
+        const THREAD_LIST_SYNC = 1 << 58;
+        /// Thread member of the current user has been updated.
+        const THREAD_MEMBER_UPDATE = 1 << 59;
+        /// Users have been added to or removed from a thread.
+        const THREAD_MEMBERS_UPDATE = 1 << 60;
+        /// Thread has been updated.
+        const THREAD_UPDATE = 1 << 61;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
         const UNAVAILABLE_GUILD = 1 << 40;
+        /// Dispatch event unknown to the library has been received.
+        ///
+        /// This isn't enabled by default and must be opted in to.
+        const UNKNOWN = 1 << 55;
+        /// Current user's notification settings for a guild have been
+        /// updated.
+        const USER_GUILD_SETTINGS_UPDATE = 1 << 54;
         /// Current user's profile has been updated.
         const USER_UPDATE = 1 << 41;
         /// Voice server has provided an update with voice session details.
@@ -165,6 +189,8 @@ impl From<EventType> for EventTypeFlags {
             EventType::ReactionRemoveAll => EventTypeFlags::REACTION_REMOVE_ALL,
             EventType::ReactionRemoveEmoji => EventTypeFlags::REACTION_REMOVE_EMOJI,
             EventType::Ready => EventTypeFlags::READY,
+            EventType::RelationshipAdd => EventTypeFlags::RELATIONSHIP_ADD,
+            EventType::RelationshipRemove => EventTypeFlags::RELATIONSHIP_REMOVE,
             EventType::Resumed => EventTypeFlags::RESUMED,
             EventType::RoleCreate => EventTypeFlags::ROLE_CREATE,
             EventType::RoleDelete => EventTypeFlags::ROLE_DELETE,
@@ -176,12 +202,20 @@ impl From<EventType> for EventTypeFlags {
             EventType::ShardReconnecting => EventTypeFlags::SHARD_RECONNECTING,
             EventType::ShardPayload => EventTypeFlags::SHARD_PAYLOAD,
             EventType::ShardResuming => EventTypeFlags::SHARD_RESUMING,
+            EventType::ThreadCreate => EventTypeFlags::THREAD_CREATE,
+            EventType::ThreadDelete => EventTypeFlags::THREAD_DELETE,
+            EventType::ThreadListSync => EventTypeFlags::THREAD_LIST_SYNC,
+            EventType::ThreadMemberUpdate => EventTypeFlags::THREAD_MEMBER_UPDATE,
+            EventType::ThreadMembersUpdate => EventTypeFlags::THREAD_MEMBERS_UPDATE,
+            EventType::ThreadUpdate => EventTypeFlags::THREAD_UPDATE,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
+            EventType::UserGuildSettingsUpdate => EventTypeFlags::USER_GUILD_SETTINGS_UPDATE,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
             EventType::VoiceServerUpdate => EventTypeFlags::VOICE_SERVER_UPDATE,
             EventType::VoiceStateUpdate => EventTypeFlags::VOICE_STATE_UPDATE,
             EventType::WebhooksUpdate => EventTypeFlags::WEBHOOKS_UPDATE,
+            EventType::Unknown(_) => EventTypeFlags::UNKNOWN,
         }
     }
 }
@@ -196,11 +230,8 @@ impl<'a> TryFrom<(u8, Option<&'a str>)> for EventTypeFlags {
             (9, _) => Ok(EventTypeFlags::GATEWAY_INVALIDATE_SESSION),
             (10, _) => Ok(EventTypeFlags::GATEWAY_HELLO),
             (11, _) => Ok(EventTypeFlags::GATEWAY_HEARTBEAT_ACK),
-            (_, Some(event_type)) => {
-                let flag = EventType::try_from(event_type).map_err(|kind| (op, Some(kind)))?;
-
-                Ok(Self::from(flag))
-            }
+            (_, Some(event_type)) => Ok(EventType::try_from(event_type)
+                .map_or(EventTypeFlags::UNKNOWN, EventTypeFlags::from)),
             (_, None) => Err((op, event_type)),
         }
     }
@@ -209,7 +240,7 @@ impl<'a> TryFrom<(u8, Option<&'a str>)> for EventTypeFlags {
 impl Default for EventTypeFlags {
     fn default() -> Self {
         let mut flags = Self::all();
-        flags.remove(Self::SHARD_PAYLOAD);
+        flags.remove(Self::SHARD_PAYLOAD | Self::UNKNOWN);
 
         flags
     }
@@ -233,4 +264,38 @@ mod tests {
         Sync,
         TryFrom<(u8, Option<&'static str>)>
     );
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(
+            EventTypeFlags::UNKNOWN,
+            EventTypeFlags::from(EventType::Unknown("SESSIONS_REPLACE".to_owned()))
+        );
+        assert_eq!(
+            Ok(EventTypeFlags::UNKNOWN),
+            EventTypeFlags::try_from((0, Some("SESSIONS_REPLACE")))
+        );
+        assert_eq!(
+            Ok(EventTypeFlags::MESSAGE_ACK),
+            EventTypeFlags::try_from((0, Some("MESSAGE_ACK")))
+        );
+        assert_eq!(Err((0, None)), EventTypeFlags::try_from((0, None)));
+        assert!(!EventTypeFlags::default().contains(EventTypeFlags::UNKNOWN));
+    }
+
+    #[test]
+    fn test_thread_events() {
+        assert_eq!(
+            Ok(EventTypeFlags::THREAD_LIST_SYNC),
+            EventTypeFlags::try_from((0, Some("THREAD_LIST_SYNC")))
+        );
+        assert!(EventTypeFlags::default().contains(
+            EventTypeFlags::THREAD_CREATE
+                | EventTypeFlags::THREAD_DELETE
+                | EventTypeFlags::THREAD_LIST_SYNC
+                | EventTypeFlags::THREAD_MEMBER_UPDATE
+                | EventTypeFlags::THREAD_MEMBERS_UPDATE
+                | EventTypeFlags::THREAD_UPDATE
+        ));
+    }
 }
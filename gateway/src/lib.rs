@@ -44,6 +44,13 @@
 //! twilight-gateway = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! #### `etf`
+//!
+//! The `etf` feature enables connecting to the gateway with Erlang's external
+//! term format instead of JSON, which is smaller and faster to decode for large
+//! guild payloads. It is selected per shard with `ShardBuilder::encoding`, and is
+//! not enabled by default.
+//!
 //! ### TLS
 //!
 //! `twilight-gateway` has features to enable [`async-tungstenite`] and
@@ -84,12 +91,20 @@
 //! Enabling **only** `simd-zlib` will make the library use [`zlib-ng`] which is a modern
 //! fork of zlib that is faster and more effective, but it needs `cmake` to compile.
 //!
+//! ### zstd
+//!
+//! The `zstd` feature enables `zstd-stream` transport compression.
+//!
+//! The transport compression of each shard is chosen at runtime with
+//! `ShardBuilder::compression`, so a shard can fall back to another mode, or to
+//! no compression, when one misbehaves.
+//!
 //! ### Metrics
 //!
 //! The `metrics` feature provides metrics information via the `metrics` crate.
 //! Some of the metrics logged are counters about received event counts and
-//! their types and gauges about the capacity and efficiency of the inflater of
-//! each shard.
+//! their types, counters about events dropped from full bounded event streams,
+//! and gauges about the capacity and efficiency of the inflater of each shard.
 //!
 //! This is disabled by default.
 //!
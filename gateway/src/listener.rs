@@ -1,15 +1,23 @@
-use crate::EventTypeFlags;
+use crate::{shard::OverflowPolicy, EventTypeFlags};
 use dashmap::DashMap;
-use std::sync::{
-    atomic::{AtomicU64, Ordering},
-    Arc,
+use futures_util::task::AtomicWaker;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver, UnboundedSender},
+    Notify,
 };
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 #[derive(Debug)]
 pub struct Listener<T> {
     pub events: EventTypeFlags,
-    pub tx: UnboundedSender<T>,
+    pub tx: ListenerSender<T>,
 }
 
 impl<T> Listener<T> {
@@ -19,6 +27,180 @@ impl<T> Listener<T> {
     }
 }
 
+/// Sending half of a listener's channel.
+#[derive(Debug)]
+pub enum ListenerSender<T> {
+    Bounded(BoundedSender<T>),
+    Unbounded(UnboundedSender<T>),
+}
+
+impl<T> ListenerSender<T> {
+    /// Return whether the receiving half of the channel was dropped.
+    pub fn is_closed(&self) -> bool {
+        match self {
+            Self::Bounded(tx) => tx.0.receiver_closed.load(Ordering::Acquire),
+            Self::Unbounded(tx) => tx.is_closed(),
+        }
+    }
+
+    /// Send an item to the listener, returning whether the listener should be
+    /// kept.
+    pub fn send(&self, item: T) -> bool {
+        match self {
+            Self::Bounded(tx) => tx.0.push(item),
+            Self::Unbounded(tx) => tx.send(item).is_ok(),
+        }
+    }
+
+    /// Return the channel if it's full and its overflow policy is to block
+    /// the sender until there's room.
+    fn blocking(&self) -> Option<Arc<BoundedChannel<T>>> {
+        match self {
+            Self::Bounded(tx) if tx.0.policy == OverflowPolicy::Block && tx.0.is_full() => {
+                Some(Arc::clone(&tx.0))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Channel with a capacity and a policy for what to do with items once it's
+/// full.
+///
+/// Unlike Tokio's bounded channel, sending is synchronous and the oldest items
+/// can be dropped to make room for new ones.
+#[derive(Debug)]
+pub struct BoundedChannel<T> {
+    capacity: usize,
+    dropped: AtomicU64,
+    policy: OverflowPolicy,
+    queue: Mutex<VecDeque<T>>,
+    receiver: AtomicWaker,
+    receiver_closed: AtomicBool,
+    sender_closed: AtomicBool,
+    writable: Notify,
+}
+
+impl<T> BoundedChannel<T> {
+    fn is_full(&self) -> bool {
+        self.queue.lock().expect("queue poisoned").len() >= self.capacity
+    }
+
+    /// Push an item to the queue according to the overflow policy, returning
+    /// whether the sender should be kept.
+    fn push(&self, item: T) -> bool {
+        if self.receiver_closed.load(Ordering::Acquire) {
+            return false;
+        }
+
+        let mut queue = self.queue.lock().expect("queue poisoned");
+
+        if queue.len() >= self.capacity {
+            match self.policy {
+                // The processor waits for room before reading the next
+                // payload, so the queue only briefly grows past its capacity
+                // when a payload results in multiple events.
+                OverflowPolicy::Block => {}
+                OverflowPolicy::DropNewest => {
+                    self.record_dropped();
+
+                    return true;
+                }
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                    self.record_dropped();
+                }
+                OverflowPolicy::Disconnect => {
+                    self.record_dropped();
+
+                    return false;
+                }
+            }
+        }
+
+        queue.push_back(item);
+        drop(queue);
+        self.receiver.wake();
+
+        true
+    }
+
+    fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+
+        #[cfg(feature = "metrics")]
+        metrics::counter!("GatewayEventsDropped", 1, "OverflowPolicy" => self.policy.name());
+    }
+
+    /// Wait until the queue has room or the receiver was dropped.
+    pub async fn writable(&self) {
+        // There's only ever one task waiting, the shard processor, so a
+        // notification sent before it starts waiting is stored as a permit.
+        while self.is_full() && !self.receiver_closed.load(Ordering::Acquire) {
+            self.writable.notified().await;
+        }
+    }
+}
+
+/// Sending half of a [`BoundedChannel`], closing the channel when dropped.
+#[derive(Debug)]
+pub struct BoundedSender<T>(Arc<BoundedChannel<T>>);
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        self.0.sender_closed.store(true, Ordering::Release);
+        self.0.receiver.wake();
+    }
+}
+
+/// Receiving half of a [`BoundedChannel`], closing the channel when dropped.
+#[derive(Debug)]
+pub struct BoundedReceiver<T>(Arc<BoundedChannel<T>>);
+
+impl<T> BoundedReceiver<T> {
+    /// Number of items that were dropped because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.0.dropped.load(Ordering::Relaxed)
+    }
+
+    pub fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        if let Some(item) = self.pop() {
+            return Poll::Ready(Some(item));
+        }
+
+        self.0.receiver.register(cx.waker());
+
+        // Check again in case an item was pushed before the waker was
+        // registered.
+        if let Some(item) = self.pop() {
+            return Poll::Ready(Some(item));
+        }
+
+        if self.0.sender_closed.load(Ordering::Acquire) {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    fn pop(&self) -> Option<T> {
+        let item = self.0.queue.lock().expect("queue poisoned").pop_front();
+
+        if item.is_some() {
+            self.0.writable.notify_one();
+        }
+
+        item
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        self.0.receiver_closed.store(true, Ordering::Release);
+        self.0.writable.notify_one();
+    }
+}
+
 #[derive(Debug)]
 struct ListenersRef<T> {
     // Bitflags of the event types that all listeners combined want.
@@ -48,12 +230,49 @@ impl<T> Listeners<T> {
         let id = self.0.id.fetch_add(1, Ordering::Release) + 1;
         let (tx, rx) = mpsc::unbounded_channel();
 
-        self.0.listeners.insert(id, Listener { events, tx });
+        self.0.listeners.insert(
+            id,
+            Listener {
+                events,
+                tx: ListenerSender::Unbounded(tx),
+            },
+        );
         self.recalculate_event_types();
 
         rx
     }
 
+    /// Add a listener with a bounded channel.
+    pub fn add_bounded(
+        &self,
+        events: EventTypeFlags,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> BoundedReceiver<T> {
+        let id = self.0.id.fetch_add(1, Ordering::Release) + 1;
+        let channel = Arc::new(BoundedChannel {
+            capacity,
+            dropped: AtomicU64::new(0),
+            policy,
+            queue: Mutex::new(VecDeque::with_capacity(capacity)),
+            receiver: AtomicWaker::new(),
+            receiver_closed: AtomicBool::new(false),
+            sender_closed: AtomicBool::new(false),
+            writable: Notify::new(),
+        });
+
+        self.0.listeners.insert(
+            id,
+            Listener {
+                events,
+                tx: ListenerSender::Bounded(BoundedSender(Arc::clone(&channel))),
+            },
+        );
+        self.recalculate_event_types();
+
+        BoundedReceiver(channel)
+    }
+
     pub fn all(&self) -> &DashMap<u64, Listener<T>> {
         &self.0.listeners
     }
@@ -73,6 +292,21 @@ impl<T> Listeners<T> {
         self.0.listeners.len()
     }
 
+    /// Wait until all listeners that block when full have room for more
+    /// items.
+    pub async fn writable(&self) {
+        let blocking = self
+            .0
+            .listeners
+            .iter()
+            .filter_map(|listener| listener.tx.blocking())
+            .collect::<Vec<_>>();
+
+        for channel in blocking {
+            channel.writable().await;
+        }
+    }
+
     pub fn remove_all(&self) {
         self.0.listeners.clear();
         self.recalculate_event_types();
@@ -101,12 +335,97 @@ impl<T> Default for Listeners<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{EventTypeFlags, Listener, Listeners};
+    use super::{BoundedReceiver, EventTypeFlags, Listener, Listeners, OverflowPolicy};
+    use futures_util::future;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
+    use tokio::time;
 
     assert_impl_all!(Listeners<()>: Clone, Debug, Default, Send, Sync);
     assert_impl_all!(Listener<()>: Debug, Send, Sync);
+    assert_impl_all!(BoundedReceiver<()>: Debug, Send, Sync);
+
+    async fn recv(rx: &BoundedReceiver<u8>) -> Option<u8> {
+        future::poll_fn(|cx| rx.poll_recv(cx)).await
+    }
+
+    /// Send items to all listeners, removing the ones that should not be kept.
+    fn send(listeners: &Listeners<u8>, items: &[u8]) {
+        for item in items {
+            listeners
+                .all()
+                .retain(|_, listener| listener.tx.send(*item));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bounded_drop_oldest() {
+        let listeners = Listeners::default();
+        let rx = listeners.add_bounded(EventTypeFlags::all(), 2, OverflowPolicy::DropOldest);
+        send(&listeners, &[1, 2, 3]);
+
+        assert_eq!(1, rx.dropped());
+        assert_eq!(Some(2), recv(&rx).await);
+        assert_eq!(Some(3), recv(&rx).await);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_drop_newest() {
+        let listeners = Listeners::default();
+        let rx = listeners.add_bounded(EventTypeFlags::all(), 2, OverflowPolicy::DropNewest);
+        send(&listeners, &[1, 2, 3]);
+
+        assert_eq!(1, rx.dropped());
+        assert_eq!(Some(1), recv(&rx).await);
+        assert_eq!(Some(2), recv(&rx).await);
+        send(&listeners, &[4]);
+        assert_eq!(Some(4), recv(&rx).await);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_disconnect() {
+        let listeners = Listeners::default();
+        let rx = listeners.add_bounded(EventTypeFlags::all(), 1, OverflowPolicy::Disconnect);
+        send(&listeners, &[1, 2]);
+
+        assert_eq!(0, listeners.len());
+        assert_eq!(1, rx.dropped());
+        assert_eq!(Some(1), recv(&rx).await);
+        assert_eq!(None, recv(&rx).await);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_block() {
+        let listeners = Listeners::default();
+        let rx = listeners.add_bounded(EventTypeFlags::all(), 1, OverflowPolicy::Block);
+        send(&listeners, &[1, 2]);
+
+        // Blocking listeners get all items, and wait for room before more are
+        // sent.
+        assert_eq!(0, rx.dropped());
+        assert!(
+            time::timeout(Duration::from_millis(10), listeners.writable())
+                .await
+                .is_err()
+        );
+        assert_eq!(Some(1), recv(&rx).await);
+        assert!(
+            time::timeout(Duration::from_millis(10), listeners.writable())
+                .await
+                .is_err()
+        );
+        assert_eq!(Some(2), recv(&rx).await);
+        time::timeout(Duration::from_millis(10), listeners.writable())
+            .await
+            .unwrap();
+
+        // A dropped receiver doesn't block.
+        send(&listeners, &[3]);
+        drop(rx);
+        time::timeout(Duration::from_millis(10), listeners.writable())
+            .await
+            .unwrap();
+    }
 
     #[test]
     fn test_total_event_types() {
@@ -1,4 +1,4 @@
-use super::{config::Config, Shard};
+use super::{config::Config, Compression, Encoding, SessionStore, Shard};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -6,7 +6,13 @@ use std::{
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client as HttpClient;
-use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents};
+use twilight_model::gateway::{
+    payload::{
+        identify::{IdentifyClientState, IdentifyProperties},
+        update_status::UpdateStatusInfo,
+    },
+    Intents,
+};
 
 /// Large threshold configuration is invalid.
 ///
@@ -166,12 +172,19 @@ impl ShardBuilder {
 
     fn _new(token: String, intents: Intents) -> Self {
         Self(Config {
+            capabilities: 61,
+            client_state: IdentifyClientState::default(),
+            compression: Compression::default(),
+            encoding: Encoding::default(),
             gateway_url: None,
             http_client: HttpClient::new(token.clone()),
+            identify_compress: false,
+            identify_properties: IdentifyProperties::default_windows_desktop(),
             intents,
             large_threshold: 250,
             presence: None,
             queue: Arc::new(Box::new(LocalQueue::new())),
+            session_store: None,
             shard: [0, 1],
             token: token.into_boxed_str(),
             session_id: None,
@@ -184,6 +197,58 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Set the capabilities bitfield to send when identifying.
+    ///
+    /// The default value is `61`, matching the desktop client.
+    pub fn capabilities(mut self, capabilities: u32) -> Self {
+        self.0.capabilities = capabilities;
+
+        self
+    }
+
+    /// Set the client state to send when identifying.
+    ///
+    /// Sending the guild hashes, read state version and highest last message
+    /// ID from a previous session lets Discord send only what has changed
+    /// since then in the `READY` payload.
+    ///
+    /// The default value is an empty client state, which requests the full
+    /// state.
+    pub fn client_state(mut self, client_state: IdentifyClientState) -> Self {
+        self.0.client_state = client_state;
+
+        self
+    }
+
+    /// Set the transport compression of the payloads received from the
+    /// gateway.
+    ///
+    /// zlib-stream compression, [`Compression::ZlibStream`], requires the
+    /// `compression` feature, and zstd-stream compression,
+    /// [`Compression::ZstdStream`], requires the `zstd` feature.
+    ///
+    /// The default value is [`Compression::ZlibStream`] if the `compression`
+    /// feature is enabled, and [`Compression::None`] otherwise.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.0.compression = compression;
+
+        self
+    }
+
+    /// Set the encoding of the payloads sent to and received from the
+    /// gateway.
+    ///
+    /// Erlang's external term format, [`Encoding::Etf`], is smaller and
+    /// faster to decode than JSON for large payloads. It requires the `etf`
+    /// feature.
+    ///
+    /// The default value is [`Encoding::Json`].
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.0.encoding = encoding;
+
+        self
+    }
+
     /// Set the URL used for connecting to Discord's gateway
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.0.gateway_url = gateway_url.map(String::into_boxed_str);
@@ -201,6 +266,32 @@ impl ShardBuilder {
         self
     }
 
+    /// Set whether to request zlib compression of individual payloads when
+    /// identifying.
+    ///
+    /// Discord doesn't allow this to be combined with transport compression,
+    /// so it's only requested when the [compression] is
+    /// [`Compression::None`]. Decompressing the payloads requires the
+    /// `compression` feature.
+    ///
+    /// The default value is `false`.
+    ///
+    /// [compression]: Self::compression
+    pub fn identify_compress(mut self, identify_compress: bool) -> Self {
+        self.0.identify_compress = identify_compress;
+
+        self
+    }
+
+    /// Set the client properties to send when identifying.
+    ///
+    /// The default value is [`IdentifyProperties::default_windows_desktop`].
+    pub fn identify_properties(mut self, identify_properties: IdentifyProperties) -> Self {
+        self.0.identify_properties = identify_properties;
+
+        self
+    }
+
     /// Set the maximum number of members in a guild to load the member list.
     ///
     /// Default value is `250`. The minimum value is `50` and the maximum is
@@ -270,6 +361,44 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the store to load the shard's session from when starting.
+    ///
+    /// When the store has a session for the shard, the shard resumes it
+    /// instead of identifying, connecting to the gateway URL the session was
+    /// on. If Discord rejects the resume, the shard identifies with the
+    /// configured [`client_state`] as if there was no stored session. Sessions
+    /// are saved with [`Shard::shutdown_and_save`].
+    ///
+    /// The default value is no store, which always starts a new session.
+    ///
+    /// # Examples
+    ///
+    /// Store sessions as files in a directory named `sessions`:
+    ///
+    /// ```no_run
+    /// use std::{env, sync::Arc};
+    /// use twilight_gateway::{shard::FileSessionStore, Intents, Shard};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    ///
+    /// let mut shard = Shard::builder(token, Intents::empty())
+    ///     .session_store(Arc::new(Box::new(FileSessionStore::new("sessions"))))
+    ///     .build();
+    /// shard.start().await?;
+    ///
+    /// // Later, before exiting the process:
+    /// shard.shutdown_and_save().await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`client_state`]: Self::client_state
+    pub fn session_store(mut self, session_store: Arc<Box<dyn SessionStore>>) -> Self {
+        self.0.session_store.replace(session_store);
+
+        self
+    }
+
     /// Set the shard ID to connect as, and the total number of shards used by
     /// the bot.
     ///
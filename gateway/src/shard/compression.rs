@@ -0,0 +1,88 @@
+/// Transport compression of the payloads received from the gateway.
+///
+/// Compression is negotiated when connecting, so a shard can fall back to
+/// another mode when one misbehaves.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// No transport compression, which is the default without the
+    /// `compression` feature.
+    ///
+    /// Payloads may still be compressed individually when requested through
+    /// [`ShardBuilder::identify_compress`].
+    ///
+    /// [`ShardBuilder::identify_compress`]: super::ShardBuilder::identify_compress
+    None,
+    /// zlib compression sharing a context across all payloads of a
+    /// connection, which is the default.
+    ///
+    /// Requires the `compression` feature.
+    #[cfg(feature = "compression")]
+    ZlibStream,
+    /// zstd compression sharing a context across all payloads of a
+    /// connection.
+    ///
+    /// Requires the `zstd` feature.
+    #[cfg(feature = "zstd")]
+    ZstdStream,
+}
+
+impl Compression {
+    /// Name of the compression, as used in the gateway URL.
+    ///
+    /// Returns `None` for [`Compression::None`], which isn't included in the
+    /// URL.
+    pub const fn name(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            #[cfg(feature = "compression")]
+            Self::ZlibStream => Some("zlib-stream"),
+            #[cfg(feature = "zstd")]
+            Self::ZstdStream => Some("zstd-stream"),
+        }
+    }
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        #[cfg(feature = "compression")]
+        {
+            Self::ZlibStream
+        }
+        #[cfg(not(feature = "compression"))]
+        {
+            Self::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Compression;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Compression: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_name() {
+        assert!(Compression::None.name().is_none());
+        #[cfg(feature = "compression")]
+        {
+            assert_eq!(Some("zlib-stream"), Compression::ZlibStream.name());
+            assert_eq!(Compression::ZlibStream, Compression::default());
+        }
+        #[cfg(feature = "zstd")]
+        assert_eq!(Some("zstd-stream"), Compression::ZstdStream.name());
+    }
+}
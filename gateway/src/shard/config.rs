@@ -1,7 +1,14 @@
+use super::{Compression, Encoding, SessionStore};
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
-use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents};
+use twilight_model::gateway::{
+    payload::{
+        identify::{IdentifyClientState, IdentifyProperties},
+        update_status::UpdateStatusInfo,
+    },
+    Intents,
+};
 
 /// The configuration used by the shard to identify with the gateway and
 /// operate.
@@ -11,12 +18,19 @@ use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents}
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(super) capabilities: u32,
+    pub(super) client_state: IdentifyClientState,
+    pub(super) compression: Compression,
+    pub(super) encoding: Encoding,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Client,
+    pub(super) identify_compress: bool,
+    pub(super) identify_properties: IdentifyProperties,
     pub(super) intents: Intents,
     pub(super) large_threshold: u64,
     pub(super) presence: Option<UpdateStatusInfo>,
     pub(super) queue: Arc<Box<dyn Queue>>,
+    pub(super) session_store: Option<Arc<Box<dyn SessionStore>>>,
     pub(crate) shard: [u64; 2],
     pub(super) token: Box<str>,
     pub(crate) session_id: Option<Box<str>>,
@@ -24,6 +38,29 @@ pub struct Config {
 }
 
 impl Config {
+    /// Return the capabilities bitfield sent when identifying.
+    pub fn capabilities(&self) -> u32 {
+        self.capabilities
+    }
+
+    /// Return an immutable reference to the client state sent when
+    /// identifying.
+    pub fn client_state(&self) -> &IdentifyClientState {
+        &self.client_state
+    }
+
+    /// Return the transport compression of the payloads received from the
+    /// gateway.
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Return the encoding of the payloads sent to and received from the
+    /// gateway.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
     /// Return an immutable reference to the url used to connect to the gateway.
     pub fn gateway_url(&self) -> Option<&str> {
         self.gateway_url.as_deref()
@@ -35,6 +72,17 @@ impl Config {
         &self.http_client
     }
 
+    /// Return whether the shard requests payload compression when identifying.
+    pub fn identify_compress(&self) -> bool {
+        self.identify_compress
+    }
+
+    /// Return an immutable reference to the client properties sent when
+    /// identifying.
+    pub fn identify_properties(&self) -> &IdentifyProperties {
+        &self.identify_properties
+    }
+
     /// Return a copy of the intents that the gateway is using.
     pub fn intents(&self) -> Intents {
         self.intents
@@ -55,6 +103,12 @@ impl Config {
         self.presence.as_ref()
     }
 
+    /// Return an immutable reference to the store that sessions are loaded
+    /// from and saved to, if there is one.
+    pub fn session_store(&self) -> Option<&Arc<Box<dyn SessionStore>>> {
+        self.session_store.as_ref()
+    }
+
     /// The shard's ID and the total number of shards used by the bot.
     pub fn shard(&self) -> [u64; 2] {
         self.shard
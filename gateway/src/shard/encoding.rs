@@ -0,0 +1,69 @@
+use super::json;
+use serde::Serialize;
+use std::error::Error;
+
+/// Encoding of the payloads sent to and received from the gateway.
+///
+/// [`Event::ShardPayload`] events and [`RawDispatch`]es contain the payloads
+/// in the shard's encoding.
+///
+/// [`Event::ShardPayload`]: twilight_model::gateway::event::Event::ShardPayload
+/// [`RawDispatch`]: super::RawDispatch
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// JSON, which is the default.
+    Json,
+    /// Erlang's external term format, which is smaller and faster to decode
+    /// for large payloads.
+    ///
+    /// Requires the `etf` feature.
+    #[cfg(feature = "etf")]
+    Etf,
+}
+
+impl Encoding {
+    /// Name of the encoding, as used in the gateway URL.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            #[cfg(feature = "etf")]
+            Self::Etf => "etf",
+        }
+    }
+
+    /// Serialize a payload to send to the gateway.
+    pub(crate) fn to_vec<T: Serialize + ?Sized>(
+        self,
+        payload: &T,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        match self {
+            Self::Json => json::to_vec(payload).map_err(|source| Box::new(source) as _),
+            #[cfg(feature = "etf")]
+            Self::Etf => super::etf::to_vec(payload).map_err(|source| Box::new(source) as _),
+        }
+    }
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Encoding;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(Encoding: Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_name() {
+        assert_eq!("json", Encoding::Json.name());
+        assert_eq!(Encoding::Json, Encoding::default());
+        #[cfg(feature = "etf")]
+        assert_eq!("etf", Encoding::Etf.name());
+    }
+}
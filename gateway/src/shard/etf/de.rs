@@ -0,0 +1,439 @@
+use super::{tag, EtfError, EtfErrorType};
+use serde::de::{
+    value::{SeqDeserializer, StrDeserializer},
+    DeserializeSeed, Deserializer as DeserializerTrait, EnumAccess, Error as DeError,
+    IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use std::{convert::TryFrom, str};
+
+/// Deserializer of a single Erlang term.
+///
+/// Erlang's external term format is self-describing, so everything is
+/// deserialized through [`Deserializer::deserialize_any`], except for options
+/// and enums.
+pub struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    /// Create a deserializer over a term, including its version number.
+    pub fn from_slice(input: &'de [u8]) -> Result<Self, EtfError> {
+        let mut deserializer = Self { input };
+
+        match deserializer.next()? {
+            tag::VERSION => Ok(deserializer),
+            version => Err(EtfError {
+                kind: EtfErrorType::VersionUnsupported { version },
+            }),
+        }
+    }
+
+    /// Return an error if the term didn't use all of the input.
+    pub fn end(&self) -> Result<(), EtfError> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(EtfError {
+                kind: EtfErrorType::TrailingBytes,
+            })
+        }
+    }
+
+    fn peek(&self) -> Result<u8, EtfError> {
+        self.input.first().copied().ok_or(EtfError {
+            kind: EtfErrorType::Eof,
+        })
+    }
+
+    fn next(&mut self) -> Result<u8, EtfError> {
+        let byte = self.peek()?;
+        self.input = &self.input[1..];
+
+        Ok(byte)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'de [u8], EtfError> {
+        if self.input.len() < len {
+            return Err(EtfError {
+                kind: EtfErrorType::Eof,
+            });
+        }
+
+        let (taken, rest) = self.input.split_at(len);
+        self.input = rest;
+
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<usize, EtfError> {
+        let bytes = self.take(2)?;
+
+        Ok(usize::from(u16::from_be_bytes([bytes[0], bytes[1]])))
+    }
+
+    fn u32(&mut self) -> Result<usize, EtfError> {
+        let bytes = self.take(4)?;
+        let len = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+        usize::try_from(len).map_err(|_| EtfError {
+            kind: EtfErrorType::Eof,
+        })
+    }
+
+    /// Read the name of an atom whose tag was already read.
+    fn atom(&mut self, tag: u8) -> Result<&'de str, EtfError> {
+        let len = match tag {
+            tag::ATOM | tag::ATOM_UTF8 => self.u16()?,
+            _ => usize::from(self.next()?),
+        };
+        let bytes = self.take(len)?;
+
+        str::from_utf8(bytes).map_err(|_| EtfError {
+            kind: EtfErrorType::AtomInvalid,
+        })
+    }
+
+    /// Read a big integer whose tag was already read.
+    fn big<V: Visitor<'de>>(&mut self, tag: u8, visitor: V) -> Result<V::Value, EtfError> {
+        let len = if tag == tag::SMALL_BIG {
+            usize::from(self.next()?)
+        } else {
+            self.u32()?
+        };
+        let negative = self.next()? != 0;
+        let digits = self.take(len)?;
+
+        // Digits are stored in little endian order, so any zeroes at the end
+        // don't change the value.
+        let significant = digits
+            .iter()
+            .rposition(|digit| *digit != 0)
+            .map_or(0, |idx| idx + 1);
+
+        if significant > 8 {
+            return Err(EtfError {
+                kind: EtfErrorType::IntegerOverflow,
+            });
+        }
+
+        let value = digits[..significant]
+            .iter()
+            .rev()
+            .fold(0, |value, digit| (value << 8) | u64::from(*digit));
+
+        if !negative {
+            return visitor.visit_u64(value);
+        }
+
+        // The magnitude of `i64::MIN` is one more than `i64::MAX`.
+        if value == 1 << 63 {
+            return visitor.visit_i64(i64::MIN);
+        }
+
+        let value = i64::try_from(value).map_err(|_| EtfError {
+            kind: EtfErrorType::IntegerOverflow,
+        })?;
+
+        visitor.visit_i64(-value)
+    }
+
+    /// Visit a list whose tag was already read.
+    fn list<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, EtfError> {
+        let len = self.u32()?;
+        let mut access = Access {
+            deserializer: self,
+            remaining: len,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+
+        if access.remaining != 0 {
+            return Err(EtfError::invalid_length(len, &"fewer elements in list"));
+        }
+
+        // Only proper lists, ending with an empty list, map to sequences.
+        match self.next()? {
+            tag::NIL => Ok(value),
+            _ => Err(EtfError {
+                kind: EtfErrorType::ListImproper,
+            }),
+        }
+    }
+
+    fn tuple<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, EtfError> {
+        let mut access = Access {
+            deserializer: self,
+            remaining: len,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+
+        if access.remaining == 0 {
+            Ok(value)
+        } else {
+            Err(EtfError::invalid_length(len, &"fewer elements in tuple"))
+        }
+    }
+
+    fn map<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value, EtfError> {
+        let len = self.u32()?;
+        let mut access = Access {
+            deserializer: self,
+            remaining: len,
+        };
+        let value = visitor.visit_map(&mut access)?;
+
+        if access.remaining == 0 {
+            Ok(value)
+        } else {
+            Err(EtfError::invalid_length(len, &"fewer entries in map"))
+        }
+    }
+
+    fn is_nil(&self) -> bool {
+        let len = match self.input.first() {
+            Some(&tag) if tag == tag::SMALL_ATOM || tag == tag::SMALL_ATOM_UTF8 => {
+                self.input.get(1).copied()
+            }
+            _ => return false,
+        };
+
+        len == Some(3) && self.input.get(2..5) == Some(b"nil")
+            || len == Some(4) && self.input.get(2..6) == Some(b"null")
+    }
+}
+
+impl<'de, 'a> DeserializerTrait<'de> for &'a mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let tag = self.next()?;
+
+        match tag {
+            tag::SMALL_INTEGER => visitor.visit_u8(self.next()?),
+            tag::INTEGER => {
+                let bytes = self.take(4)?;
+
+                visitor.visit_i32(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            }
+            tag::NEW_FLOAT => {
+                let bytes = self.take(8)?;
+                let mut float = [0; 8];
+                float.copy_from_slice(bytes);
+
+                visitor.visit_f64(f64::from_be_bytes(float))
+            }
+            tag::FLOAT => {
+                let bytes = self.take(31)?;
+                let float = str::from_utf8(bytes)
+                    .ok()
+                    .and_then(|float| float.trim_end_matches('\0').parse().ok())
+                    .ok_or(EtfError {
+                        kind: EtfErrorType::FloatInvalid,
+                    })?;
+
+                visitor.visit_f64(float)
+            }
+            tag::SMALL_BIG | tag::LARGE_BIG => self.big(tag, visitor),
+            tag::ATOM | tag::SMALL_ATOM | tag::ATOM_UTF8 | tag::SMALL_ATOM_UTF8 => {
+                match self.atom(tag)? {
+                    "nil" | "null" => visitor.visit_unit(),
+                    "true" => visitor.visit_bool(true),
+                    "false" => visitor.visit_bool(false),
+                    atom => visitor.visit_borrowed_str(atom),
+                }
+            }
+            tag::BINARY => {
+                let len = self.u32()?;
+                let bytes = self.take(len)?;
+
+                match str::from_utf8(bytes) {
+                    Ok(string) => visitor.visit_borrowed_str(string),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            }
+            // Lists of bytes may be encoded as "strings".
+            tag::STRING => {
+                let len = self.u16()?;
+                let bytes = self.take(len)?;
+
+                visitor.visit_seq(SeqDeserializer::new(bytes.iter().copied()))
+            }
+            tag::NIL => visitor.visit_seq(Access {
+                deserializer: self,
+                remaining: 0,
+            }),
+            tag::LIST => self.list(visitor),
+            tag::SMALL_TUPLE => {
+                let len = usize::from(self.next()?);
+
+                self.tuple(len, visitor)
+            }
+            tag::LARGE_TUPLE => {
+                let len = self.u32()?;
+
+                self.tuple(len, visitor)
+            }
+            tag::MAP => self.map(visitor),
+            tag => Err(EtfError {
+                kind: EtfErrorType::TagUnsupported { tag },
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.is_nil() {
+            self.deserialize_any(serde::de::IgnoredAny)?;
+
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let tag = self.peek()?;
+
+        match tag {
+            // Variants with data are maps with a single entry, like in JSON.
+            tag::MAP => {
+                self.next()?;
+
+                match self.u32()? {
+                    1 => visitor.visit_enum(self),
+                    len => Err(EtfError::invalid_length(len, &"map with a single entry")),
+                }
+            }
+            tag::ATOM | tag::SMALL_ATOM | tag::ATOM_UTF8 | tag::SMALL_ATOM_UTF8 => {
+                self.next()?;
+                let variant: StrDeserializer<'_, EtfError> = self.atom(tag)?.into_deserializer();
+
+                visitor.visit_enum(variant)
+            }
+            tag::BINARY => {
+                self.next()?;
+                let len = self.u32()?;
+                let bytes = self.take(len)?;
+                let variant =
+                    str::from_utf8(bytes).map_err(|source| EtfError::custom(source.to_string()))?;
+                let variant: StrDeserializer<'_, EtfError> = variant.into_deserializer();
+
+                visitor.visit_enum(variant)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Access to the elements of a list or tuple, or the entries of a map.
+struct Access<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> SeqAccess<'de> for Access<'a, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, 'a> MapAccess<'de> for Access<'a, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, 'a> EnumAccess<'de> for &'a mut Deserializer<'de> {
+    type Error = EtfError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(&mut *self)?;
+
+        Ok((variant, self))
+    }
+}
+
+impl<'de, 'a> VariantAccess<'de> for &'a mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        serde::de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_any(visitor)
+    }
+}
@@ -0,0 +1,944 @@
+//! Erlang's external term format, used by the gateway with `encoding=etf`.
+//!
+//! Terms are deserialized through serde like JSON is, so that the same
+//! [`GatewayEventDeserializer`] is used for both encodings.
+//!
+//! Refer to [Erlang's documentation] for the format.
+//!
+//! [Erlang's documentation]: https://erlang.org/doc/apps/erts/erl_ext_dist.html
+
+mod de;
+mod ser;
+
+use self::{de::Deserializer, ser::Serializer};
+use super::json::{GatewayEventParsingError, GatewayEventParsingErrorType};
+use serde::{
+    de::{DeserializeSeed, Error as DeError},
+    Deserialize, Serialize,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::gateway::event::{DispatchEvent, GatewayEvent, GatewayEventDeserializer};
+
+/// Tags of the terms that Discord sends, preceding their contents.
+mod tag {
+    pub const VERSION: u8 = 131;
+    pub const NEW_FLOAT: u8 = 70;
+    pub const SMALL_INTEGER: u8 = 97;
+    pub const INTEGER: u8 = 98;
+    pub const FLOAT: u8 = 99;
+    pub const ATOM: u8 = 100;
+    pub const SMALL_TUPLE: u8 = 104;
+    pub const LARGE_TUPLE: u8 = 105;
+    pub const NIL: u8 = 106;
+    pub const STRING: u8 = 107;
+    pub const LIST: u8 = 108;
+    pub const BINARY: u8 = 109;
+    pub const SMALL_BIG: u8 = 110;
+    pub const LARGE_BIG: u8 = 111;
+    pub const SMALL_ATOM: u8 = 115;
+    pub const MAP: u8 = 116;
+    pub const ATOM_UTF8: u8 = 118;
+    pub const SMALL_ATOM_UTF8: u8 = 119;
+}
+
+/// Encoding or decoding a term failed.
+#[derive(Debug)]
+pub struct EtfError {
+    kind: EtfErrorType,
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EtfErrorType::AtomInvalid => f.write_str("atom isn't valid UTF-8"),
+            EtfErrorType::Custom { message } => f.write_str(message),
+            EtfErrorType::Eof => f.write_str("term ended unexpectedly"),
+            EtfErrorType::FloatInvalid => f.write_str("float is invalid"),
+            EtfErrorType::IntegerOverflow => f.write_str("integer doesn't fit in 64 bits"),
+            EtfErrorType::LengthOverflow => f.write_str("value is too long to be encoded"),
+            EtfErrorType::ListImproper => f.write_str("list doesn't end with an empty list"),
+            EtfErrorType::TagUnsupported { tag } => {
+                f.write_fmt(format_args!("term tag {} is unsupported", tag))
+            }
+            EtfErrorType::TrailingBytes => f.write_str("term is followed by trailing bytes"),
+            EtfErrorType::VersionUnsupported { version } => {
+                f.write_fmt(format_args!("format version {} is unsupported", version))
+            }
+        }
+    }
+}
+
+impl Error for EtfError {}
+
+impl DeError for EtfError {
+    fn custom<T: Display>(message: T) -> Self {
+        Self {
+            kind: EtfErrorType::Custom {
+                message: message.to_string(),
+            },
+        }
+    }
+}
+
+/// Type of [`EtfError`] that occurred.
+#[derive(Debug)]
+pub enum EtfErrorType {
+    /// Name of an atom isn't valid UTF-8.
+    AtomInvalid,
+    /// Serde returned an error.
+    Custom { message: String },
+    /// Input ended in the middle of a term.
+    Eof,
+    /// Float encoded as a string couldn't be parsed.
+    FloatInvalid,
+    /// Big integer doesn't fit in 64 bits.
+    IntegerOverflow,
+    /// Length of a binary, list or map doesn't fit in 32 bits.
+    LengthOverflow,
+    /// List ends with something other than an empty list.
+    ListImproper,
+    /// Term of a type that doesn't map to serde's data model, such as a
+    /// process ID.
+    TagUnsupported { tag: u8 },
+    /// Input continues after the term.
+    TrailingBytes,
+    /// Format version isn't 131.
+    VersionUnsupported { version: u8 },
+}
+
+/// Deserialize a value from a term.
+///
+/// # Errors
+///
+/// Returns an error if the input isn't a term of the value.
+pub fn from_slice<'a, T: Deserialize<'a>>(input: &'a [u8]) -> Result<T, EtfError> {
+    let mut deserializer = Deserializer::from_slice(input)?;
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+/// Serialize a value into a term.
+///
+/// # Errors
+///
+/// Returns an error if the value can't be serialized.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let mut serializer = Serializer::new();
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.into_inner())
+}
+
+/// Read the opcode, sequence number and event type of a gateway payload,
+/// without deserializing its data.
+pub fn parts(input: &[u8]) -> Option<(u8, Option<u64>, Option<String>)> {
+    #[derive(Deserialize)]
+    struct Parts<'a> {
+        op: u8,
+        #[serde(default)]
+        s: Option<u64>,
+        #[serde(borrow, default)]
+        t: Option<&'a str>,
+    }
+
+    let parts = from_slice::<Parts<'_>>(input).ok()?;
+
+    Some((parts.op, parts.s, parts.t.map(ToOwned::to_owned)))
+}
+
+/// Parse a gateway event from a term with headers.
+///
+/// # Errors
+///
+/// Returns [`GatewayEventParsingErrorType::PayloadInvalid`] if the payload
+/// isn't a valid term.
+///
+/// Returns [`GatewayEventParsingErrorType::Deserializing`] if the payload
+/// failed to deserialize.
+pub fn parse_gateway_event(
+    op: u8,
+    sequence: Option<u64>,
+    event_type: Option<&str>,
+    input: &[u8],
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    let gateway_deserializer = GatewayEventDeserializer::new(op, sequence, event_type);
+    let mut deserializer =
+        Deserializer::from_slice(input).map_err(|source| GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::PayloadInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+    gateway_deserializer
+        .deserialize(&mut deserializer)
+        .map_err(|source| GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        })
+}
+
+/// Parse a dispatch event unknown to the library from a term, converting its
+/// data to JSON.
+///
+/// # Errors
+///
+/// Returns [`GatewayEventParsingErrorType::Deserializing`] if the payload
+/// isn't a valid term or has no data.
+pub fn parse_unknown_dispatch(
+    sequence: u64,
+    event_type: &str,
+    input: &[u8],
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    #[derive(Deserialize)]
+    struct Payload {
+        d: serde_json::Value,
+    }
+
+    let payload = from_slice::<Payload>(input).map_err(|source| GatewayEventParsingError {
+        kind: GatewayEventParsingErrorType::Deserializing,
+        source: Some(Box::new(source)),
+    })?;
+
+    Ok(GatewayEvent::Dispatch(
+        sequence,
+        Box::new(DispatchEvent::Unknown {
+            name: event_type.to_owned(),
+            data: payload.d.to_string(),
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        from_slice, parse_gateway_event, parse_unknown_dispatch, parts, tag, to_vec, EtfError,
+        EtfErrorType,
+    };
+    use crate::shard::json;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::{
+            event::{DispatchEvent, GatewayEvent},
+            payload::{
+                identify::{Identify, IdentifyInfo, IdentifyProperties},
+                resume::Resume,
+                GuildSubscribe, Heartbeat, RequestGuildMembers, UpdateStatus, UpdateVoiceState,
+            },
+            presence::Status,
+        },
+        id::{ChannelId, GuildId, MessageId, UserId},
+    };
+
+    assert_impl_all!(EtfError: Error, Send, Sync);
+    assert_impl_all!(EtfErrorType: Debug, Send, Sync);
+
+    fn user() -> Value {
+        json!({
+            "avatar": null,
+            "discriminator": "0001",
+            "id": "2",
+            "username": "twilight",
+        })
+    }
+
+    fn member() -> Value {
+        json!({
+            "deaf": false,
+            "guild_id": "1",
+            "hoisted_role": null,
+            "joined_at": "2021-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "nick": null,
+            "pending": false,
+            "roles": ["4"],
+            "user": user(),
+        })
+    }
+
+    fn role() -> Value {
+        json!({
+            "color": 0,
+            "hoist": false,
+            "id": "4",
+            "managed": false,
+            "mentionable": false,
+            "name": "role",
+            "permissions": "104324673",
+            "position": 1,
+        })
+    }
+
+    fn guild() -> Value {
+        json!({
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "application_id": null,
+            "banner": null,
+            "channels": [{
+                "id": "3",
+                "guild_id": "1",
+                "name": "general",
+                "nsfw": false,
+                "permission_overwrites": [],
+                "position": 0,
+                "type": 0,
+            }],
+            "default_message_notifications": 0,
+            "description": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": ["NEWS"],
+            "icon": null,
+            "id": "1",
+            "large": false,
+            "members": [member()],
+            "mfa_level": 0,
+            "name": "guild",
+            "nsfw": false,
+            "owner_id": "2",
+            "preferred_locale": "en-US",
+            "premium_tier": 1,
+            "region": "us-east",
+            "roles": [role()],
+            "rules_channel_id": null,
+            "splash": null,
+            "system_channel_flags": 0,
+            "system_channel_id": null,
+            "vanity_url_code": null,
+            "verification_level": 0,
+        })
+    }
+
+    fn message() -> Value {
+        json!({
+            "attachments": [],
+            "author": user(),
+            "channel_id": "3",
+            "content": "hello",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "5",
+            "member": {
+                "deaf": false,
+                "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                "mute": false,
+                "nick": "nick",
+                "roles": [],
+            },
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "nonce": null,
+            "pinned": false,
+            "timestamp": "2021-01-01T00:00:00.000000+00:00",
+            "tts": false,
+            "type": 0,
+        })
+    }
+
+    fn presence() -> Value {
+        json!({
+            "activities": [{
+                "created_at": 1_600_000_000_000_u64,
+                "name": "game",
+                "type": 0,
+            }],
+            "client_status": { "desktop": "online" },
+            "game": null,
+            "guild_id": "1",
+            "status": "online",
+            "user": { "id": "2" },
+        })
+    }
+
+    fn guild_settings() -> Value {
+        json!({
+            "channel_overrides": [],
+            "guild_id": "1",
+            "message_notifications": 1,
+            "mute_config": null,
+            "muted": false,
+            "suppress_everyone": false,
+            "version": 3,
+        })
+    }
+
+    fn voice_state() -> Value {
+        json!({
+            "channel_id": "3",
+            "deaf": false,
+            "guild_id": "1",
+            "member": member(),
+            "mute": false,
+            "request_to_speak_timestamp": null,
+            "self_deaf": false,
+            "self_mute": true,
+            "session_id": "session",
+            "suppress": false,
+            "user_id": "2",
+        })
+    }
+
+    fn thread() -> Value {
+        json!({
+            "id": "7",
+            "guild_id": "1",
+            "last_message_id": null,
+            "member_count": 2,
+            "message_count": 300,
+            "name": "thread",
+            "owner_id": "2",
+            "parent_id": "3",
+            "rate_limit_per_user": 0,
+            "thread_metadata": {
+                "archive_timestamp": "2021-07-01T00:00:00.000000+00:00",
+                "archived": false,
+                "auto_archive_duration": 1440,
+                "locked": false,
+            },
+            "type": 11,
+        })
+    }
+
+    fn thread_member() -> Value {
+        json!({
+            "flags": 1,
+            "id": "7",
+            "join_timestamp": "2021-07-01T00:00:00.000000+00:00",
+            "user_id": "2",
+        })
+    }
+
+    /// Data of every dispatch event, as Discord sends it in JSON.
+    #[allow(clippy::too_many_lines)]
+    fn dispatches() -> Vec<(&'static str, Value)> {
+        vec![
+            (
+                "CHANNEL_CREATE",
+                json!({
+                    "id": "3",
+                    "guild_id": "1",
+                    "name": "general",
+                    "nsfw": false,
+                    "permission_overwrites": [],
+                    "position": 0,
+                    "type": 0,
+                }),
+            ),
+            (
+                "CHANNEL_DELETE",
+                json!({
+                    "id": "3",
+                    "guild_id": "1",
+                    "name": "voice",
+                    "permission_overwrites": [],
+                    "position": 1,
+                    "type": 2,
+                    "bitrate": 64000,
+                    "user_limit": null,
+                }),
+            ),
+            (
+                "CHANNEL_PINS_UPDATE",
+                json!({
+                    "channel_id": "3",
+                    "guild_id": "1",
+                    "last_pin_timestamp": null,
+                }),
+            ),
+            (
+                "CHANNEL_UPDATE",
+                json!({
+                    "id": "6",
+                    "name": "category",
+                    "guild_id": "1",
+                    "permission_overwrites": [],
+                    "position": 2,
+                    "type": 4,
+                }),
+            ),
+            ("GUILD_BAN_ADD", json!({ "guild_id": "1", "user": user() })),
+            (
+                "GUILD_BAN_REMOVE",
+                json!({ "guild_id": "1", "user": user() }),
+            ),
+            ("GUILD_CREATE", guild()),
+            ("GUILD_DELETE", json!({ "id": "1", "unavailable": true })),
+            (
+                "GUILD_EMOJIS_UPDATE",
+                json!({
+                    "emojis": [{
+                        "animated": true,
+                        "available": true,
+                        "id": "7",
+                        "managed": false,
+                        "name": "emoji",
+                        "require_colons": true,
+                        "roles": [],
+                    }],
+                    "guild_id": "1",
+                }),
+            ),
+            ("GUILD_INTEGRATIONS_UPDATE", json!({ "guild_id": "1" })),
+            (
+                "GUILD_MEMBERS_CHUNK",
+                json!({
+                    "chunk_count": 1,
+                    "chunk_index": 0,
+                    "guild_id": "1",
+                    "members": [member()],
+                    "not_found": ["8"],
+                    "presences": [presence()],
+                }),
+            ),
+            ("GUILD_MEMBER_ADD", member()),
+            (
+                "GUILD_MEMBER_LIST_UPDATE",
+                json!({
+                    "groups": [{ "count": 1, "id": "online" }],
+                    "guild_id": "1",
+                    "id": "everyone",
+                    "member_count": 1,
+                    "online_count": 1,
+                    "ops": [{
+                        "op": "SYNC",
+                        "range": [0, 99],
+                        "items": [
+                            { "group": { "count": 1, "id": "online" } },
+                            { "member": {
+                                "deaf": false,
+                                "hoisted_role": null,
+                                "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                                "mute": false,
+                                "presence": presence(),
+                                "roles": [],
+                                "user": user(),
+                            } },
+                        ],
+                    }],
+                }),
+            ),
+            (
+                "GUILD_MEMBER_REMOVE",
+                json!({ "guild_id": "1", "user": user() }),
+            ),
+            (
+                "GUILD_MEMBER_UPDATE",
+                json!({
+                    "guild_id": "1",
+                    "deaf": false,
+                    "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                    "mute": false,
+                    "nick": "nick",
+                    "premium_since": null,
+                    "roles": ["4"],
+                    "user": user(),
+                }),
+            ),
+            (
+                "GUILD_ROLE_CREATE",
+                json!({ "guild_id": "1", "role": role() }),
+            ),
+            (
+                "GUILD_ROLE_DELETE",
+                json!({ "guild_id": "1", "role_id": "4" }),
+            ),
+            (
+                "GUILD_ROLE_UPDATE",
+                json!({ "guild_id": "1", "role": role() }),
+            ),
+            ("GUILD_UPDATE", guild()),
+            (
+                "INVITE_CREATE",
+                json!({
+                    "channel_id": "3",
+                    "code": "twilight",
+                    "created_at": "2021-01-01T00:00:00.000000+00:00",
+                    "guild_id": "1",
+                    "max_age": 86400,
+                    "max_uses": 0,
+                    "temporary": false,
+                    "uses": 0,
+                }),
+            ),
+            (
+                "INVITE_DELETE",
+                json!({
+                    "channel_id": "3",
+                    "code": "twilight",
+                    "guild_id": "1",
+                }),
+            ),
+            (
+                "MESSAGE_ACK",
+                json!({ "channel_id": "3", "message_id": "5", "version": 7 }),
+            ),
+            ("MESSAGE_CREATE", message()),
+            (
+                "MESSAGE_DELETE",
+                json!({ "channel_id": "3", "guild_id": "1", "id": "5" }),
+            ),
+            (
+                "MESSAGE_DELETE_BULK",
+                json!({
+                    "channel_id": "3",
+                    "guild_id": "1",
+                    "ids": ["5", "9"],
+                }),
+            ),
+            (
+                "MESSAGE_UPDATE",
+                json!({
+                    "channel_id": "3",
+                    "content": "edited",
+                    "edited_timestamp": "2021-01-02T00:00:00.000000+00:00",
+                    "id": "5",
+                }),
+            ),
+            ("PRESENCE_UPDATE", presence()),
+            (
+                "MESSAGE_REACTION_ADD",
+                json!({
+                    "channel_id": "3",
+                    "emoji": { "id": null, "name": "🦀" },
+                    "guild_id": "1",
+                    "member": member(),
+                    "message_id": "5",
+                    "user_id": "2",
+                }),
+            ),
+            (
+                "MESSAGE_REACTION_REMOVE",
+                json!({
+                    "channel_id": "3",
+                    "emoji": { "animated": false, "id": "7", "name": "emoji" },
+                    "guild_id": "1",
+                    "message_id": "5",
+                    "user_id": "2",
+                }),
+            ),
+            (
+                "MESSAGE_REACTION_REMOVE_ALL",
+                json!({
+                    "channel_id": "3",
+                    "guild_id": "1",
+                    "message_id": "5",
+                }),
+            ),
+            (
+                "MESSAGE_REACTION_REMOVE_EMOJI",
+                json!({
+                    "channel_id": "3",
+                    "emoji": { "id": null, "name": "🦀" },
+                    "guild_id": "1",
+                    "message_id": "5",
+                }),
+            ),
+            (
+                "READY",
+                json!({
+                    "guilds": [guild(), { "id": "10", "unavailable": true }],
+                    "merged_members": [[{
+                        "deaf": false,
+                        "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                        "mute": false,
+                        "nick": null,
+                        "roles": [],
+                    }]],
+                    "private_channels": [{
+                        "id": "11",
+                        "recipient_ids": ["2"],
+                        "type": 1,
+                    }],
+                    "read_state": {
+                        "entries": [{ "id": "3", "last_message_id": "5", "mention_count": 0 }],
+                        "partial": false,
+                        "version": 1,
+                    },
+                    "relationships": [{ "id": "2", "type": 1, "user_id": "2" }],
+                    "session_id": "session",
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0001",
+                        "id": "12",
+                        "mfa_enabled": false,
+                        "username": "me",
+                    },
+                    "user_guild_settings": {
+                        "entries": [guild_settings()],
+                        "partial": false,
+                        "version": 1,
+                    },
+                    "users": [user()],
+                    "v": 9,
+                }),
+            ),
+            (
+                "RELATIONSHIP_ADD",
+                json!({ "id": "2", "type": 1, "user": user() }),
+            ),
+            ("RELATIONSHIP_REMOVE", json!({ "id": "2", "type": 1 })),
+            ("THREAD_CREATE", thread()),
+            (
+                "THREAD_DELETE",
+                json!({ "guild_id": "1", "id": "7", "parent_id": "3", "type": 11 }),
+            ),
+            (
+                "THREAD_LIST_SYNC",
+                json!({
+                    "channel_ids": ["3"],
+                    "guild_id": "1",
+                    "members": [thread_member()],
+                    "threads": [thread()],
+                }),
+            ),
+            ("THREAD_MEMBER_UPDATE", thread_member()),
+            (
+                "THREAD_MEMBERS_UPDATE",
+                json!({
+                    "added_members": [thread_member()],
+                    "guild_id": "1",
+                    "id": "7",
+                    "member_count": 2,
+                    "removed_member_ids": ["4"],
+                }),
+            ),
+            ("THREAD_UPDATE", thread()),
+            (
+                "TYPING_START",
+                json!({
+                    "channel_id": "3",
+                    "guild_id": "1",
+                    "member": member(),
+                    "timestamp": 1_600_000_000_u64,
+                    "user_id": "2",
+                }),
+            ),
+            ("USER_GUILD_SETTINGS_UPDATE", guild_settings()),
+            (
+                "USER_UPDATE",
+                json!({
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "email": "me@example.com",
+                    "id": "12",
+                    "mfa_enabled": true,
+                    "username": "me",
+                    "verified": true,
+                }),
+            ),
+            (
+                "VOICE_SERVER_UPDATE",
+                json!({
+                    "endpoint": "voice.discord.media",
+                    "guild_id": "1",
+                    "token": "token",
+                }),
+            ),
+            ("VOICE_STATE_UPDATE", voice_state()),
+            (
+                "WEBHOOKS_UPDATE",
+                json!({ "channel_id": "3", "guild_id": "1" }),
+            ),
+        ]
+    }
+
+    fn dispatch(event: GatewayEvent) -> DispatchEvent {
+        match event {
+            GatewayEvent::Dispatch(_, dispatch) => *dispatch,
+            other => panic!("expected dispatch, got {:?}", other),
+        }
+    }
+
+    /// Every dispatch event decodes to the same event from ETF as it does from
+    /// JSON.
+    #[test]
+    fn test_dispatch_corpus() {
+        for (event_type, data) in dispatches() {
+            let payload = json!({ "op": 0, "s": 1, "t": event_type, "d": data });
+
+            let mut json = payload.to_string();
+            let expected = json::parse_gateway_event(0, Some(1), Some(event_type), &mut json)
+                .unwrap_or_else(|source| panic!("{} from json: {:?}", event_type, source));
+            let expected = dispatch(expected);
+
+            let bytes = to_vec(&payload).unwrap();
+            assert_eq!(
+                Some((0, Some(1), Some(event_type.to_owned()))),
+                parts(&bytes),
+                "{}",
+                event_type,
+            );
+
+            let event = parse_gateway_event(0, Some(1), Some(event_type), &bytes)
+                .unwrap_or_else(|source| panic!("{} from etf: {:?}", event_type, source));
+            assert_eq!(expected, dispatch(event), "{}", event_type);
+        }
+    }
+
+    /// Every command encodes to the same value in ETF as it does in JSON.
+    #[test]
+    fn test_command_corpus() {
+        fn assert_command<T: Serialize>(command: &T) {
+            let expected = serde_json::to_value(command).unwrap();
+            let bytes = to_vec(command).unwrap();
+
+            assert_eq!(expected, from_slice::<Value>(&bytes).unwrap());
+        }
+
+        assert_command(
+            &GuildSubscribe::builder(GuildId(1))
+                .activities(true)
+                .channel(ChannelId(3), vec![(0, 99)])
+                .members(vec![UserId(2)])
+                .typing(true)
+                .build(),
+        );
+        assert_command(&Heartbeat::new(1));
+        assert_command(&Identify::new(IdentifyInfo::new(
+            "token",
+            IdentifyProperties::default_windows_desktop(),
+        )));
+        assert_command(&RequestGuildMembers::builder(GuildId(1)).query("", Some(0)));
+        assert_command(
+            &RequestGuildMembers::builder(GuildId(1))
+                .nonce("nonce")
+                .presences(true)
+                .user_id(UserId(2)),
+        );
+        assert_command(&Resume::new(1, "session", "token"));
+        assert_command(&UpdateStatus::new(None, false, Some(1), Status::Idle));
+        assert_command(&UpdateVoiceState::new(
+            GuildId(1),
+            ChannelId(3),
+            false,
+            true,
+        ));
+        assert_command(&UpdateVoiceState::new(GuildId(1), None, false, false));
+    }
+
+    #[test]
+    fn test_gateway_events() {
+        let hello = to_vec(&json!({ "op": 10, "d": { "heartbeat_interval": 41250 } })).unwrap();
+        assert_eq!(Some((10, None, None)), parts(&hello));
+        assert!(matches!(
+            parse_gateway_event(10, None, None, &hello).unwrap(),
+            GatewayEvent::Hello(41250)
+        ));
+
+        let ack = to_vec(&json!({ "op": 11, "d": null })).unwrap();
+        assert!(matches!(
+            parse_gateway_event(11, None, None, &ack).unwrap(),
+            GatewayEvent::HeartbeatAck
+        ));
+
+        let invalidate = to_vec(&json!({ "op": 9, "d": false })).unwrap();
+        assert!(matches!(
+            parse_gateway_event(9, None, None, &invalidate).unwrap(),
+            GatewayEvent::InvalidateSession(false)
+        ));
+    }
+
+    #[test]
+    fn test_unknown_dispatch() {
+        let data = json!({ "count": 1, "sessions": [{ "status": "online" }] });
+        let bytes =
+            to_vec(&json!({ "op": 0, "s": 1, "t": "SESSIONS_REPLACE", "d": data })).unwrap();
+
+        let event = parse_unknown_dispatch(1, "SESSIONS_REPLACE", &bytes).unwrap();
+        assert_eq!(
+            DispatchEvent::Unknown {
+                name: "SESSIONS_REPLACE".to_owned(),
+                data: data.to_string(),
+            },
+            dispatch(event)
+        );
+    }
+
+    /// Discord sends snowflakes as integers and `null` as the `nil` atom in ETF.
+    #[test]
+    fn test_discord_terms() {
+        #[derive(Debug, serde::Deserialize, PartialEq)]
+        struct Ack {
+            channel_id: ChannelId,
+            message_id: MessageId,
+            version: Option<u64>,
+        }
+
+        let mut bytes = vec![tag::VERSION, tag::MAP, 0, 0, 0, 3];
+
+        // "channel_id" => 1 << 62, as a big integer.
+        bytes.extend_from_slice(&[tag::BINARY, 0, 0, 0, 10]);
+        bytes.extend_from_slice(b"channel_id");
+        bytes.extend_from_slice(&[tag::SMALL_BIG, 8, 0]);
+        bytes.extend_from_slice(&(1_u64 << 62).to_le_bytes());
+        // message_id => 5, with the key as an atom.
+        bytes.extend_from_slice(&[tag::SMALL_ATOM_UTF8, 10]);
+        bytes.extend_from_slice(b"message_id");
+        bytes.extend_from_slice(&[tag::SMALL_INTEGER, 5]);
+        // version => nil
+        bytes.extend_from_slice(&[tag::ATOM, 0, 7]);
+        bytes.extend_from_slice(b"version");
+        bytes.extend_from_slice(&[tag::SMALL_ATOM, 3]);
+        bytes.extend_from_slice(b"nil");
+
+        assert_eq!(
+            Ack {
+                channel_id: ChannelId(1 << 62),
+                message_id: MessageId(5),
+                version: None,
+            },
+            from_slice::<Ack>(&bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        for value in &[0, 255, 256, -1, i64::from(i32::MIN), i64::MAX, i64::MIN] {
+            assert_eq!(*value, from_slice::<i64>(&to_vec(value).unwrap()).unwrap());
+        }
+
+        assert_eq!(
+            u64::MAX,
+            from_slice::<u64>(&to_vec(&u64::MAX).unwrap()).unwrap()
+        );
+        let float = from_slice::<f64>(&to_vec(&1.5).unwrap()).unwrap();
+        assert!((float - 1.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            from_slice::<u8>(&[130, tag::SMALL_INTEGER, 1])
+                .unwrap_err()
+                .kind,
+            EtfErrorType::VersionUnsupported { version: 130 }
+        ));
+        assert!(matches!(
+            from_slice::<String>(&[tag::VERSION, tag::BINARY, 0, 0, 0, 5, b'a'])
+                .unwrap_err()
+                .kind,
+            EtfErrorType::Eof
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[tag::VERSION, tag::SMALL_INTEGER, 1, 2])
+                .unwrap_err()
+                .kind,
+            EtfErrorType::TrailingBytes
+        ));
+        assert!(matches!(
+            from_slice::<Value>(&[tag::VERSION, 103]).unwrap_err().kind,
+            EtfErrorType::TagUnsupported { tag: 103 }
+        ));
+        assert!(parts(&[tag::VERSION, tag::NIL]).is_none());
+    }
+}
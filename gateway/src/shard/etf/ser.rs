@@ -0,0 +1,450 @@
+use super::{tag, EtfError, EtfErrorType};
+use serde::ser::{
+    Error as SerError, Serialize, SerializeMap, SerializeSeq, SerializeStruct,
+    SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    Serializer as SerializerTrait,
+};
+use std::convert::TryFrom;
+
+/// Serializer writing Erlang terms to a buffer.
+///
+/// Values are encoded the way Discord expects them: strings and map keys are
+/// binaries, `None` and unit are the `nil` atom and sequences are lists.
+pub struct Serializer {
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    /// Create a serializer, writing the version number of the format.
+    pub fn new() -> Self {
+        Self {
+            output: vec![tag::VERSION],
+        }
+    }
+
+    /// Consume the serializer, returning the serialized term.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.output
+    }
+
+    fn atom(&mut self, name: &str) {
+        self.output.push(tag::SMALL_ATOM_UTF8);
+        // Atoms written by the serializer are all short and known.
+        #[allow(clippy::cast_possible_truncation)]
+        self.output.push(name.len() as u8);
+        self.output.extend_from_slice(name.as_bytes());
+    }
+
+    fn binary(&mut self, bytes: &[u8]) -> Result<(), EtfError> {
+        let len = u32::try_from(bytes.len()).map_err(|_| EtfError {
+            kind: EtfErrorType::LengthOverflow,
+        })?;
+
+        self.output.push(tag::BINARY);
+        self.output.extend_from_slice(&len.to_be_bytes());
+        self.output.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    /// Write the header of a list or map whose length is filled in once it's
+    /// known.
+    fn compound(&mut self, tag: u8, kind: Kind) -> Compound<'_> {
+        let start = self.output.len();
+        self.output.push(tag);
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            kind,
+            len: 0,
+            serializer: self,
+            start,
+        }
+    }
+
+    /// Write the header of a map with a single entry for an enum variant, and
+    /// its key.
+    fn variant(&mut self, variant: &str) -> Result<(), EtfError> {
+        self.output.push(tag::MAP);
+        self.output.extend_from_slice(&1_u32.to_be_bytes());
+
+        self.binary(variant.as_bytes())
+    }
+
+    fn i64(&mut self, value: i64) {
+        if let Ok(value) = u8::try_from(value) {
+            self.output.push(tag::SMALL_INTEGER);
+            self.output.push(value);
+        } else if let Ok(value) = i32::try_from(value) {
+            self.output.push(tag::INTEGER);
+            self.output.extend_from_slice(&value.to_be_bytes());
+        } else {
+            let magnitude = u64::try_from(i128::from(value).abs()).unwrap_or(u64::MAX);
+
+            self.big(value < 0, magnitude);
+        }
+    }
+
+    fn u64(&mut self, value: u64) {
+        match i64::try_from(value) {
+            Ok(value) => self.i64(value),
+            Err(_) => self.big(false, value),
+        }
+    }
+
+    fn big(&mut self, negative: bool, magnitude: u64) {
+        let digits = magnitude.to_le_bytes();
+        let len = digits
+            .iter()
+            .rposition(|digit| *digit != 0)
+            .map_or(0, |idx| idx + 1);
+
+        self.output.push(tag::SMALL_BIG);
+        // There are at most 8 digits.
+        #[allow(clippy::cast_possible_truncation)]
+        self.output.push(len as u8);
+        self.output.push(u8::from(negative));
+        self.output.extend_from_slice(&digits[..len]);
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Kind {
+    List,
+    Map,
+}
+
+/// List or map whose length is filled in when it ends.
+pub struct Compound<'a> {
+    kind: Kind,
+    len: u32,
+    serializer: &'a mut Serializer,
+    start: usize,
+}
+
+impl Compound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.len = self.len.checked_add(1).ok_or(EtfError {
+            kind: EtfErrorType::LengthOverflow,
+        })?;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn finish(self) {
+        let output = &mut self.serializer.output;
+
+        if self.kind == Kind::List {
+            // Empty lists are encoded as their own tag, without a tail.
+            if self.len == 0 {
+                output.truncate(self.start);
+                output.push(tag::NIL);
+
+                return;
+            }
+
+            output.push(tag::NIL);
+        }
+
+        output[self.start + 1..self.start + 5].copy_from_slice(&self.len.to_be_bytes());
+    }
+}
+
+impl<'a> SerializerTrait for &'a mut Serializer {
+    type Ok = ();
+    type Error = EtfError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), EtfError> {
+        self.atom(if v { "true" } else { "false" });
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), EtfError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), EtfError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), EtfError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), EtfError> {
+        self.i64(v);
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), EtfError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), EtfError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), EtfError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), EtfError> {
+        self.u64(v);
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), EtfError> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), EtfError> {
+        self.output.push(tag::NEW_FLOAT);
+        self.output.extend_from_slice(&v.to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), EtfError> {
+        self.binary(v.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), EtfError> {
+        self.binary(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), EtfError> {
+        self.binary(v)
+    }
+
+    fn serialize_none(self) -> Result<(), EtfError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), EtfError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), EtfError> {
+        self.atom("nil");
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), EtfError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), EtfError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.variant(variant)?;
+
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a>, EtfError> {
+        Ok(self.compound(tag::LIST, Kind::List))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>, EtfError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<Compound<'a>, EtfError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, EtfError> {
+        self.variant(variant)?;
+
+        Ok(self.compound(tag::LIST, Kind::List))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Compound<'a>, EtfError> {
+        Ok(self.compound(tag::MAP, Kind::Map))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Compound<'a>, EtfError> {
+        self.serialize_map(None)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, EtfError> {
+        self.variant(variant)?;
+
+        Ok(self.compound(tag::MAP, Kind::Map))
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), EtfError> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.element(key)?;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.finish();
+
+        Ok(())
+    }
+}
+
+impl SerError for EtfError {
+    fn custom<T: std::fmt::Display>(message: T) -> Self {
+        Self {
+            kind: EtfErrorType::Custom {
+                message: message.to_string(),
+            },
+        }
+    }
+}
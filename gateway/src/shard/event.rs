@@ -13,7 +13,7 @@
 //! [`EventType`]: ::twilight_model::gateway::event::EventType
 //! [`Shard::some_events`]: super::Shard::some_events
 
-use crate::EventTypeFlags;
+use crate::{listener::BoundedReceiver, EventTypeFlags};
 use futures_util::stream::Stream;
 use std::{
     pin::Pin,
@@ -22,6 +22,42 @@ use std::{
 use tokio::sync::mpsc::UnboundedReceiver;
 use twilight_model::gateway::event::Event;
 
+/// What to do when an event is emitted to a bounded event stream that is full.
+///
+/// Refer to [`Shard::some_bounded_events`] for more information.
+///
+/// [`Shard::some_bounded_events`]: super::Shard::some_bounded_events
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Stop processing payloads from the gateway until the stream has room.
+    ///
+    /// This applies backpressure to the gateway connection, so a stream that
+    /// isn't polled stalls the shard, including other event streams, and may
+    /// make Discord close the connection.
+    Block,
+    /// Disconnect the stream, ending it once the events already in it have
+    /// been received.
+    Disconnect,
+    /// Drop the newest event, which is the event being emitted.
+    DropNewest,
+    /// Drop the oldest event in the stream to make room for the new one.
+    DropOldest,
+}
+
+impl OverflowPolicy {
+    /// Name of the policy, used as a metric label.
+    #[cfg(feature = "metrics")]
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Block => "Block",
+            Self::Disconnect => "Disconnect",
+            Self::DropNewest => "DropNewest",
+            Self::DropOldest => "DropOldest",
+        }
+    }
+}
+
 /// A stream of events from a [`Shard`].
 ///
 /// The events of this stream may or may not be filtered. You can check the
@@ -40,33 +76,74 @@ use twilight_model::gateway::event::Event;
 /// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
 pub struct Events {
     event_types: EventTypeFlags,
-    rx: UnboundedReceiver<Event>,
+    rx: EventsReceiver,
+}
+
+/// Receiving half of the channel of an event stream.
+enum EventsReceiver {
+    Bounded(BoundedReceiver<Event>),
+    Unbounded(UnboundedReceiver<Event>),
 }
 
 impl Events {
     pub(super) fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
-        Self { event_types, rx }
+        Self {
+            event_types,
+            rx: EventsReceiver::Unbounded(rx),
+        }
+    }
+
+    pub(super) fn bounded(event_types: EventTypeFlags, rx: BoundedReceiver<Event>) -> Self {
+        Self {
+            event_types,
+            rx: EventsReceiver::Bounded(rx),
+        }
     }
 
     /// Returns the event types that can be passed to this stream.
     pub fn event_types(&self) -> EventTypeFlags {
         self.event_types
     }
+
+    /// Returns the number of events that were dropped because the stream was
+    /// full.
+    ///
+    /// This is always 0 for unbounded streams.
+    pub fn dropped(&self) -> u64 {
+        match &self.rx {
+            EventsReceiver::Bounded(rx) => rx.dropped(),
+            EventsReceiver::Unbounded(_) => 0,
+        }
+    }
 }
 
 impl Stream for Events {
     type Item = Event;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.rx.poll_recv(cx)
+        match &mut self.rx {
+            EventsReceiver::Bounded(rx) => rx.poll_recv(cx),
+            EventsReceiver::Unbounded(rx) => rx.poll_recv(cx),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Events;
+    use super::{Events, OverflowPolicy};
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
 
     assert_impl_all!(Events: Send, Stream, Sync);
+    assert_impl_all!(
+        OverflowPolicy: Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
 }
@@ -1,11 +1,12 @@
 use super::{
     builder::ShardBuilder,
     config::Config,
-    event::Events,
-    json,
-    processor::{ConnectingErrorType, Latency, Session, ShardProcessor},
+    event::{Events, OverflowPolicy},
+    processor::{ConnectingErrorType, Latency, Session, ShardProcessor, Subscriptions},
+    raw_dispatch::{RawDispatch, RawDispatches},
     raw_message::Message,
     stage::Stage,
+    StoredSession,
 };
 use crate::{listener::Listeners, EventTypeFlags, Intents};
 use futures_util::stream::StreamExt;
@@ -21,7 +22,13 @@ use tokio::{sync::watch::Receiver as WatchReceiver, task::JoinHandle};
 use tokio_tungstenite::tungstenite::protocol::{
     frame::coding::CloseCode, CloseFrame as TungsteniteCloseFrame,
 };
-use twilight_model::gateway::event::Event;
+use twilight_model::{
+    gateway::{
+        event::Event,
+        payload::{guild_subscribe::GuildSubscribeInfo, GuildSubscribe},
+    },
+    id::GuildId,
+};
 
 /// Sending a command failed.
 #[derive(Debug)]
@@ -72,7 +79,7 @@ impl Display for CommandError {
             CommandErrorType::Sending => {
                 f.write_str("sending the message over the websocket failed")
             }
-            CommandErrorType::Serializing => f.write_str("serializing the value as json failed"),
+            CommandErrorType::Serializing => f.write_str("serializing the value failed"),
             CommandErrorType::SessionInactive => Display::fmt(&SessionInactiveError, f),
         }
     }
@@ -93,7 +100,7 @@ pub enum CommandErrorType {
     /// Sending the payload over the WebSocket failed. This is indicative of a
     /// shutdown shard.
     Sending,
-    /// Serializing the payload as JSON failed.
+    /// Serializing the payload in the shard's encoding failed.
     Serializing,
     /// Shard's session is inactive because the shard hasn't been started.
     SessionInactive,
@@ -171,6 +178,68 @@ pub enum SendErrorType {
     SessionInactive,
 }
 
+/// Saving the session of a shard to its store failed.
+///
+/// Returned by [`Shard::shutdown_and_save`].
+#[derive(Debug)]
+pub struct SaveSessionError {
+    kind: SaveSessionErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SaveSessionError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub fn kind(&self) -> &SaveSessionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SaveSessionErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for SaveSessionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            SaveSessionErrorType::Saving => f.write_str("saving the session to the store failed"),
+            SaveSessionErrorType::StoreMissing => {
+                f.write_str("the shard wasn't configured with a session store")
+            }
+        }
+    }
+}
+
+impl Error for SaveSessionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SaveSessionError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SaveSessionErrorType {
+    /// Saving the session with the [`SessionStore`] failed.
+    ///
+    /// [`SessionStore`]: super::SessionStore
+    Saving,
+    /// Shard wasn't configured with a [`SessionStore`].
+    ///
+    /// [`SessionStore`]: super::SessionStore
+    StoreMissing,
+}
+
 /// Starting a shard and connecting to the gateway failed.
 #[derive(Debug)]
 pub struct ShardStartError {
@@ -201,6 +270,9 @@ impl ShardStartError {
 impl Display for ShardStartError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ShardStartErrorType::Decompressing => {
+                f.write_str("creating the decompression context failed")
+            }
             ShardStartErrorType::Establishing => f.write_str("establishing the connection failed"),
             ShardStartErrorType::ParsingGatewayUrl { url } => {
                 f.write_fmt(format_args!("the gateway url `{}` is invalid", url,))
@@ -224,6 +296,11 @@ impl Error for ShardStartError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ShardStartErrorType {
+    /// Creating the context for decompressing payloads with the configured
+    /// [`Compression`] failed.
+    ///
+    /// [`Compression`]: super::Compression
+    Decompressing,
     /// Establishing a connection to the gateway failed.
     Establishing,
     /// Parsing the gateway URL provided by Discord to connect to the gateway
@@ -299,9 +376,12 @@ pub struct ResumeSession {
 #[derive(Debug)]
 struct ShardRef {
     config: Arc<Config>,
+    gateway_url: OnceCell<Box<str>>,
     listeners: Listeners<Event>,
     processor_handle: OnceCell<JoinHandle<()>>,
+    raw_listeners: Listeners<RawDispatch>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
+    subscriptions: Arc<Subscriptions>,
 }
 
 /// Shard to run and manage a session with the gateway.
@@ -402,9 +482,12 @@ impl Shard {
 
         Self(Arc::new(ShardRef {
             config,
+            gateway_url: OnceCell::new(),
             listeners: Listeners::default(),
             processor_handle: OnceCell::new(),
+            raw_listeners: Listeners::default(),
             session: OnceCell::new(),
+            subscriptions: Arc::new(Subscriptions::default()),
         }))
     }
 
@@ -423,8 +506,17 @@ impl Shard {
     /// Start the shard, connecting it to the gateway and starting the process
     /// of receiving and processing events.
     ///
+    /// If the shard has a [`SessionStore`] with a session for the shard, the
+    /// session is resumed. Stored sessions that can't be loaded or don't
+    /// belong to the shard are ignored and the shard identifies instead.
+    ///
+    /// [`SessionStore`]: super::SessionStore
+    ///
     /// # Errors
     ///
+    /// Returns a [`ShardStartErrorType::Decompressing`] error type if the
+    /// context for decompressing payloads couldn't be created.
+    ///
     /// Returns a [`ShardStartErrorType::Establishing`] error type if
     /// establishing a connection to the gateway failed.
     ///
@@ -434,8 +526,12 @@ impl Shard {
     /// Returns a [`ShardStartErrorType::RetrievingGatewayUrl`] error type if
     /// the gateway URL couldn't be retrieved from the HTTP API.
     pub async fn start(&mut self) -> Result<(), ShardStartError> {
+        let stored = self.load_session().await;
+
         let url = if let Some(u) = self.0.config.gateway_url.clone() {
             u.into_string()
+        } else if let Some(stored) = stored.as_ref() {
+            stored.gateway_url.clone()
         } else {
             self.0
                 .config
@@ -450,15 +546,21 @@ impl Shard {
                 .url
         };
 
+        // We know that this hasn't been set, so we can ignore the result.
+        let _url = self.0.gateway_url.set(url.clone().into_boxed_str());
+
         let config = Arc::clone(&self.0.config);
         let listeners = self.0.listeners.clone();
+        let raw_listeners = self.0.raw_listeners.clone();
+        let subscriptions = Arc::clone(&self.0.subscriptions);
         let (processor, wrx) =
-            ShardProcessor::new(config, url, listeners)
+            ShardProcessor::new(config, url, listeners, raw_listeners, subscriptions, stored)
                 .await
                 .map_err(|source| {
                     let (kind, source) = source.into_parts();
 
                     let new_kind = match kind {
+                        ConnectingErrorType::Decompressing => ShardStartErrorType::Decompressing,
                         ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
                         ConnectingErrorType::ParsingUrl { url } => {
                             ShardStartErrorType::ParsingGatewayUrl { url }
@@ -550,6 +652,111 @@ impl Shard {
         Events::new(event_types, rx)
     }
 
+    /// Create a new stream of events from the shard that holds at most
+    /// `capacity` events.
+    ///
+    /// This is like [`events`], but a stream that isn't polled fast enough
+    /// doesn't make memory usage grow without limit. Instead, the
+    /// [`OverflowPolicy`] determines what happens when the stream is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    ///
+    /// [`events`]: Self::events
+    pub fn bounded_events(&self, capacity: usize, policy: OverflowPolicy) -> Events {
+        self.some_bounded_events(EventTypeFlags::default(), capacity, policy)
+    }
+
+    /// Create a new filtered stream of events from the shard that holds at
+    /// most `capacity` events.
+    ///
+    /// This is like [`some_events`], but the [`OverflowPolicy`] determines
+    /// what happens when the stream is full. The number of events dropped so
+    /// far is available from [`Events::dropped`], and with the `metrics`
+    /// feature enabled it's also counted by the `GatewayEventsDropped` metric.
+    ///
+    /// # Examples
+    ///
+    /// Only keep the 1000 most recent member list updates, dropping older
+    /// ones during bursts:
+    ///
+    /// ```no_run
+    /// use twilight_gateway::{shard::OverflowPolicy, EventTypeFlags, Intents, Shard};
+    /// use std::env;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let mut shard = Shard::new(env::var("DISCORD_TOKEN")?, Intents::empty());
+    /// let events = shard.some_bounded_events(
+    ///     EventTypeFlags::MEMBER_LIST_UPDATE,
+    ///     1000,
+    ///     OverflowPolicy::DropOldest,
+    /// );
+    /// shard.start().await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    ///
+    /// [`some_events`]: Self::some_events
+    pub fn some_bounded_events(
+        &self,
+        event_types: EventTypeFlags,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> Events {
+        assert!(capacity > 0, "bounded event streams need a capacity");
+
+        let rx = self.0.listeners.add_bounded(event_types, capacity, policy);
+
+        Events::bounded(event_types, rx)
+    }
+
+    /// Create a new stream of raw dispatch events from the shard.
+    ///
+    /// Only dispatch events with the types specified in the bitflags are sent
+    /// over the stream. Unlike the [`some_events`] stream, the events aren't
+    /// deserialized: each [`RawDispatch`] contains the payload's bytes as they
+    /// were received, and can be deserialized on demand. This is useful for
+    /// forwarding events to another process or writing them to disk.
+    ///
+    /// Raw dispatch streams don't cause events to be deserialized. If no event
+    /// stream wants an event type, then no time is spent deserializing it.
+    ///
+    /// The returned stream implements [`futures::stream::Stream`].
+    ///
+    /// # Examples
+    ///
+    /// Write the payloads of message creations to standard output:
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use std::{env, io::Write};
+    /// use twilight_gateway::{EventTypeFlags, Intents, Shard};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let mut shard = Shard::new(env::var("DISCORD_TOKEN")?, Intents::empty());
+    /// let mut dispatches = shard.raw_dispatches(EventTypeFlags::MESSAGE_CREATE);
+    /// shard.start().await?;
+    ///
+    /// while let Some(dispatch) = dispatches.next().await {
+    ///     std::io::stdout().write_all(dispatch.bytes())?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
+    /// [`some_events`]: Self::some_events
+    pub fn raw_dispatches(&self, event_types: EventTypeFlags) -> RawDispatches {
+        let rx = self.0.raw_listeners.add(event_types);
+
+        RawDispatches::new(event_types, rx)
+    }
+
     /// Retrieve information about the running of the shard, such as the current
     /// connection stage.
     ///
@@ -577,17 +784,23 @@ impl Shard {
     /// restarting.
     ///
     /// Returns a [`CommandErrorType::Serializing`] error type if the provided
-    /// value failed to serialize into JSON.
+    /// value failed to serialize into the shard's [encoding].
     ///
     /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
     /// has not been started.
+    ///
+    /// [encoding]: super::Encoding
     pub async fn command(&self, value: &impl serde::Serialize) -> Result<(), CommandError> {
-        let json = json::to_vec(value).map_err(|source| CommandError {
-            source: Some(Box::new(source)),
-            kind: CommandErrorType::Serializing,
-        })?;
-
-        self.send(Message::Binary(json))
+        let bytes = self
+            .config()
+            .encoding()
+            .to_vec(value)
+            .map_err(|source| CommandError {
+                source: Some(source),
+                kind: CommandErrorType::Serializing,
+            })?;
+
+        self.send(Message::Binary(bytes))
             .await
             .map_err(CommandError::from_send)
     }
@@ -689,6 +902,75 @@ impl Shard {
             .map_err(CommandError::from_send)
     }
 
+    /// Subscribe to a guild's typing, activities, threads, and member list
+    /// ranges.
+    ///
+    /// The subscription is recorded and merged into any previous subscription
+    /// to the same guild, and is sent again whenever the shard resumes or
+    /// reconnects.
+    ///
+    /// # Examples
+    ///
+    /// Subscribe to typing events and the first 100 members of a channel's
+    /// member list:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_gateway::{Intents, Shard};
+    /// use twilight_model::{
+    ///     gateway::payload::GuildSubscribe,
+    ///     id::{ChannelId, GuildId},
+    /// };
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let mut shard = Shard::new(token, Intents::GUILDS);
+    /// shard.start().await?;
+    ///
+    /// let subscribe = GuildSubscribe::builder(GuildId(1))
+    ///     .typing(true)
+    ///     .channel(ChannelId(2), vec![(0, 99)])
+    ///     .build();
+    /// shard.subscribe_guild(subscribe).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CommandErrorType::Sending`] error type if the message could
+    /// not be sent over the websocket. The subscription is still recorded and
+    /// will be sent once the shard has reconnected.
+    ///
+    /// Returns a [`CommandErrorType::Serializing`] error type if the
+    /// subscription failed to serialize into the shard's encoding.
+    ///
+    /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
+    /// has not been started.
+    pub async fn subscribe_guild(&self, subscribe: GuildSubscribe) -> Result<(), CommandError> {
+        self.0.subscriptions.insert(subscribe.d.clone());
+
+        self.command(&subscribe).await
+    }
+
+    /// Return a copy of the recorded subscription to a guild, if any.
+    ///
+    /// This is the merged result of all calls to [`subscribe_guild`] for the
+    /// guild.
+    ///
+    /// [`subscribe_guild`]: Self::subscribe_guild
+    pub fn guild_subscription(&self, guild_id: GuildId) -> Option<GuildSubscribeInfo> {
+        self.0.subscriptions.get(guild_id)
+    }
+
+    /// Forget the recorded subscription to a guild, returning it if there was
+    /// one.
+    ///
+    /// This doesn't send anything to the gateway; it only stops the
+    /// subscription from being sent again on future sessions.
+    pub fn remove_guild_subscription(&self, guild_id: GuildId) -> Option<GuildSubscribeInfo> {
+        self.0.subscriptions.remove(guild_id)
+    }
+
     /// Shut down the shard.
     ///
     /// The shard will cleanly close the connection by sending a normal close
@@ -696,6 +978,7 @@ impl Shard {
     /// not be resumable.
     pub fn shutdown(&self) {
         self.0.listeners.remove_all();
+        self.0.raw_listeners.remove_all();
 
         if let Some(processor_handle) = self.0.processor_handle.get() {
             processor_handle.abort();
@@ -721,6 +1004,7 @@ impl Shard {
     /// [`ClusterBuilder::resume_sessions`]: crate::cluster::ClusterBuilder::resume_sessions
     pub fn shutdown_resumable(&self) -> (u64, Option<ResumeSession>) {
         self.0.listeners.remove_all();
+        self.0.raw_listeners.remove_all();
 
         if let Some(processor_handle) = self.0.processor_handle.get() {
             processor_handle.abort();
@@ -751,6 +1035,114 @@ impl Shard {
         (shard_id, data)
     }
 
+    /// Shut down the shard in a resumable fashion and save its session to the
+    /// configured [`SessionStore`].
+    ///
+    /// The next time a shard with the same store and shard ID starts, it
+    /// resumes the saved session. Returns the saved session, or `None` if the
+    /// shard had no session to save, such as when it was never started.
+    ///
+    /// [`SessionStore`]: super::SessionStore
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SaveSessionErrorType::Saving`] error type if the store
+    /// failed to save the session. The shard is shut down regardless.
+    ///
+    /// Returns a [`SaveSessionErrorType::StoreMissing`] error type if the
+    /// shard wasn't configured with a session store, in which case the shard
+    /// isn't shut down.
+    pub async fn shutdown_and_save(&self) -> Result<Option<StoredSession>, SaveSessionError> {
+        let store = self.config().session_store().ok_or(SaveSessionError {
+            kind: SaveSessionErrorType::StoreMissing,
+            source: None,
+        })?;
+
+        let versions = self.session().ok().map(|session| session.versions());
+        let (_, resume) = self.shutdown_resumable();
+
+        let (resume, gateway_url, (read_state_version, user_guild_settings_version)) =
+            match (resume, self.0.gateway_url.get(), versions) {
+                (Some(resume), Some(gateway_url), Some(versions)) => {
+                    (resume, gateway_url, versions)
+                }
+                _ => return Ok(None),
+            };
+
+        let stored = StoredSession {
+            gateway_url: gateway_url.to_string(),
+            read_state_version,
+            sequence: resume.sequence,
+            session_id: resume.session_id,
+            shard: self.config().shard(),
+            user_guild_settings_version,
+        };
+
+        store
+            .save(&stored)
+            .await
+            .map_err(|source| SaveSessionError {
+                kind: SaveSessionErrorType::Saving,
+                source: Some(source),
+            })?;
+
+        Ok(Some(stored))
+    }
+
+    /// Load the session to resume from the session store, if there is one.
+    ///
+    /// Sessions provided through the configuration take precedence.
+    async fn load_session(&self) -> Option<StoredSession> {
+        let store = self.0.config.session_store()?;
+        let shard = self.0.config.shard();
+
+        if self.0.config.session_id.is_some() {
+            return None;
+        }
+
+        let stored = match store.load(shard).await {
+            Ok(stored) => stored?,
+            Err(source) => {
+                tracing::warn!(
+                    shard_id = shard[0],
+                    shard_total = shard[1],
+                    "failed to load stored session: {}",
+                    source,
+                );
+
+                return None;
+            }
+        };
+
+        if stored.is_valid_for(shard) {
+            tracing::debug!(
+                session_id = %stored.session_id,
+                shard_id = shard[0],
+                shard_total = shard[1],
+                "loaded stored session",
+            );
+
+            return Some(stored);
+        }
+
+        tracing::warn!(
+            shard_id = shard[0],
+            shard_total = shard[1],
+            "stored session is invalid for the shard, removing it",
+        );
+
+        if let Err(source) = store.remove(shard).await {
+            tracing::warn!(
+                shard_id = shard[0],
+                shard_total = shard[1],
+                "failed to remove stored session: {}",
+                source,
+            );
+        }
+
+        None
+    }
+
     /// Return a handle to the current session.
     ///
     /// # Errors
@@ -766,8 +1158,9 @@ impl Shard {
 #[cfg(test)]
 mod tests {
     use super::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
-        SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
+        CommandError, CommandErrorType, Information, ResumeSession, SaveSessionError,
+        SaveSessionErrorType, SendError, SendErrorType, SessionInactiveError, Shard,
+        ShardStartError, ShardStartErrorType,
     };
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{error::Error, fmt::Debug};
@@ -776,6 +1169,8 @@ mod tests {
     assert_impl_all!(CommandError: Error, Send, Sync);
     assert_impl_all!(Information: Clone, Debug, Send, Sync);
     assert_impl_all!(ResumeSession: Clone, Debug, Send, Sync);
+    assert_impl_all!(SaveSessionErrorType: Debug, Send, Sync);
+    assert_impl_all!(SaveSessionError: Error, Send, Sync);
     assert_impl_all!(SendErrorType: Debug, Send, Sync);
     assert_impl_all!(SendError: Error, Send, Sync);
     assert_impl_all!(SessionInactiveError: Error, Send, Sync);
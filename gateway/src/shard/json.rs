@@ -3,11 +3,13 @@ pub use serde_json::{from_slice, from_str, to_string, to_vec, Error as JsonError
 #[cfg(feature = "simd-json")]
 pub use simd_json::{from_slice, from_str, to_string, to_vec, Error as JsonError};
 
+use serde::Deserialize;
+use serde_json::value::RawValue;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::gateway::event::GatewayEvent;
+use twilight_model::gateway::event::{DispatchEvent, GatewayEvent};
 
 #[derive(Debug)]
 pub struct GatewayEventParsingError {
@@ -134,12 +136,70 @@ pub fn parse_gateway_event(
         })
 }
 
+/// Parse a dispatch event unknown to the library from a string, keeping its
+/// data as the raw JSON it was received as.
+///
+/// # Errors
+///
+/// Returns [`GatewayEventParsingError::Deserializing`] if the payload has no
+/// data.
+pub fn parse_unknown_dispatch(
+    sequence: u64,
+    event_type: &str,
+    json: &str,
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    #[derive(Deserialize)]
+    struct Payload<'a> {
+        #[serde(borrow)]
+        d: &'a RawValue,
+    }
+
+    let payload = serde_json::from_str::<Payload<'_>>(json).map_err(|source| {
+        tracing::debug!("invalid JSON: {}", json);
+
+        GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    })?;
+
+    Ok(GatewayEvent::Dispatch(
+        sequence,
+        Box::new(DispatchEvent::Unknown {
+            name: event_type.to_owned(),
+            data: payload.d.get().to_owned(),
+        }),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{GatewayEventParsingError, GatewayEventParsingErrorType};
+    use super::{parse_unknown_dispatch, GatewayEventParsingError, GatewayEventParsingErrorType};
     use static_assertions::assert_impl_all;
     use std::{error::Error, fmt::Debug};
+    use twilight_model::gateway::event::{DispatchEvent, GatewayEvent};
 
     assert_impl_all!(GatewayEventParsingErrorType: Debug, Send, Sync);
     assert_impl_all!(GatewayEventParsingError: Error, Send, Sync);
+
+    /// The data of unknown events is kept as it was received, without
+    /// reordering keys or changing whitespace.
+    #[test]
+    fn test_parse_unknown_dispatch() {
+        let data = r#"{"zeta": 1, "alpha": [{"b":2,"a":1}], "mid": null}"#;
+        let json = format!(r#"{{"t":"SESSIONS_REPLACE","s":5,"op":0,"d":{}}}"#, data);
+
+        match parse_unknown_dispatch(5, "SESSIONS_REPLACE", &json).unwrap() {
+            GatewayEvent::Dispatch(5, event) => assert_eq!(
+                DispatchEvent::Unknown {
+                    name: "SESSIONS_REPLACE".to_owned(),
+                    data: data.to_owned(),
+                },
+                *event
+            ),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        assert!(parse_unknown_dispatch(5, "SESSIONS_REPLACE", r#"{"op":0}"#).is_err());
+    }
 }
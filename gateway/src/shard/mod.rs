@@ -12,6 +12,10 @@
 //! Shards are configurable through the [`ShardBuilder`], which provides a clean
 //! interface for correctly configuring a shard.
 //!
+//! Sessions can outlive the process by configuring a [`SessionStore`], such as
+//! the [`FileSessionStore`]: sessions saved to it with
+//! [`Shard::shutdown_and_save`] are resumed the next time the shard starts.
+//!
 //! [`Event`]: ::twilight_model::gateway::event::Event
 //! [`Disconnected`]: Stage::Disconnected
 //! [`Resuming`]: Stage::Resuming
@@ -20,25 +24,40 @@
 //! [new messages]: ::twilight_model::gateway::event::Event::MessageCreate
 
 pub mod raw_message;
+pub mod recording;
 pub mod stage;
 
 mod builder;
+mod compression;
 mod config;
+mod encoding;
+#[cfg(feature = "etf")]
+mod etf;
 mod event;
 mod r#impl;
 mod json;
 mod processor;
+mod raw_dispatch;
+mod session_store;
 
 pub use self::{
     builder::{
         LargeThresholdError, LargeThresholdErrorType, ShardBuilder, ShardIdError, ShardIdErrorType,
     },
+    compression::Compression,
     config::Config,
-    event::Events,
+    encoding::Encoding,
+    event::{Events, OverflowPolicy},
     processor::heartbeat::Latency,
     r#impl::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
-        SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
+        CommandError, CommandErrorType, Information, ResumeSession, SaveSessionError,
+        SaveSessionErrorType, SendError, SendErrorType, SessionInactiveError, Shard,
+        ShardStartError, ShardStartErrorType,
+    },
+    raw_dispatch::{RawDispatch, RawDispatchError, RawDispatchErrorType, RawDispatches},
+    session_store::{
+        FileSessionStore, FileSessionStoreError, FileSessionStoreErrorType, SessionStore,
+        SessionStoreFuture, StoredSession,
     },
     stage::Stage,
 };
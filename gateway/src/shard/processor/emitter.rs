@@ -1,11 +1,16 @@
-use super::super::json;
+#[cfg(feature = "etf")]
+use super::super::etf;
+use super::super::{
+    json::{self, GatewayEventParsingError},
+    Encoding, RawDispatch,
+};
 use crate::{listener::Listeners, EventTypeFlags};
 use std::{
     convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::gateway::event::{shard::Payload, Event};
+use twilight_model::gateway::event::{shard::Payload, Event, GatewayEvent};
 
 #[derive(Debug)]
 pub struct EmitJsonError {
@@ -60,17 +65,22 @@ pub enum EmitJsonErrorType {
 #[derive(Clone, Debug)]
 pub struct Emitter {
     listeners: Listeners<Event>,
+    raw_listeners: Listeners<RawDispatch>,
 }
 
 impl Emitter {
-    /// Create a new emitter for events and bytes.
-    pub fn new(listeners: Listeners<Event>) -> Self {
-        Self { listeners }
+    /// Create a new emitter for events, bytes and raw dispatch events.
+    pub fn new(listeners: Listeners<Event>, raw_listeners: Listeners<RawDispatch>) -> Self {
+        Self {
+            listeners,
+            raw_listeners,
+        }
     }
 
-    /// Consume the emitter, returning the inner listeners.
-    pub fn into_listeners(self) -> Listeners<Event> {
-        self.listeners
+    /// Remove all listeners of events and raw dispatch events.
+    pub fn remove_all(self) {
+        self.listeners.remove_all();
+        self.raw_listeners.remove_all();
     }
 
     /// Determine if any of the listeners want a certain event type.
@@ -78,6 +88,12 @@ impl Emitter {
         self.listeners.event_types().contains(event_type)
     }
 
+    /// Wait until all listeners whose overflow policy is to block have room
+    /// for more events.
+    pub async fn writable(&self) {
+        self.listeners.writable().await;
+    }
+
     /// Send some bytes to listeners that have subscribed to shard payloads.
     ///
     /// Shard payload events aren't subscribed to by default and must be opted in
@@ -96,6 +112,31 @@ impl Emitter {
         });
     }
 
+    /// Send a dispatch event's payload to raw dispatch listeners that have
+    /// subscribed to its event type, without deserializing it.
+    ///
+    /// The payload is copied once and shared between the listeners.
+    pub fn raw(&self, seq: u64, event_type: &str, bytes: &[u8], encoding: Encoding) {
+        let flag = match EventTypeFlags::try_from((0, Some(event_type))) {
+            Ok(flag) => flag,
+            Err(_) => return,
+        };
+
+        if !self.raw_listeners.event_types().contains(flag) {
+            return;
+        }
+
+        let dispatch = RawDispatch::new(event_type, seq, bytes, encoding);
+
+        self.raw_listeners.all().retain(|_, listener| {
+            if !listener.wants(flag) {
+                return !listener.tx.is_closed();
+            }
+
+            listener.tx.send(dispatch.clone())
+        });
+    }
+
     /// Send an event to listeners that have subscribed to its event type.
     #[tracing::instrument(level = "trace")]
     pub fn event(&self, event: Event) {
@@ -134,6 +175,43 @@ impl Emitter {
         seq: Option<u64>,
         event_type: Option<&str>,
         json: &mut str,
+    ) -> Result<(), EmitJsonError> {
+        self.parsed(op, event_type, |flag| match (seq, event_type) {
+            (Some(seq), Some(event_type)) if flag == EventTypeFlags::UNKNOWN => {
+                json::parse_unknown_dispatch(seq, event_type, json)
+            }
+            _ => json::parse_gateway_event(op, seq, event_type, json),
+        })
+    }
+
+    /// Emit an ETF payload that hasn't been deserialized yet, but only if at
+    /// least one of the listeners wants the event type.
+    #[cfg(feature = "etf")]
+    pub fn etf(
+        &self,
+        op: u8,
+        seq: Option<u64>,
+        event_type: Option<&str>,
+        bytes: &[u8],
+    ) -> Result<(), EmitJsonError> {
+        self.parsed(op, event_type, |flag| match (seq, event_type) {
+            (Some(seq), Some(event_type)) if flag == EventTypeFlags::UNKNOWN => {
+                etf::parse_unknown_dispatch(seq, event_type, bytes)
+            }
+            _ => etf::parse_gateway_event(op, seq, event_type, bytes),
+        })
+    }
+
+    /// Parse and emit a payload, but only if at least one of the listeners
+    /// wants the event type.
+    ///
+    /// The parser is given the flag of the event type, since dispatch events
+    /// unknown to the library are parsed differently.
+    fn parsed(
+        &self,
+        op: u8,
+        event_type: Option<&str>,
+        parse: impl FnOnce(EventTypeFlags) -> Result<GatewayEvent, GatewayEventParsingError>,
     ) -> Result<(), EmitJsonError> {
         let flag = EventTypeFlags::try_from((op, event_type)).map_err(|(op, event_type)| {
             EmitJsonError {
@@ -149,13 +227,10 @@ impl Emitter {
             return Ok(());
         }
 
-        let gateway_event =
-            json::parse_gateway_event(op, seq, event_type, json).map_err(|source| {
-                EmitJsonError {
-                    kind: EmitJsonErrorType::Parsing,
-                    source: Some(Box::new(source)),
-                }
-            })?;
+        let gateway_event = parse(flag).map_err(|source| EmitJsonError {
+            kind: EmitJsonErrorType::Parsing,
+            source: Some(Box::new(source)),
+        })?;
         self.event(Event::from(gateway_event));
 
         Ok(())
@@ -184,14 +259,14 @@ impl Emitter {
                 return !listener.tx.is_closed();
             }
 
-            listener.tx.send(f(idx)).is_ok()
+            listener.tx.send(f(idx))
         });
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Emitter;
+    use super::{Emitter, Encoding, RawDispatch};
     use crate::{listener::Listeners, Event, EventTypeFlags};
     use tokio::time::{timeout, Duration};
 
@@ -199,7 +274,7 @@ mod tests {
     async fn test_bytes_send() {
         let listeners = Listeners::default();
         let mut rx = listeners.add(EventTypeFlags::SHARD_PAYLOAD);
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, Listeners::default());
         emitter.bytes(&[1]);
         assert_eq!(1, emitter.listeners.len());
 
@@ -211,7 +286,7 @@ mod tests {
     fn test_event_removes_closed_channels() {
         let listeners = Listeners::default();
         listeners.add(EventTypeFlags::default());
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, Listeners::default());
         emitter.event(Event::GatewayReconnect);
         assert!(emitter.listeners.all().is_empty());
     }
@@ -221,7 +296,7 @@ mod tests {
         let listeners = Listeners::default();
         let mut rx1 = listeners.add(EventTypeFlags::default());
         let mut rx2 = listeners.add(EventTypeFlags::default());
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, Listeners::default());
         emitter.event(Event::GatewayReconnect);
         assert_eq!(2, emitter.listeners.len());
 
@@ -236,4 +311,26 @@ mod tests {
             .await
             .is_err());
     }
+
+    #[tokio::test]
+    async fn test_raw_sends_to_raw_rxs() {
+        let listeners = Listeners::default();
+        let mut rx = listeners.add(EventTypeFlags::default());
+        let raw_listeners = Listeners::default();
+        let mut raw_rx = raw_listeners.add(EventTypeFlags::MESSAGE_ACK);
+        let emitter = Emitter::new(listeners, raw_listeners);
+
+        emitter.raw(2, "TYPING_START", b"{}", Encoding::Json);
+        emitter.raw(3, "MESSAGE_ACK", b"{}", Encoding::Json);
+        emitter.raw(4, "UNKNOWN", b"{}", Encoding::Json);
+
+        assert_eq!(
+            RawDispatch::new("MESSAGE_ACK", 3, b"{}", Encoding::Json),
+            raw_rx.recv().await.unwrap()
+        );
+        assert!(timeout(Duration::from_millis(10), raw_rx.recv())
+            .await
+            .is_err());
+        assert!(timeout(Duration::from_millis(10), rx.recv()).await.is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use super::{
-    super::json,
+    super::Encoding,
     session::{SessionSendError, SessionSendErrorType},
 };
 use serde::{Deserialize, Serialize};
@@ -175,6 +175,7 @@ impl Default for Heartbeats {
 }
 
 pub struct Heartbeater {
+    encoding: Encoding,
     heartbeats: Arc<Heartbeats>,
     interval: u64,
     seq: Arc<AtomicU64>,
@@ -183,12 +184,14 @@ pub struct Heartbeater {
 
 impl Heartbeater {
     pub fn new(
+        encoding: Encoding,
         heartbeats: Arc<Heartbeats>,
         interval: u64,
         seq: Arc<AtomicU64>,
         tx: UnboundedSender<TungsteniteMessage>,
     ) -> Self {
         Self {
+            encoding,
             heartbeats,
             interval,
             seq,
@@ -231,10 +234,13 @@ impl Heartbeater {
 
             let seq = self.seq.load(Ordering::Acquire);
             let heartbeat = Heartbeat::new(seq);
-            let bytes = json::to_vec(&heartbeat).map_err(|source| SessionSendError {
-                kind: SessionSendErrorType::Serializing,
-                source: Some(Box::new(source)),
-            })?;
+            let bytes = self
+                .encoding
+                .to_vec(&heartbeat)
+                .map_err(|source| SessionSendError {
+                    kind: SessionSendErrorType::Serializing,
+                    source: Some(source),
+                })?;
 
             tracing::debug!(seq, "sending heartbeat");
             self.tx
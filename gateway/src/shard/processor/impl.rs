@@ -1,20 +1,23 @@
-#[cfg(feature = "compression")]
+#[cfg(feature = "etf")]
+use super::super::etf;
 use super::inflater::Inflater;
 use super::{
     super::{
         config::Config,
         json::{self, GatewayEventParsingError, GatewayEventParsingErrorType},
         stage::Stage,
-        ShardStream,
+        Compression, Encoding, RawDispatch, ShardStream, StoredSession,
     },
-    emitter::{EmitJsonErrorType, Emitter},
+    emitter::{EmitJsonError, EmitJsonErrorType, Emitter},
     session::{Session, SessionSendError, SessionSendErrorType},
     socket_forwarder::SocketForwarder,
+    subscriptions::Subscriptions,
 };
 use crate::{event::EventTypeFlags, listener::Listeners};
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
+    convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     str,
@@ -35,9 +38,9 @@ use twilight_model::gateway::{
         DispatchEvent, Event, GatewayEvent, GatewayEventDeserializer,
     },
     payload::{
-        identify::{Identify, IdentifyInfo, IdentifyProperties},
+        identify::{Identify, IdentifyClientState, IdentifyInfo},
         resume::Resume,
-        Ready,
+        GuildSubscribe, Ready,
     },
     Intents, OpCode,
 };
@@ -61,6 +64,9 @@ impl ConnectingError {
 impl Display for ConnectingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ConnectingErrorType::Decompressing => {
+                f.write_str("failed to create the decompression context")
+            }
             ConnectingErrorType::Establishing => f.write_str("failed to establish the connection"),
             ConnectingErrorType::ParsingUrl { url } => {
                 f.write_fmt(format_args!("the gateway url `{}` is invalid", url,))
@@ -81,6 +87,7 @@ impl Error for ConnectingError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ConnectingErrorType {
+    Decompressing,
     Establishing,
     ParsingUrl { url: String },
 }
@@ -92,6 +99,19 @@ struct ProcessError {
 }
 
 impl ProcessError {
+    fn from_emit(source: EmitJsonError) -> Self {
+        let (kind, source) = source.into_parts();
+
+        let kind = match kind {
+            EmitJsonErrorType::Parsing => ProcessErrorType::ParsingPayload,
+            EmitJsonErrorType::EventTypeUnknown { event_type, op } => {
+                ProcessErrorType::EventTypeUnknown { event_type, op }
+            }
+        };
+
+        Self { kind, source }
+    }
+
     fn fatal(&self) -> bool {
         matches!(
             self.kind,
@@ -168,14 +188,7 @@ impl ReceivingEventError {
     }
 
     fn reconnectable(&self) -> bool {
-        #[cfg(feature = "compression")]
-        {
-            matches!(self.kind, ReceivingEventErrorType::Decompressing)
-        }
-        #[cfg(not(feature = "compression"))]
-        {
-            false
-        }
+        matches!(self.kind, ReceivingEventErrorType::Decompressing)
     }
 
     fn resumable(&self) -> bool {
@@ -189,7 +202,6 @@ impl Display for ReceivingEventError {
             ReceivingEventErrorType::AuthorizationInvalid { shard_id, .. } => f.write_fmt(
                 format_args!("the authorization token for shard {} is invalid", shard_id),
             ),
-            #[cfg(feature = "compression")]
             ReceivingEventErrorType::Decompressing => {
                 f.write_str("a frame could not be decompressed")
             }
@@ -225,7 +237,6 @@ impl Error for ReceivingEventError {
 enum ReceivingEventErrorType {
     /// Provided authorization token is invalid.
     AuthorizationInvalid { shard_id: u64, token: String },
-    #[cfg(feature = "compression")]
     /// Decompressing a frame from Discord failed.
     Decompressing,
     /// The event stream has ended, this is recoverable by resuming.
@@ -262,13 +273,11 @@ struct ReadyMinimal {
 pub struct ShardProcessor {
     pub config: Arc<Config>,
     pub emitter: Emitter,
-    pub properties: IdentifyProperties,
     pub rx: UnboundedReceiver<Message>,
     pub session: Arc<Session>,
-    #[cfg(feature = "compression")]
+    pub subscriptions: Arc<Subscriptions>,
+    client_state: IdentifyClientState,
     inflater: Inflater,
-    #[cfg(not(feature = "compression"))]
-    buffer: Vec<u8>,
     url: Box<str>,
     resume: Option<(u64, Box<str>)>,
     wtx: WatchSender<Arc<Session>>,
@@ -279,10 +288,19 @@ impl ShardProcessor {
         config: Arc<Config>,
         mut url: String,
         listeners: Listeners<Event>,
+        raw_listeners: Listeners<RawDispatch>,
+        subscriptions: Arc<Subscriptions>,
+        stored: Option<StoredSession>,
     ) -> Result<(Self, WatchReceiver<Arc<Session>>), ConnectingError> {
         //if we got resume info we don't need to wait
         let shard_id = config.shard();
-        let resumable = config.sequence.is_some() && config.session_id.is_some();
+        let resume = match (config.session_id.clone(), config.sequence) {
+            (Some(session_id), Some(sequence)) => Some((session_id, sequence)),
+            _ => stored
+                .as_ref()
+                .map(|stored| (stored.session_id.clone().into_boxed_str(), stored.sequence)),
+        };
+        let resumable = resume.is_some();
         if !resumable {
             tracing::debug!("shard {:?} is not resumable", shard_id);
             tracing::debug!("shard {:?} queued", shard_id);
@@ -290,14 +308,22 @@ impl ShardProcessor {
             tracing::debug!("shard {:?} finished queue", config.shard());
         }
 
-        let properties = IdentifyProperties::default_windows_desktop();
+        url.push_str("?encoding=");
+        url.push_str(config.encoding().name());
+        url.push_str("&v=9");
+
+        if let Some(compression) = config.compression().name() {
+            url.push_str("&compress=");
+            url.push_str(compression);
+        }
 
-        #[cfg(feature = "compression")]
-        url.push_str("?encoding=json&v=9&compress=zlib-stream");
-        #[cfg(not(feature = "compression"))]
-        url.push_str("?encoding=json&v=9");
+        let inflater =
+            Inflater::new(config.compression(), shard_id).map_err(|source| ConnectingError {
+                kind: ConnectingErrorType::Decompressing,
+                source: Some(source),
+            })?;
 
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, raw_listeners);
         emitter.event(Event::ShardConnecting(Connecting {
             gateway: url.clone(),
             shard_id: config.shard()[0],
@@ -308,26 +334,34 @@ impl ShardProcessor {
             forwarder.run().await;
         });
 
-        let session = Arc::new(Session::new(tx));
-        if resumable {
-            session.set_id(config.session_id.clone().unwrap());
-            session
-                .seq
-                .store(config.sequence.unwrap(), Ordering::Relaxed)
+        let session = Arc::new(Session::new(tx, config.encoding()));
+        if let Some((session_id, sequence)) = resume {
+            session.set_id(session_id);
+            session.seq.store(sequence, Ordering::Relaxed);
+        }
+
+        // The stored versions are only kept for the resume attempt. A restarted
+        // process has nothing cached, so if the resume fails the shard has to
+        // identify with the configured client state to get a full `READY`.
+        if let Some(stored) = stored {
+            session.set_versions(
+                stored.read_state_version,
+                stored.user_guild_settings_version,
+            );
         }
 
+        let client_state = config.client_state().clone();
+
         let (wtx, wrx) = watch_channel(Arc::clone(&session));
 
         let mut processor = Self {
             config,
             emitter,
-            properties,
             rx,
             session,
-            #[cfg(feature = "compression")]
-            inflater: Inflater::new(shard_id),
-            #[cfg(not(feature = "compression"))]
-            buffer: Vec::new(),
+            subscriptions,
+            client_state,
+            inflater,
             url: url.into_boxed_str(),
             resume: None,
             wtx,
@@ -343,6 +377,10 @@ impl ShardProcessor {
 
     pub async fn run(mut self) {
         loop {
+            // Apply backpressure by not reading more payloads until bounded
+            // listeners that block have room.
+            self.emitter.writable().await;
+
             match self.next_payload().await {
                 Ok(v) => v,
                 Err(source) => {
@@ -380,26 +418,25 @@ impl ShardProcessor {
             }
         }
 
-        self.emitter.into_listeners().remove_all();
+        self.emitter.remove_all();
     }
 
     #[allow(clippy::too_many_lines)]
     async fn process(&mut self) -> Result<(), ProcessError> {
+        #[cfg(feature = "etf")]
+        {
+            if self.config.encoding() == Encoding::Etf {
+                return self.process_etf().await;
+            }
+        }
+
         let (op, seq, event_type) = {
-            #[cfg(feature = "compression")]
             let json =
                 str::from_utf8_mut(self.inflater.buffer_mut()).map_err(|source| ProcessError {
                     kind: ProcessErrorType::PayloadNotUtf8,
                     source: Some(Box::new(source)),
                 })?;
 
-            #[cfg(not(feature = "compression"))]
-            let json =
-                str::from_utf8_mut(self.buffer.as_mut_slice()).map_err(|source| ProcessError {
-                    kind: ProcessErrorType::PayloadNotUtf8,
-                    source: Some(Box::new(source)),
-                })?;
-
             tracing::trace!(%json, "Received JSON");
             let emitter = self.emitter.clone();
 
@@ -414,7 +451,6 @@ impl ShardProcessor {
                     // should be a good trade-off either way.
                     (op, seq, event_type.map(ToOwned::to_owned))
                 } else {
-                    #[cfg(feature = "compression")]
                     tracing::warn!(
                         json = ?self.inflater.buffer_ref(),
                         shard_id = self.config.shard()[0],
@@ -423,15 +459,6 @@ impl ShardProcessor {
                         stage = ?self.session.stage(),
                         "received payload without opcode",
                     );
-                    #[cfg(not(feature = "compression"))]
-                    tracing::warn!(
-                        json = ?self.buffer,
-                        shard_id = self.config.shard()[0],
-                        shard_total = self.config.shard()[1],
-                        seq = self.session.seq(),
-                        stage = ?self.session.stage(),
-                        "received payload without opcode",
-                    );
 
                     return Err(ProcessError {
                         kind: ProcessErrorType::ParsingPayload,
@@ -442,6 +469,14 @@ impl ShardProcessor {
                     });
                 };
 
+            // Raw dispatch listeners get the payload before it's parsed, since
+            // parsing may modify the buffer.
+            if op == u8::from(OpCode::Event) {
+                if let (Some(seq), Some(event_type)) = (seq, event_type.as_deref()) {
+                    emitter.raw(seq, event_type, json.as_bytes(), Encoding::Json);
+                }
+            }
+
             // We can do a few little optimisation tricks here. For the
             // "heartbeat ack" and "reconnect" opcodes we can construct
             // the gateway events without needing to go through a serde
@@ -455,9 +490,9 @@ impl ShardProcessor {
             if matches!(op, 1 | 7 | 9 | 10 | 11) {
                 // Have to use an if statement here if we want to use the OpCode
                 // enum, since matching with repr values isn't allowed.
-                let gateway_event = if op == OpCode::HeartbeatAck as u8 {
+                let gateway_event = if op == u8::from(OpCode::HeartbeatAck) {
                     GatewayEvent::HeartbeatAck
-                } else if op == OpCode::Reconnect as u8 {
+                } else if op == u8::from(OpCode::Reconnect) {
                     GatewayEvent::Reconnect
                 } else {
                     json::parse_gateway_event(op, seq, event_type.as_deref(), json).map_err(
@@ -495,10 +530,7 @@ impl ShardProcessor {
 
                 return Ok(());
             } else if event_type.as_deref() == Some("READY") {
-                #[cfg(feature = "compression")]
                 let buf_ref = self.inflater.buffer_mut();
-                #[cfg(not(feature = "compression"))]
-                let buf_ref = self.buffer.as_mut_slice();
 
                 let ready =
                     json::from_slice::<ReadyMinimal>(buf_ref).map_err(|source| ProcessError {
@@ -509,7 +541,7 @@ impl ShardProcessor {
                         })),
                     })?;
 
-                self.process_ready(&ready.d);
+                self.process_ready(seq, &ready.d);
                 emitter.event(Event::Ready(Box::new(ready.d)));
 
                 return Ok(());
@@ -522,42 +554,129 @@ impl ShardProcessor {
 
         // We already know from earlier that the payload is valid UTF-8, so we
         // can skip having to re-validate here since it hasn't been mutated.
-        #[cfg(feature = "compression")]
         let json = unsafe { str::from_utf8_unchecked_mut(self.inflater.buffer_mut()) };
-        #[cfg(not(feature = "compression"))]
-        let json = unsafe { str::from_utf8_unchecked_mut(self.buffer.as_mut_slice()) };
 
         self.emitter
             .json(op, Some(seq), event_type.as_deref(), json)
-            .map_err(|source| {
-                let (kind, source) = source.into_parts();
+            .map_err(ProcessError::from_emit)
+    }
 
-                let new_kind = match kind {
-                    EmitJsonErrorType::Parsing => ProcessErrorType::ParsingPayload,
-                    EmitJsonErrorType::EventTypeUnknown { event_type, op } => {
-                        ProcessErrorType::EventTypeUnknown { event_type, op }
-                    }
-                };
+    /// Process a payload encoded in Erlang's external term format.
+    ///
+    /// Payloads go through the same steps as JSON payloads do in [`process`],
+    /// but the opcode, sequence and event type can't be found without
+    /// decoding the term.
+    ///
+    /// [`process`]: Self::process
+    #[cfg(feature = "etf")]
+    async fn process_etf(&mut self) -> Result<(), ProcessError> {
+        let parsing = |source: GatewayEventParsingError| ProcessError {
+            kind: ProcessErrorType::ParsingPayload,
+            source: Some(Box::new(source)),
+        };
+        let emitter = self.emitter.clone();
 
-                ProcessError {
-                    kind: new_kind,
-                    source,
-                }
+        let bytes = self.inflater.buffer_ref();
+
+        let (op, seq, event_type) = etf::parts(bytes).ok_or_else(|| {
+            tracing::warn!(
+                shard_id = self.config.shard()[0],
+                shard_total = self.config.shard()[1],
+                seq = self.session.seq(),
+                stage = ?self.session.stage(),
+                "received payload without opcode",
+            );
+
+            parsing(GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::PayloadInvalid,
+                source: None,
             })
+        })?;
+
+        if op == u8::from(OpCode::Event) {
+            if let (Some(seq), Some(event_type)) = (seq, event_type.as_deref()) {
+                emitter.raw(seq, event_type, bytes, Encoding::Etf);
+            }
+        }
+
+        if matches!(op, 1 | 7 | 9 | 10 | 11) {
+            let gateway_event =
+                etf::parse_gateway_event(op, seq, event_type.as_deref(), bytes).map_err(parsing)?;
+
+            self.process_gateway_event(&gateway_event).await?;
+            emitter.event(Event::from(gateway_event));
+
+            if let Some(seq) = seq {
+                self.session.set_seq(seq);
+            }
+
+            return Ok(());
+        }
+
+        let seq = seq.ok_or(ProcessError {
+            kind: ProcessErrorType::SequenceMissing,
+            source: None,
+        })?;
+
+        match event_type.as_deref() {
+            Some("RESUMED") => {
+                self.process_resumed(seq);
+
+                if emitter.wants(EventTypeFlags::RESUMED) {
+                    let gateway_event =
+                        GatewayEvent::Dispatch(seq, Box::new(DispatchEvent::Resumed));
+
+                    emitter.event(Event::from(gateway_event));
+                }
+
+                Ok(())
+            }
+            Some("READY") => {
+                let ready = etf::from_slice::<ReadyMinimal>(bytes).map_err(|source| {
+                    parsing(GatewayEventParsingError {
+                        kind: GatewayEventParsingErrorType::Deserializing,
+                        source: Some(Box::new(source)),
+                    })
+                })?;
+
+                self.process_ready(seq, &ready.d);
+                emitter.event(Event::Ready(Box::new(ready.d)));
+
+                Ok(())
+            }
+            event_type => {
+                self.session.set_seq(seq);
+
+                emitter
+                    .etf(op, Some(seq), event_type, bytes)
+                    .map_err(ProcessError::from_emit)
+            }
+        }
     }
 
-    fn process_ready(&mut self, ready: &Ready) {
+    fn process_ready(&mut self, seq: u64, ready: &Ready) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
+        self.session.set_seq(seq);
         self.session.set_stage(Stage::Connected);
         self.session
             .set_id(ready.session_id.clone().into_boxed_str());
+        let user_guild_settings_version =
+            i64::try_from(ready.user_guild_settings.version).unwrap_or(-1);
+        self.session
+            .set_versions(ready.read_state.version, user_guild_settings_version);
+
+        // Everything in this `READY` has been received, so later identifies
+        // only need what changed since then.
+        self.client_state.read_state_version = ready.read_state.version;
+        self.client_state.user_guild_settings_version = user_guild_settings_version;
 
         self.emitter.event(Event::ShardConnected(Connected {
             heartbeat_interval: self.session.heartbeat_interval(),
             shard_id: self.config.shard()[0],
         }));
+        self.resubscribe();
     }
 
     fn process_resumed(&self, seq: u64) {
@@ -571,6 +690,26 @@ impl ShardProcessor {
             shard_id: self.config.shard()[0],
         }));
         self.session.heartbeats.receive();
+        self.resubscribe();
+    }
+
+    /// Send the recorded guild subscriptions over the current session.
+    ///
+    /// Subscriptions belong to a session, so they need to be sent again once a
+    /// new session is started or an old one is resumed.
+    fn resubscribe(&self) {
+        for info in self.subscriptions.all() {
+            let guild_id = info.guild_id;
+
+            if let Err(source) = self.session.send(GuildSubscribe::new(info)) {
+                tracing::warn!(
+                    shard_id = self.config.shard()[0],
+                    %guild_id,
+                    "failed to resend guild subscription: {}",
+                    source,
+                );
+            }
+        }
     }
 
     async fn process_gateway_event(&mut self, event: &GatewayEvent) -> Result<(), ProcessError> {
@@ -712,10 +851,7 @@ impl ShardProcessor {
     /// Returns [`ReceivingEventError::AuthorizationInvalid`] if the provided authorization
     /// is invalid.
     async fn next_payload(&mut self) -> Result<(), ReceivingEventError> {
-        #[cfg(feature = "compression")]
         self.inflater.clear();
-        #[cfg(not(feature = "compression"))]
-        self.buffer.clear();
 
         loop {
             // Returns None when the socket forwarder has ended, meaning the
@@ -744,62 +880,25 @@ impl ShardProcessor {
     ///
     /// If a ping or pong are received, then they are ignored.
     ///
-    /// Text messages aren't sent by Discord, so they are left unhandled.
+    /// Text messages are only sent by Discord without transport compression,
+    /// so they are left unhandled otherwise.
     async fn handle_message<'a>(
         &'a mut self,
         msg: &'a mut Message,
     ) -> Result<bool, ReceivingEventError> {
         match msg {
-            #[allow(unused_variables)]
-            Message::Binary(bin) => {
-                #[cfg(feature = "compression")]
-                {
-                    self.inflater.extend(&bin[..]);
-
-                    let bytes = match self.inflater.msg() {
-                        Ok(Some(bytes)) => bytes,
-                        Ok(None) => return Ok(false),
-                        Err(source) => {
-                            return Err(ReceivingEventError {
-                                kind: ReceivingEventErrorType::Decompressing,
-                                source: Some(Box::new(source)),
-                            })
-                        }
-                    };
-
-                    self.emitter.bytes(bytes);
-
-                    Ok(true)
-                }
-                #[cfg(not(feature = "compression"))]
-                {
-                    /* Intentionally left empty as Discord should
-                     * not send binary payloads when not using compression
-                     */
-                    Ok(false)
-                }
-            }
+            Message::Binary(bin) => self.inflate(bin),
             Message::Close(close_frame) => {
                 self.handle_close(close_frame.as_ref()).await?;
 
                 Ok(false)
             }
-            #[allow(unused_variables)]
             Message::Text(json) => {
-                #[cfg(feature = "compression")]
-                {
-                    /* Empty intentionally as discord does not
-                     * send text when comppresion is enabled
-                     */
-                    Ok(false)
+                if self.config.compression() != Compression::None {
+                    return Ok(false);
                 }
-                #[cfg(not(feature = "compression"))]
-                {
-                    self.emitter.bytes(json.as_bytes());
-                    self.buffer.extend_from_slice(json.as_bytes());
 
-                    Ok(true)
-                }
+                self.inflate(json.as_bytes())
             }
             // Discord doesn't appear to send Text messages, so we can ignore
             // these.
@@ -807,6 +906,27 @@ impl ShardProcessor {
         }
     }
 
+    /// Add the bytes of a message to the inflater, returning whether a
+    /// decompressed message buffer is available.
+    fn inflate(&mut self, bytes: &[u8]) -> Result<bool, ReceivingEventError> {
+        self.inflater.extend(bytes);
+
+        let bytes = match self.inflater.msg() {
+            Ok(Some(bytes)) => bytes,
+            Ok(None) => return Ok(false),
+            Err(source) => {
+                return Err(ReceivingEventError {
+                    kind: ReceivingEventErrorType::Decompressing,
+                    source: Some(source),
+                })
+            }
+        };
+
+        self.emitter.bytes(bytes);
+
+        Ok(true)
+    }
+
     async fn handle_close(
         &mut self,
         close_frame: Option<&CloseFrame<'_>>,
@@ -892,10 +1012,23 @@ impl ShardProcessor {
     async fn identify(&mut self) -> Result<(), SessionSendError> {
         self.session.set_stage(Stage::Identifying);
 
-        let identify = Identify::new(IdentifyInfo::new(
+        let mut info = IdentifyInfo::new(
             self.config.token(),
-            self.properties.clone(),
-        ));
+            self.config.identify_properties().clone(),
+        );
+        info.capabilities = self.config.capabilities();
+        info.client_state = self.client_state.clone();
+        // Discord doesn't allow payload compression with transport
+        // compression, and payloads can only be decompressed with zlib.
+        info.compress = cfg!(feature = "compression")
+            && self.config.compression() == Compression::None
+            && self.config.identify_compress();
+
+        if let Some(presence) = self.config.presence() {
+            info.presence = Some(presence.clone());
+        }
+
+        let identify = Identify::new(info);
         self.emitter.event(Event::ShardIdentifying(Identifying {
             shard_id: self.config.shard()[0],
             shard_total: self.config.shard()[1],
@@ -1006,16 +1139,22 @@ impl ShardProcessor {
         tokio::spawn(forwarder.run());
 
         self.rx = rx;
-        self.session = Arc::new(Session::new(tx));
+
+        let (read_state_version, user_guild_settings_version) = self.session.versions();
+        self.session = Arc::new(Session::new(tx, self.config.encoding()));
+        self.session
+            .set_versions(read_state_version, user_guild_settings_version);
 
         if let Err(why) = self.wtx.send(Arc::clone(&self.session)) {
             tracing::error!("failed to broadcast new session: {:?}", why);
         }
 
         self.session.set_stage(stage);
-        #[cfg(feature = "compression")]
-        self.inflater.reset();
-        #[cfg(not(feature = "compression"))]
-        self.buffer.clear();
+
+        // Payloads that fail to decompress with the old context make the
+        // shard reconnect again.
+        if let Err(source) = self.inflater.reset() {
+            tracing::error!("failed to reset the inflater: {}", source);
+        }
     }
 }
@@ -1,30 +1,100 @@
-use flate2::{Decompress, DecompressError, FlushDecompress};
-use std::{convert::TryInto, mem, time::Instant};
+use crate::shard::Compression;
+#[cfg(feature = "compression")]
+use flate2::{Decompress, FlushDecompress, Status};
+#[cfg(feature = "compression")]
+use std::convert::TryInto;
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
+    time::Instant,
+};
+#[cfg(feature = "zstd")]
+use zstd::stream::raw::{Decoder as ZstdDecoder, InBuffer, Operation, OutBuffer};
 
+#[cfg(feature = "compression")]
 const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
 const INTERNAL_BUFFER_SIZE: usize = 32 * 1024;
 
+/// First byte of zlib streams using a 32 KiB window, which is what Discord
+/// compresses individual payloads with when asked to while identifying.
+///
+/// JSON payloads start with `{` and ETF payloads with the version number, so
+/// this doesn't conflict with uncompressed payloads.
+#[cfg(feature = "compression")]
+const ZLIB_HEADER: u8 = 0x78;
+
+/// Decompression context of the transport.
+enum Decoder {
+    /// Messages are complete payloads, which may each be compressed when
+    /// payload compression was requested.
+    None,
+    /// Messages share a zlib context, with payloads ending in a sync flush.
+    #[cfg(feature = "compression")]
+    Zlib(Decompress),
+    /// Messages share a zstd context, with each message being a payload.
+    #[cfg(feature = "zstd")]
+    Zstd(ZstdDecoder<'static>),
+}
+
+impl Decoder {
+    fn new(compression: Compression) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(match compression {
+            Compression::None => Self::None,
+            #[cfg(feature = "compression")]
+            Compression::ZlibStream => Self::Zlib(Decompress::new(true)),
+            #[cfg(feature = "zstd")]
+            Compression::ZstdStream => Self::Zstd(ZstdDecoder::new()?),
+        })
+    }
+}
+
+impl Debug for Decoder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Self::None => "None",
+            #[cfg(feature = "compression")]
+            Self::Zlib(_) => "Zlib",
+            #[cfg(feature = "zstd")]
+            Self::Zstd(_) => "Zstd",
+        })
+    }
+}
+
+/// Buffer of the messages received from the gateway, decompressing them into
+/// payloads according to the shard's compression.
 #[derive(Debug)]
 pub struct Inflater {
-    decompress: Decompress,
+    compression: Compression,
+    decoder: Decoder,
     compressed: Vec<u8>,
-    internal_buffer: Vec<u8>,
     buffer: Vec<u8>,
     last_resize: Instant,
     shard: [u64; 2],
+    total_in: u64,
+    total_out: u64,
 }
 
 impl Inflater {
     /// Create a new inflater for a shard.
-    pub fn new(shard: [u64; 2]) -> Self {
-        Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decompression context couldn't be created.
+    pub fn new(
+        compression: Compression,
+        shard: [u64; 2],
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
             buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
             compressed: Vec::new(),
-            decompress: Decompress::new(true),
-            internal_buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            compression,
+            decoder: Decoder::new(compression)?,
             last_resize: Instant::now(),
             shard,
-        }
+            total_in: 0,
+            total_out: 0,
+        })
     }
 
     /// Return an immutable reference to the buffer.
@@ -48,46 +118,73 @@ impl Inflater {
     ///
     /// # Errors
     ///
-    /// This returns `flate2`'s `DecompressError` as its method's type signature
-    /// indicates it can return an error, however in reality in versions up to
-    /// 1.0.17 it won't.
+    /// Returns an error if the received bytes couldn't be decompressed.
     #[tracing::instrument(level = "trace")]
-    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, DecompressError> {
-        let length = self.compressed.len();
-
-        // Check if a partial payload was received. If it was, we can just
-        // return that no decompressed message is available.
-        if length < 4 || self.compressed[(length - 4)..] != ZLIB_SUFFIX {
-            return Ok(None);
-        }
+    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, Box<dyn Error + Send + Sync>> {
+        match &mut self.decoder {
+            Decoder::None => {
+                #[cfg(feature = "compression")]
+                {
+                    if self.compressed.first() == Some(&ZLIB_HEADER) {
+                        let mut decompress = Decompress::new(true);
 
-        let before = self.decompress.total_in();
-        let mut offset = 0;
-
-        loop {
-            self.internal_buffer.clear();
+                        Self::inflate(
+                            &mut decompress,
+                            &self.compressed,
+                            &mut self.buffer,
+                            FlushDecompress::Finish,
+                        )?;
+                    } else {
+                        self.buffer.extend_from_slice(&self.compressed);
+                    }
+                }
+                #[cfg(not(feature = "compression"))]
+                self.buffer.extend_from_slice(&self.compressed);
+            }
+            #[cfg(feature = "compression")]
+            Decoder::Zlib(decompress) => {
+                let length = self.compressed.len();
 
-            self.decompress.decompress_vec(
-                &self.compressed[offset..],
-                &mut self.internal_buffer,
-                FlushDecompress::Sync,
-            )?;
+                // Check if a partial payload was received. If it was, we can
+                // just return that no decompressed message is available.
+                if length < 4 || self.compressed[(length - 4)..] != ZLIB_SUFFIX {
+                    return Ok(None);
+                }
 
-            offset = (self.decompress.total_in() - before)
-                .try_into()
-                .unwrap_or_default();
-            self.buffer.extend_from_slice(&self.internal_buffer[..]);
+                Self::inflate(
+                    decompress,
+                    &self.compressed,
+                    &mut self.buffer,
+                    FlushDecompress::Sync,
+                )?;
+            }
+            #[cfg(feature = "zstd")]
+            Decoder::Zstd(decoder) => {
+                let mut input = InBuffer::around(&self.compressed);
 
-            let not_at_capacity = self.internal_buffer.len() < self.internal_buffer.capacity();
+                loop {
+                    self.buffer.reserve(INTERNAL_BUFFER_SIZE);
+                    let start = self.buffer.len();
+                    let mut output = OutBuffer::around_pos(&mut self.buffer, start);
+                    decoder.run(&mut input, &mut output)?;
+                    let written = output.pos();
 
-            if not_at_capacity || offset > self.compressed.len() {
-                break;
+                    // Discord flushes the context after each message, so the
+                    // payload is complete once the output buffer isn't filled.
+                    if input.pos() == self.compressed.len() && written < self.buffer.capacity() {
+                        break;
+                    }
+                }
             }
         }
 
+        self.total_in += self.compressed.len() as u64;
+        self.total_out += self.buffer.len() as u64;
+
         tracing::trace!(
             bytes_in = self.compressed.len(),
             bytes_out = self.buffer.len(),
+            compression = ?self.compression,
             shard_id = self.shard[0],
             shard_total = self.shard[1],
             "payload lengths",
@@ -96,19 +193,18 @@ impl Inflater {
 
         // It doesn't matter if we lose precision for logging.
         #[allow(clippy::cast_precision_loss)]
-        let saved_percentage =
-            self.decompress.total_in() as f64 / self.decompress.total_out() as f64;
+        let saved_percentage = self.total_in as f64 / self.total_out as f64;
         let saved_percentage_readable = saved_percentage * 100.0;
 
-        let saved_kib = (self.decompress.total_out() - self.decompress.total_in()) / 1_024;
+        let saved_kib = self.total_out.saturating_sub(self.total_in) / 1_024;
 
         tracing::trace!(
             saved_kib = saved_kib,
             saved_percentage = %saved_percentage_readable,
             shard_id = self.shard[0],
             shard_total = self.shard[1],
-            total_in = self.decompress.total_in(),
-            total_out = self.decompress.total_out(),
+            total_in = self.total_in,
+            total_out = self.total_out,
             "data saved",
         );
 
@@ -119,6 +215,34 @@ impl Inflater {
         Ok(Some(&mut self.buffer))
     }
 
+    /// Inflate zlib compressed bytes into the buffer.
+    #[cfg(feature = "compression")]
+    fn inflate(
+        decompress: &mut Decompress,
+        compressed: &[u8],
+        buffer: &mut Vec<u8>,
+        flush: FlushDecompress,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let before = decompress.total_in();
+
+        loop {
+            let offset: usize = (decompress.total_in() - before)
+                .try_into()
+                .unwrap_or_default();
+
+            buffer.reserve(INTERNAL_BUFFER_SIZE);
+            let status = decompress.decompress_vec(&compressed[offset..], buffer, flush)?;
+
+            let not_at_capacity = buffer.len() < buffer.capacity();
+
+            if status == Status::StreamEnd || not_at_capacity || offset > compressed.len() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Clear the buffer and shrink it if the capacity is too large.
     ///
     /// If the capacity is 4 times larger than the buffer length then the
@@ -128,13 +252,18 @@ impl Inflater {
         self.shrink();
 
         self.compressed.clear();
-        self.internal_buffer.clear();
         self.buffer.clear();
     }
 
     /// Reset the state of the inflater back to its default state.
-    pub fn reset(&mut self) {
-        let _old_inflater = mem::replace(self, Self::new(self.shard));
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decompression context couldn't be created.
+    pub fn reset(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let _old_inflater = mem::replace(self, Self::new(self.compression, self.shard)?);
+
+        Ok(())
     }
 
     /// Log metrics about the inflater.
@@ -147,11 +276,11 @@ impl Inflater {
         );
         metrics::gauge!(
             format!("Inflater-In-{}", self.shard[0]),
-            self.decompress.total_in() as f64
+            self.total_in as f64
         );
         metrics::gauge!(
             format!("Inflater-Out-{}", self.shard[0]),
-            self.decompress.total_out() as f64
+            self.total_out as f64
         );
     }
 
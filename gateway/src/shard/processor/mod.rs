@@ -2,14 +2,15 @@ pub mod heartbeat;
 
 mod emitter;
 mod r#impl;
-#[cfg(feature = "compression")]
 mod inflater;
 mod session;
 mod socket_forwarder;
+mod subscriptions;
 mod throttle;
 
 pub use self::{
     heartbeat::Latency,
     r#impl::{ConnectingError, ConnectingErrorType, ShardProcessor},
     session::Session,
+    subscriptions::Subscriptions,
 };
@@ -1,5 +1,5 @@
 use super::{
-    super::{json, stage::Stage},
+    super::{stage::Stage, Encoding},
     heartbeat::{Heartbeater, Heartbeats},
     throttle::Throttle,
 };
@@ -9,7 +9,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::{
-        atomic::{AtomicU64, AtomicU8, Ordering},
+        atomic::{AtomicI64, AtomicU64, AtomicU8, Ordering},
         Arc, Mutex as MutexSync,
     },
     time::Duration,
@@ -40,7 +40,7 @@ impl SessionSendError {
 impl Display for SessionSendError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
-            SessionSendErrorType::Serializing => f.write_str("failed to serialize payload as json"),
+            SessionSendErrorType::Serializing => f.write_str("failed to serialize payload"),
             SessionSendErrorType::Sending => f.write_str("failed to send message over websocket"),
         }
     }
@@ -63,30 +63,36 @@ pub enum SessionSendErrorType {
 
 #[derive(Debug)]
 pub struct Session {
+    pub encoding: Encoding,
     // Needs to be Arc so it can be cloned in the `Drop` impl when spawned on
     // the runtime.
     pub heartbeater_handle: Arc<MutexSync<Option<JoinHandle<()>>>>,
     pub heartbeats: Arc<Heartbeats>,
     pub heartbeat_interval: AtomicU64,
     pub id: MutexSync<Option<Box<str>>>,
+    pub read_state_version: AtomicU64,
     pub seq: Arc<AtomicU64>,
     pub stage: AtomicU8,
     pub tx: UnboundedSender<TungsteniteMessage>,
     pub ratelimit: Mutex<Throttle>,
+    pub user_guild_settings_version: AtomicI64,
 }
 
 impl Session {
-    pub fn new(tx: UnboundedSender<TungsteniteMessage>) -> Self {
+    pub fn new(tx: UnboundedSender<TungsteniteMessage>, encoding: Encoding) -> Self {
         Self {
+            encoding,
             heartbeater_handle: Arc::new(MutexSync::new(None)),
             heartbeats: Arc::new(Heartbeats::default()),
             heartbeat_interval: AtomicU64::new(0),
             id: MutexSync::new(None),
+            read_state_version: AtomicU64::new(0),
             seq: Arc::new(AtomicU64::new(0)),
             stage: AtomicU8::new(Stage::default() as u8),
             tx,
             // 520 instead of 500 to make sure that it can heartbeat.
             ratelimit: Mutex::new(Throttle::new(Duration::from_millis(520))),
+            user_guild_settings_version: AtomicI64::new(-1),
         }
     }
 
@@ -101,10 +107,13 @@ impl Session {
     /// receiving channel has hung up. This will only happen when the shard has
     /// either not started or has already shutdown.
     pub fn send(&self, payload: impl Serialize) -> Result<(), SessionSendError> {
-        let bytes = json::to_vec(&payload).map_err(|source| SessionSendError {
-            kind: SessionSendErrorType::Serializing,
-            source: Some(Box::new(source)),
-        })?;
+        let bytes = self
+            .encoding
+            .to_vec(&payload)
+            .map_err(|source| SessionSendError {
+                kind: SessionSendErrorType::Serializing,
+                source: Some(source),
+            })?;
 
         self.tx
             .send(TungsteniteMessage::Binary(bytes))
@@ -164,6 +173,23 @@ impl Session {
         self.id.lock().expect("id poisoned").replace(new_id);
     }
 
+    /// Returns the read state and user guild settings versions of the
+    /// session.
+    pub fn versions(&self) -> (u64, i64) {
+        (
+            self.read_state_version.load(Ordering::Relaxed),
+            self.user_guild_settings_version.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Sets the read state and user guild settings versions.
+    pub fn set_versions(&self, read_state_version: u64, user_guild_settings_version: i64) {
+        self.read_state_version
+            .store(read_state_version, Ordering::Release);
+        self.user_guild_settings_version
+            .store(user_guild_settings_version, Ordering::Release);
+    }
+
     pub fn stop_heartbeater(&self) {
         if let Some(handle) = self
             .heartbeater_handle
@@ -180,7 +206,8 @@ impl Session {
         let seq = Arc::clone(&self.seq);
         let heartbeats = Arc::clone(&self.heartbeats);
 
-        let heartbeater = Heartbeater::new(heartbeats, interval, seq, self.tx.clone()).run();
+        let heartbeater =
+            Heartbeater::new(self.encoding, heartbeats, interval, seq, self.tx.clone()).run();
         let handle = tokio::spawn(heartbeater);
 
         if let Some(old) = self
@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::Mutex};
+use twilight_model::{gateway::payload::guild_subscribe::GuildSubscribeInfo, id::GuildId};
+
+/// Guild subscriptions made by a shard.
+///
+/// Subscriptions are kept across sessions so that they can be sent again after
+/// the shard resumes or reconnects.
+#[derive(Debug, Default)]
+pub struct Subscriptions(Mutex<HashMap<GuildId, GuildSubscribeInfo>>);
+
+impl Subscriptions {
+    /// Return a copy of the subscription to a guild.
+    pub fn get(&self, guild_id: GuildId) -> Option<GuildSubscribeInfo> {
+        self.0
+            .lock()
+            .expect("subscriptions poisoned")
+            .get(&guild_id)
+            .cloned()
+    }
+
+    /// Record a subscription, merging it into an existing subscription to the
+    /// same guild.
+    pub fn insert(&self, info: GuildSubscribeInfo) {
+        let mut subscriptions = self.0.lock().expect("subscriptions poisoned");
+
+        if let Some(existing) = subscriptions.get_mut(&info.guild_id) {
+            existing.merge(info);
+        } else {
+            subscriptions.insert(info.guild_id, info);
+        }
+    }
+
+    /// Forget the subscription to a guild, returning it if there was one.
+    pub fn remove(&self, guild_id: GuildId) -> Option<GuildSubscribeInfo> {
+        self.0
+            .lock()
+            .expect("subscriptions poisoned")
+            .remove(&guild_id)
+    }
+
+    /// Return a copy of all recorded subscriptions.
+    pub fn all(&self) -> Vec<GuildSubscribeInfo> {
+        self.0
+            .lock()
+            .expect("subscriptions poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Subscriptions;
+    use twilight_model::{
+        gateway::payload::GuildSubscribe,
+        id::{ChannelId, GuildId},
+    };
+
+    #[test]
+    fn test_insert_merges() {
+        let subscriptions = Subscriptions::default();
+        subscriptions.insert(
+            GuildSubscribe::builder(GuildId(1))
+                .typing(true)
+                .channel(ChannelId(2), vec![(0, 99)])
+                .build()
+                .d,
+        );
+        subscriptions.insert(
+            GuildSubscribe::builder(GuildId(1))
+                .channel(ChannelId(3), vec![(0, 99)])
+                .build()
+                .d,
+        );
+
+        let info = subscriptions.get(GuildId(1)).expect("subscribed");
+        assert_eq!(Some(true), info.typing);
+        assert_eq!(2, info.channels.len());
+        assert_eq!(1, subscriptions.all().len());
+
+        assert!(subscriptions.remove(GuildId(1)).is_some());
+        assert!(subscriptions.get(GuildId(1)).is_none());
+    }
+}
@@ -0,0 +1,309 @@
+//! Dispatch events passed through without deserializing them.
+//!
+//! Deserializing every dispatch event into an [`Event`] isn't always needed,
+//! for example when events are only forwarded to another process or written to
+//! disk. A [`RawDispatches`] stream created with [`Shard::raw_dispatches`]
+//! yields [`RawDispatch`]es containing the payload as it was received, which
+//! can be deserialized on demand.
+//!
+//! [`Event`]: twilight_model::gateway::event::Event
+//! [`Shard::raw_dispatches`]: super::Shard::raw_dispatches
+
+#[cfg(feature = "etf")]
+use super::etf;
+use super::{
+    json::{self, GatewayEventParsingError},
+    Encoding,
+};
+use crate::EventTypeFlags;
+use futures_util::stream::Stream;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc::UnboundedReceiver;
+use twilight_model::gateway::event::{Event, EventType};
+
+/// Deserializing a [`RawDispatch`] failed.
+#[derive(Debug)]
+pub struct RawDispatchError {
+    kind: RawDispatchErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RawDispatchError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RawDispatchErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (RawDispatchErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RawDispatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            RawDispatchErrorType::Deserializing => {
+                f.write_str("deserializing the dispatch payload failed")
+            }
+        }
+    }
+}
+
+impl Error for RawDispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RawDispatchError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RawDispatchErrorType {
+    /// Deserializing the payload failed.
+    Deserializing,
+}
+
+/// Dispatch event received from the gateway, before deserialization.
+///
+/// Cloning a raw dispatch is cheap, since its payload is shared.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawDispatch {
+    bytes: Arc<[u8]>,
+    encoding: Encoding,
+    event_type: Box<str>,
+    seq: u64,
+}
+
+impl RawDispatch {
+    pub(crate) fn new(event_type: &str, seq: u64, bytes: &[u8], encoding: Encoding) -> Self {
+        Self {
+            bytes: Arc::from(bytes),
+            encoding,
+            event_type: Box::from(event_type),
+            seq,
+        }
+    }
+
+    /// Raw bytes of the whole payload, including the opcode, sequence number
+    /// and event name, in the shard's [encoding].
+    ///
+    /// [encoding]: Self::encoding
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Encoding of the payload.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Name of the dispatch event, such as `MESSAGE_CREATE`.
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// Type of the dispatch event, if it's known.
+    pub fn kind(&self) -> Option<EventType> {
+        EventType::try_from(self.event_type()).ok()
+    }
+
+    /// Sequence number of the dispatch event.
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Deserialize the data of the dispatch event into a type.
+    ///
+    /// # Examples
+    ///
+    /// Deserialize a message creation into only the fields that are needed:
+    ///
+    /// ```rust
+    /// # fn run(dispatch: twilight_gateway::shard::RawDispatch) -> Result<(), Box<dyn std::error::Error>> {
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Content {
+    ///     content: String,
+    /// }
+    ///
+    /// if dispatch.event_type() == "MESSAGE_CREATE" {
+    ///     let message = dispatch.deserialize::<Content>()?;
+    ///     println!("message: {}", message.content);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawDispatchErrorType::Deserializing`] error type if the
+    /// data couldn't be deserialized into the type.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, RawDispatchError> {
+        #[derive(Deserialize)]
+        struct Payload<T> {
+            d: T,
+        }
+
+        let deserializing = |source: Box<dyn Error + Send + Sync>| RawDispatchError {
+            kind: RawDispatchErrorType::Deserializing,
+            source: Some(source),
+        };
+
+        match self.encoding {
+            Encoding::Json => {
+                #[cfg(not(feature = "simd-json"))]
+                let payload = json::from_slice::<Payload<T>>(&self.bytes);
+                // The SIMD deserializer needs a mutable buffer.
+                #[cfg(feature = "simd-json")]
+                let payload = json::from_slice::<Payload<T>>(&mut self.bytes.to_vec());
+
+                payload
+                    .map(|payload| payload.d)
+                    .map_err(|source| deserializing(Box::new(source)))
+            }
+            #[cfg(feature = "etf")]
+            Encoding::Etf => etf::from_slice::<Payload<T>>(&self.bytes)
+                .map(|payload| payload.d)
+                .map_err(|source| deserializing(Box::new(source))),
+        }
+    }
+
+    /// Deserialize the dispatch event into an [`Event`], like the shard's
+    /// event streams do.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawDispatchErrorType::Deserializing`] error type if the
+    /// payload couldn't be deserialized into an event.
+    pub fn event(&self) -> Result<Event, RawDispatchError> {
+        let deserializing = |source: Option<Box<dyn Error + Send + Sync>>| RawDispatchError {
+            kind: RawDispatchErrorType::Deserializing,
+            source,
+        };
+
+        let gateway_event = match self.encoding {
+            Encoding::Json => {
+                let mut json = String::from_utf8(self.bytes.to_vec())
+                    .map_err(|source| deserializing(Some(Box::new(source))))?;
+
+                json::parse_gateway_event(0, Some(self.seq), Some(self.event_type()), &mut json)
+            }
+            #[cfg(feature = "etf")]
+            Encoding::Etf => {
+                etf::parse_gateway_event(0, Some(self.seq), Some(self.event_type()), &self.bytes)
+            }
+        };
+
+        gateway_event
+            .map(Event::from)
+            .map_err(|source: GatewayEventParsingError| deserializing(Some(Box::new(source))))
+    }
+}
+
+/// A stream of raw dispatch events from a [`Shard`].
+///
+/// Refer to [`Shard::raw_dispatches`] for more information.
+///
+/// This implements [`futures::stream::Stream`].
+///
+/// [`Shard`]: super::Shard
+/// [`Shard::raw_dispatches`]: super::Shard::raw_dispatches
+/// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
+pub struct RawDispatches {
+    event_types: EventTypeFlags,
+    rx: UnboundedReceiver<RawDispatch>,
+}
+
+impl RawDispatches {
+    pub(super) fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<RawDispatch>) -> Self {
+        Self { event_types, rx }
+    }
+
+    /// Returns the event types that can be passed to this stream.
+    pub fn event_types(&self) -> EventTypeFlags {
+        self.event_types
+    }
+}
+
+impl Stream for RawDispatches {
+    type Item = RawDispatch;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Encoding, RawDispatch, RawDispatchError, RawDispatchErrorType, RawDispatches};
+    use futures_util::stream::Stream;
+    use serde::Deserialize;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::gateway::event::{Event, EventType};
+
+    assert_impl_all!(RawDispatch: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(RawDispatchError: Error, Send, Sync);
+    assert_impl_all!(RawDispatchErrorType: Debug, Send, Sync);
+    assert_impl_all!(RawDispatches: Send, Stream, Sync);
+
+    const PAYLOAD: &str =
+        r#"{"t":"MESSAGE_ACK","s":3,"op":0,"d":{"channel_id":"1","message_id":"2","version":7}}"#;
+
+    #[test]
+    fn test_accessors() {
+        let dispatch = RawDispatch::new("MESSAGE_ACK", 3, PAYLOAD.as_bytes(), Encoding::Json);
+
+        assert_eq!(PAYLOAD.as_bytes(), dispatch.bytes());
+        assert_eq!("MESSAGE_ACK", dispatch.event_type());
+        assert_eq!(Some(EventType::MessageAck), dispatch.kind());
+        assert_eq!(Encoding::Json, dispatch.encoding());
+        assert_eq!(3, dispatch.seq());
+        assert_eq!(
+            None,
+            RawDispatch::new("UNKNOWN", 1, b"{}", Encoding::Json).kind()
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Ack {
+            version: u64,
+        }
+
+        let dispatch = RawDispatch::new("MESSAGE_ACK", 3, PAYLOAD.as_bytes(), Encoding::Json);
+
+        assert_eq!(Ack { version: 7 }, dispatch.deserialize::<Ack>().unwrap());
+        assert!(matches!(
+            dispatch.deserialize::<String>().unwrap_err().kind(),
+            RawDispatchErrorType::Deserializing
+        ));
+    }
+
+    #[test]
+    fn test_event() {
+        let dispatch = RawDispatch::new("MESSAGE_ACK", 3, PAYLOAD.as_bytes(), Encoding::Json);
+
+        assert!(matches!(dispatch.event().unwrap(), Event::MessageAck(_)));
+    }
+}
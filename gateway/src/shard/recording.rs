@@ -0,0 +1,676 @@
+//! Record the payloads received by shards and replay them later.
+//!
+//! A [`Recorder`] writes the decompressed payloads of [`Event::ShardPayload`]
+//! events, in either [`Encoding`], to a writer, such as a file. A [`Replayer`] reads them back as
+//! [`Record`]s, which can be deserialized into [`Event`]s the same way that
+//! the shard does, and passed to a cache, standby or any other handler. This
+//! is useful for reproducing bugs offline and for benchmarking event
+//! processing.
+//!
+//! # Format
+//!
+//! Each record is written on its own line, prefixed with its length and
+//! metadata, separated by single spaces:
+//!
+//! ```text
+//! <length> <shard ID> <encoding> <sequence or -> <timestamp> <payload>
+//! ```
+//!
+//! The length is the number of bytes of the payload, and the timestamp is the
+//! number of milliseconds since the Unix epoch at which the payload was
+//! recorded. The encoding is the [name] of the payload's encoding, such as
+//! `json`. The sequence is `-` for payloads without one, such as heartbeat
+//! acknowledgements. Records of `etf` payloads can only be replayed with the
+//! `etf` feature enabled.
+//!
+//! # Examples
+//!
+//! Record the payloads of a shard to a file:
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use std::{env, fs::File, io::BufWriter};
+//! use twilight_gateway::{shard::recording::Recorder, Event, EventTypeFlags, Intents, Shard};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let mut shard = Shard::new(env::var("DISCORD_TOKEN")?, Intents::empty());
+//! let mut events = shard.some_events(EventTypeFlags::SHARD_PAYLOAD);
+//! shard.start().await?;
+//!
+//! let encoding = shard.config().encoding();
+//! let mut recorder = Recorder::new(BufWriter::new(File::create("shard.rec")?));
+//!
+//! while let Some(event) = events.next().await {
+//!     if let Event::ShardPayload(payload) = event {
+//!         recorder.record(0, encoding, &payload.bytes)?;
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Replay the recorded payloads:
+//!
+//! ```no_run
+//! use std::{fs::File, io::BufReader};
+//! use twilight_gateway::shard::recording::Replayer;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let replayer = Replayer::new(BufReader::new(File::open("shard.rec")?));
+//!
+//! for record in replayer {
+//!     let record = record?;
+//!     let event = record.event()?;
+//!
+//!     println!("shard {}: {:?}", record.shard_id, event.kind());
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`Event::ShardPayload`]: twilight_model::gateway::event::Event::ShardPayload
+//! [name]: Encoding::name
+
+#[cfg(feature = "etf")]
+use super::etf;
+use super::{json, Encoding};
+use crate::EventTypeFlags;
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{BufRead, Error as IoError, ErrorKind as IoErrorKind, Read, Write},
+    iter::FusedIterator,
+    str,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::gateway::event::{Event, GatewayEventDeserializer};
+
+/// Recording, replaying or deserializing a [`Record`] failed.
+#[derive(Debug)]
+pub struct RecordingError {
+    kind: RecordingErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RecordingError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RecordingErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (RecordingErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn format(source: Option<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            kind: RecordingErrorType::Format,
+            source,
+        }
+    }
+
+    fn io(source: IoError) -> Self {
+        Self {
+            kind: RecordingErrorType::Io,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for RecordingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            RecordingErrorType::Deserializing => {
+                f.write_str("deserializing the recorded payload failed")
+            }
+            RecordingErrorType::Format => f.write_str("record is malformed"),
+            RecordingErrorType::Io => f.write_str("reading or writing a record failed"),
+        }
+    }
+}
+
+impl Error for RecordingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RecordingError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RecordingErrorType {
+    /// Deserializing the payload of a record into an event failed.
+    Deserializing,
+    /// Record is malformed or truncated.
+    Format,
+    /// Reading from or writing to the underlying reader or writer failed.
+    Io,
+}
+
+/// Payload received by a shard, along with when and by which shard it was
+/// received.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Record {
+    /// Encoding of the payload.
+    pub encoding: Encoding,
+    /// Decompressed payload as it was received from the gateway.
+    pub payload: Vec<u8>,
+    /// Sequence number of the payload, if it has one.
+    pub seq: Option<u64>,
+    /// ID of the shard that received the payload.
+    pub shard_id: u64,
+    /// Number of milliseconds since the Unix epoch at which the payload was
+    /// recorded.
+    pub timestamp: u64,
+}
+
+impl Record {
+    /// Deserialize the payload into an [`Event`], like the shard does.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordingErrorType::Deserializing`] error type if the
+    /// payload couldn't be deserialized into an event.
+    pub fn event(&self) -> Result<Event, RecordingError> {
+        let deserializing = |source: Option<Box<dyn Error + Send + Sync>>| RecordingError {
+            kind: RecordingErrorType::Deserializing,
+            source,
+        };
+
+        let event = match self.encoding {
+            Encoding::Json => {
+                let mut json = String::from_utf8(self.payload.clone())
+                    .map_err(|source| deserializing(Some(Box::new(source))))?;
+
+                let (op, seq, event_type) = GatewayEventDeserializer::from_json(&json)
+                    .ok_or_else(|| deserializing(None))?
+                    .into_parts();
+                let event_type = event_type.map(ToOwned::to_owned);
+
+                match (seq, event_type.as_deref()) {
+                    (Some(seq), Some(event_type)) if is_unknown(op, event_type) => {
+                        json::parse_unknown_dispatch(seq, event_type, &json)
+                    }
+                    _ => json::parse_gateway_event(op, seq, event_type.as_deref(), &mut json),
+                }
+            }
+            #[cfg(feature = "etf")]
+            Encoding::Etf => {
+                let (op, seq, event_type) =
+                    etf::parts(&self.payload).ok_or_else(|| deserializing(None))?;
+
+                match (seq, event_type.as_deref()) {
+                    (Some(seq), Some(event_type)) if is_unknown(op, event_type) => {
+                        etf::parse_unknown_dispatch(seq, event_type, &self.payload)
+                    }
+                    _ => etf::parse_gateway_event(op, seq, event_type.as_deref(), &self.payload),
+                }
+            }
+        };
+
+        event
+            .map(Event::from)
+            .map_err(|source| deserializing(Some(Box::new(source))))
+    }
+}
+
+/// Whether a payload is a dispatch event unknown to the library.
+fn is_unknown(op: u8, event_type: &str) -> bool {
+    EventTypeFlags::try_from((op, Some(event_type))) == Ok(EventTypeFlags::UNKNOWN)
+}
+
+/// Writes the payloads received by shards as [`Record`]s.
+///
+/// Refer to the [module-level documentation] for the format and an example.
+///
+/// Writes aren't buffered, so wrapping a file in a [`BufWriter`] is
+/// recommended.
+///
+/// [`BufWriter`]: std::io::BufWriter
+/// [module-level documentation]: self
+#[derive(Debug)]
+pub struct Recorder<W> {
+    writer: W,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Create a new recorder writing to a writer.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Record a payload received by a shard, timestamped with the current
+    /// time.
+    ///
+    /// The sequence number is read from the payload, which is in the given
+    /// encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordingErrorType::Io`] error type if writing the record
+    /// failed.
+    pub fn record(
+        &mut self,
+        shard_id: u64,
+        encoding: Encoding,
+        payload: &[u8],
+    ) -> Result<(), RecordingError> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| {
+                u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+            });
+
+        self.record_at(shard_id, encoding, timestamp, payload)
+    }
+
+    /// Record a payload received by a shard with a timestamp, in milliseconds
+    /// since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordingErrorType::Io`] error type if writing the record
+    /// failed.
+    pub fn record_at(
+        &mut self,
+        shard_id: u64,
+        encoding: Encoding,
+        timestamp: u64,
+        payload: &[u8],
+    ) -> Result<(), RecordingError> {
+        let seq = match encoding {
+            Encoding::Json => str::from_utf8(payload)
+                .ok()
+                .and_then(GatewayEventDeserializer::from_json)
+                .and_then(|deserializer| deserializer.into_parts().1),
+            #[cfg(feature = "etf")]
+            Encoding::Etf => etf::parts(payload).and_then(|(_, seq, _)| seq),
+        };
+
+        match seq {
+            Some(seq) => write!(
+                self.writer,
+                "{} {} {} {} {} ",
+                payload.len(),
+                shard_id,
+                encoding.name(),
+                seq,
+                timestamp
+            ),
+            None => write!(
+                self.writer,
+                "{} {} {} - {} ",
+                payload.len(),
+                shard_id,
+                encoding.name(),
+                timestamp
+            ),
+        }
+        .and_then(|()| self.writer.write_all(payload))
+        .and_then(|()| self.writer.write_all(b"\n"))
+        .map_err(RecordingError::io)
+    }
+
+    /// Flush the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordingErrorType::Io`] error type if flushing failed.
+    pub fn flush(&mut self) -> Result<(), RecordingError> {
+        self.writer.flush().map_err(RecordingError::io)
+    }
+
+    /// Consume the recorder, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads [`Record`]s written by a [`Recorder`].
+///
+/// This is an iterator over the records in the order that they were
+/// recorded. Iteration ends at the end of the reader; a truncated or
+/// otherwise malformed record yields a [`RecordingErrorType::Format`] error
+/// type. The position in the reader is unknown after an error, so iteration
+/// ends after the first one.
+///
+/// Refer to the [module-level documentation] for an example.
+///
+/// [module-level documentation]: self
+#[derive(Debug)]
+pub struct Replayer<R> {
+    buf: Vec<u8>,
+    failed: bool,
+    reader: R,
+}
+
+impl<R: BufRead> Replayer<R> {
+    /// Create a new replayer reading from a reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            buf: Vec::new(),
+            failed: false,
+            reader,
+        }
+    }
+
+    /// Consume the replayer, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the next space-terminated field of a record's header.
+    ///
+    /// Returns `None` if the reader is at its end before the field.
+    fn field(&mut self) -> Result<Option<&str>, RecordingError> {
+        self.buf.clear();
+
+        let read = self
+            .reader
+            .read_until(b' ', &mut self.buf)
+            .map_err(RecordingError::io)?;
+
+        if read == 0 {
+            return Ok(None);
+        }
+
+        if self.buf.pop() != Some(b' ') {
+            return Err(RecordingError::format(None));
+        }
+
+        str::from_utf8(&self.buf)
+            .map(Some)
+            .map_err(|source| RecordingError::format(Some(Box::new(source))))
+    }
+
+    fn number(&mut self) -> Result<Option<u64>, RecordingError> {
+        match self.field()? {
+            Some("-") => Ok(None),
+            Some(field) => field
+                .parse()
+                .map(Some)
+                .map_err(|source| RecordingError::format(Some(Box::new(source)))),
+            None => Err(RecordingError::format(None)),
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>, RecordingError> {
+        let len = match self.field()? {
+            Some(field) => field
+                .parse::<usize>()
+                .map_err(|source| RecordingError::format(Some(Box::new(source))))?,
+            None => return Ok(None),
+        };
+        let shard_id = self.number()?.ok_or_else(|| RecordingError::format(None))?;
+        let encoding = match self.field()? {
+            Some("json") => Encoding::Json,
+            #[cfg(feature = "etf")]
+            Some("etf") => Encoding::Etf,
+            _ => return Err(RecordingError::format(None)),
+        };
+        let seq = self.number()?;
+        let timestamp = self.number()?.ok_or_else(|| RecordingError::format(None))?;
+
+        // The length comes from the reader, so the payload is only allocated
+        // as it's read instead of trusting the length up front.
+        let mut payload = Vec::new();
+        let mut newline = [0];
+
+        (&mut self.reader)
+            .take(len as u64)
+            .read_to_end(&mut payload)
+            .map_err(RecordingError::io)?;
+
+        if payload.len() != len {
+            return Err(RecordingError::format(None));
+        }
+
+        self.reader.read_exact(&mut newline).map_err(|source| {
+            if source.kind() == IoErrorKind::UnexpectedEof {
+                RecordingError::format(Some(Box::new(source)))
+            } else {
+                RecordingError::io(source)
+            }
+        })?;
+
+        if newline != [b'\n'] {
+            return Err(RecordingError::format(None));
+        }
+
+        Ok(Some(Record {
+            encoding,
+            payload,
+            seq,
+            shard_id,
+            timestamp,
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for Replayer<R> {
+    type Item = Result<Record, RecordingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let record = self.read_record().transpose();
+        self.failed = matches!(record, Some(Err(_)));
+
+        record
+    }
+}
+
+impl<R: BufRead> FusedIterator for Replayer<R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Record, Recorder, RecordingError, RecordingErrorType, Replayer};
+    use crate::shard::Encoding;
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        io::{BufReader, Cursor},
+        iter::FusedIterator,
+    };
+    use twilight_model::gateway::event::Event;
+
+    assert_impl_all!(Record: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Recorder<Vec<u8>>: Debug, Send, Sync);
+    assert_impl_all!(RecordingError: Error, Send, Sync);
+    assert_impl_all!(RecordingErrorType: Debug, Send, Sync);
+    assert_impl_all!(Replayer<BufReader<Cursor<Vec<u8>>>>: Debug, FusedIterator, Send, Sync);
+
+    const DISPATCH: &str =
+        r#"{"t":"MESSAGE_ACK","s":3,"op":0,"d":{"channel_id":"1","message_id":"2","version":7}}"#;
+    const HEARTBEAT_ACK: &str = r#"{"op":11,"d":null}"#;
+
+    fn recording() -> Vec<u8> {
+        let mut recorder = Recorder::new(Vec::new());
+        recorder
+            .record_at(1, Encoding::Json, 1000, DISPATCH.as_bytes())
+            .unwrap();
+        recorder
+            .record_at(0, Encoding::Json, 2000, HEARTBEAT_ACK.as_bytes())
+            .unwrap();
+
+        recorder.into_inner()
+    }
+
+    #[test]
+    fn test_format() {
+        let expected = format!(
+            "{} 1 json 3 1000 {}\n{} 0 json - 2000 {}\n",
+            DISPATCH.len(),
+            DISPATCH,
+            HEARTBEAT_ACK.len(),
+            HEARTBEAT_ACK
+        );
+
+        assert_eq!(expected.as_bytes(), recording().as_slice());
+    }
+
+    #[test]
+    fn test_replay() {
+        let records = Replayer::new(Cursor::new(recording()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                Record {
+                    encoding: Encoding::Json,
+                    payload: DISPATCH.as_bytes().to_vec(),
+                    seq: Some(3),
+                    shard_id: 1,
+                    timestamp: 1000,
+                },
+                Record {
+                    encoding: Encoding::Json,
+                    payload: HEARTBEAT_ACK.as_bytes().to_vec(),
+                    seq: None,
+                    shard_id: 0,
+                    timestamp: 2000,
+                },
+            ],
+            records
+        );
+
+        assert!(matches!(records[0].event().unwrap(), Event::MessageAck(_)));
+        assert!(matches!(
+            records[1].event().unwrap(),
+            Event::GatewayHeartbeatAck
+        ));
+    }
+
+    #[test]
+    fn test_unknown_event() {
+        let record = Record {
+            encoding: Encoding::Json,
+            payload: br#"{"t":"SESSIONS_REPLACE","s":4,"op":0,"d":{"b":1,"a":2}}"#.to_vec(),
+            seq: Some(4),
+            shard_id: 0,
+            timestamp: 0,
+        };
+
+        match record.event().unwrap() {
+            Event::Unknown { name, data } => {
+                assert_eq!("SESSIONS_REPLACE", name);
+                assert_eq!(r#"{"b":1,"a":2}"#, data);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "etf")]
+    #[test]
+    fn test_etf() {
+        use crate::shard::etf;
+        use serde_json::json;
+
+        let payload = etf::to_vec(&json!({
+            "t": "MESSAGE_ACK",
+            "s": 3,
+            "op": 0,
+            "d": { "channel_id": "1", "message_id": "2", "version": 7 },
+        }))
+        .unwrap();
+        let mut recorder = Recorder::new(Vec::new());
+        recorder
+            .record_at(1, Encoding::Etf, 1000, &payload)
+            .unwrap();
+
+        let record = Replayer::new(Cursor::new(recorder.into_inner()))
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Encoding::Etf, record.encoding);
+        assert_eq!(Some(3), record.seq);
+        assert_eq!(payload, record.payload);
+        assert!(matches!(record.event().unwrap(), Event::MessageAck(_)));
+    }
+
+    #[test]
+    fn test_unknown_encoding() {
+        let bytes = format!("{} 0 xml - 0 {}\n", HEARTBEAT_ACK.len(), HEARTBEAT_ACK);
+
+        assert!(matches!(
+            Replayer::new(Cursor::new(bytes.into_bytes()))
+                .next()
+                .unwrap()
+                .unwrap_err()
+                .kind(),
+            RecordingErrorType::Format
+        ));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let mut bytes = recording();
+        bytes.truncate(bytes.len() - 5);
+
+        let mut replayer = Replayer::new(Cursor::new(bytes));
+
+        assert!(replayer.next().unwrap().is_ok());
+        assert!(matches!(
+            replayer.next().unwrap().unwrap_err().kind(),
+            RecordingErrorType::Format
+        ));
+        assert!(replayer.next().is_none());
+    }
+
+    #[test]
+    fn test_length_too_large() {
+        let mut bytes = format!("{} 0 json - 0 ", usize::MAX).into_bytes();
+        bytes.extend_from_slice(HEARTBEAT_ACK.as_bytes());
+        bytes.push(b'\n');
+        bytes.extend_from_slice(&recording());
+
+        let mut replayer = Replayer::new(Cursor::new(bytes));
+
+        assert!(matches!(
+            replayer.next().unwrap().unwrap_err().kind(),
+            RecordingErrorType::Format
+        ));
+        assert!(replayer.next().is_none());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(Replayer::new(Cursor::new(Vec::new())).next().is_none());
+    }
+
+    #[test]
+    fn test_invalid_payload() {
+        let mut recorder = Recorder::new(Vec::new());
+        recorder
+            .record_at(0, Encoding::Json, 0, b"not json")
+            .unwrap();
+
+        let record = Replayer::new(Cursor::new(recorder.into_inner()))
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(None, record.seq);
+        assert!(matches!(
+            record.event().unwrap_err().kind(),
+            RecordingErrorType::Deserializing
+        ));
+    }
+}
@@ -0,0 +1,359 @@
+//! Persisting shard sessions so that they can be resumed after the process
+//! restarts.
+//!
+//! A shard configured with a [`SessionStore`] through
+//! [`ShardBuilder::session_store`] loads its session from the store when it
+//! starts and tries to resume it instead of identifying. If Discord doesn't
+//! accept the resume, the shard falls back to identifying with the client
+//! state from its config, so that a full `READY` payload is received. The
+//! stored read state and user guild settings versions are only used for the
+//! resume attempt.
+//!
+//! Sessions are saved to the store with [`Shard::shutdown_and_save`] or
+//! [`Cluster::down_and_save`].
+//!
+//! [`Cluster::down_and_save`]: crate::Cluster::down_and_save
+//! [`Shard::shutdown_and_save`]: super::Shard::shutdown_and_save
+//! [`ShardBuilder::session_store`]: super::ShardBuilder::session_store
+
+use super::json;
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    io::ErrorKind as IoErrorKind,
+    path::PathBuf,
+    pin::Pin,
+};
+use url::Url;
+
+/// Saving or loading a session with a [`FileSessionStore`] failed.
+#[derive(Debug)]
+pub struct FileSessionStoreError {
+    kind: FileSessionStoreErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl FileSessionStoreError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &FileSessionStoreErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        FileSessionStoreErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for FileSessionStoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            FileSessionStoreErrorType::Deserializing { path } => {
+                f.write_fmt(format_args!("session file {} is invalid", path.display()))
+            }
+            FileSessionStoreErrorType::Io { path } => f.write_fmt(format_args!(
+                "accessing session file {} failed",
+                path.display()
+            )),
+            FileSessionStoreErrorType::Serializing => f.write_str("failed to serialize session"),
+        }
+    }
+}
+
+impl Error for FileSessionStoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`FileSessionStoreError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FileSessionStoreErrorType {
+    /// Session file doesn't contain a valid session.
+    Deserializing {
+        /// Path of the file.
+        path: PathBuf,
+    },
+    /// Reading, writing or removing a session file failed.
+    Io {
+        /// Path of the file.
+        path: PathBuf,
+    },
+    /// Serializing a session failed.
+    Serializing,
+}
+
+/// Future returned by the methods of a [`SessionStore`].
+pub type SessionStoreFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, Box<dyn Error + Send + Sync>>> + Send + 'a>>;
+
+/// Session of a shard, saved to resume it after the process restarts.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StoredSession {
+    /// URL of the gateway that the session was connected to.
+    pub gateway_url: String,
+    /// Version of the read states received with the session.
+    pub read_state_version: u64,
+    /// Last received event sequence number.
+    pub sequence: u64,
+    /// ID of the session.
+    pub session_id: String,
+    /// ID of the shard and the total number of shards.
+    pub shard: [u64; 2],
+    /// Version of the user guild settings received with the session, or `-1`
+    /// if there are none.
+    pub user_guild_settings_version: i64,
+}
+
+impl StoredSession {
+    /// Whether the session can be resumed by a shard.
+    ///
+    /// The session must belong to the same shard out of the same total number
+    /// of shards, and have a session ID and a valid gateway URL.
+    pub fn is_valid_for(&self, shard: [u64; 2]) -> bool {
+        self.shard == shard && !self.session_id.is_empty() && Url::parse(&self.gateway_url).is_ok()
+    }
+}
+
+/// Storage of shard sessions that outlives the process.
+///
+/// Implement this to store sessions somewhere other than the files of a
+/// [`FileSessionStore`], such as a database shared by multiple processes.
+pub trait SessionStore: Debug + Send + Sync {
+    /// Load the saved session of a shard, if there is one.
+    fn load<'a>(&'a self, shard: [u64; 2]) -> SessionStoreFuture<'a, Option<StoredSession>>;
+
+    /// Remove the saved session of a shard, if there is one.
+    fn remove<'a>(&'a self, shard: [u64; 2]) -> SessionStoreFuture<'a, ()>;
+
+    /// Save the session of a shard, replacing the previously saved one.
+    fn save<'a>(&'a self, session: &'a StoredSession) -> SessionStoreFuture<'a, ()>;
+}
+
+/// [`SessionStore`] saving each shard's session as a JSON file in a
+/// directory.
+///
+/// Files are named after the shard, such as `shard-0.json`, and are replaced
+/// atomically when saving.
+#[derive(Clone, Debug)]
+pub struct FileSessionStore {
+    directory: PathBuf,
+}
+
+impl FileSessionStore {
+    /// Create a store saving sessions in a directory.
+    ///
+    /// The directory must exist.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Return the path of the file of a shard's session.
+    pub fn path(&self, shard_id: u64) -> PathBuf {
+        self.directory.join(format!("shard-{}.json", shard_id))
+    }
+
+    async fn load_file(
+        &self,
+        shard: [u64; 2],
+    ) -> Result<Option<StoredSession>, FileSessionStoreError> {
+        let path = self.path(shard[0]);
+
+        let mut bytes = match tokio::fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(source) if source.kind() == IoErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(FileSessionStoreError {
+                    kind: FileSessionStoreErrorType::Io { path },
+                    source: Some(Box::new(source)),
+                })
+            }
+        };
+
+        json::from_slice(bytes.as_mut_slice())
+            .map(Some)
+            .map_err(|source| FileSessionStoreError {
+                kind: FileSessionStoreErrorType::Deserializing { path },
+                source: Some(Box::new(source)),
+            })
+    }
+
+    async fn remove_file(&self, shard: [u64; 2]) -> Result<(), FileSessionStoreError> {
+        let path = self.path(shard[0]);
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(source) if source.kind() == IoErrorKind::NotFound => Ok(()),
+            Err(source) => Err(FileSessionStoreError {
+                kind: FileSessionStoreErrorType::Io { path },
+                source: Some(Box::new(source)),
+            }),
+        }
+    }
+
+    async fn save_file(&self, session: &StoredSession) -> Result<(), FileSessionStoreError> {
+        let path = self.path(session.shard[0]);
+        let bytes = json::to_vec(session).map_err(|source| FileSessionStoreError {
+            kind: FileSessionStoreErrorType::Serializing,
+            source: Some(Box::new(source)),
+        })?;
+
+        // Write to a temporary file first so that a session file is never
+        // left half written.
+        let temporary = path.with_extension("json.tmp");
+
+        let written = match tokio::fs::write(&temporary, bytes).await {
+            Ok(()) => tokio::fs::rename(&temporary, &path).await,
+            Err(source) => Err(source),
+        };
+
+        written.map_err(|source| FileSessionStoreError {
+            kind: FileSessionStoreErrorType::Io { path },
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn load<'a>(&'a self, shard: [u64; 2]) -> SessionStoreFuture<'a, Option<StoredSession>> {
+        Box::pin(async move { self.load_file(shard).await.map_err(From::from) })
+    }
+
+    fn remove<'a>(&'a self, shard: [u64; 2]) -> SessionStoreFuture<'a, ()> {
+        Box::pin(async move { self.remove_file(shard).await.map_err(From::from) })
+    }
+
+    fn save<'a>(&'a self, session: &'a StoredSession) -> SessionStoreFuture<'a, ()> {
+        Box::pin(async move { self.save_file(session).await.map_err(From::from) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FileSessionStore, FileSessionStoreError, FileSessionStoreErrorType, SessionStore,
+        StoredSession,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        env,
+        error::Error,
+        fmt::Debug,
+        process,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    assert_impl_all!(FileSessionStore: Clone, Debug, SessionStore, Send, Sync);
+    assert_impl_all!(FileSessionStoreError: Error, Send, Sync);
+    assert_impl_all!(FileSessionStoreErrorType: Debug, Send, Sync);
+    assert_impl_all!(StoredSession: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_obj_safe!(SessionStore);
+
+    fn session(shard: [u64; 2]) -> StoredSession {
+        StoredSession {
+            gateway_url: "wss://gateway.discord.gg".to_owned(),
+            read_state_version: 12,
+            sequence: 345,
+            session_id: "session".to_owned(),
+            shard,
+            user_guild_settings_version: 6,
+        }
+    }
+
+    /// Create an empty directory for a test.
+    fn directory(name: &str) -> std::path::PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let directory = env::temp_dir().join(format!(
+            "twilight-gateway-{}-{}-{}",
+            name,
+            process::id(),
+            nanos
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
+    #[test]
+    fn test_is_valid_for() {
+        assert!(session([1, 4]).is_valid_for([1, 4]));
+        assert!(!session([1, 4]).is_valid_for([1, 8]));
+        assert!(!session([1, 4]).is_valid_for([2, 4]));
+
+        let mut invalid = session([0, 1]);
+        invalid.session_id.clear();
+        assert!(!invalid.is_valid_for([0, 1]));
+
+        let mut invalid = session([0, 1]);
+        invalid.gateway_url = "not a url".to_owned();
+        assert!(!invalid.is_valid_for([0, 1]));
+    }
+
+    #[tokio::test]
+    async fn test_file_store() {
+        let directory = directory("session-store");
+        let store = FileSessionStore::new(&directory);
+
+        assert!(store.load([0, 2]).await.unwrap().is_none());
+
+        store.save(&session([0, 2])).await.unwrap();
+        store.save(&session([1, 2])).await.unwrap();
+        assert_eq!(Some(session([0, 2])), store.load([0, 2]).await.unwrap());
+        assert_eq!(Some(session([1, 2])), store.load([1, 2]).await.unwrap());
+
+        let mut newer = session([0, 2]);
+        newer.sequence = 400;
+        store.save(&newer).await.unwrap();
+        assert_eq!(Some(newer), store.load([0, 2]).await.unwrap());
+
+        store.remove([0, 2]).await.unwrap();
+        store.remove([0, 2]).await.unwrap();
+        assert!(store.load([0, 2]).await.unwrap().is_none());
+        assert!(!store.path(0).exists());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_store_invalid() {
+        let directory = directory("session-store-invalid");
+        let store = FileSessionStore::new(&directory);
+        std::fs::write(store.path(0), b"{\"session_id\":").unwrap();
+
+        let error = store
+            .load([0, 1])
+            .await
+            .unwrap_err()
+            .downcast::<FileSessionStoreError>()
+            .unwrap();
+        assert!(matches!(
+            error.kind(),
+            FileSessionStoreErrorType::Deserializing { path } if *path == store.path(0)
+        ));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -1,22 +1,91 @@
-use super::{Client, State};
-use crate::ratelimiting::Ratelimiter;
-use hyper::header::HeaderMap;
+use super::{Client, RetryPolicy, State};
+use crate::{
+    ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    API_VERSION,
+};
+use hyper::header::{HeaderMap, HeaderValue};
 use std::{
-    sync::{atomic::AtomicBool, Arc},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{atomic::AtomicBool, Arc, Mutex},
     time::Duration,
 };
 use twilight_model::channel::message::allowed_mentions::AllowedMentions;
 
+/// User agent configuration is invalid.
+///
+/// Returned by [`ClientBuilder::user_agent`].
+#[derive(Debug)]
+pub struct UserAgentError {
+    kind: UserAgentErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UserAgentError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub fn kind(&self) -> &UserAgentErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (UserAgentErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UserAgentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UserAgentErrorType::Invalid { .. } => {
+                f.write_str("the user agent is not a valid header value")
+            }
+        }
+    }
+}
+
+impl Error for UserAgentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UserAgentError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UserAgentErrorType {
+    /// The user agent contains characters that aren't allowed in a header
+    /// value.
+    Invalid {
+        /// Provided user agent.
+        user_agent: String,
+    },
+}
+
 #[derive(Debug)]
 /// A builder for [`Client`].
 pub struct ClientBuilder {
+    pub(crate) api_version: u8,
+    pub(crate) base_url: Option<Box<str>>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
+    pub(crate) retry_policy: RetryPolicy,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
+    pub(crate) track_ack_token: bool,
     pub(crate) use_http: bool,
+    pub(crate) user_agent: Option<HeaderValue>,
 }
 
 impl ClientBuilder {
@@ -43,18 +112,71 @@ impl ClientBuilder {
         Client {
             state: Arc::new(State {
                 http,
+                ack_token: Mutex::new(None),
+                api_version: self.api_version,
+                base_url: self.base_url,
                 default_headers: self.default_headers,
+                global_ratelimit_reset: Mutex::new(None),
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
+                retry_policy: self.retry_policy,
                 timeout: self.timeout,
                 token_invalid: AtomicBool::new(false),
                 token: self.token,
+                track_ack_token: self.track_ack_token,
                 default_allowed_mentions: self.default_allowed_mentions,
                 use_http: self.use_http,
+                user_agent: self.user_agent.unwrap_or_else(|| {
+                    HeaderValue::from_static(concat!(
+                        "DiscordBot (",
+                        env!("CARGO_PKG_HOMEPAGE"),
+                        ", ",
+                        env!("CARGO_PKG_VERSION"),
+                        ") Twilight-rs",
+                    ))
+                }),
             }),
         }
     }
 
+    /// Set the version of the Discord API to make requests to.
+    ///
+    /// The default is [`API_VERSION`], which is the version that the models
+    /// of this crate are made for.
+    pub fn api_version(mut self, api_version: u8) -> Self {
+        self.api_version = api_version;
+
+        self
+    }
+
+    /// Set the base URL of the API, including the scheme and, optionally, the
+    /// port.
+    ///
+    /// Requests are made to `{base_url}/api/v{api_version}/{path}`. This takes
+    /// precedence over the [`proxy`]. The default is `https://discord.com`.
+    ///
+    /// # Examples
+    ///
+    /// Make requests to a mock server on port 8080:
+    ///
+    /// ```rust
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .base_url("http://localhost:8080")
+    ///     .build();
+    /// ```
+    ///
+    /// [`proxy`]: Self::proxy
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        let base_url = base_url.trim_end_matches('/');
+
+        self.base_url.replace(base_url.into());
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
@@ -68,6 +190,8 @@ impl ClientBuilder {
     /// **Note** that this isn't currently a traditional proxy, but is for
     /// working with something like [twilight's HTTP proxy server].
     ///
+    /// This is ignored if a [`base_url`] is set.
+    ///
     /// # Examples
     ///
     /// Set the proxy to `twilight_http_proxy.internal`:
@@ -82,6 +206,7 @@ impl ClientBuilder {
     /// # Ok(()) }
     /// ```
     ///
+    /// [`base_url`]: Self::base_url
     /// [twilight's HTTP proxy server]: https://github.com/twilight-rs/http-proxy
     pub fn proxy(mut self, proxy_url: impl Into<String>, use_http: bool) -> Self {
         self.proxy.replace(proxy_url.into().into_boxed_str());
@@ -95,10 +220,39 @@ impl ClientBuilder {
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by [`ClientBuilder::build`].
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    /// If this method is not called at all then a default
+    /// [`InMemoryRatelimiter`] will be created by [`ClientBuilder::build`].
+    /// Use [`custom_ratelimiter`] to use another implementation of
+    /// [`Ratelimiter`].
+    ///
+    /// [`custom_ratelimiter`]: Self::custom_ratelimiter
+    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<InMemoryRatelimiter>>) -> Self {
+        self.ratelimiter = ratelimiter
+            .into()
+            .map(|ratelimiter| Box::new(ratelimiter) as Box<dyn Ratelimiter>);
+
+        self
+    }
+
+    /// Set an implementation of [`Ratelimiter`] to use instead of the default
+    /// [`InMemoryRatelimiter`].
+    ///
+    /// Use a [`SharedRatelimiter`] to share ratelimits between processes.
+    ///
+    /// [`SharedRatelimiter`]: crate::ratelimiting::SharedRatelimiter
+    pub fn custom_ratelimiter(mut self, ratelimiter: impl Ratelimiter + 'static) -> Self {
+        self.ratelimiter = Some(Box::new(ratelimiter));
+
+        self
+    }
+
+    /// Set the policy for retrying requests that failed for transient
+    /// reasons, such as ratelimits and gateway errors.
+    ///
+    /// The default is [`RetryPolicy::new`]. Use [`RetryPolicy::disabled`] to
+    /// never retry requests.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
 
         self
     }
@@ -127,18 +281,62 @@ impl ClientBuilder {
 
         self
     }
+
+    /// Set the user agent sent with every request.
+    ///
+    /// The default is `DiscordBot (<homepage>, <version>) Twilight-rs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UserAgentErrorType::Invalid`] error type if the user agent
+    /// isn't a valid header value.
+    pub fn user_agent(self, user_agent: impl Into<String>) -> Result<Self, UserAgentError> {
+        self._user_agent(user_agent.into())
+    }
+
+    fn _user_agent(mut self, user_agent: String) -> Result<Self, UserAgentError> {
+        match HeaderValue::from_str(&user_agent) {
+            Ok(value) => {
+                self.user_agent.replace(value);
+
+                Ok(self)
+            }
+            Err(source) => Err(UserAgentError {
+                kind: UserAgentErrorType::Invalid { user_agent },
+                source: Some(Box::new(source)),
+            }),
+        }
+    }
+
+    /// Set whether the client should keep track of the ack token.
+    ///
+    /// Discord returns a new token each time a message is acked, which is
+    /// expected to be sent with the next ack. When enabled, the client stores
+    /// the returned token and uses it for acks that don't provide their own.
+    ///
+    /// The default is `false`.
+    pub fn track_ack_token(mut self, track_ack_token: bool) -> Self {
+        self.track_ack_token = track_ack_token;
+
+        self
+    }
 }
 
 impl Default for ClientBuilder {
     fn default() -> Self {
         Self {
+            api_version: API_VERSION,
+            base_url: None,
             default_allowed_mentions: None,
             default_headers: None,
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
+            retry_policy: RetryPolicy::new(),
             timeout: Duration::from_secs(10),
             token: None,
+            track_ack_token: false,
             use_http: false,
+            user_agent: None,
         }
     }
 }
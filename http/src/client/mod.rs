@@ -1,17 +1,21 @@
 mod builder;
+mod retry;
 
-pub use self::builder::ClientBuilder;
+pub use self::{
+    builder::{ClientBuilder, UserAgentError, UserAgentErrorType},
+    retry::RetryPolicy,
+};
 
 use crate::{
     api_error::{ApiError, ErrorCode},
     error::{Error, ErrorType, Result},
     ratelimiting::{RatelimitHeaders, Ratelimiter},
     request::{
+        channel::thread::create_thread::CreateThreadError,
         guild::{create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError},
         prelude::*,
         GetUserApplicationInfo, Method, Request,
     },
-    API_VERSION,
 };
 use bytes::Bytes;
 use hyper::{
@@ -27,13 +31,15 @@ use std::{
     result::Result as StdResult,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Mutex,
     },
     time::Duration,
 };
-use tokio::time;
+use tokio::time::{self, Instant};
 use twilight_model::{
-    channel::message::allowed_mentions::AllowedMentions,
+    channel::{
+        message::allowed_mentions::AllowedMentions, thread::AutoArchiveDuration, ChannelType,
+    },
     guild::Permissions,
     id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, UserId, WebhookId},
 };
@@ -44,26 +50,39 @@ type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
 type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
 
 struct State {
+    ack_token: Mutex<Option<String>>,
+    api_version: u8,
+    base_url: Option<Box<str>>,
     http: HyperClient<HttpsConnector<HttpConnector>, Body>,
     default_headers: Option<HeaderMap>,
+    /// When the global ratelimit that was last hit resets.
+    global_ratelimit_reset: Mutex<Option<Instant>>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    retry_policy: RetryPolicy,
     timeout: Duration,
     token_invalid: AtomicBool,
     token: Option<Box<str>>,
+    track_ack_token: bool,
     use_http: bool,
+    user_agent: HeaderValue,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
 }
 
 impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("State")
+            .field("api_version", &self.api_version)
+            .field("base_url", &self.base_url)
             .field("http", &self.http)
             .field("default_headers", &self.default_headers)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
+            .field("retry_policy", &self.retry_policy)
             .field("token", &self.token)
+            .field("track_ack_token", &self.track_ack_token)
             .field("use_http", &self.use_http)
+            .field("user_agent", &self.user_agent)
             .finish()
     }
 }
@@ -169,8 +188,8 @@ impl Client {
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.state.ratelimiter.as_deref()
     }
 
     /// Get the audit log for a guild.
@@ -195,6 +214,11 @@ impl Client {
         GetAuditLog::new(self, guild_id)
     }
 
+    /// Mark all messages in a channel up to and including a message as read.
+    ///
+    /// If the client is tracking the ack token and `token` is `None`, then the
+    /// stored token is used. Refer to [`ClientBuilder::track_ack_token`] for
+    /// more information.
     pub fn ack_message(
         &self,
         channel_id: ChannelId,
@@ -204,6 +228,63 @@ impl Client {
         AckMessage::new(self, channel_id, message_id, token)
     }
 
+    /// Mark the messages of multiple channels as read at once.
+    ///
+    /// Each item is a channel paired with the latest message to mark as read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::id::{ChannelId, MessageId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token");
+    /// client
+    ///     .bulk_ack_messages(vec![
+    ///         (ChannelId(1), MessageId(2)),
+    ///         (ChannelId(3), MessageId(4)),
+    ///     ])
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn bulk_ack_messages(
+        &self,
+        read_states: impl IntoIterator<Item = (ChannelId, MessageId)>,
+    ) -> BulkAckMessages<'_> {
+        BulkAckMessages::new(self, read_states)
+    }
+
+    /// Mark all channels in a guild as read.
+    pub fn ack_guild(&self, guild_id: GuildId) -> AckGuild<'_> {
+        AckGuild::new(self, guild_id)
+    }
+
+    /// Get the ack token stored by the client.
+    ///
+    /// This is always `None` unless the client was configured to track the ack
+    /// token via [`ClientBuilder::track_ack_token`].
+    pub fn ack_token(&self) -> Option<String> {
+        self.state
+            .ack_token
+            .lock()
+            .expect("ack token poisoned")
+            .clone()
+    }
+
+    /// Set or clear the ack token stored by the client.
+    ///
+    /// This is useful for restoring a token from a previous session.
+    pub fn set_ack_token(&self, token: Option<String>) {
+        *self.state.ack_token.lock().expect("ack token poisoned") = token;
+    }
+
+    /// Whether the client keeps track of the ack token.
+    pub(crate) fn tracks_ack_token(&self) -> bool {
+        self.state.track_ack_token
+    }
+
     /// Retrieve the bans for a guild.
     ///
     /// # Examples
@@ -335,6 +416,119 @@ impl Client {
         FollowNewsChannel::new(self, channel_id, webhook_channel_id)
     }
 
+    /// Create a thread in a channel that is not started from a message.
+    ///
+    /// The kind must be one of [`ChannelType::GuildNewsThread`],
+    /// [`ChannelType::GuildPublicThread`] or
+    /// [`ChannelType::GuildPrivateThread`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadErrorType::NameInvalid`] error type when the
+    /// length of the name is either fewer than 1 UTF-16 character or more than
+    /// 100 UTF-16 characters.
+    ///
+    /// Returns a [`CreateThreadErrorType::TypeInvalid`] error type when the
+    /// kind is not a thread type.
+    ///
+    /// [`ChannelType::GuildNewsThread`]: ::twilight_model::channel::ChannelType::GuildNewsThread
+    /// [`ChannelType::GuildPublicThread`]: ::twilight_model::channel::ChannelType::GuildPublicThread
+    /// [`ChannelType::GuildPrivateThread`]: ::twilight_model::channel::ChannelType::GuildPrivateThread
+    /// [`CreateThreadErrorType::NameInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::NameInvalid
+    /// [`CreateThreadErrorType::TypeInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::TypeInvalid
+    pub fn create_thread(
+        &self,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+        kind: ChannelType,
+    ) -> StdResult<CreateThread<'_>, CreateThreadError> {
+        CreateThread::new(self, channel_id, name, auto_archive_duration, kind)
+    }
+
+    /// Create a thread started from a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadErrorType::NameInvalid`] error type when the
+    /// length of the name is either fewer than 1 UTF-16 character or more than
+    /// 100 UTF-16 characters.
+    ///
+    /// [`CreateThreadErrorType::NameInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::NameInvalid
+    pub fn create_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> StdResult<CreateThreadFromMessage<'_>, CreateThreadError> {
+        CreateThreadFromMessage::new(self, channel_id, message_id, name, auto_archive_duration)
+    }
+
+    /// Update a thread.
+    ///
+    /// All fields are optional. The minimum length of the name is 1 UTF-16
+    /// character and the maximum is 100 UTF-16 characters.
+    pub fn update_thread(&self, channel_id: ChannelId) -> UpdateThread<'_> {
+        UpdateThread::new(self, channel_id)
+    }
+
+    /// Add the current user to a thread.
+    pub fn join_thread(&self, channel_id: ChannelId) -> JoinThread<'_> {
+        JoinThread::new(self, channel_id)
+    }
+
+    /// Remove the current user from a thread.
+    pub fn leave_thread(&self, channel_id: ChannelId) -> LeaveThread<'_> {
+        LeaveThread::new(self, channel_id)
+    }
+
+    /// Add a user to a thread.
+    pub fn add_thread_member(&self, channel_id: ChannelId, user_id: UserId) -> AddThreadMember<'_> {
+        AddThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Remove a user from a thread.
+    pub fn remove_thread_member(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> RemoveThreadMember<'_> {
+        RemoveThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Get the members of a thread.
+    pub fn thread_members(&self, channel_id: ChannelId) -> GetThreadMembers<'_> {
+        GetThreadMembers::new(self, channel_id)
+    }
+
+    /// Get the active threads of a guild, along with the current user's thread
+    /// members for them.
+    pub fn active_threads(&self, guild_id: GuildId) -> GetActiveThreads<'_> {
+        GetActiveThreads::new(self, guild_id)
+    }
+
+    /// Get the archived public threads of a channel.
+    pub fn public_archived_threads(&self, channel_id: ChannelId) -> GetPublicArchivedThreads<'_> {
+        GetPublicArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel.
+    ///
+    /// Requires the `MANAGE_THREADS` permission.
+    pub fn private_archived_threads(&self, channel_id: ChannelId) -> GetPrivateArchivedThreads<'_> {
+        GetPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel that the current user has
+    /// joined.
+    pub fn joined_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+    ) -> GetJoinedPrivateArchivedThreads<'_> {
+        GetJoinedPrivateArchivedThreads::new(self, channel_id)
+    }
+
     /// Get the invites for a guild channel.
     ///
     /// This method only works if the channel is of type `GuildChannel`.
@@ -1257,6 +1451,45 @@ impl Client {
         CreatePrivateChannel::new(self, recipient_id)
     }
 
+    /// Get the current user's relationships, such as friends, blocked users
+    /// and pending friend requests.
+    pub fn relationships(&self) -> GetRelationships<'_> {
+        GetRelationships::new(self)
+    }
+
+    /// Send a friend request to a user, or block them by setting the
+    /// relationship's [`kind`].
+    ///
+    /// [`kind`]: CreateRelationship::kind
+    pub fn create_relationship(&self, user_id: UserId) -> CreateRelationship<'_> {
+        CreateRelationship::new(self, user_id)
+    }
+
+    /// Accept a pending friend request from a user.
+    ///
+    /// This is the same as [`create_relationship`] without a kind, which
+    /// accepts the user's pending friend request if they've sent one.
+    ///
+    /// [`create_relationship`]: Self::create_relationship
+    pub fn accept_relationship(&self, user_id: UserId) -> CreateRelationship<'_> {
+        self.create_relationship(user_id)
+    }
+
+    /// Send a friend request to a user by their username and discriminator.
+    pub fn send_friend_request(
+        &self,
+        username: impl Into<String>,
+        discriminator: u16,
+    ) -> SendFriendRequest<'_> {
+        SendFriendRequest::new(self, username.into(), discriminator)
+    }
+
+    /// Remove a friend, unblock a user, or cancel or decline a pending friend
+    /// request.
+    pub fn delete_relationship(&self, user_id: UserId) -> DeleteRelationship<'_> {
+        DeleteRelationship::new(self, user_id)
+    }
+
     /// Get the roles of a guild.
     pub fn roles(&self, guild_id: GuildId) -> GetGuildRoles<'_> {
         GetGuildRoles::new(self, guild_id)
@@ -1384,6 +1617,17 @@ impl Client {
         GetUser::new(self, user_id.to_string())
     }
 
+    /// Update the current user's notification settings for a guild, such as
+    /// muting it or overriding the settings of its channels.
+    ///
+    /// Pass `None` to update the settings of private channels.
+    pub fn update_user_guild_settings(
+        &self,
+        guild_id: Option<GuildId>,
+    ) -> UpdateUserGuildSettings<'_> {
+        UpdateUserGuildSettings::new(self, guild_id)
+    }
+
     /// Update another user's voice state.
     ///
     /// # Caveats
@@ -1543,7 +1787,6 @@ impl Client {
     ///
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
     /// token has become invalid due to expiration, revokation, etc.
-    #[allow(clippy::too_many_lines)]
     pub async fn raw(&self, request: Request) -> Result<Response<Body>> {
         if self.state.token_invalid.load(Ordering::Relaxed) {
             return Err(Error {
@@ -1552,19 +1795,91 @@ impl Client {
             });
         }
 
+        let bucket = request.path.clone();
+        let req = self.build_request(request)?;
+
+        let inner = self.state.http.request(req);
+        let fut = time::timeout(self.state.timeout, inner);
+
+        let ratelimiter = match self.state.ratelimiter.as_ref() {
+            Some(ratelimiter) => ratelimiter,
+            None => {
+                return fut
+                    .await
+                    .map_err(|source| Error {
+                        kind: ErrorType::RequestTimedOut,
+                        source: Some(Box::new(source)),
+                    })?
+                    .map_err(|source| Error {
+                        kind: ErrorType::RequestError,
+                        source: Some(Box::new(source)),
+                    });
+            }
+        };
+
+        let rx = ratelimiter.ticket(bucket).await.map_err(|source| Error {
+            kind: ErrorType::Ratelimiting,
+            source: Some(Box::new(source)),
+        })?;
+        let tx = rx.await.map_err(|source| Error {
+            kind: ErrorType::RequestCanceled,
+            source: Some(Box::new(source)),
+        })?;
+
+        let resp = fut
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::RequestTimedOut,
+                source: Some(Box::new(source)),
+            })?
+            .map_err(|source| Error {
+                kind: ErrorType::RequestError,
+                source: Some(Box::new(source)),
+            })?;
+
+        // If the API sent back an Unauthorized response, then the client's
+        // configured token is permanently invalid and future requests must be
+        // ignored to avoid API bans.
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            self.state.token_invalid.store(true, Ordering::Relaxed);
+        }
+
+        match RatelimitHeaders::try_from(resp.headers()) {
+            Ok(v) => {
+                let _res = tx.send(Some(v));
+            }
+            Err(why) => {
+                tracing::warn!("header parsing failed: {:?}; {:?}", why, resp);
+
+                let _res = tx.send(None);
+            }
+        }
+
+        Ok(resp)
+    }
+
+    /// Build the HTTP request of a request, with its URL and headers.
+    fn build_request(&self, request: Request) -> Result<hyper::Request<Body>> {
         let Request {
             body,
             form,
             headers: req_headers,
             method,
-            path: bucket,
+            path: _,
             path_str: path,
         } = request;
 
-        let protocol = if self.state.use_http { "http" } else { "https" };
-        let host = self.state.proxy.as_deref().unwrap_or("discord.com");
+        let url = if let Some(base_url) = self.state.base_url.as_deref() {
+            format!("{}/api/v{}/{}", base_url, self.state.api_version, path)
+        } else {
+            let protocol = if self.state.use_http { "http" } else { "https" };
+            let host = self.state.proxy.as_deref().unwrap_or("discord.com");
 
-        let url = format!("{}://{}/api/v{}/{}", protocol, host, API_VERSION, path);
+            format!(
+                "{}://{}/api/v{}/{}",
+                protocol, host, self.state.api_version, path
+            )
+        };
         tracing::debug!("URL: {:?}", url);
 
         let mut builder = hyper::Request::builder()
@@ -1587,14 +1902,6 @@ impl Client {
             }
         }
 
-        let user_agent = HeaderValue::from_static(concat!(
-            "DiscordBot (",
-            env!("CARGO_PKG_HOMEPAGE"),
-            ", ",
-            env!("CARGO_PKG_VERSION"),
-            ") Twilight-rs",
-        ));
-
         if let Some(headers) = builder.headers_mut() {
             if let Some(form) = &form {
                 if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
@@ -1608,7 +1915,7 @@ impl Client {
                 headers.insert(CONTENT_TYPE, content_type);
             }
 
-            headers.insert(USER_AGENT, user_agent);
+            headers.insert(USER_AGENT, self.state.user_agent.clone());
 
             if let Some(req_headers) = req_headers {
                 for (maybe_name, value) in req_headers {
@@ -1657,61 +1964,7 @@ impl Client {
             })?
         };
 
-        let inner = self.state.http.request(req);
-        let fut = time::timeout(self.state.timeout, inner);
-
-        let ratelimiter = match self.state.ratelimiter.as_ref() {
-            Some(ratelimiter) => ratelimiter,
-            None => {
-                return fut
-                    .await
-                    .map_err(|source| Error {
-                        kind: ErrorType::RequestTimedOut,
-                        source: Some(Box::new(source)),
-                    })?
-                    .map_err(|source| Error {
-                        kind: ErrorType::RequestError,
-                        source: Some(Box::new(source)),
-                    });
-            }
-        };
-
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx.await.map_err(|source| Error {
-            kind: ErrorType::RequestCanceled,
-            source: Some(Box::new(source)),
-        })?;
-
-        let resp = fut
-            .await
-            .map_err(|source| Error {
-                kind: ErrorType::RequestTimedOut,
-                source: Some(Box::new(source)),
-            })?
-            .map_err(|source| Error {
-                kind: ErrorType::RequestError,
-                source: Some(Box::new(source)),
-            })?;
-
-        // If the API sent back an Unauthorized response, then the client's
-        // configured token is permanently invalid and future requests must be
-        // ignored to avoid API bans.
-        if resp.status() == StatusCode::UNAUTHORIZED {
-            self.state.token_invalid.store(true, Ordering::Relaxed);
-        }
-
-        match RatelimitHeaders::try_from(resp.headers()) {
-            Ok(v) => {
-                let _res = tx.send(Some(v));
-            }
-            Err(why) => {
-                tracing::warn!("header parsing failed: {:?}; {:?}", why, resp);
-
-                let _res = tx.send(None);
-            }
-        }
-
-        Ok(resp)
+        Ok(req)
     }
 
     /// Execute a request, chunking and deserializing the response.
@@ -1768,8 +2021,84 @@ impl Client {
         Ok(())
     }
 
+    /// Hold all requests of the client until the global ratelimit resets.
+    fn lock_global_ratelimit(&self, reset_after: Duration) {
+        let reset = Instant::now() + reset_after;
+        let mut global = self
+            .state
+            .global_ratelimit_reset
+            .lock()
+            .expect("global ratelimit poisoned");
+
+        tracing::debug!(
+            "global ratelimit hit, holding requests for {:?}",
+            reset_after
+        );
+
+        if global.map_or(true, |current| current < reset) {
+            global.replace(reset);
+        }
+    }
+
+    /// Wait until the global ratelimit that was last hit resets.
+    async fn wait_for_global_ratelimit(&self) {
+        let reset = *self
+            .state
+            .global_ratelimit_reset
+            .lock()
+            .expect("global ratelimit poisoned");
+
+        if let Some(reset) = reset {
+            time::sleep_until(reset).await;
+        }
+    }
+
+    /// Perform a request, retrying it according to the client's
+    /// [`RetryPolicy`].
     async fn make_request(&self, request: Request) -> Result<Response<Body>> {
-        let resp = self.raw(request).await?;
+        let policy = self.state.retry_policy;
+        let method = request.method;
+        // Wider than the policy's maximum number of retries, so that the last
+        // attempt doesn't overflow.
+        let mut attempt: u16 = 0;
+
+        let resp = loop {
+            attempt += 1;
+
+            self.wait_for_global_ratelimit().await;
+
+            let result = self.raw(request.clone()).await;
+
+            if let Ok(resp) = &result {
+                if let Some(reset_after) = retry::global_ratelimit(resp.status(), resp.headers()) {
+                    self.lock_global_ratelimit(reset_after);
+                }
+            }
+
+            let delay = match &result {
+                Ok(resp) => policy.response_delay(attempt, method, resp.status(), resp.headers()),
+                Err(e) if matches!(e.kind, ErrorType::RequestTimedOut) => {
+                    policy.timeout_delay(attempt, method)
+                }
+                Err(_) => None,
+            };
+
+            match delay {
+                Some(delay) => {
+                    tracing::debug!(
+                        attempt,
+                        path = ?request.path,
+                        status = ?result.as_ref().map(Response::status).ok(),
+                        "retrying request in {:?}",
+                        delay,
+                    );
+
+                    time::sleep(delay).await;
+                }
+                None => break result?,
+            }
+        };
+
         let status = resp.status();
 
         if status.is_success() {
@@ -1824,3 +2153,68 @@ impl Client {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, UserAgentErrorType};
+    use crate::{request::Request, routing::Route};
+    use hyper::header::USER_AGENT;
+
+    fn gateway(client: &Client) -> hyper::Request<hyper::Body> {
+        client
+            .build_request(Request::from_route(Route::GetGateway))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_default_url() {
+        let request = gateway(&Client::new("token"));
+
+        assert_eq!("https://discord.com/api/v9/gateway", request.uri());
+    }
+
+    #[test]
+    fn test_base_url_and_api_version() {
+        let client = Client::builder()
+            .base_url("http://localhost:8080/")
+            .api_version(8)
+            .proxy("proxy.internal", true)
+            .build();
+
+        assert_eq!(
+            "http://localhost:8080/api/v8/gateway",
+            gateway(&client).uri()
+        );
+    }
+
+    #[test]
+    fn test_proxy_url() {
+        let client = Client::builder().proxy("proxy.internal", true).build();
+
+        assert_eq!(
+            "http://proxy.internal/api/v9/gateway",
+            gateway(&client).uri()
+        );
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let default = gateway(&Client::new("token"));
+        assert!(default.headers()[USER_AGENT]
+            .to_str()
+            .unwrap()
+            .starts_with("DiscordBot ("));
+
+        let client = Client::builder()
+            .user_agent("my-client/1.0")
+            .unwrap()
+            .build();
+        assert_eq!("my-client/1.0", gateway(&client).headers()[USER_AGENT]);
+
+        let error = Client::builder().user_agent("invalid\n").unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            UserAgentErrorType::Invalid { user_agent } if user_agent == "invalid\n"
+        ));
+    }
+}
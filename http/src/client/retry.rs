@@ -0,0 +1,363 @@
+use crate::request::Method;
+use hyper::{header::HeaderMap, StatusCode};
+use rand::Rng;
+use std::time::Duration;
+
+/// Policy for retrying requests that failed for transient reasons.
+///
+/// Two kinds of failures are retried:
+///
+/// - Requests that got a `429 Too Many Requests` response. These are retried
+/// after the delay specified by the `retry-after` header, regardless of the
+/// method, because Discord didn't process them. If the response is for the
+/// global ratelimit then every request of the client waits for it to reset;
+/// - Idempotent requests (`DELETE`, `GET`, and `PUT`) that got a `502 Bad
+/// Gateway` or `503 Service Unavailable` response, or that timed out. These
+/// are retried after an exponential backoff with jitter.
+///
+/// The default policy retries each request up to 3 times, starting with a
+/// backoff of 500 milliseconds and capping delays at 30 seconds.
+///
+/// # Examples
+///
+/// Only retry ratelimited requests, at most 5 times:
+///
+/// ```rust
+/// use twilight_http::{client::RetryPolicy, Client};
+///
+/// let policy = RetryPolicy::new().max_retries(5).server_errors(false);
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .retry_policy(policy)
+///     .build();
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    base_delay: Duration,
+    max_delay: Duration,
+    max_retries: u8,
+    ratelimited: bool,
+    server_errors: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy with the default configuration.
+    pub fn new() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_retries: 3,
+            ratelimited: true,
+            server_errors: true,
+        }
+    }
+
+    /// Create a retry policy that never retries requests.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ratelimited: false,
+            server_errors: false,
+            ..Self::new()
+        }
+    }
+
+    /// Set the delay before the first retry of a request that failed due to
+    /// a server error or a timeout.
+    ///
+    /// The delay doubles after each attempt. The default is 500 milliseconds.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+
+        self
+    }
+
+    /// Set the maximum delay before retrying a request.
+    ///
+    /// Ratelimited requests with a `retry-after` longer than this are not
+    /// retried. The default is 30 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Set the maximum number of times a request is retried.
+    ///
+    /// The default is 3.
+    pub fn max_retries(mut self, max_retries: u8) -> Self {
+        self.max_retries = max_retries;
+
+        self
+    }
+
+    /// Set whether to retry requests that got a `429 Too Many Requests`
+    /// response.
+    ///
+    /// The default is `true`.
+    pub fn ratelimited(mut self, ratelimited: bool) -> Self {
+        self.ratelimited = ratelimited;
+
+        self
+    }
+
+    /// Set whether to retry idempotent requests that got a `502 Bad Gateway`
+    /// or `503 Service Unavailable` response, or that timed out.
+    ///
+    /// The default is `true`.
+    pub fn server_errors(mut self, server_errors: bool) -> Self {
+        self.server_errors = server_errors;
+
+        self
+    }
+
+    /// Delay before retrying a request that got a response, if it should be
+    /// retried.
+    ///
+    /// `attempt` is the number of attempts already made, starting at 1.
+    pub(crate) fn response_delay(
+        &self,
+        attempt: u16,
+        method: Method,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if attempt > u16::from(self.max_retries) {
+            return None;
+        }
+
+        match status {
+            StatusCode::TOO_MANY_REQUESTS if self.ratelimited => {
+                let delay = retry_after(headers).unwrap_or_else(|| self.backoff(attempt));
+
+                if delay <= self.max_delay {
+                    Some(delay)
+                } else {
+                    None
+                }
+            }
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE
+                if self.server_errors && is_idempotent(method) =>
+            {
+                Some(self.backoff(attempt))
+            }
+            _ => None,
+        }
+    }
+
+    /// Delay before retrying a request that timed out, if it should be
+    /// retried.
+    ///
+    /// `attempt` is the number of attempts already made, starting at 1.
+    pub(crate) fn timeout_delay(&self, attempt: u16, method: Method) -> Option<Duration> {
+        if attempt > u16::from(self.max_retries) || !self.server_errors || !is_idempotent(method) {
+            return None;
+        }
+
+        Some(self.backoff(attempt))
+    }
+
+    /// Exponential backoff with "equal jitter": half of the delay is fixed
+    /// and the other half is random, so that concurrent retries spread out.
+    fn backoff(&self, attempt: u16) -> Duration {
+        let exponent = u32::from(attempt.saturating_sub(1)).min(16);
+        let delay = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        let half = delay / 2;
+        #[allow(clippy::cast_possible_truncation)]
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+
+        half + Duration::from_millis(jitter)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Time until the global ratelimit resets, if the response is for the global
+/// ratelimit.
+pub(crate) fn global_ratelimit(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+
+    let global = headers
+        .get("x-ratelimit-global")
+        .and_then(|value| value.to_str().ok())
+        .map_or(false, |value| value.eq_ignore_ascii_case("true"));
+
+    if global {
+        retry_after(headers)
+    } else {
+        None
+    }
+}
+
+const fn is_idempotent(method: Method) -> bool {
+    matches!(method, Method::Delete | Method::Get | Method::Put)
+}
+
+/// Parse the `retry-after` header, which is a number of seconds that may
+/// contain a fraction.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let secs = headers
+        .get("retry-after")?
+        .to_str()
+        .ok()?
+        .parse::<f64>()
+        .ok()?;
+
+    if secs.is_finite() && secs >= 0.0 {
+        Some(Duration::from_secs_f64(secs))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::request::Method;
+    use hyper::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(RetryPolicy: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn retry_after(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static(value));
+
+        headers
+    }
+
+    #[test]
+    fn test_ratelimited() {
+        let policy = RetryPolicy::new();
+
+        assert_eq!(
+            Some(Duration::from_millis(1500)),
+            policy.response_delay(
+                1,
+                Method::Post,
+                StatusCode::TOO_MANY_REQUESTS,
+                &retry_after("1.5")
+            )
+        );
+        assert_eq!(
+            None,
+            policy.response_delay(
+                1,
+                Method::Post,
+                StatusCode::TOO_MANY_REQUESTS,
+                &retry_after("3600")
+            )
+        );
+        assert_eq!(
+            None,
+            policy.response_delay(
+                4,
+                Method::Post,
+                StatusCode::TOO_MANY_REQUESTS,
+                &retry_after("1")
+            )
+        );
+        assert_eq!(
+            None,
+            RetryPolicy::new().ratelimited(false).response_delay(
+                1,
+                Method::Get,
+                StatusCode::TOO_MANY_REQUESTS,
+                &retry_after("1")
+            )
+        );
+    }
+
+    #[test]
+    fn test_server_errors() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_secs(2))
+            .max_delay(Duration::from_secs(5));
+        let headers = HeaderMap::new();
+
+        let delay = policy
+            .response_delay(1, Method::Get, StatusCode::BAD_GATEWAY, &headers)
+            .unwrap();
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+
+        let delay = policy
+            .response_delay(3, Method::Delete, StatusCode::SERVICE_UNAVAILABLE, &headers)
+            .unwrap();
+        assert!(delay >= Duration::from_millis(2500) && delay <= Duration::from_secs(5));
+
+        assert!(policy
+            .response_delay(1, Method::Post, StatusCode::BAD_GATEWAY, &headers)
+            .is_none());
+        assert!(policy
+            .response_delay(1, Method::Get, StatusCode::INTERNAL_SERVER_ERROR, &headers)
+            .is_none());
+        assert!(policy.timeout_delay(1, Method::Put).is_some());
+        assert!(policy.timeout_delay(1, Method::Patch).is_none());
+        assert!(policy.timeout_delay(4, Method::Get).is_none());
+    }
+
+    #[test]
+    fn test_disabled() {
+        let policy = RetryPolicy::disabled();
+
+        assert!(policy
+            .response_delay(
+                1,
+                Method::Get,
+                StatusCode::TOO_MANY_REQUESTS,
+                &retry_after("1")
+            )
+            .is_none());
+        assert!(policy.timeout_delay(1, Method::Get).is_none());
+    }
+
+    #[test]
+    fn test_max_retries_limit() {
+        let policy = RetryPolicy::new()
+            .max_retries(u8::MAX)
+            .max_delay(Duration::from_millis(10));
+        let headers = HeaderMap::new();
+
+        assert!(policy
+            .response_delay(255, Method::Get, StatusCode::BAD_GATEWAY, &headers)
+            .is_some());
+        assert!(policy
+            .response_delay(256, Method::Get, StatusCode::BAD_GATEWAY, &headers)
+            .is_none());
+        assert!(policy.timeout_delay(256, Method::Get).is_none());
+    }
+
+    #[test]
+    fn test_global_ratelimit() {
+        let mut headers = retry_after("2");
+
+        assert_eq!(
+            None,
+            super::global_ratelimit(StatusCode::TOO_MANY_REQUESTS, &headers)
+        );
+
+        headers.insert("x-ratelimit-global", HeaderValue::from_static("true"));
+
+        assert_eq!(
+            Some(Duration::from_secs(2)),
+            super::global_ratelimit(StatusCode::TOO_MANY_REQUESTS, &headers)
+        );
+        assert_eq!(None, super::global_ratelimit(StatusCode::OK, &headers));
+    }
+}
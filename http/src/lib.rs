@@ -100,7 +100,7 @@ pub mod request;
 pub mod routing;
 
 /// Discord API version used by this crate.
-pub const API_VERSION: u8 = 8;
+pub const API_VERSION: u8 = 9;
 
 pub use crate::{
     client::Client,
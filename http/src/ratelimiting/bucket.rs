@@ -1,9 +1,15 @@
-use super::{headers::RatelimitHeaders, GlobalLockPair};
+use super::{
+    event::{Emitter, RatelimitEvent},
+    headers::RatelimitHeaders,
+    in_memory::GlobalLockPair,
+    BucketSnapshot, TicketSender,
+};
 use crate::routing::Path;
+use futures_util::FutureExt;
 use std::{
     collections::HashMap,
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
     time::{Duration, Instant},
@@ -17,6 +23,19 @@ use tokio::{
     time::{sleep, timeout},
 };
 
+/// Key that buckets are stored under.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BucketKey {
+    /// Bucket shared by all paths with the same bucket hash and major
+    /// parameter.
+    Hash {
+        hash: String,
+        major_parameter: Option<u64>,
+    },
+    /// Bucket of a path whose bucket hash isn't known yet.
+    Path(Path),
+}
+
 #[derive(Clone, Debug)]
 pub enum TimeRemaining {
     Finished,
@@ -58,6 +77,29 @@ impl Bucket {
         self.reset_after.load(Ordering::Relaxed)
     }
 
+    pub async fn snapshot(&self, key: &BucketKey) -> BucketSnapshot {
+        let known = |value: u64| Some(value).filter(|value| *value != u64::MAX);
+
+        let hash = match key {
+            BucketKey::Hash { hash, .. } => Some(hash.clone()),
+            BucketKey::Path(_) => None,
+        };
+
+        let reset_after = match self.time_remaining().await {
+            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+            TimeRemaining::Some(duration) => Some(duration),
+        };
+
+        BucketSnapshot {
+            hash,
+            limit: known(self.limit()),
+            path: self.path.clone(),
+            queue_len: self.queue.len(),
+            remaining: known(self.remaining()),
+            reset_after,
+        }
+    }
+
     pub async fn time_remaining(&self) -> TimeRemaining {
         let reset_after = self.reset_after();
         let started_at = match *self.started_at.lock().await {
@@ -112,26 +154,48 @@ impl Bucket {
     }
 }
 
+/// Request waiting in a bucket's queue, with the path it's made to.
+type QueuedTicket = (Path, Sender<TicketSender>);
+
 #[derive(Debug)]
 pub struct BucketQueue {
-    rx: Mutex<UnboundedReceiver<Sender<Sender<Option<RatelimitHeaders>>>>>,
-    tx: UnboundedSender<Sender<Sender<Option<RatelimitHeaders>>>>,
+    len: AtomicUsize,
+    rx: Mutex<UnboundedReceiver<QueuedTicket>>,
+    tx: UnboundedSender<QueuedTicket>,
 }
 
 impl BucketQueue {
-    pub fn push(&self, tx: Sender<Sender<Option<RatelimitHeaders>>>) {
-        let _sent = self.tx.send(tx);
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
     }
 
-    pub async fn pop(
-        &self,
-        timeout_duration: Duration,
-    ) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    pub fn push(&self, path: Path, tx: Sender<TicketSender>) {
+        self.len.fetch_add(1, Ordering::Relaxed);
+
+        if self.tx.send((path, tx)).is_err() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<QueuedTicket> {
+        let mut rx = self.rx.lock().await;
+
+        let ticket = timeout(timeout_duration, rx.recv()).await.ok().flatten();
+
+        if ticket.is_some() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+        }
+
+        ticket
+    }
+
+    /// Move all queued requests into another queue, keeping their order.
+    pub async fn drain_into(&self, other: &Self) {
         let mut rx = self.rx.lock().await;
 
-        match timeout(timeout_duration, rx.recv()).await.ok() {
-            Some(x) => x,
-            None => None,
+        while let Some(Some((path, tx))) = rx.recv().now_or_never() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+            other.push(path, tx);
         }
     }
 }
@@ -141,6 +205,7 @@ impl Default for BucketQueue {
         let (tx, rx) = mpsc::unbounded_channel();
 
         Self {
+            len: AtomicUsize::new(0),
             rx: Mutex::new(rx),
             tx,
         }
@@ -149,9 +214,14 @@ impl Default for BucketQueue {
 
 pub(super) struct BucketQueueTask {
     bucket: Arc<Bucket>,
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    buckets: Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>,
+    emitter: Emitter,
     global: Arc<GlobalLockPair>,
-    path: Path,
+    hashes: Arc<Mutex<HashMap<Path, String>>>,
+    key: BucketKey,
+    /// Whether the queue was merged into the bucket of its hash, after which
+    /// the task stops.
+    merged: bool,
 }
 
 impl BucketQueueTask {
@@ -159,35 +229,42 @@ impl BucketQueueTask {
 
     pub fn new(
         bucket: Arc<Bucket>,
-        buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+        buckets: Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>,
+        emitter: Emitter,
         global: Arc<GlobalLockPair>,
-        path: Path,
+        hashes: Arc<Mutex<HashMap<Path, String>>>,
+        key: BucketKey,
     ) -> Self {
         Self {
             bucket,
             buckets,
+            emitter,
             global,
-            path,
+            hashes,
+            key,
+            merged: false,
         }
     }
 
-    pub async fn run(self) {
-        let span = tracing::debug_span!("background queue task", path=?self.path);
+    pub async fn run(mut self) {
+        let span = tracing::debug_span!("background queue task", key=?self.key);
 
-        while let Some(queue_tx) = self.next().await {
+        while let Some((path, queue_tx)) = self.next().await {
             let (tx, rx) = oneshot::channel();
 
             if self.global.is_locked() {
                 self.global.0.lock().await;
             }
 
+            self.emitter
+                .emit(&RatelimitEvent::RequestReleased { path: path.clone() });
             let _sent = queue_tx.send(tx);
 
             tracing::debug!(parent: &span, "starting to wait for response headers",);
 
             // TODO: Find a better way of handling nested types.
             match timeout(Self::WAIT, rx).await {
-                Ok(Ok(Some(headers))) => self.handle_headers(&headers).await,
+                Ok(Ok(Some(headers))) => self.handle_headers(&path, &headers).await,
                 // - None was sent through the channel (request aborted)
                 // - channel was closed
                 // - timeout reached
@@ -195,14 +272,29 @@ impl BucketQueueTask {
                     tracing::debug!(parent: &span, "receiver timed out");
                 }
             }
+
+            if self.merged {
+                tracing::debug!(parent: &span, "queue merged into shared bucket");
+
+                return;
+            }
         }
 
         tracing::debug!(parent: &span, "bucket appears finished, removing");
 
-        self.buckets.lock().await.remove(&self.path);
+        let mut buckets = self.buckets.lock().await;
+
+        if buckets
+            .get(&self.key)
+            .map_or(false, |bucket| Arc::ptr_eq(bucket, &self.bucket))
+        {
+            buckets.remove(&self.key);
+        }
     }
 
-    async fn handle_headers(&self, headers: &RatelimitHeaders) {
+    async fn handle_headers(&mut self, path: &Path, headers: &RatelimitHeaders) {
+        let mut shared = None;
+
         let ratelimits = match headers {
             RatelimitHeaders::GlobalLimited { reset_after } => {
                 self.lock_global(Duration::from_secs(*reset_after)).await;
@@ -211,6 +303,7 @@ impl BucketQueueTask {
             }
             RatelimitHeaders::None => return,
             RatelimitHeaders::Present {
+                bucket,
                 global,
                 limit,
                 remaining,
@@ -221,16 +314,87 @@ impl BucketQueueTask {
                     self.lock_global(Duration::from_secs(*reset_after)).await;
                 }
 
+                if let Some(hash) = bucket {
+                    shared = self.learn_hash(path, hash).await;
+                }
+
                 Some((*limit, *remaining, *reset_after))
             }
         };
 
-        tracing::debug!(path=?self.path, "updating bucket");
+        tracing::debug!(key=?self.key, "updating bucket");
         self.bucket.update(ratelimits).await;
+
+        if let Some(shared) = shared {
+            tracing::debug!(?path, "updating shared bucket");
+            shared.update(ratelimits).await;
+        }
+    }
+
+    /// Remember the bucket hash of a path, so that later requests to it use
+    /// the bucket shared by all paths with that hash.
+    ///
+    /// If this bucket is the path's own bucket and no bucket exists for the
+    /// hash yet, then this bucket becomes the shared bucket and keeps its
+    /// ratelimit state. If a shared bucket already exists, then the requests
+    /// queued in the path's own bucket are moved into it and this task stops.
+    /// The shared bucket is returned if it's another bucket, so that it can be
+    /// updated too.
+    async fn learn_hash(&mut self, path: &Path, hash: &str) -> Option<Arc<Bucket>> {
+        let key = BucketKey::Hash {
+            hash: hash.to_owned(),
+            major_parameter: path.major_parameter(),
+        };
+
+        {
+            let mut hashes = self.hashes.lock().await;
+
+            if hashes.get(path).map(String::as_str) != Some(hash) {
+                tracing::debug!(?path, hash, "learned bucket hash of path");
+                hashes.insert(path.clone(), hash.to_owned());
+            }
+        }
+
+        let mut buckets = self.buckets.lock().await;
+
+        let is_own = matches!(&self.key, BucketKey::Path(own) if own == path)
+            && buckets
+                .get(&self.key)
+                .map_or(false, |bucket| Arc::ptr_eq(bucket, &self.bucket));
+
+        if let Some(shared) = buckets.get(&key) {
+            if Arc::ptr_eq(shared, &self.bucket) {
+                return None;
+            }
+
+            let shared = Arc::clone(shared);
+
+            if is_own {
+                tracing::debug!(?path, hash, "merging queue into shared bucket");
+
+                // Requests are only queued while the buckets are locked, so
+                // none can be added to this queue once it's removed.
+                buckets.remove(&self.key);
+                self.bucket.queue.drain_into(&shared.queue).await;
+                self.merged = true;
+            }
+
+            return Some(shared);
+        }
+
+        if is_own {
+            buckets.remove(&self.key);
+            buckets.insert(key.clone(), Arc::clone(&self.bucket));
+            self.key = key;
+        }
+
+        None
     }
 
     async fn lock_global(&self, wait: Duration) {
-        tracing::debug!(path=?self.path, "request got global ratelimited");
+        tracing::debug!(key=?self.key, "request got global ratelimited");
+        self.emitter
+            .emit(&RatelimitEvent::GlobalRatelimited { reset_after: wait });
         self.global.lock();
         let lock = self.global.0.lock().await;
         sleep(wait).await;
@@ -239,8 +403,8 @@ impl BucketQueueTask {
         drop(lock);
     }
 
-    async fn next(&self) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
-        tracing::debug!(path=?self.path, "starting to get next in queue");
+    async fn next(&self) -> Option<QueuedTicket> {
+        tracing::debug!(key=?self.key, "starting to get next in queue");
 
         self.wait_if_needed().await;
 
@@ -248,7 +412,7 @@ impl BucketQueueTask {
     }
 
     async fn wait_if_needed(&self) {
-        let span = tracing::debug_span!("waiting for bucket to refresh", path=?self.path);
+        let span = tracing::debug_span!("waiting for bucket to refresh", key=?self.key);
 
         let wait = {
             if self.bucket.remaining() > 0 {
@@ -274,6 +438,10 @@ impl BucketQueueTask {
             "waiting for ratelimit to pass",
         );
 
+        self.emitter.emit(&RatelimitEvent::BucketExhausted {
+            path: self.bucket.path.clone(),
+            reset_after: wait,
+        });
         sleep(wait).await;
 
         tracing::debug!(parent: &span, "done waiting for ratelimit to pass");
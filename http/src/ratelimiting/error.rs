@@ -39,6 +39,13 @@ impl RatelimitError {
         }
     }
 
+    pub(super) fn store(source: Box<dyn Error + Send + Sync>) -> Self {
+        Self {
+            kind: RatelimitErrorType::Store,
+            source: Some(source),
+        }
+    }
+
     pub(super) fn header_not_utf8(name: &'static str, value: Vec<u8>, source: ToStrError) -> Self {
         Self {
             kind: RatelimitErrorType::HeaderNotUtf8 { name, value },
@@ -72,6 +79,7 @@ impl Display for RatelimitError {
                 "The header {:?} should be an integer but isn't: {:?}",
                 name, text
             ),
+            RatelimitErrorType::Store => f.write_str("The ratelimit store failed"),
         }
     }
 }
@@ -88,9 +96,28 @@ impl Error for RatelimitError {
 #[non_exhaustive]
 pub enum RatelimitErrorType {
     NoHeaders,
-    HeaderMissing { name: &'static str },
-    HeaderNotUtf8 { name: &'static str, value: Vec<u8> },
-    ParsingBoolText { name: &'static str, text: String },
-    ParsingFloatText { name: &'static str, text: String },
-    ParsingIntText { name: &'static str, text: String },
+    HeaderMissing {
+        name: &'static str,
+    },
+    HeaderNotUtf8 {
+        name: &'static str,
+        value: Vec<u8>,
+    },
+    ParsingBoolText {
+        name: &'static str,
+        text: String,
+    },
+    ParsingFloatText {
+        name: &'static str,
+        text: String,
+    },
+    ParsingIntText {
+        name: &'static str,
+        text: String,
+    },
+    /// The [`KeyValueStore`] of a [`SharedRatelimiter`] returned an error.
+    ///
+    /// [`KeyValueStore`]: super::KeyValueStore
+    /// [`SharedRatelimiter`]: super::SharedRatelimiter
+    Store,
 }
@@ -0,0 +1,125 @@
+//! Events that ratelimiters emit to subscribers.
+
+use crate::routing::Path;
+use futures_util::stream::Stream;
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// Event emitted by a ratelimiter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RatelimitEvent {
+    /// A bucket has no requests remaining, and requests to it are held until
+    /// it resets.
+    BucketExhausted {
+        /// Path of the bucket.
+        path: Path,
+        /// Time until the bucket resets.
+        reset_after: Duration,
+    },
+    /// The global ratelimit was hit, and all requests are held until it
+    /// resets.
+    GlobalRatelimited {
+        /// Time until the global ratelimit resets.
+        reset_after: Duration,
+    },
+    /// A request was queued in the bucket of its path.
+    RequestQueued {
+        /// Path of the request.
+        path: Path,
+    },
+    /// A request was released from its queue and may now be made.
+    RequestReleased {
+        /// Path of the request.
+        path: Path,
+    },
+}
+
+/// Stream of events from a ratelimiter.
+///
+/// This implements [`futures::stream::Stream`].
+///
+/// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
+#[derive(Debug)]
+pub struct RatelimitEvents {
+    rx: UnboundedReceiver<RatelimitEvent>,
+}
+
+impl RatelimitEvents {
+    /// Create a stream of events from the receiving half of a channel.
+    ///
+    /// This is useful for implementing [`Ratelimiter::subscribe`].
+    ///
+    /// [`Ratelimiter::subscribe`]: super::Ratelimiter::subscribe
+    pub fn new(rx: UnboundedReceiver<RatelimitEvent>) -> Self {
+        Self { rx }
+    }
+}
+
+impl Stream for RatelimitEvents {
+    type Item = RatelimitEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// Sends events to all subscribers, forgetting those that went away.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Emitter {
+    subscribers: Arc<Mutex<Vec<UnboundedSender<RatelimitEvent>>>>,
+}
+
+impl Emitter {
+    pub fn subscribe(&self) -> RatelimitEvents {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.subscribers
+            .lock()
+            .expect("subscribers poisoned")
+            .push(tx);
+
+        RatelimitEvents::new(rx)
+    }
+
+    pub fn emit(&self, event: &RatelimitEvent) {
+        let mut subscribers = self.subscribers.lock().expect("subscribers poisoned");
+
+        if subscribers.is_empty() {
+            return;
+        }
+
+        subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Emitter, RatelimitEvent, RatelimitEvents};
+    use crate::routing::Path;
+    use futures_util::stream::{Stream, StreamExt};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(RatelimitEvent: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(RatelimitEvents: Debug, Send, Stream, Sync);
+
+    #[tokio::test]
+    async fn test_emitter() {
+        let emitter = Emitter::default();
+        let mut events = emitter.subscribe();
+        drop(emitter.subscribe());
+
+        let event = RatelimitEvent::RequestQueued {
+            path: Path::ChannelsIdMessages(1),
+        };
+        emitter.emit(&event);
+
+        assert_eq!(Some(event), events.next().await);
+        assert_eq!(1, emitter.subscribers.lock().unwrap().len());
+    }
+}
@@ -0,0 +1,357 @@
+use super::{
+    bucket::{Bucket, BucketKey, BucketQueueTask, TimeRemaining},
+    event::{Emitter, RatelimitEvent, RatelimitEvents},
+    GetBucketsFuture, GetTicketFuture, IsGloballyLockedFuture, RatelimitHeaders, Ratelimiter,
+    TicketReceiver, TimeUntilAvailableFuture,
+};
+use crate::routing::Path;
+use std::{
+    collections::hash_map::{Entry, HashMap},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::{
+    oneshot::{self, Sender},
+    Mutex,
+};
+
+/// Global lock. We use a pair to avoid actually locking the mutex every check.
+/// This allows futures to only wait on the global lock when a global ratelimit
+/// is in place by, in turn, waiting for a guard, and then each immediately
+/// dropping it.
+#[derive(Debug, Default)]
+pub(super) struct GlobalLockPair(pub(super) Mutex<()>, AtomicBool);
+
+impl GlobalLockPair {
+    pub fn lock(&self) {
+        self.1.store(true, Ordering::Release);
+    }
+
+    pub fn unlock(&self) {
+        self.1.store(false, Ordering::Release);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.1.load(Ordering::Relaxed)
+    }
+}
+
+/// Default ratelimiter, which keeps track of buckets in the memory of the
+/// current process.
+///
+/// Paths start out in buckets of their own. Once a response tells which
+/// bucket a path is in, through its `x-ratelimit-bucket` header, the path
+/// shares a bucket with all other paths that have the same bucket hash and
+/// [major parameter].
+///
+/// [major parameter]: Path::major_parameter
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRatelimiter {
+    buckets: Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>,
+    emitter: Emitter,
+    global: Arc<GlobalLockPair>,
+    hashes: Arc<Mutex<HashMap<Path, String>>>,
+}
+
+impl InMemoryRatelimiter {
+    /// Create a new ratelimiter.
+    ///
+    /// Most users won't need to use this directly. If you're creating your own
+    /// HTTP proxy then this is good to use for your own ratelimiting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn enqueue(self, path: Path) -> TicketReceiver {
+        tracing::debug!("getting bucket for path: {:?}", path);
+
+        let (tx, rx) = oneshot::channel();
+        let key = self.key(&path).await;
+        let (bucket, fresh) = self.entry(key.clone(), path.clone(), tx).await;
+        self.emitter.emit(&RatelimitEvent::RequestQueued { path });
+
+        if fresh {
+            tokio::spawn(
+                BucketQueueTask::new(
+                    bucket,
+                    Arc::clone(&self.buckets),
+                    self.emitter.clone(),
+                    Arc::clone(&self.global),
+                    Arc::clone(&self.hashes),
+                    key,
+                )
+                .run(),
+            );
+        }
+
+        rx
+    }
+
+    /// Key of the bucket that a path is in, which is its bucket hash and
+    /// major parameter if the hash is known.
+    async fn key(&self, path: &Path) -> BucketKey {
+        match self.hashes.lock().await.get(path) {
+            Some(hash) => BucketKey::Hash {
+                hash: hash.clone(),
+                major_parameter: path.major_parameter(),
+            },
+            None => BucketKey::Path(path.clone()),
+        }
+    }
+
+    async fn entry(
+        &self,
+        key: BucketKey,
+        path: Path,
+        tx: Sender<Sender<Option<RatelimitHeaders>>>,
+    ) -> (Arc<Bucket>, bool) {
+        // nb: not realisically point of contention
+        let mut buckets = self.buckets.lock().await;
+
+        match buckets.entry(key) {
+            Entry::Occupied(bucket) => {
+                tracing::debug!("got existing bucket: {:?}", path);
+
+                let bucket = bucket.into_mut();
+                bucket.queue.push(path.clone(), tx);
+                tracing::debug!("added request into bucket queue: {:?}", path);
+
+                (Arc::clone(&bucket), false)
+            }
+            Entry::Vacant(entry) => {
+                tracing::debug!("making new bucket for path: {:?}", path);
+                let bucket = Bucket::new(path.clone());
+                bucket.queue.push(path, tx);
+
+                let bucket = Arc::new(bucket);
+                entry.insert(Arc::clone(&bucket));
+
+                (bucket, true)
+            }
+        }
+    }
+}
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn ticket(&self, path: Path) -> GetTicketFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move { Ok(ratelimiter.enqueue(path).await) })
+    }
+
+    fn time_until_available(&self, path: Path) -> TimeUntilAvailableFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            let key = ratelimiter.key(&path).await;
+
+            let bucket = match ratelimiter.buckets.lock().await.get(&key) {
+                Some(bucket) => Arc::clone(bucket),
+                None => return Ok(None),
+            };
+
+            match bucket.time_remaining().await {
+                TimeRemaining::Finished | TimeRemaining::NotStarted => Ok(None),
+                TimeRemaining::Some(duration) => Ok(Some(duration)),
+            }
+        })
+    }
+
+    fn buckets(&self) -> GetBucketsFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            let buckets = ratelimiter
+                .buckets
+                .lock()
+                .await
+                .iter()
+                .map(|(key, bucket)| (key.clone(), Arc::clone(bucket)))
+                .collect::<Vec<_>>();
+
+            let mut snapshots = Vec::with_capacity(buckets.len());
+
+            for (key, bucket) in buckets {
+                snapshots.push(bucket.snapshot(&key).await);
+            }
+
+            Ok(snapshots)
+        })
+    }
+
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture {
+        let locked = self.global.is_locked();
+
+        Box::pin(async move { Ok(locked) })
+    }
+
+    fn subscribe(&self) -> RatelimitEvents {
+        self.emitter.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryRatelimiter;
+    use crate::{
+        ratelimiting::{bucket::BucketKey, RatelimitEvent, RatelimitHeaders, Ratelimiter},
+        request::Method,
+        routing::Path,
+    };
+    use futures_util::StreamExt;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+
+    fn headers(bucket: &str) -> RatelimitHeaders {
+        RatelimitHeaders::Present {
+            bucket: Some(bucket.to_owned()),
+            global: false,
+            limit: 5,
+            remaining: 0,
+            reset: 0,
+            reset_after: 60_000,
+        }
+    }
+
+    async fn request(ratelimiter: &InMemoryRatelimiter, path: Path, headers: RatelimitHeaders) {
+        let ticket = ratelimiter.ticket(path.clone()).await.unwrap();
+        ticket.await.unwrap().send(Some(headers)).unwrap();
+
+        // Wait for the headers to be handled by the bucket's queue task, which
+        // starts the ratelimit of the path's bucket.
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while ratelimiter
+                .time_until_available(path.clone())
+                .await
+                .unwrap()
+                .is_none()
+            {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("headers weren't handled");
+    }
+
+    #[tokio::test]
+    async fn test_bucket_hash_merges_paths() {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let edit = Path::ChannelsIdMessagesId(Method::Patch, 1);
+        let delete = Path::ChannelsIdMessagesId(Method::Delete, 1);
+        let other_channel = Path::ChannelsIdMessagesId(Method::Delete, 2);
+
+        request(&ratelimiter, edit.clone(), headers("abc")).await;
+
+        assert!(ratelimiter
+            .time_until_available(edit.clone())
+            .await
+            .unwrap()
+            .is_some());
+        // The bucket hash of the path isn't known yet.
+        assert!(ratelimiter
+            .time_until_available(delete.clone())
+            .await
+            .unwrap()
+            .is_none());
+
+        request(&ratelimiter, delete.clone(), headers("abc")).await;
+        let hashes = ratelimiter.hashes.lock().await;
+        assert_eq!(Some("abc"), hashes.get(&delete).map(String::as_str));
+        drop(hashes);
+
+        let wait = ratelimiter
+            .time_until_available(delete)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(wait > Duration::from_secs(59));
+
+        // Paths with another major parameter are in another bucket.
+        request(&ratelimiter, other_channel.clone(), headers("abc")).await;
+        let buckets = ratelimiter.buckets.lock().await;
+        assert!(buckets.contains_key(&BucketKey::Hash {
+            hash: "abc".to_owned(),
+            major_parameter: Some(1),
+        }));
+        assert!(buckets.contains_key(&BucketKey::Hash {
+            hash: "abc".to_owned(),
+            major_parameter: Some(2),
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_bucket_hash_merges_queued_requests() {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let edit = Path::ChannelsIdMessagesId(Method::Patch, 1);
+        let delete = Path::ChannelsIdMessagesId(Method::Delete, 1);
+        let key = BucketKey::Hash {
+            hash: "abc".to_owned(),
+            major_parameter: Some(1),
+        };
+
+        request(&ratelimiter, edit, headers("abc")).await;
+
+        // Queue a second request to a path whose bucket hash isn't known yet
+        // while its first request is in flight.
+        let first = ratelimiter.ticket(delete.clone()).await.unwrap();
+        let headers_tx = first.await.unwrap();
+        let _second = ratelimiter.ticket(delete.clone()).await.unwrap();
+        headers_tx.send(Some(headers("abc"))).unwrap();
+
+        // Wait for the path's queue to be merged into the shared bucket.
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while ratelimiter
+                .buckets
+                .lock()
+                .await
+                .contains_key(&BucketKey::Path(delete.clone()))
+            {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("queue wasn't merged");
+
+        let buckets = ratelimiter.buckets.lock().await;
+        assert_eq!(1, buckets.len());
+        assert_eq!(1, buckets[&key].queue.len());
+    }
+
+    #[tokio::test]
+    async fn test_introspection() {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let mut events = ratelimiter.subscribe();
+        let path = Path::ChannelsIdMessages(1);
+
+        request(&ratelimiter, path.clone(), headers("abc")).await;
+
+        assert_eq!(
+            Some(RatelimitEvent::RequestQueued { path: path.clone() }),
+            events.next().await
+        );
+        assert_eq!(
+            Some(RatelimitEvent::RequestReleased { path: path.clone() }),
+            events.next().await
+        );
+        assert!(matches!(
+            events.next().await,
+            Some(RatelimitEvent::BucketExhausted { path: exhausted, .. }) if exhausted == path
+        ));
+
+        let snapshots = ratelimiter.buckets().await.unwrap();
+        assert_eq!(1, snapshots.len());
+        let snapshot = &snapshots[0];
+        assert_eq!(Some("abc"), snapshot.hash.as_deref());
+        assert_eq!(Some(5), snapshot.limit);
+        assert_eq!(path, snapshot.path);
+        assert_eq!(0, snapshot.queue_len);
+        assert_eq!(Some(0), snapshot.remaining);
+        assert!(snapshot.reset_after.is_some());
+
+        assert!(!ratelimiter.is_globally_locked().await.unwrap());
+    }
+}
@@ -1,126 +1,109 @@
+//! Ratelimiting of requests made by the HTTP client.
+//!
+//! The client asks its [`Ratelimiter`] for a ticket before each request, and
+//! sends the ratelimit headers of the response back through the ticket. By
+//! default an [`InMemoryRatelimiter`] is used, which keeps track of buckets
+//! in the current process. Processes that share a token can instead share
+//! their buckets through an external key-value store with a
+//! [`SharedRatelimiter`].
+
 pub mod error;
+pub mod event;
 
 mod bucket;
 mod headers;
+mod in_memory;
+mod shared;
 
 pub use self::{
     error::{RatelimitError, RatelimitResult},
+    event::{RatelimitEvent, RatelimitEvents},
     headers::RatelimitHeaders,
+    in_memory::InMemoryRatelimiter,
+    shared::{KeyValueStore, LocalStore, SharedRatelimiter, StoreFuture},
 };
 
 use crate::routing::Path;
-use bucket::{Bucket, BucketQueueTask, TimeRemaining};
-use std::{
-    collections::hash_map::{Entry, HashMap},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
-    },
-    time::Duration,
-};
-use tokio::sync::{
-    oneshot::{self, Receiver, Sender},
-    Mutex,
-};
-
-/// Global lock. We use a pair to avoid actually locking the mutex every check.
-/// This allows futures to only wait on the global lock when a global ratelimit
-/// is in place by, in turn, waiting for a guard, and then each immediately
-/// dropping it.
-#[derive(Debug, Default)]
-struct GlobalLockPair(Mutex<()>, AtomicBool);
-
-impl GlobalLockPair {
-    pub fn lock(&self) {
-        self.1.store(true, Ordering::Release);
-    }
-
-    pub fn unlock(&self) {
-        self.1.store(false, Ordering::Release);
-    }
-
-    pub fn is_locked(&self) -> bool {
-        self.1.load(Ordering::Relaxed)
-    }
-}
-
-#[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
-    global: Arc<GlobalLockPair>,
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+use tokio::sync::oneshot::{Receiver, Sender};
+
+/// Sender of the ratelimit headers of a response, or `None` if the request
+/// failed without a response.
+pub type TicketSender = Sender<Option<RatelimitHeaders>>;
+
+/// Receiver of a [`TicketSender`], which is received once the request may be
+/// made.
+pub type TicketReceiver = Receiver<TicketSender>;
+
+/// Future returned by [`Ratelimiter::ticket`].
+pub type GetTicketFuture = Pin<Box<dyn Future<Output = RatelimitResult<TicketReceiver>> + Send>>;
+
+/// Future returned by [`Ratelimiter::buckets`].
+pub type GetBucketsFuture =
+    Pin<Box<dyn Future<Output = RatelimitResult<Vec<BucketSnapshot>>> + Send>>;
+
+/// Future returned by [`Ratelimiter::is_globally_locked`].
+pub type IsGloballyLockedFuture = Pin<Box<dyn Future<Output = RatelimitResult<bool>> + Send>>;
+
+/// Future returned by [`Ratelimiter::time_until_available`].
+pub type TimeUntilAvailableFuture =
+    Pin<Box<dyn Future<Output = RatelimitResult<Option<Duration>>> + Send>>;
+
+/// Snapshot of the state of a bucket.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BucketSnapshot {
+    /// Bucket hash of the bucket, if it's known.
+    pub hash: Option<String>,
+    /// Total number of requests that can be made before the bucket resets,
+    /// if it's known.
+    pub limit: Option<u64>,
+    /// Path of the bucket.
+    ///
+    /// If several paths share the bucket then this is the first path that
+    /// was used.
+    pub path: Path,
+    /// Number of requests waiting for the bucket.
+    pub queue_len: usize,
+    /// Number of requests remaining before the bucket resets, if it's known.
+    pub remaining: Option<u64>,
+    /// Time until the bucket resets, if it has started.
+    pub reset_after: Option<Duration>,
 }
 
-impl Ratelimiter {
-    /// Create a new ratelimiter.
+/// Backend that decides when requests may be made without being ratelimited.
+///
+/// # Implementing
+///
+/// Before making a request the client calls [`ticket`] and waits for the
+/// returned [`TicketReceiver`] to receive a [`TicketSender`]. Once the
+/// response arrives the client sends its [`RatelimitHeaders`] through the
+/// sender, or `None` if the request failed.
+///
+/// [`ticket`]: Self::ticket
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Get a ticket for making a request to a path.
     ///
-    /// Most users won't need to use this directly. If you're creating your own
-    /// HTTP proxy then this is good to use for your own ratelimiting.
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
-        tracing::debug!("getting bucket for path: {:?}", path);
-
-        let (tx, rx) = oneshot::channel();
-        let (bucket, fresh) = self.entry(path.clone(), tx).await;
-
-        if fresh {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
-        }
-
-        rx
-    }
+    /// The returned receiver resolves when the request may be made.
+    fn ticket(&self, path: Path) -> GetTicketFuture;
 
     /// Provide an estimate for the time left until a path can be used
     /// without being ratelimited.
     ///
     /// This method is not guaranteed to be accurate and may return
     /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
-        let buckets = self.buckets.lock().await;
-        match buckets.get(path)?.time_remaining().await {
-            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
-            TimeRemaining::Some(duration) => Some(duration),
-        }
-    }
-
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
-        // nb: not realisically point of contention
-        let mut buckets = self.buckets.lock().await;
-
-        match buckets.entry(path.clone()) {
-            Entry::Occupied(bucket) => {
-                tracing::debug!("got existing bucket: {:?}", path);
-
-                let bucket = bucket.into_mut();
-                bucket.queue.push(tx);
-                tracing::debug!("added request into bucket queue: {:?}", path);
-
-                (Arc::clone(&bucket), false)
-            }
-            Entry::Vacant(entry) => {
-                tracing::debug!("making new bucket for path: {:?}", path);
-                let bucket = Bucket::new(path.clone());
-                bucket.queue.push(tx);
-
-                let bucket = Arc::new(bucket);
-                entry.insert(Arc::clone(&bucket));
-
-                (bucket, true)
-            }
-        }
-    }
+    fn time_until_available(&self, path: Path) -> TimeUntilAvailableFuture;
+
+    /// Take a snapshot of all known buckets.
+    fn buckets(&self) -> GetBucketsFuture;
+
+    /// Whether the global ratelimit has been hit and requests are held until
+    /// it resets.
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture;
+
+    /// Subscribe to the events of the ratelimiter.
+    ///
+    /// Events are buffered until the returned stream is polled, so it should
+    /// be polled continuously or dropped.
+    fn subscribe(&self) -> RatelimitEvents;
 }
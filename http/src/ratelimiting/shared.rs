@@ -0,0 +1,521 @@
+use super::{
+    error::RatelimitError,
+    event::{Emitter, RatelimitEvent, RatelimitEvents},
+    BucketSnapshot, GetBucketsFuture, GetTicketFuture, IsGloballyLockedFuture, RatelimitHeaders,
+    Ratelimiter, TimeUntilAvailableFuture,
+};
+use crate::routing::Path;
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    error::Error,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::{sync::oneshot, time::sleep};
+
+/// Future returned by the methods of a [`KeyValueStore`].
+pub type StoreFuture<T> =
+    Pin<Box<dyn Future<Output = Result<T, Box<dyn Error + Send + Sync>>> + Send>>;
+
+/// External key-value store that a [`SharedRatelimiter`] keeps its buckets
+/// in.
+///
+/// Values are integers that expire after some time, which maps cleanly onto
+/// stores like Redis. The store is expected to remove expired keys on its
+/// own: an expired key must behave as if it doesn't exist.
+pub trait KeyValueStore: Debug + Send + Sync {
+    /// Atomically decrement the value of a key, returning the new value.
+    ///
+    /// If the key doesn't exist then it must not be created, and `None` must
+    /// be returned.
+    fn decrement(&self, key: String) -> StoreFuture<Option<i64>>;
+
+    /// Get the value of a key, if it exists.
+    fn get(&self, key: String) -> StoreFuture<Option<i64>>;
+
+    /// Set the value of a key, which expires after the provided duration.
+    fn set(&self, key: String, value: i64, expires_in: Duration) -> StoreFuture<()>;
+
+    /// Get the time until a key expires, if it exists.
+    fn ttl(&self, key: String) -> StoreFuture<Option<Duration>>;
+}
+
+/// [`KeyValueStore`] that keeps its values in the memory of the current
+/// process.
+///
+/// This is useful as a stand-in for an external store in tests, or when
+/// several clients in the same process share a [`SharedRatelimiter`].
+#[derive(Clone, Debug, Default)]
+pub struct LocalStore {
+    values: Arc<Mutex<HashMap<String, (i64, Instant)>>>,
+}
+
+impl LocalStore {
+    /// Create a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_value<T>(&self, key: &str, f: impl FnOnce(&mut (i64, Instant)) -> T) -> Option<T> {
+        let mut values = self.values.lock().expect("local store poisoned");
+        let now = Instant::now();
+
+        if values
+            .get(key)
+            .map_or(false, |(_, expires_at)| *expires_at <= now)
+        {
+            values.remove(key);
+        }
+
+        values.get_mut(key).map(f)
+    }
+}
+
+impl KeyValueStore for LocalStore {
+    fn decrement(&self, key: String) -> StoreFuture<Option<i64>> {
+        let value = self.with_value(&key, |(value, _)| {
+            *value -= 1;
+
+            *value
+        });
+
+        Box::pin(async move { Ok(value) })
+    }
+
+    fn get(&self, key: String) -> StoreFuture<Option<i64>> {
+        let value = self.with_value(&key, |(value, _)| *value);
+
+        Box::pin(async move { Ok(value) })
+    }
+
+    fn set(&self, key: String, value: i64, expires_in: Duration) -> StoreFuture<()> {
+        self.values
+            .lock()
+            .expect("local store poisoned")
+            .insert(key, (value, Instant::now() + expires_in));
+
+        Box::pin(async { Ok(()) })
+    }
+
+    fn ttl(&self, key: String) -> StoreFuture<Option<Duration>> {
+        let ttl = self.with_value(&key, |(_, expires_at)| {
+            expires_at.saturating_duration_since(Instant::now())
+        });
+
+        Box::pin(async move { Ok(ttl) })
+    }
+}
+
+/// Ratelimiter that shares its buckets with other processes through a
+/// [`KeyValueStore`].
+///
+/// Every process using the same token should use a shared ratelimiter with
+/// the same store and key prefix, so that they take each other's requests
+/// into account and don't trip the global ratelimit.
+///
+/// Buckets are only known once a response for their path has been received,
+/// so concurrent first requests to a path aren't limited. Only the buckets of
+/// paths that this process made requests to are included in
+/// [`Ratelimiter::buckets`], and their limits aren't known. Unlike the
+/// [`InMemoryRatelimiter`], buckets are kept per path and aren't merged by
+/// their bucket hash.
+///
+/// [`InMemoryRatelimiter`]: super::InMemoryRatelimiter
+///
+/// # Examples
+///
+/// Share a ratelimiter between two clients, using a [`LocalStore`] as a
+/// stand-in for an external store:
+///
+/// ```rust
+/// use twilight_http::{
+///     ratelimiting::{LocalStore, SharedRatelimiter},
+///     Client,
+/// };
+///
+/// let store = LocalStore::new();
+///
+/// let first = Client::builder()
+///     .custom_ratelimiter(SharedRatelimiter::new(store.clone()))
+///     .build();
+/// let second = Client::builder()
+///     .custom_ratelimiter(SharedRatelimiter::new(store))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct SharedRatelimiter<S> {
+    emitter: Emitter,
+    /// Paths that this process has made requests to, with the number of
+    /// requests waiting for each of them.
+    paths: Arc<Mutex<HashMap<Path, usize>>>,
+    prefix: Arc<str>,
+    store: Arc<S>,
+}
+
+impl<S: KeyValueStore + 'static> SharedRatelimiter<S> {
+    /// Default prefix of the keys that buckets are stored under.
+    pub const DEFAULT_PREFIX: &'static str = "twilight-ratelimit:";
+
+    /// Create a new shared ratelimiter storing its buckets in a store.
+    pub fn new(store: S) -> Self {
+        Self {
+            emitter: Emitter::default(),
+            paths: Arc::default(),
+            prefix: Arc::from(Self::DEFAULT_PREFIX),
+            store: Arc::new(store),
+        }
+    }
+
+    /// Set the prefix of the keys that buckets are stored under.
+    ///
+    /// Use different prefixes to use the same store for several tokens.
+    /// Defaults to [`DEFAULT_PREFIX`].
+    ///
+    /// [`DEFAULT_PREFIX`]: Self::DEFAULT_PREFIX
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Arc::from(prefix.into());
+
+        self
+    }
+
+    fn bucket_key(&self, path: &Path) -> String {
+        format!("{}bucket:{}", self.prefix, path)
+    }
+
+    fn global_key(&self) -> String {
+        format!("{}global", self.prefix)
+    }
+
+    /// Wait until a request to a bucket may be made, taking one of its
+    /// remaining requests.
+    async fn acquire(&self, path: &Path) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.update_waiting(path, |waiting| *waiting += 1);
+        self.emitter
+            .emit(&RatelimitEvent::RequestQueued { path: path.clone() });
+
+        let result = self.wait(path).await;
+
+        self.update_waiting(path, |waiting| *waiting -= 1);
+        self.emitter
+            .emit(&RatelimitEvent::RequestReleased { path: path.clone() });
+
+        result
+    }
+
+    fn update_waiting(&self, path: &Path, f: impl FnOnce(&mut usize)) {
+        let mut paths = self.paths.lock().expect("paths poisoned");
+
+        f(paths.entry(path.clone()).or_default());
+    }
+
+    async fn wait(&self, path: &Path) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let bucket_key = self.bucket_key(path);
+
+        loop {
+            if let Some(wait) = self.store.ttl(self.global_key()).await? {
+                tracing::debug!(?path, ?wait, "waiting for global ratelimit to pass");
+                sleep(wait).await;
+
+                continue;
+            }
+
+            match self.store.decrement(bucket_key.clone()).await? {
+                Some(remaining) if remaining < 0 => {}
+                _ => return Ok(()),
+            }
+
+            match self.store.ttl(bucket_key.clone()).await? {
+                Some(wait) => {
+                    tracing::debug!(?path, ?wait, "waiting for ratelimit to pass");
+                    self.emitter.emit(&RatelimitEvent::BucketExhausted {
+                        path: path.clone(),
+                        reset_after: wait,
+                    });
+                    sleep(wait).await;
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Store the ratelimit headers of a response.
+    async fn update(
+        &self,
+        path: &Path,
+        headers: RatelimitHeaders,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match headers {
+            RatelimitHeaders::GlobalLimited { reset_after } => {
+                self.lock_global(Duration::from_secs(reset_after)).await
+            }
+            RatelimitHeaders::None => Ok(()),
+            RatelimitHeaders::Present {
+                global,
+                remaining,
+                reset_after,
+                ..
+            } => {
+                let reset_after = Duration::from_millis(reset_after);
+
+                if global {
+                    self.lock_global(reset_after).await?;
+                }
+
+                let remaining = i64::try_from(remaining).unwrap_or(i64::MAX);
+
+                self.store
+                    .set(self.bucket_key(path), remaining, reset_after)
+                    .await
+            }
+        }
+    }
+
+    async fn lock_global(&self, reset_after: Duration) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.emitter
+            .emit(&RatelimitEvent::GlobalRatelimited { reset_after });
+
+        self.store.set(self.global_key(), 1, reset_after).await
+    }
+
+    async fn snapshot(
+        &self,
+        path: Path,
+        queue_len: usize,
+    ) -> Result<BucketSnapshot, Box<dyn Error + Send + Sync>> {
+        let bucket_key = self.bucket_key(&path);
+        let remaining = self.store.get(bucket_key.clone()).await?;
+        let reset_after = self.store.ttl(bucket_key).await?;
+
+        Ok(BucketSnapshot {
+            hash: None,
+            limit: None,
+            path,
+            queue_len,
+            remaining: remaining.map(|remaining| u64::try_from(remaining).unwrap_or(0)),
+            reset_after,
+        })
+    }
+
+    async fn time_until_available(
+        &self,
+        path: &Path,
+    ) -> Result<Option<Duration>, Box<dyn Error + Send + Sync>> {
+        if let Some(wait) = self.store.ttl(self.global_key()).await? {
+            return Ok(Some(wait));
+        }
+
+        let bucket_key = self.bucket_key(path);
+
+        match self.store.get(bucket_key.clone()).await? {
+            Some(remaining) if remaining <= 0 => self.store.ttl(bucket_key).await,
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<S> Clone for SharedRatelimiter<S> {
+    fn clone(&self) -> Self {
+        Self {
+            emitter: self.emitter.clone(),
+            paths: Arc::clone(&self.paths),
+            prefix: Arc::clone(&self.prefix),
+            store: Arc::clone(&self.store),
+        }
+    }
+}
+
+impl<S: KeyValueStore + 'static> Ratelimiter for SharedRatelimiter<S> {
+    fn ticket(&self, path: Path) -> GetTicketFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            ratelimiter
+                .acquire(&path)
+                .await
+                .map_err(RatelimitError::store)?;
+
+            let (tx, rx) = oneshot::channel();
+            let (headers_tx, headers_rx) = oneshot::channel();
+            let _sent = tx.send(headers_tx);
+
+            tokio::spawn(async move {
+                if let Ok(Some(headers)) = headers_rx.await {
+                    if let Err(source) = ratelimiter.update(&path, headers).await {
+                        tracing::warn!(?path, "failed to store ratelimit headers: {}", source);
+                    }
+                }
+            });
+
+            Ok(rx)
+        })
+    }
+
+    fn time_until_available(&self, path: Path) -> TimeUntilAvailableFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            SharedRatelimiter::time_until_available(&ratelimiter, &path)
+                .await
+                .map_err(RatelimitError::store)
+        })
+    }
+
+    fn buckets(&self) -> GetBucketsFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            let paths = ratelimiter
+                .paths
+                .lock()
+                .expect("paths poisoned")
+                .iter()
+                .map(|(path, waiting)| (path.clone(), *waiting))
+                .collect::<Vec<_>>();
+
+            let mut snapshots = Vec::with_capacity(paths.len());
+
+            for (path, waiting) in paths {
+                let snapshot = ratelimiter
+                    .snapshot(path, waiting)
+                    .await
+                    .map_err(RatelimitError::store)?;
+
+                snapshots.push(snapshot);
+            }
+
+            Ok(snapshots)
+        })
+    }
+
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            let ttl = ratelimiter
+                .store
+                .ttl(ratelimiter.global_key())
+                .await
+                .map_err(RatelimitError::store)?;
+
+            Ok(ttl.is_some())
+        })
+    }
+
+    fn subscribe(&self) -> RatelimitEvents {
+        self.emitter.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyValueStore, LocalStore, SharedRatelimiter};
+    use crate::{
+        ratelimiting::{RatelimitHeaders, Ratelimiter},
+        routing::Path,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(LocalStore: Clone, Debug, Default, KeyValueStore, Send, Sync);
+    assert_impl_all!(SharedRatelimiter<LocalStore>: Clone, Debug, Ratelimiter, Send, Sync);
+
+    #[tokio::test]
+    async fn test_local_store() {
+        let store = LocalStore::new();
+
+        assert_eq!(None, store.decrement("a".to_owned()).await.unwrap());
+        assert_eq!(None, store.get("a".to_owned()).await.unwrap());
+
+        store
+            .set("a".to_owned(), 2, Duration::from_secs(30))
+            .await
+            .unwrap();
+        assert_eq!(Some(1), store.decrement("a".to_owned()).await.unwrap());
+        assert_eq!(Some(1), store.get("a".to_owned()).await.unwrap());
+        assert!(store.ttl("a".to_owned()).await.unwrap().is_some());
+
+        store
+            .set("b".to_owned(), 1, Duration::from_secs(0))
+            .await
+            .unwrap();
+        assert_eq!(None, store.get("b".to_owned()).await.unwrap());
+        assert_eq!(None, store.ttl("b".to_owned()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_shared_buckets() {
+        let store = LocalStore::new();
+        let first = SharedRatelimiter::new(store.clone());
+        let second = SharedRatelimiter::new(store.clone());
+        let path = Path::ChannelsIdMessages(1);
+
+        let ticket = first.ticket(path.clone()).await.unwrap();
+        let headers_tx = ticket.await.unwrap();
+        headers_tx
+            .send(Some(RatelimitHeaders::Present {
+                bucket: None,
+                global: false,
+                limit: 5,
+                remaining: 0,
+                reset: 0,
+                reset_after: 60_000,
+            }))
+            .unwrap();
+
+        // Wait for the headers to be stored by the background task.
+        let key = "twilight-ratelimit:bucket:channels/1/messages".to_owned();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while store.get(key.clone()).await.unwrap().is_none() {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("headers weren't stored");
+
+        let wait = Ratelimiter::time_until_available(&second, path)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(wait > Duration::from_secs(59));
+
+        let snapshots = second.buckets().await.unwrap();
+        assert!(snapshots.is_empty());
+        let snapshots = first.buckets().await.unwrap();
+        assert_eq!(1, snapshots.len());
+        assert_eq!(Some(0), snapshots[0].remaining);
+        assert!(snapshots[0].reset_after.is_some());
+        assert!(
+            Ratelimiter::time_until_available(&second, Path::ChannelsIdMessages(2))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prefix() {
+        let store = LocalStore::new();
+        let ratelimiter = SharedRatelimiter::new(store.clone()).prefix("bot-1:");
+        let path = Path::ChannelsIdMessages(1);
+
+        ratelimiter
+            .update(&path, RatelimitHeaders::GlobalLimited { reset_after: 60 })
+            .await
+            .unwrap();
+
+        assert!(store
+            .ttl("bot-1:global".to_owned())
+            .await
+            .unwrap()
+            .is_some());
+        assert!(SharedRatelimiter::new(store)
+            .time_until_available(&path)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
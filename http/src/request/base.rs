@@ -80,7 +80,7 @@ impl RequestBuilder {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Request {
     /// The body of the request, if any.
     pub body: Option<Vec<u8>>,
@@ -2,7 +2,7 @@ use crate::request::prelude::*;
 use serde::Deserialize;
 use twilight_model::id::{ChannelId, MessageId};
 
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct AckMessageToken {
     pub token: String,
 }
@@ -13,12 +13,17 @@ struct AckMessageFields {
 }
 
 /// Ack a message by [`ChannelId`] and [`MessageId`].
+///
+/// When the client tracks the ack token, the stored token is used if none is
+/// provided, and the returned token is stored for the next ack. The stored
+/// token is read when the request is started, so that a request created
+/// before an earlier ack completes still sends the latest token.
 pub struct AckMessage<'a> {
     channel_id: ChannelId,
     fut: Option<Pending<'a, AckMessageToken>>,
-    fields: AckMessageFields,
     http: &'a Client,
     message_id: MessageId,
+    token: Option<String>,
 }
 
 impl<'a> AckMessage<'a> {
@@ -31,21 +36,42 @@ impl<'a> AckMessage<'a> {
         Self {
             channel_id,
             fut: None,
-            fields: AckMessageFields { token },
             http,
             message_id,
+            token,
         }
     }
 
     fn start(&mut self) -> Result<()> {
+        let http = self.http;
+        let fields = AckMessageFields {
+            token: self.token.take().or_else(|| {
+                if http.tracks_ack_token() {
+                    http.ack_token()
+                } else {
+                    None
+                }
+            }),
+        };
+
         let request = Request::builder(Route::AckMessage {
             channel_id: self.channel_id.0,
             message_id: self.message_id.0,
         })
-        .json(&self.fields)?;
+        .json(&fields)?;
+
+        let fut = http.request::<AckMessageToken>(request.build());
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        if http.tracks_ack_token() {
+            self.fut.replace(Box::pin(async move {
+                let token = fut.await?;
+                http.set_ack_token(Some(token.token.clone()));
+
+                Ok(token)
+            }));
+        } else {
+            self.fut.replace(Box::pin(fut));
+        }
 
         Ok(())
     }
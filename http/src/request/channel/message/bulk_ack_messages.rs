@@ -0,0 +1,57 @@
+use crate::request::prelude::*;
+use twilight_model::id::{ChannelId, MessageId};
+
+#[derive(Serialize)]
+struct BulkAckMessagesEntry {
+    channel_id: ChannelId,
+    message_id: MessageId,
+}
+
+#[derive(Serialize)]
+struct BulkAckMessagesFields {
+    read_states: Vec<BulkAckMessagesEntry>,
+}
+
+/// Mark the messages of multiple channels as read at once.
+///
+/// Each entry marks all messages of a channel up to and including the provided
+/// message as read, like [`AckMessage`] does for a single channel.
+///
+/// [`AckMessage`]: super::AckMessage
+pub struct BulkAckMessages<'a> {
+    fields: BulkAckMessagesFields,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+}
+
+impl<'a> BulkAckMessages<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        read_states: impl IntoIterator<Item = (ChannelId, MessageId)>,
+    ) -> Self {
+        Self {
+            fields: BulkAckMessagesFields {
+                read_states: read_states
+                    .into_iter()
+                    .map(|(channel_id, message_id)| BulkAckMessagesEntry {
+                        channel_id,
+                        message_id,
+                    })
+                    .collect(),
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::builder(Route::BulkAckMessages).json(&self.fields)?;
+
+        self.fut
+            .replace(Box::pin(self.http.verify(request.build())));
+
+        Ok(())
+    }
+}
+
+poll_req!(BulkAckMessages<'_>, ());
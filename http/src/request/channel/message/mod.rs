@@ -5,14 +5,16 @@ pub mod get_channel_messages_configured;
 pub mod update_message;
 
 mod ack_message;
+mod bulk_ack_messages;
 mod delete_message;
 mod delete_messages;
 mod get_message;
 
 pub use self::{
-    ack_message::AckMessage, ack_message::AckMessageToken, create_message::CreateMessage,
-    crosspost_message::CrosspostMessage, delete_message::DeleteMessage,
-    delete_messages::DeleteMessages, get_channel_messages::GetChannelMessages,
+    ack_message::AckMessage, ack_message::AckMessageToken, bulk_ack_messages::BulkAckMessages,
+    create_message::CreateMessage, crosspost_message::CrosspostMessage,
+    delete_message::DeleteMessage, delete_messages::DeleteMessages,
+    get_channel_messages::GetChannelMessages,
     get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
     update_message::UpdateMessage,
 };
@@ -0,0 +1,156 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, Channel, ChannelType},
+    id::ChannelId,
+};
+
+/// Returned when the thread can not be created as configured.
+#[derive(Debug)]
+pub struct CreateThreadError {
+    pub(super) kind: CreateThreadErrorType,
+}
+
+impl CreateThreadError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub fn kind(&self) -> &CreateThreadErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateThreadErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for CreateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateThreadErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            CreateThreadErrorType::TypeInvalid { .. } => {
+                f.write_str("the channel type is not a thread type")
+            }
+        }
+    }
+}
+
+impl Error for CreateThreadError {}
+
+/// Type of [`CreateThreadError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateThreadErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 character or more
+    /// than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The provided channel type is not a thread type.
+    TypeInvalid {
+        /// Provided channel type.
+        kind: ChannelType,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateThreadFields {
+    auto_archive_duration: AutoArchiveDuration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    name: String,
+}
+
+/// Create a thread in a channel that is not started from a message.
+///
+/// The name must be between 1 and 100 UTF-16 characters and the kind must be
+/// one of the thread channel types.
+pub struct CreateThread<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFields,
+    fut: Option<Pending<'a, Channel>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateThread<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        Self::_new(http, channel_id, name.into(), auto_archive_duration, kind)
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: String,
+        auto_archive_duration: AutoArchiveDuration,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        if !validate::thread_name(&name) {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::NameInvalid { name },
+            });
+        }
+
+        if !kind.is_thread() {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::TypeInvalid { kind },
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFields {
+                auto_archive_duration,
+                invitable: None,
+                kind,
+                name,
+            },
+            fut: None,
+            http,
+        })
+    }
+
+    /// Set whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only applicable to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable.replace(invitable);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::builder(Route::CreateThread {
+            channel_id: self.channel_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThread<'_>, Channel);
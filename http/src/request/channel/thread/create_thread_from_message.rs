@@ -0,0 +1,82 @@
+use super::create_thread::{CreateThreadError, CreateThreadErrorType};
+use crate::request::prelude::*;
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, Channel},
+    id::{ChannelId, MessageId},
+};
+
+#[derive(Serialize)]
+struct CreateThreadFromMessageFields {
+    auto_archive_duration: AutoArchiveDuration,
+    name: String,
+}
+
+/// Create a thread started from a message.
+///
+/// The thread is a public thread in text channels and a news thread in news
+/// channels. The name must be between 1 and 100 UTF-16 characters.
+pub struct CreateThreadFromMessage<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFromMessageFields,
+    fut: Option<Pending<'a, Channel>>,
+    http: &'a Client,
+    message_id: MessageId,
+}
+
+impl<'a> CreateThreadFromMessage<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Result<Self, CreateThreadError> {
+        Self::_new(
+            http,
+            channel_id,
+            message_id,
+            name.into(),
+            auto_archive_duration,
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: String,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Result<Self, CreateThreadError> {
+        if !validate::thread_name(&name) {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::NameInvalid { name },
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFromMessageFields {
+                auto_archive_duration,
+                name,
+            },
+            fut: None,
+            http,
+            message_id,
+        })
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::builder(Route::CreateThreadFromMessage {
+            channel_id: self.channel_id.0,
+            message_id: self.message_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThreadFromMessage<'_>, Channel);
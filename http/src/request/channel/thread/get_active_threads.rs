@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadsListing, id::GuildId};
+
+/// Get the active threads of a guild.
+pub struct GetActiveThreads<'a> {
+    fut: Option<Pending<'a, ThreadsListing>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetActiveThreads<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from_route(Route::GetActiveThreads {
+            guild_id: self.guild_id.0,
+        });
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetActiveThreads<'_>, ThreadsListing);
@@ -0,0 +1,58 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+#[derive(Default)]
+struct GetJoinedPrivateArchivedThreadsFields {
+    before: Option<ChannelId>,
+    limit: Option<u64>,
+}
+
+/// Get the archived private threads of a channel that the current user has
+/// joined.
+///
+/// Threads are returned in descending order of their ID.
+pub struct GetJoinedPrivateArchivedThreads<'a> {
+    channel_id: ChannelId,
+    fields: GetJoinedPrivateArchivedThreadsFields,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+}
+
+impl<'a> GetJoinedPrivateArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: GetJoinedPrivateArchivedThreadsFields::default(),
+            fut: None,
+            http,
+        }
+    }
+
+    /// Get threads with an ID lower than this one.
+    pub fn before(mut self, before: ChannelId) -> Self {
+        self.fields.before.replace(before);
+
+        self
+    }
+
+    /// Set the maximum number of threads to get.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.fields.limit.replace(limit);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from_route(Route::GetJoinedPrivateArchivedThreads {
+            before: self.fields.before.map(|id| id.0),
+            channel_id: self.channel_id.0,
+            limit: self.fields.limit,
+        });
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetJoinedPrivateArchivedThreads<'_>, ThreadsListing);
@@ -0,0 +1,23 @@
+pub mod create_thread;
+pub mod update_thread;
+
+mod add_thread_member;
+mod create_thread_from_message;
+mod get_active_threads;
+mod get_joined_private_archived_threads;
+mod get_private_archived_threads;
+mod get_public_archived_threads;
+mod get_thread_members;
+mod join_thread;
+mod leave_thread;
+mod remove_thread_member;
+
+pub use self::{
+    add_thread_member::AddThreadMember, create_thread::CreateThread,
+    create_thread_from_message::CreateThreadFromMessage, get_active_threads::GetActiveThreads,
+    get_joined_private_archived_threads::GetJoinedPrivateArchivedThreads,
+    get_private_archived_threads::GetPrivateArchivedThreads,
+    get_public_archived_threads::GetPublicArchivedThreads, get_thread_members::GetThreadMembers,
+    join_thread::JoinThread, leave_thread::LeaveThread, remove_thread_member::RemoveThreadMember,
+    update_thread::UpdateThread,
+};
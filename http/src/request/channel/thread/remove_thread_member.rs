@@ -0,0 +1,34 @@
+use crate::request::prelude::*;
+use twilight_model::id::{ChannelId, UserId};
+
+/// Remove a user from a thread.
+pub struct RemoveThreadMember<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> RemoveThreadMember<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId, user_id: UserId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+            user_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from_route(Route::RemoveThreadMember {
+            channel_id: self.channel_id.0,
+            user_id: self.user_id.0,
+        });
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RemoveThreadMember<'_>, ());
@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::id::GuildId;
+
+/// Mark all channels in a guild as read.
+pub struct AckGuild<'a> {
+    fut: Option<Pending<'a, ()>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> AckGuild<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from_route(Route::AckGuild {
+            guild_id: self.guild_id.0,
+        });
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(AckGuild<'_>, ());
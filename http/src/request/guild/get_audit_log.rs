@@ -145,7 +145,7 @@ impl<'a> GetAuditLog<'a> {
 
     fn start(&mut self) -> Result<()> {
         let request = Request::from_route(Route::GetAuditLogs {
-            action_type: self.fields.action_type.map(|x| x as u64),
+            action_type: self.fields.action_type.map(|x| u64::from(u8::from(x))),
             before: self.fields.before,
             guild_id: self.guild_id.0,
             limit: self.fields.limit,
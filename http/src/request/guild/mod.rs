@@ -11,6 +11,7 @@ pub mod role;
 pub mod update_guild;
 pub mod user;
 
+mod ack_guild;
 mod delete_guild;
 mod get_guild;
 mod get_guild_channels;
@@ -27,7 +28,7 @@ mod update_guild_welcome_screen;
 mod update_guild_widget;
 
 pub use self::{
-    create_guild::CreateGuild, create_guild_channel::CreateGuildChannel,
+    ack_guild::AckGuild, create_guild::CreateGuild, create_guild_channel::CreateGuildChannel,
     create_guild_prune::CreateGuildPrune, delete_guild::DeleteGuild, get_audit_log::GetAuditLog,
     get_guild::GetGuild, get_guild_channels::GetGuildChannels, get_guild_invites::GetGuildInvites,
     get_guild_preview::GetGuildPreview, get_guild_prune_count::GetGuildPruneCount,
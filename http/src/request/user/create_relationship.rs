@@ -0,0 +1,53 @@
+use crate::request::prelude::*;
+use twilight_model::{id::UserId, user::RelationshipType};
+
+#[derive(Default, Serialize)]
+struct CreateRelationshipFields {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<RelationshipType>,
+}
+
+/// Create or change a relationship with a user.
+///
+/// Without a type this sends a friend request to the user, or accepts their
+/// pending friend request if they've sent one.
+pub struct CreateRelationship<'a> {
+    fields: CreateRelationshipFields,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> CreateRelationship<'a> {
+    pub(crate) fn new(http: &'a Client, user_id: UserId) -> Self {
+        Self {
+            fields: CreateRelationshipFields::default(),
+            fut: None,
+            http,
+            user_id,
+        }
+    }
+
+    /// Set the type of relationship to create.
+    ///
+    /// Use [`RelationshipType::Blocked`] to block the user.
+    pub fn kind(mut self, kind: RelationshipType) -> Self {
+        self.fields.kind.replace(kind);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::builder(Route::CreateRelationship {
+            user_id: self.user_id.0,
+        })
+        .json(&self.fields)?
+        .build();
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateRelationship<'_>, ());
@@ -0,0 +1,34 @@
+use crate::request::prelude::*;
+use twilight_model::id::UserId;
+
+/// Remove a relationship with a user.
+///
+/// This removes a friend, unblocks a user, or cancels or declines a pending
+/// friend request.
+pub struct DeleteRelationship<'a> {
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> DeleteRelationship<'a> {
+    pub(crate) fn new(http: &'a Client, user_id: UserId) -> Self {
+        Self {
+            fut: None,
+            http,
+            user_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from_route(Route::DeleteRelationship {
+            user_id: self.user_id.0,
+        });
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(DeleteRelationship<'_>, ());
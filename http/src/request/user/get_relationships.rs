@@ -0,0 +1,25 @@
+use crate::request::prelude::*;
+use twilight_model::user::Relationship;
+
+/// Get the current user's relationships, such as friends, blocked users and
+/// pending friend requests.
+pub struct GetRelationships<'a> {
+    fut: Option<Pending<'a, Vec<Relationship>>>,
+    http: &'a Client,
+}
+
+impl<'a> GetRelationships<'a> {
+    pub(crate) fn new(http: &'a Client) -> Self {
+        Self { fut: None, http }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from_route(Route::GetRelationships);
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetRelationships<'_>, Vec<Relationship>);
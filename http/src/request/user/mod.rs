@@ -2,14 +2,21 @@ pub mod get_current_user_guilds;
 pub mod update_current_user;
 
 mod create_private_channel;
+mod create_relationship;
+mod delete_relationship;
 mod get_current_user;
 mod get_current_user_connections;
+mod get_relationships;
 mod get_user;
 mod leave_guild;
+mod send_friend_request;
+mod update_user_guild_settings;
 
 pub use self::{
-    create_private_channel::CreatePrivateChannel, get_current_user::GetCurrentUser,
+    create_private_channel::CreatePrivateChannel, create_relationship::CreateRelationship,
+    delete_relationship::DeleteRelationship, get_current_user::GetCurrentUser,
     get_current_user_connections::GetCurrentUserConnections,
-    get_current_user_guilds::GetCurrentUserGuilds, get_user::GetUser, leave_guild::LeaveGuild,
-    update_current_user::UpdateCurrentUser,
+    get_current_user_guilds::GetCurrentUserGuilds, get_relationships::GetRelationships,
+    get_user::GetUser, leave_guild::LeaveGuild, send_friend_request::SendFriendRequest,
+    update_current_user::UpdateCurrentUser, update_user_guild_settings::UpdateUserGuildSettings,
 };
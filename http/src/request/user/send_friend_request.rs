@@ -0,0 +1,39 @@
+use crate::request::prelude::*;
+
+#[derive(Serialize)]
+struct SendFriendRequestFields {
+    discriminator: u16,
+    username: String,
+}
+
+/// Send a friend request to a user by their username and discriminator.
+pub struct SendFriendRequest<'a> {
+    fields: SendFriendRequestFields,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+}
+
+impl<'a> SendFriendRequest<'a> {
+    pub(crate) fn new(http: &'a Client, username: String, discriminator: u16) -> Self {
+        Self {
+            fields: SendFriendRequestFields {
+                discriminator,
+                username,
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::builder(Route::SendFriendRequest)
+            .json(&self.fields)?
+            .build();
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(SendFriendRequest<'_>, ());
@@ -0,0 +1,130 @@
+use crate::request::prelude::*;
+use std::collections::HashMap;
+use twilight_model::{
+    id::{ChannelId, GuildId},
+    user::{ChannelOverride, MessageNotificationLevel, MuteConfig, UserGuildSettings},
+};
+
+#[derive(Default, Serialize)]
+struct UpdateUserGuildSettingsFields {
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    channel_overrides: HashMap<ChannelId, ChannelOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hide_muted_channels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_notifications: Option<MessageNotificationLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mobile_push: Option<bool>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mute_config: Option<Option<MuteConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    muted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppress_everyone: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppress_roles: Option<bool>,
+}
+
+/// Update the current user's notification settings for a guild, or for
+/// private channels if there is no guild.
+///
+/// All parameters are optional, and only the ones that are set are changed.
+pub struct UpdateUserGuildSettings<'a> {
+    fields: UpdateUserGuildSettingsFields,
+    fut: Option<Pending<'a, UserGuildSettings>>,
+    guild_id: Option<GuildId>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateUserGuildSettings<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: Option<GuildId>) -> Self {
+        Self {
+            fields: UpdateUserGuildSettingsFields::default(),
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set the notification settings of a channel, overriding the guild's.
+    ///
+    /// Calling this again for the same channel replaces its override.
+    pub fn channel_override(mut self, channel_override: ChannelOverride) -> Self {
+        self.fields
+            .channel_overrides
+            .insert(channel_override.channel_id, channel_override);
+
+        self
+    }
+
+    /// Set whether to hide muted channels in the channel list.
+    pub fn hide_muted_channels(mut self, hide_muted_channels: bool) -> Self {
+        self.fields.hide_muted_channels.replace(hide_muted_channels);
+
+        self
+    }
+
+    /// Set which messages to notify about.
+    pub fn message_notifications(
+        mut self,
+        message_notifications: MessageNotificationLevel,
+    ) -> Self {
+        self.fields
+            .message_notifications
+            .replace(message_notifications);
+
+        self
+    }
+
+    /// Set whether to send push notifications to mobile devices.
+    pub fn mobile_push(mut self, mobile_push: bool) -> Self {
+        self.fields.mobile_push.replace(mobile_push);
+
+        self
+    }
+
+    /// Set how long the guild is muted for.
+    ///
+    /// Pass `None` to mute the guild indefinitely.
+    pub fn mute_config(mut self, mute_config: impl Into<Option<MuteConfig>>) -> Self {
+        self.fields.mute_config.replace(mute_config.into());
+
+        self
+    }
+
+    /// Set whether the guild is muted.
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.fields.muted.replace(muted);
+
+        self
+    }
+
+    /// Set whether to suppress `@everyone` and `@here` mentions.
+    pub fn suppress_everyone(mut self, suppress_everyone: bool) -> Self {
+        self.fields.suppress_everyone.replace(suppress_everyone);
+
+        self
+    }
+
+    /// Set whether to suppress role mentions.
+    pub fn suppress_roles(mut self, suppress_roles: bool) -> Self {
+        self.fields.suppress_roles.replace(suppress_roles);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::builder(Route::UpdateUserGuildSettings {
+            guild_id: self.guild_id.map(|id| id.0),
+        })
+        .json(&self.fields)?
+        .build();
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateUserGuildSettings<'_>, UserGuildSettings);
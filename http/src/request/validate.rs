@@ -387,6 +387,17 @@ fn _username(value: &str) -> bool {
     (2..=32).contains(&len)
 }
 
+pub fn thread_name(value: impl AsRef<str>) -> bool {
+    _thread_name(value.as_ref())
+}
+
+fn _thread_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/channel#start-thread-without-message-json-params>
+    (1..=100).contains(&len)
+}
+
 pub fn template_name(value: impl AsRef<str>) -> bool {
     _template_name(value.as_ref())
 }
@@ -734,6 +745,15 @@ mod tests {
         assert!(!nickname("a".repeat(33)));
     }
 
+    #[test]
+    fn test_thread_name() {
+        assert!(thread_name("a"));
+        assert!(thread_name("a".repeat(100)));
+
+        assert!(!thread_name(""));
+        assert!(!thread_name("a".repeat(101)));
+    }
+
     #[test]
     fn test_username() {
         assert!(username("aa"));
@@ -93,6 +93,8 @@ pub enum Path {
     /// Operating on an individual channel's message's reactions while
     /// specifying the user ID and emoji type.
     ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Operating on the threads started from an individual channel's message.
+    ChannelsIdMessagesIdThreads(u64),
     /// Operating on a channel's permission overwrites by ID.
     ChannelsIdPermissionsOverwriteId(u64),
     /// Operating on a channel's pins.
@@ -101,8 +103,21 @@ pub enum Path {
     ChannelsIdPinsMessageId(u64),
     /// Operating on a group DM's recipients.
     ChannelsIdRecipients(u64),
+    /// Operating on a thread's members.
+    ChannelsIdThreadMembers(u64),
+    /// Operating on an individual member of a thread.
+    ChannelsIdThreadMembersId(u64),
+    /// Operating on a channel's threads.
+    ChannelsIdThreads(u64),
+    /// Operating on a channel's archived private threads.
+    ChannelsIdThreadsArchivedPrivate(u64),
+    /// Operating on a channel's archived public threads.
+    ChannelsIdThreadsArchivedPublic(u64),
     /// Operating on a channel's typing indicator.
     ChannelsIdTyping(u64),
+    /// Operating on a channel's archived private threads that the current
+    /// user has joined.
+    ChannelsIdUsersIdThreads(u64),
     /// Operating on a channel's webhooks.
     ChannelsIdWebhooks(u64),
     /// Operating on a channel's followers.
@@ -115,6 +130,8 @@ pub enum Path {
     Guilds,
     /// Operating on one of user's guilds.
     GuildsId(u64),
+    /// Operating on the read state of a guild.
+    GuildsIdAck(u64),
     GuildsIdBans(u64),
     GuildsIdBansId(u64),
     GuildsIdAuditLogs(u64),
@@ -139,6 +156,8 @@ pub enum Path {
     GuildsIdRolesId(u64),
     GuildsIdTemplates(u64),
     GuildsIdTemplatesCode(u64),
+    /// Operating on a guild's active threads.
+    GuildsIdThreads(u64),
     GuildsIdVanityUrl(u64),
     GuildsIdVoiceStates(u64),
     GuildsIdWelcomeScreen(u64),
@@ -146,12 +165,20 @@ pub enum Path {
     InvitesCode,
     UsersId,
     OauthApplicationsMe,
+    /// Operating on the read states of multiple channels at once.
+    ReadStatesAckBulk,
     UsersIdConnections,
     UsersIdChannels,
+    /// Operating on the current user's relationships.
+    UsersIdRelationships,
+    /// Operating on the current user's relationship with a user.
+    UsersIdRelationshipsId,
     /// Operating on the state of a guild that the user is in.
     UsersIdGuilds,
     /// Operating on the state of a guild that the user is in.
     UsersIdGuildsId,
+    /// Operating on the current user's notification settings for a guild.
+    UsersIdGuildsIdSettings,
     /// Operating on the voice regions available to the current user.
     VoiceRegions,
     /// Operating on a message created by a webhook.
@@ -160,6 +187,233 @@ pub enum Path {
     WebhooksId(u64),
 }
 
+impl Path {
+    /// Major parameter of the path, which is the ID of the channel, guild, or
+    /// webhook it operates on.
+    ///
+    /// Discord ratelimits paths with the same bucket separately for each
+    /// major parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::routing::Path;
+    ///
+    /// assert_eq!(Some(123), Path::ChannelsIdPins(123).major_parameter());
+    /// assert_eq!(None, Path::VoiceRegions.major_parameter());
+    /// ```
+    #[allow(clippy::enum_glob_use)]
+    pub fn major_parameter(&self) -> Option<u64> {
+        use Path::*;
+
+        match self {
+            ChannelsIdMessagesId(_, id)
+            | ChannelsId(id)
+            | ChannelsIdInvites(id)
+            | ChannelsIdMessages(id)
+            | ChannelsIdMessagesBulkDelete(id)
+            | ChannelsIdMessagesIdCrosspost(id)
+            | ChannelsIdMessagesIdReactions(id)
+            | ChannelsIdMessagesIdReactionsUserIdType(id)
+            | ChannelsIdMessagesIdThreads(id)
+            | ChannelsIdPermissionsOverwriteId(id)
+            | ChannelsIdPins(id)
+            | ChannelsIdPinsMessageId(id)
+            | ChannelsIdRecipients(id)
+            | ChannelsIdThreadMembers(id)
+            | ChannelsIdThreadMembersId(id)
+            | ChannelsIdThreads(id)
+            | ChannelsIdThreadsArchivedPrivate(id)
+            | ChannelsIdThreadsArchivedPublic(id)
+            | ChannelsIdTyping(id)
+            | ChannelsIdUsersIdThreads(id)
+            | ChannelsIdWebhooks(id)
+            | ChannelsIdFollowers(id)
+            | GuildsId(id)
+            | GuildsIdAck(id)
+            | GuildsIdBans(id)
+            | GuildsIdBansId(id)
+            | GuildsIdAuditLogs(id)
+            | GuildsIdBansUserId(id)
+            | GuildsIdChannels(id)
+            | GuildsIdWidget(id)
+            | GuildsIdEmojis(id)
+            | GuildsIdEmojisId(id)
+            | GuildsIdIntegrations(id)
+            | GuildsIdIntegrationsId(id)
+            | GuildsIdIntegrationsIdSync(id)
+            | GuildsIdInvites(id)
+            | GuildsIdMembers(id)
+            | GuildsIdMembersId(id)
+            | GuildsIdMembersIdRolesId(id)
+            | GuildsIdMembersMeNick(id)
+            | GuildsIdMembersSearch(id)
+            | GuildsIdPreview(id)
+            | GuildsIdPrune(id)
+            | GuildsIdRegions(id)
+            | GuildsIdRoles(id)
+            | GuildsIdRolesId(id)
+            | GuildsIdTemplates(id)
+            | GuildsIdTemplatesCode(id)
+            | GuildsIdThreads(id)
+            | GuildsIdVanityUrl(id)
+            | GuildsIdVoiceStates(id)
+            | GuildsIdWelcomeScreen(id)
+            | GuildsIdWebhooks(id)
+            | WebhooksIdTokenMessagesId(id)
+            | WebhooksId(id) => Some(*id),
+            Gateway
+            | GatewayBot
+            | Guilds
+            | InvitesCode
+            | UsersId
+            | OauthApplicationsMe
+            | ReadStatesAckBulk
+            | UsersIdConnections
+            | UsersIdChannels
+            | UsersIdRelationships
+            | UsersIdRelationshipsId
+            | UsersIdGuilds
+            | UsersIdGuildsId
+            | UsersIdGuildsIdSettings
+            | VoiceRegions => None,
+        }
+    }
+}
+
+impl Display for Path {
+    /// Format the path as the route that it's made to, with its major
+    /// parameter filled in and its other parameters as placeholders.
+    ///
+    /// This is stable and unique for each path, so it's useful as a key to
+    /// store the ratelimits of paths under. Paths that share a route but are
+    /// ratelimited separately for some methods are prefixed by the method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::{request::Method, routing::Path};
+    ///
+    /// assert_eq!("channels/123/pins", Path::ChannelsIdPins(123).to_string());
+    /// assert_eq!(
+    ///     "DELETE channels/123/messages/:message_id",
+    ///     Path::ChannelsIdMessagesId(Method::Delete, 123).to_string(),
+    /// );
+    /// ```
+    #[allow(clippy::enum_glob_use, clippy::too_many_lines)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        use Path::*;
+
+        match self {
+            ChannelsId(id) => write!(f, "channels/{}", id),
+            ChannelsIdInvites(id) => write!(f, "channels/{}/invites", id),
+            ChannelsIdMessages(id) => write!(f, "channels/{}/messages", id),
+            ChannelsIdMessagesBulkDelete(id) => {
+                write!(f, "channels/{}/messages/bulk-delete", id)
+            }
+            ChannelsIdMessagesId(method, id) => write!(
+                f,
+                "{} channels/{}/messages/:message_id",
+                method.into_hyper().as_str(),
+                id,
+            ),
+            ChannelsIdMessagesIdCrosspost(id) => {
+                write!(f, "channels/{}/messages/:message_id/crosspost", id)
+            }
+            ChannelsIdMessagesIdReactions(id) => {
+                write!(f, "channels/{}/messages/:message_id/reactions/:emoji", id)
+            }
+            ChannelsIdMessagesIdReactionsUserIdType(id) => write!(
+                f,
+                "channels/{}/messages/:message_id/reactions/:emoji/:user_id",
+                id,
+            ),
+            ChannelsIdMessagesIdThreads(id) => {
+                write!(f, "channels/{}/messages/:message_id/threads", id)
+            }
+            ChannelsIdPermissionsOverwriteId(id) => {
+                write!(f, "channels/{}/permissions/:overwrite_id", id)
+            }
+            ChannelsIdPins(id) => write!(f, "channels/{}/pins", id),
+            ChannelsIdPinsMessageId(id) => write!(f, "channels/{}/pins/:message_id", id),
+            ChannelsIdRecipients(id) => write!(f, "channels/{}/recipients/:user_id", id),
+            ChannelsIdThreadMembers(id) => write!(f, "channels/{}/thread-members", id),
+            ChannelsIdThreadMembersId(id) => {
+                write!(f, "channels/{}/thread-members/:user_id", id)
+            }
+            ChannelsIdThreads(id) => write!(f, "channels/{}/threads", id),
+            ChannelsIdThreadsArchivedPrivate(id) => {
+                write!(f, "channels/{}/threads/archived/private", id)
+            }
+            ChannelsIdThreadsArchivedPublic(id) => {
+                write!(f, "channels/{}/threads/archived/public", id)
+            }
+            ChannelsIdTyping(id) => write!(f, "channels/{}/typing", id),
+            ChannelsIdUsersIdThreads(id) => {
+                write!(f, "channels/{}/users/:user_id/threads/archived/private", id)
+            }
+            ChannelsIdWebhooks(id) => write!(f, "channels/{}/webhooks", id),
+            ChannelsIdFollowers(id) => write!(f, "channels/{}/followers", id),
+            Gateway => f.write_str("gateway"),
+            GatewayBot => f.write_str("gateway/bot"),
+            Guilds => f.write_str("guilds"),
+            GuildsId(id) => write!(f, "guilds/{}", id),
+            GuildsIdAck(id) => write!(f, "guilds/{}/ack", id),
+            GuildsIdBans(id) => write!(f, "guilds/{}/bans", id),
+            GuildsIdBansId(id) => write!(f, "GET guilds/{}/bans/:user_id", id),
+            GuildsIdAuditLogs(id) => write!(f, "guilds/{}/audit-logs", id),
+            GuildsIdBansUserId(id) => write!(f, "guilds/{}/bans/:user_id", id),
+            GuildsIdChannels(id) => write!(f, "guilds/{}/channels", id),
+            GuildsIdWidget(id) => write!(f, "guilds/{}/widget", id),
+            GuildsIdEmojis(id) => write!(f, "guilds/{}/emojis", id),
+            GuildsIdEmojisId(id) => write!(f, "guilds/{}/emojis/:emoji_id", id),
+            GuildsIdIntegrations(id) => write!(f, "guilds/{}/integrations", id),
+            GuildsIdIntegrationsId(id) => {
+                write!(f, "guilds/{}/integrations/:integration_id", id)
+            }
+            GuildsIdIntegrationsIdSync(id) => {
+                write!(f, "guilds/{}/integrations/:integration_id/sync", id)
+            }
+            GuildsIdInvites(id) => write!(f, "guilds/{}/invites", id),
+            GuildsIdMembers(id) => write!(f, "guilds/{}/members", id),
+            GuildsIdMembersId(id) => write!(f, "guilds/{}/members/:user_id", id),
+            GuildsIdMembersIdRolesId(id) => {
+                write!(f, "guilds/{}/members/:user_id/roles/:role_id", id)
+            }
+            GuildsIdMembersMeNick(id) => write!(f, "guilds/{}/members/@me/nick", id),
+            GuildsIdMembersSearch(id) => write!(f, "guilds/{}/members/search", id),
+            GuildsIdPreview(id) => write!(f, "guilds/{}/preview", id),
+            GuildsIdPrune(id) => write!(f, "guilds/{}/prune", id),
+            GuildsIdRegions(id) => write!(f, "guilds/{}/regions", id),
+            GuildsIdRoles(id) => write!(f, "guilds/{}/roles", id),
+            GuildsIdRolesId(id) => write!(f, "guilds/{}/roles/:role_id", id),
+            GuildsIdTemplates(id) => write!(f, "guilds/{}/templates", id),
+            GuildsIdTemplatesCode(id) => write!(f, "guilds/{}/templates/:code", id),
+            GuildsIdThreads(id) => write!(f, "guilds/{}/threads/active", id),
+            GuildsIdVanityUrl(id) => write!(f, "guilds/{}/vanity-url", id),
+            GuildsIdVoiceStates(id) => write!(f, "guilds/{}/voice-states/:user_id", id),
+            GuildsIdWelcomeScreen(id) => write!(f, "guilds/{}/welcome-screen", id),
+            GuildsIdWebhooks(id) => write!(f, "guilds/{}/webhooks", id),
+            InvitesCode => f.write_str("invites/:code"),
+            UsersId => f.write_str("users/:user_id"),
+            OauthApplicationsMe => f.write_str("oauth2/applications/@me"),
+            ReadStatesAckBulk => f.write_str("read-states/ack-bulk"),
+            UsersIdConnections => f.write_str("users/:user_id/connections"),
+            UsersIdChannels => f.write_str("users/:user_id/channels"),
+            UsersIdRelationships => f.write_str("users/:user_id/relationships"),
+            UsersIdRelationshipsId => f.write_str("users/:user_id/relationships/:target_id"),
+            UsersIdGuilds => f.write_str("users/:user_id/guilds"),
+            UsersIdGuildsId => f.write_str("users/:user_id/guilds/:guild_id"),
+            UsersIdGuildsIdSettings => f.write_str("users/:user_id/guilds/:guild_id/settings"),
+            VoiceRegions => f.write_str("voice/regions"),
+            WebhooksIdTokenMessagesId(id) => {
+                write!(f, "webhooks/{}/:token/messages/:message_id", id)
+            }
+            WebhooksId(id) => write!(f, "webhooks/{}", id),
+        }
+    }
+}
+
 impl FromStr for Path {
     type Err = PathParseError;
 
@@ -223,13 +477,28 @@ impl FromStr for Path {
             ["channels", id, "messages", _, "reactions", _, _] => {
                 ChannelsIdMessagesIdReactionsUserIdType(parse_id(id)?)
             }
+            ["channels", id, "messages", _, "threads"] => {
+                ChannelsIdMessagesIdThreads(parse_id(id)?)
+            }
             ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(parse_id(id)?),
             ["channels", id, "pins"] => ChannelsIdPins(parse_id(id)?),
             ["channels", id, "pins", _] => ChannelsIdPinsMessageId(parse_id(id)?),
             ["channels", id, "recipients"] | ["channels", id, "recipients", _] => {
                 ChannelsIdRecipients(parse_id(id)?)
             }
+            ["channels", id, "thread-members"] => ChannelsIdThreadMembers(parse_id(id)?),
+            ["channels", id, "thread-members", _] => ChannelsIdThreadMembersId(parse_id(id)?),
+            ["channels", id, "threads"] => ChannelsIdThreads(parse_id(id)?),
+            ["channels", id, "threads", "archived", "private"] => {
+                ChannelsIdThreadsArchivedPrivate(parse_id(id)?)
+            }
+            ["channels", id, "threads", "archived", "public"] => {
+                ChannelsIdThreadsArchivedPublic(parse_id(id)?)
+            }
             ["channels", id, "typing"] => ChannelsIdTyping(parse_id(id)?),
+            ["channels", id, "users", _, "threads", "archived", "private"] => {
+                ChannelsIdUsersIdThreads(parse_id(id)?)
+            }
             ["channels", id, "webhooks"] | ["channels", id, "webhooks", _] => {
                 ChannelsIdWebhooks(parse_id(id)?)
             }
@@ -237,6 +506,7 @@ impl FromStr for Path {
             ["gateway", "bot"] => GatewayBot,
             ["guilds"] => Guilds,
             ["guilds", id] => GuildsId(parse_id(id)?),
+            ["guilds", id, "ack"] => GuildsIdAck(parse_id(id)?),
             ["guilds", id, "audit-logs"] => GuildsIdAuditLogs(parse_id(id)?),
             ["guilds", id, "bans"] => GuildsIdBans(parse_id(id)?),
             ["guilds", id, "bans", _] => GuildsIdBansUserId(parse_id(id)?),
@@ -262,17 +532,22 @@ impl FromStr for Path {
             ["guilds", id, "roles", _] => GuildsIdRolesId(parse_id(id)?),
             ["guilds", id, "templates"] => GuildsIdTemplates(parse_id(id)?),
             ["guilds", id, "templates", _] => GuildsIdTemplatesCode(parse_id(id)?),
+            ["guilds", id, "threads", "active"] => GuildsIdThreads(parse_id(id)?),
             ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(parse_id(id)?),
             ["guilds", id, "voice-states", _] => GuildsIdVoiceStates(parse_id(id)?),
             ["guilds", id, "welcome-screen"] => GuildsIdWelcomeScreen(parse_id(id)?),
             ["guilds", id, "webhooks"] => GuildsIdWebhooks(parse_id(id)?),
             ["invites", _] => InvitesCode,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["read-states", "ack-bulk"] => ReadStatesAckBulk,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
+            ["users", _, "relationships"] => UsersIdRelationships,
+            ["users", _, "relationships", _] => UsersIdRelationshipsId,
             ["users", _, "guilds"] => UsersIdGuilds,
             ["users", _, "guilds", _] => UsersIdGuildsId,
+            ["users", _, "guilds", _, "settings"] => UsersIdGuildsIdSettings,
             ["voice", "regions"] => VoiceRegions,
             ["webhooks", id] | ["webhooks", id, _] => WebhooksId(parse_id(id)?),
             ["webhooks", id, _, "messages", _] => WebhooksIdTokenMessagesId(parse_id(id)?),
@@ -306,6 +581,11 @@ impl TryFrom<(Method, &str)> for Path {
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Route {
+    /// Route information to mark all channels in a guild as read.
+    AckGuild {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Mark all messages up to an including the provided id as read
     AckMessage { channel_id: u64, message_id: u64 },
     /// Route information to add a user to a guild.
@@ -319,6 +599,16 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to add a user to a thread.
+    AddThreadMember {
+        /// The ID of the thread.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
+    /// Route information to mark the latest messages of multiple channels as
+    /// read.
+    BulkAckMessages,
     /// Route information to create a ban on a user in a guild.
     CreateBan {
         /// The number of days' worth of the user's messages to delete in the
@@ -380,6 +670,11 @@ pub enum Route {
     },
     /// Route information to create a private channel.
     CreatePrivateChannel,
+    /// Route information to create or change a relationship with a user.
+    CreateRelationship {
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to create a reaction on a message.
     CreateReaction {
         /// The ID of the channel.
@@ -399,6 +694,18 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a thread that isn't started from a message.
+    CreateThread {
+        /// The ID of the channel.
+        channel_id: u64,
+    },
+    /// Route information to create a thread started from a message.
+    CreateThreadFromMessage {
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The ID of the message.
+        message_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -500,6 +807,11 @@ pub enum Route {
         /// The ID of the user. This can be `@me` to specify the current user.
         user: String,
     },
+    /// Route information to remove a relationship with a user.
+    DeleteRelationship {
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to delete a guild's role.
     DeleteRole {
         /// The ID of the guild.
@@ -541,6 +853,11 @@ pub enum Route {
         /// The ID of the channel to follow.
         channel_id: u64,
     },
+    /// Route information to get the active threads of a guild.
+    GetActiveThreads {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a paginated list of audit logs in a guild.
     GetAuditLogs {
         /// The type of action to get audit logs for.
@@ -699,6 +1016,16 @@ pub enum Route {
         /// Whether to retrieve statistics about the invite.
         with_counts: bool,
     },
+    /// Route information to get the archived private threads of a channel
+    /// that the current user has joined.
+    GetJoinedPrivateArchivedThreads {
+        /// The maximum ID of threads to get.
+        before: Option<u64>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get a member.
     GetMember {
         /// The ID of the guild.
@@ -731,6 +1058,26 @@ pub enum Route {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to get the archived private threads of a channel.
+    GetPrivateArchivedThreads {
+        /// The maximum archive timestamp of threads to get.
+        before: Option<String>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
+    /// Route information to get the archived public threads of a channel.
+    GetPublicArchivedThreads {
+        /// The maximum archive timestamp of threads to get.
+        before: Option<String>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
+    /// Route information to get the current user's relationships.
+    GetRelationships,
     /// Route information to get the users who reacted to a message with a
     /// specified emoji.
     GetReactionUsers {
@@ -757,6 +1104,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get the members of a thread.
+    GetThreadMembers {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to get the current user.
     GetUser {
         /// The ID of the target user. This can be `@me` to specify the current
@@ -777,11 +1129,21 @@ pub enum Route {
         /// The ID of the webhook.
         webhook_id: u64,
     },
+    /// Route information to add the current user to a thread.
+    JoinThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to leave the guild.
     LeaveGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to remove the current user from a thread.
+    LeaveThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to pin a message to a channel.
     PinMessage {
         /// The ID of the channel.
@@ -805,6 +1167,13 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to remove a user from a thread.
+    RemoveThreadMember {
+        /// The ID of the thread.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -814,6 +1183,9 @@ pub enum Route {
         /// Query to search by.
         query: String,
     },
+    /// Route information to send a friend request by username and
+    /// discriminator.
+    SendFriendRequest,
     /// Route information to sync a guild's integration.
     SyncGuildIntegration {
         /// The ID of the guild.
@@ -927,6 +1299,12 @@ pub enum Route {
         /// The template code.
         template_code: String,
     },
+    /// Route information to update the current user's notification settings
+    /// for a guild, or for private channels if there is no guild.
+    UpdateUserGuildSettings {
+        /// The ID of the guild.
+        guild_id: Option<u64>,
+    },
     /// Route information to update a user's voice state.
     UpdateUserVoiceState {
         /// ID of the guild.
@@ -960,6 +1338,11 @@ impl Route {
     #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
     pub fn into_parts(self) -> (Method, Path, Cow<'static, str>) {
         match self {
+            Self::AckGuild { guild_id } => (
+                Method::Post,
+                Path::GuildsIdAck(guild_id),
+                format!("guilds/{}/ack", guild_id).into(),
+            ),
             Self::AckMessage {
                 channel_id,
                 message_id,
@@ -982,6 +1365,19 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::AddThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                Method::Put,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/{}", channel_id, user_id).into(),
+            ),
+            Self::BulkAckMessages => (
+                Method::Post,
+                Path::ReadStatesAckBulk,
+                "read-states/ack-bulk".into(),
+            ),
             Self::CreateBan {
                 guild_id,
                 delete_message_days,
@@ -1070,6 +1466,11 @@ impl Route {
                 Path::UsersIdChannels,
                 "users/@me/channels".into(),
             ),
+            Self::CreateRelationship { user_id } => (
+                Method::Put,
+                Path::UsersIdRelationshipsId,
+                format!("users/@me/relationships/{}", user_id).into(),
+            ),
             Self::CreateReaction {
                 channel_id,
                 emoji,
@@ -1093,6 +1494,19 @@ impl Route {
                 Path::GuildsIdTemplates(guild_id),
                 format!("guilds/{}/templates", guild_id).into(),
             ),
+            Self::CreateThread { channel_id } => (
+                Method::Post,
+                Path::ChannelsIdThreads(channel_id),
+                format!("channels/{}/threads", channel_id).into(),
+            ),
+            Self::CreateThreadFromMessage {
+                channel_id,
+                message_id,
+            } => (
+                Method::Post,
+                Path::ChannelsIdMessagesIdThreads(channel_id),
+                format!("channels/{}/messages/{}/threads", channel_id, message_id).into(),
+            ),
             Self::CreateTypingTrigger { channel_id } => (
                 Method::Post,
                 Path::ChannelsIdTyping(channel_id),
@@ -1200,6 +1614,11 @@ impl Route {
                 )
                 .into(),
             ),
+            Self::DeleteRelationship { user_id } => (
+                Method::Delete,
+                Path::UsersIdRelationshipsId,
+                format!("users/@me/relationships/{}", user_id).into(),
+            ),
             Self::DeleteRole { guild_id, role_id } => (
                 Method::Delete,
                 Path::GuildsIdRolesId(guild_id),
@@ -1250,6 +1669,11 @@ impl Route {
                 Path::ChannelsIdFollowers(channel_id),
                 format!("channels/{}/followers", channel_id).into(),
             ),
+            Self::GetActiveThreads { guild_id } => (
+                Method::Get,
+                Path::GuildsIdThreads(guild_id),
+                format!("guilds/{}/threads/active", guild_id).into(),
+            ),
             Self::GetAuditLogs {
                 action_type,
                 before,
@@ -1452,6 +1876,30 @@ impl Route {
                 Path::InvitesCode,
                 format!("invites/{}?with-counts={}", code, with_counts).into(),
             ),
+            Self::GetJoinedPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!(
+                    "channels/{}/users/@me/threads/archived/private?",
+                    channel_id
+                );
+
+                if let Some(before) = before {
+                    let _ = write!(path, "before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::Get,
+                    Path::ChannelsIdUsersIdThreads(channel_id),
+                    path.into(),
+                )
+            }
             Self::GetMember { guild_id, user_id } => (
                 Method::Get,
                 Path::GuildsIdMembersId(guild_id),
@@ -1501,6 +1949,61 @@ impl Route {
                 Path::ChannelsIdPins(channel_id),
                 format!("channels/{}/pins", channel_id).into(),
             ),
+            Self::GetPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!("channels/{}/threads/archived/private?", channel_id);
+
+                if let Some(before) = before {
+                    let _ = write!(
+                        path,
+                        "before={}",
+                        utf8_percent_encode(&before, NON_ALPHANUMERIC)
+                    );
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::Get,
+                    Path::ChannelsIdThreadsArchivedPrivate(channel_id),
+                    path.into(),
+                )
+            }
+            Self::GetPublicArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!("channels/{}/threads/archived/public?", channel_id);
+
+                if let Some(before) = before {
+                    let _ = write!(
+                        path,
+                        "before={}",
+                        utf8_percent_encode(&before, NON_ALPHANUMERIC)
+                    );
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::Get,
+                    Path::ChannelsIdThreadsArchivedPublic(channel_id),
+                    path.into(),
+                )
+            }
+            Self::GetRelationships => (
+                Method::Get,
+                Path::UsersIdRelationships,
+                "users/@me/relationships".into(),
+            ),
             Self::GetReactionUsers {
                 after,
                 before,
@@ -1552,6 +2055,11 @@ impl Route {
                 Path::UsersIdChannels,
                 "users/@me/channels".into(),
             ),
+            Self::GetThreadMembers { channel_id } => (
+                Method::Get,
+                Path::ChannelsIdThreadMembers(channel_id),
+                format!("channels/{}/thread-members", channel_id).into(),
+            ),
             Self::GetUser { target_user } => (
                 Method::Get,
                 Path::UsersId,
@@ -1568,11 +2076,21 @@ impl Route {
 
                 (Method::Get, Path::WebhooksId(webhook_id), path.into())
             }
+            Self::JoinThread { channel_id } => (
+                Method::Put,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/@me", channel_id).into(),
+            ),
             Self::LeaveGuild { guild_id } => (
                 Method::Delete,
                 Path::UsersIdGuildsId,
                 format!("users/@me/guilds/{}", guild_id).into(),
             ),
+            Self::LeaveThread { channel_id } => (
+                Method::Delete,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/@me", channel_id).into(),
+            ),
             Self::PinMessage {
                 channel_id,
                 message_id,
@@ -1595,6 +2113,14 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::RemoveThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                Method::Delete,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/{}", channel_id, user_id).into(),
+            ),
             Self::SearchGuildMembers {
                 guild_id,
                 limit,
@@ -1612,6 +2138,11 @@ impl Route {
                     path.into(),
                 )
             }
+            Self::SendFriendRequest => (
+                Method::Post,
+                Path::UsersIdRelationships,
+                "users/@me/relationships".into(),
+            ),
             Self::SyncGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1724,6 +2255,14 @@ impl Route {
                 Path::GuildsIdTemplatesCode(guild_id),
                 format!("guilds/{}/templates/{}", guild_id, template_code).into(),
             ),
+            Self::UpdateUserGuildSettings { guild_id } => (
+                Method::Patch,
+                Path::UsersIdGuildsIdSettings,
+                match guild_id {
+                    Some(guild_id) => format!("users/@me/guilds/{}/settings", guild_id).into(),
+                    None => "users/@me/guilds/@me/settings".into(),
+                },
+            ),
             Self::UpdateUserVoiceState { guild_id, user_id } => (
                 Method::Patch,
                 Path::GuildsIdVoiceStates(guild_id),
@@ -1754,10 +2293,106 @@ impl Route {
 
 #[cfg(test)]
 mod tests {
-    use super::{Path, PathParseErrorType};
+    use super::{Path, PathParseErrorType, Route};
     use crate::request::Method;
     use std::{convert::TryFrom, error::Error, str::FromStr};
 
+    #[test]
+    fn test_path_display_round_trip() -> Result<(), Box<dyn Error>> {
+        let paths = [
+            Path::ChannelsId(1),
+            Path::ChannelsIdInvites(1),
+            Path::ChannelsIdMessages(1),
+            Path::ChannelsIdMessagesBulkDelete(1),
+            Path::ChannelsIdMessagesIdCrosspost(1),
+            Path::ChannelsIdMessagesIdReactions(1),
+            Path::ChannelsIdMessagesIdReactionsUserIdType(1),
+            Path::ChannelsIdMessagesIdThreads(1),
+            Path::ChannelsIdPermissionsOverwriteId(1),
+            Path::ChannelsIdPins(1),
+            Path::ChannelsIdPinsMessageId(1),
+            Path::ChannelsIdRecipients(1),
+            Path::ChannelsIdThreadMembers(1),
+            Path::ChannelsIdThreadMembersId(1),
+            Path::ChannelsIdThreads(1),
+            Path::ChannelsIdThreadsArchivedPrivate(1),
+            Path::ChannelsIdThreadsArchivedPublic(1),
+            Path::ChannelsIdTyping(1),
+            Path::ChannelsIdUsersIdThreads(1),
+            Path::ChannelsIdWebhooks(1),
+            Path::ChannelsIdFollowers(1),
+            Path::Gateway,
+            Path::GatewayBot,
+            Path::Guilds,
+            Path::GuildsId(1),
+            Path::GuildsIdAck(1),
+            Path::GuildsIdBans(1),
+            Path::GuildsIdAuditLogs(1),
+            Path::GuildsIdBansUserId(1),
+            Path::GuildsIdChannels(1),
+            Path::GuildsIdWidget(1),
+            Path::GuildsIdEmojis(1),
+            Path::GuildsIdEmojisId(1),
+            Path::GuildsIdIntegrations(1),
+            Path::GuildsIdIntegrationsId(1),
+            Path::GuildsIdIntegrationsIdSync(1),
+            Path::GuildsIdInvites(1),
+            Path::GuildsIdMembers(1),
+            Path::GuildsIdMembersId(1),
+            Path::GuildsIdMembersIdRolesId(1),
+            Path::GuildsIdMembersMeNick(1),
+            Path::GuildsIdMembersSearch(1),
+            Path::GuildsIdPreview(1),
+            Path::GuildsIdPrune(1),
+            Path::GuildsIdRegions(1),
+            Path::GuildsIdRoles(1),
+            Path::GuildsIdRolesId(1),
+            Path::GuildsIdTemplates(1),
+            Path::GuildsIdTemplatesCode(1),
+            Path::GuildsIdThreads(1),
+            Path::GuildsIdVanityUrl(1),
+            Path::GuildsIdVoiceStates(1),
+            Path::GuildsIdWelcomeScreen(1),
+            Path::GuildsIdWebhooks(1),
+            Path::InvitesCode,
+            Path::UsersId,
+            Path::OauthApplicationsMe,
+            Path::ReadStatesAckBulk,
+            Path::UsersIdConnections,
+            Path::UsersIdChannels,
+            Path::UsersIdRelationships,
+            Path::UsersIdRelationshipsId,
+            Path::UsersIdGuilds,
+            Path::UsersIdGuildsId,
+            Path::UsersIdGuildsIdSettings,
+            Path::VoiceRegions,
+            Path::WebhooksIdTokenMessagesId(1),
+            Path::WebhooksId(1),
+        ];
+
+        for path in &paths {
+            assert_eq!(*path, Path::from_str(&path.to_string())?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_display_method() {
+        assert_eq!(
+            "DELETE channels/1/messages/:message_id",
+            Path::ChannelsIdMessagesId(Method::Delete, 1).to_string(),
+        );
+        assert_eq!(
+            "PATCH channels/1/messages/:message_id",
+            Path::ChannelsIdMessagesId(Method::Patch, 1).to_string(),
+        );
+        assert_ne!(
+            Path::GuildsIdBansId(1).to_string(),
+            Path::GuildsIdBansUserId(1).to_string(),
+        );
+    }
+
     #[test]
     fn test_path_prefix_unimportant() -> Result<(), Box<dyn Error>> {
         assert_eq!(Path::Guilds, Path::from_str("guilds")?);
@@ -1771,10 +2406,82 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::UsersIdRelationshipsId,
+            Path::from_str("/users/@me/relationships/123")?
+        );
+        assert_eq!(Path::GuildsIdAck(123), Path::from_str("/guilds/123/ack")?);
+        assert_eq!(
+            Path::ReadStatesAckBulk,
+            Path::from_str("/read-states/ack-bulk")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_threads() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Path::ChannelsIdMessagesIdThreads(123),
+            Path::from_str("channels/123/messages/456/threads")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreadMembersId(123),
+            Path::from_str("channels/123/thread-members/@me")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreadsArchivedPublic(123),
+            Path::from_str("channels/123/threads/archived/public")?
+        );
+        assert_eq!(
+            Path::ChannelsIdUsersIdThreads(123),
+            Path::from_str("channels/123/users/@me/threads/archived/private")?
+        );
+        assert_eq!(
+            Path::GuildsIdThreads(123),
+            Path::from_str("guilds/123/threads/active")?
+        );
 
         Ok(())
     }
 
+    #[test]
+    fn test_route_user_guild_settings() {
+        let (method, path, uri) = Route::UpdateUserGuildSettings {
+            guild_id: Some(123),
+        }
+        .into_parts();
+        assert_eq!(Method::Patch, method);
+        assert_eq!(Path::UsersIdGuildsIdSettings, path);
+        assert_eq!("users/@me/guilds/123/settings", uri);
+
+        let (_, path, uri) = Route::UpdateUserGuildSettings { guild_id: None }.into_parts();
+        assert_eq!(Path::UsersIdGuildsIdSettings, path);
+        assert_eq!("users/@me/guilds/@me/settings", uri);
+    }
+
+    #[test]
+    fn test_route_threads() {
+        let (method, path, uri) = Route::GetPublicArchivedThreads {
+            before: Some("2021-07-01T00:00:00+00:00".to_owned()),
+            channel_id: 123,
+            limit: Some(10),
+        }
+        .into_parts();
+
+        assert_eq!(Method::Get, method);
+        assert_eq!(Path::ChannelsIdThreadsArchivedPublic(123), path);
+        assert_eq!(
+            "channels/123/threads/archived/public?before=2021%2D07%2D01T00%3A00%3A00%2B00%3A00&limit=10",
+            uri
+        );
+
+        let (method, path, uri) = Route::LeaveThread { channel_id: 123 }.into_parts();
+        assert_eq!(Method::Delete, method);
+        assert_eq!(Path::ChannelsIdThreadMembersId(123), path);
+        assert_eq!("channels/123/thread-members/@me", uri);
+    }
+
     #[test]
     fn test_path_message_id() -> Result<(), Box<dyn Error>> {
         assert!(matches!(
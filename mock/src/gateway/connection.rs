@@ -0,0 +1,317 @@
+use super::{
+    deflater::{self, Deflater, Transport, ZstdDeflater},
+    ReceivedPayload, State,
+};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    io::Error as IoError,
+    sync::{atomic::Ordering, Arc, PoisonError},
+};
+use tokio::{net::TcpStream, sync::mpsc::UnboundedReceiver};
+use tokio_tungstenite::{
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Error as WebSocketError, Message,
+    },
+    WebSocketStream,
+};
+use twilight_model::{
+    gateway::payload::{ReadStateWrapper, Ready, UserGuildSettingsWrapper},
+    id::UserId,
+    user::CurrentUser,
+};
+
+/// Scripted action for the open connections of a mock gateway.
+#[derive(Clone, Debug)]
+pub enum Command {
+    /// Close the connection with a close code.
+    Close { code: u16, reason: String },
+    /// Send a dispatch event if the connection has a session.
+    Dispatch { data: Value, event_type: String },
+    /// Send an `INVALID_SESSION` payload.
+    InvalidateSession { resumable: bool },
+    /// Send a `RECONNECT` payload.
+    Reconnect,
+}
+
+/// Compression context of a connection's transport.
+enum Compressor {
+    /// Payloads are sent as text, or compressed individually if requested
+    /// when identifying.
+    None {
+        compress_payloads: bool,
+    },
+    ZlibStream(Deflater),
+    ZstdStream(ZstdDeflater),
+}
+
+/// Single client connection to a mock gateway.
+pub struct Connection {
+    compressor: Compressor,
+    id: u64,
+    rx: UnboundedReceiver<Command>,
+    session: Option<String>,
+    state: Arc<State>,
+    stream: WebSocketStream<TcpStream>,
+}
+
+impl Connection {
+    pub fn new(
+        id: u64,
+        stream: WebSocketStream<TcpStream>,
+        transport: Transport,
+        rx: UnboundedReceiver<Command>,
+        state: Arc<State>,
+    ) -> Result<Self, IoError> {
+        let compressor = match transport {
+            Transport::None => Compressor::None {
+                compress_payloads: false,
+            },
+            Transport::ZlibStream => Compressor::ZlibStream(Deflater::new()),
+            Transport::ZstdStream => Compressor::ZstdStream(ZstdDeflater::new()?),
+        };
+
+        Ok(Self {
+            compressor,
+            id,
+            rx,
+            session: None,
+            state,
+            stream,
+        })
+    }
+
+    pub async fn run(mut self) {
+        let heartbeat_interval = self.state.heartbeat_interval.load(Ordering::Relaxed);
+
+        if let Err(source) = self
+            .send(&json!({ "op": 10, "d": { "heartbeat_interval": heartbeat_interval } }))
+            .await
+        {
+            tracing::debug!(connection = self.id, "sending hello failed: {}", source);
+
+            return;
+        }
+
+        loop {
+            let result = tokio::select! {
+                message = self.stream.next() => match message {
+                    Some(Ok(Message::Binary(bytes))) => self.receive(&bytes).await,
+                    Some(Ok(Message::Text(text))) => self.receive(text.as_bytes()).await,
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => Ok(()),
+                    Some(Err(source)) => Err(source),
+                },
+                command = self.rx.recv() => match command {
+                    Some(Command::Close { code, reason }) => {
+                        let frame = CloseFrame {
+                            code: CloseCode::from(code),
+                            reason: Cow::Owned(reason),
+                        };
+
+                        if let Err(source) = self.stream.close(Some(frame)).await {
+                            tracing::debug!(connection = self.id, "closing failed: {}", source);
+                        }
+
+                        break;
+                    }
+                    Some(command) => self.command(command).await,
+                    None => break,
+                },
+            };
+
+            if let Err(source) = result {
+                tracing::debug!(connection = self.id, "connection failed: {}", source);
+
+                break;
+            }
+        }
+
+        tracing::debug!(connection = self.id, "mock gateway connection ended");
+    }
+
+    async fn command(&mut self, command: Command) -> Result<(), WebSocketError> {
+        match command {
+            Command::Close { .. } => Ok(()),
+            Command::Dispatch { data, event_type } => self.dispatch(&event_type, data).await,
+            Command::InvalidateSession { resumable } => {
+                if !resumable {
+                    if let Some(session) = self.session.take() {
+                        self.state
+                            .sessions
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .remove(&session);
+                    }
+                }
+
+                self.send(&json!({ "op": 9, "d": resumable })).await
+            }
+            Command::Reconnect => self.send(&json!({ "op": 7, "d": null })).await,
+        }
+    }
+
+    /// Send a dispatch event with the next sequence number of the session.
+    async fn dispatch(&mut self, event_type: &str, data: Value) -> Result<(), WebSocketError> {
+        let seq = {
+            let session = match self.session.as_ref() {
+                Some(session) => session,
+                None => return Ok(()),
+            };
+            let mut sessions = self
+                .state
+                .sessions
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let seq = sessions.entry(session.clone()).or_default();
+            *seq += 1;
+
+            *seq
+        };
+
+        self.send(&json!({ "op": 0, "s": seq, "t": event_type, "d": data }))
+            .await
+    }
+
+    async fn receive(&mut self, bytes: &[u8]) -> Result<(), WebSocketError> {
+        let mut payload = match serde_json::from_slice::<Value>(bytes) {
+            Ok(payload) => payload,
+            Err(source) => {
+                tracing::warn!(connection = self.id, "received invalid payload: {}", source);
+
+                return Ok(());
+            }
+        };
+
+        let op = match payload.get("op").and_then(Value::as_u64).map(u8::try_from) {
+            Some(Ok(op)) => op,
+            _ => {
+                tracing::warn!(
+                    connection = self.id,
+                    "received payload without valid opcode"
+                );
+
+                return Ok(());
+            }
+        };
+        let d = payload.get_mut("d").map(Value::take).unwrap_or_default();
+
+        tracing::debug!(connection = self.id, op, "mock gateway received payload");
+
+        let received = ReceivedPayload {
+            connection: self.id,
+            d,
+            op,
+        };
+        self.state
+            .payloads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(received.clone());
+        let _sent = self.state.payload_tx.send(received.clone());
+
+        match op {
+            1 if self.state.heartbeat_acks.load(Ordering::Relaxed) => {
+                self.send(&json!({ "op": 11, "d": null })).await
+            }
+            2 => {
+                let compress = received.d.get("compress").and_then(Value::as_bool);
+
+                if let Compressor::None { compress_payloads } = &mut self.compressor {
+                    *compress_payloads = compress == Some(true);
+                }
+
+                self.identify().await
+            }
+            6 => self.resume(&received.d).await,
+            _ => Ok(()),
+        }
+    }
+
+    async fn identify(&mut self) -> Result<(), WebSocketError> {
+        let number = self.state.next_session.fetch_add(1, Ordering::Relaxed) + 1;
+        let session_id = format!("mock-session-{}", number);
+
+        self.state
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(session_id.clone(), 0);
+        self.session = Some(session_id.clone());
+
+        let ready = serde_json::to_value(ready(session_id)).unwrap_or_default();
+
+        self.dispatch("READY", ready).await
+    }
+
+    async fn resume(&mut self, d: &Value) -> Result<(), WebSocketError> {
+        let session_id = d.get("session_id").and_then(Value::as_str);
+        let known = session_id.map_or(false, |session_id| {
+            self.state
+                .sessions
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .contains_key(session_id)
+        });
+
+        if !known {
+            return self.send(&json!({ "op": 9, "d": false })).await;
+        }
+
+        self.session = session_id.map(ToOwned::to_owned);
+
+        self.dispatch("RESUMED", json!({})).await
+    }
+
+    async fn send(&mut self, payload: &Value) -> Result<(), WebSocketError> {
+        let json = payload.to_string();
+
+        let message = match &mut self.compressor {
+            // Discord only compresses large payloads individually, which
+            // dispatch events are the most likely to be.
+            Compressor::None {
+                compress_payloads: true,
+            } if payload["op"] == 0 => Message::Binary(deflater::compress_payload(json.as_bytes())),
+            Compressor::None { .. } => Message::Text(json),
+            Compressor::ZlibStream(deflater) => Message::Binary(deflater.compress(json.as_bytes())),
+            Compressor::ZstdStream(deflater) => {
+                Message::Binary(deflater.compress(json.as_bytes())?)
+            }
+        };
+
+        self.stream.send(message).await
+    }
+}
+
+/// Minimal `READY` payload for a new session.
+fn ready(session_id: String) -> Ready {
+    Ready {
+        users: Vec::new(),
+        guilds: Vec::new(),
+        private_channels: Vec::new(),
+        merged_members: Vec::new(),
+        relationships: Vec::new(),
+        session_id,
+        shard: None,
+        user: CurrentUser {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(1),
+            locale: None,
+            mfa_enabled: false,
+            name: "mock".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            verified: None,
+        },
+        read_state: ReadStateWrapper::default(),
+        user_guild_settings: UserGuildSettingsWrapper::default(),
+        version: 9,
+    }
+}
@@ -0,0 +1,251 @@
+use flate2::{Compress, Compression, FlushCompress, Status};
+use std::{convert::TryInto, io::Error as IoError};
+use zstd::stream::raw::{Encoder, InBuffer, Operation, OutBuffer};
+
+/// Transport compression requested by a connection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Transport {
+    /// Payloads are sent as text, unless payload compression was requested
+    /// when identifying.
+    None,
+    /// Payloads share a zlib context.
+    ZlibStream,
+    /// Payloads share a zstd context.
+    ZstdStream,
+}
+
+impl Transport {
+    /// Parse the transport from the query of the gateway URL.
+    pub fn from_query(query: &str) -> Self {
+        let compress = query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("compress="));
+
+        match compress {
+            Some("zlib-stream") => Self::ZlibStream,
+            Some("zstd-stream") => Self::ZstdStream,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Compress a single payload into its own zlib stream, like Discord does for
+/// large payloads of connections that requested payload compression.
+pub fn compress_payload(payload: &[u8]) -> Vec<u8> {
+    let mut compress = Compress::new(Compression::default(), true);
+    let mut output = Vec::with_capacity(payload.len() / 2 + 64);
+
+    loop {
+        let offset = compress.total_in().try_into().unwrap_or(payload.len());
+
+        match compress.compress_vec(&payload[offset..], &mut output, FlushCompress::Finish) {
+            Ok(Status::StreamEnd) => break,
+            Ok(_) => output.reserve(output.capacity().max(64)),
+            Err(source) => {
+                tracing::warn!("compressing a payload failed: {}", source);
+
+                break;
+            }
+        }
+    }
+
+    output
+}
+
+/// Compressor for a `zlib-stream` transport.
+///
+/// All payloads of a connection share one zlib context, and each payload is
+/// flushed with a sync flush so that it ends with the `00 00 ff ff` suffix
+/// that clients look for, like Discord does.
+#[derive(Debug)]
+pub struct Deflater {
+    compress: Compress,
+}
+
+impl Deflater {
+    pub fn new() -> Self {
+        Self {
+            compress: Compress::new(Compression::default(), true),
+        }
+    }
+
+    /// Compress a payload, returning the bytes to send in a binary message.
+    pub fn compress(&mut self, payload: &[u8]) -> Vec<u8> {
+        let before = self.compress.total_in();
+        let mut output = Vec::with_capacity(payload.len() / 2 + 64);
+
+        loop {
+            let offset = (self.compress.total_in() - before)
+                .try_into()
+                .unwrap_or(payload.len());
+
+            let status = match self.compress.compress_vec(
+                &payload[offset..],
+                &mut output,
+                FlushCompress::Sync,
+            ) {
+                Ok(status) => status,
+                Err(source) => {
+                    tracing::warn!("compressing a payload failed: {}", source);
+
+                    break;
+                }
+            };
+
+            // A full output buffer means that there may be more to flush, so
+            // reserve more space and keep going.
+            if status != Status::BufError && output.len() < output.capacity() {
+                break;
+            }
+
+            output.reserve(output.capacity().max(64));
+        }
+
+        output
+    }
+}
+
+/// Compressor for a `zstd-stream` transport.
+///
+/// All payloads of a connection share one zstd context, and the context is
+/// flushed after each payload so that each message can be decompressed on its
+/// own.
+pub struct ZstdDeflater {
+    encoder: Encoder<'static>,
+}
+
+impl ZstdDeflater {
+    pub fn new() -> Result<Self, IoError> {
+        Ok(Self {
+            encoder: Encoder::new(0)?,
+        })
+    }
+
+    /// Compress a payload, returning the bytes to send in a binary message.
+    pub fn compress(&mut self, payload: &[u8]) -> Result<Vec<u8>, IoError> {
+        let mut input = InBuffer::around(payload);
+        let mut output = Vec::with_capacity(payload.len() / 2 + 64);
+
+        while input.pos() < payload.len() {
+            output.reserve(output.capacity().max(64));
+            let pos = output.len();
+            self.encoder
+                .run(&mut input, &mut OutBuffer::around_pos(&mut output, pos))?;
+        }
+
+        loop {
+            output.reserve(output.capacity().max(64));
+            let pos = output.len();
+            let remaining = self
+                .encoder
+                .flush(&mut OutBuffer::around_pos(&mut output, pos))?;
+
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compress_payload, Deflater, Transport, ZstdDeflater};
+    use flate2::{Decompress, FlushDecompress};
+    use zstd::stream::raw::{Decoder, Operation};
+
+    fn inflate(decompress: &mut Decompress, bytes: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(1024);
+        decompress
+            .decompress_vec(bytes, &mut output, FlushDecompress::Sync)
+            .unwrap();
+
+        output
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut deflater = Deflater::new();
+        let mut decompress = Decompress::new(true);
+
+        let first = deflater.compress(br#"{"op":10,"d":{"heartbeat_interval":41250}}"#);
+        assert!(first.ends_with(&[0x00, 0x00, 0xff, 0xff]));
+        assert_eq!(
+            br#"{"op":10,"d":{"heartbeat_interval":41250}}"#.to_vec(),
+            inflate(&mut decompress, &first)
+        );
+
+        // The second payload depends on the context of the first one.
+        let second = deflater.compress(br#"{"op":11,"d":null}"#);
+        assert!(second.ends_with(&[0x00, 0x00, 0xff, 0xff]));
+        assert_eq!(
+            br#"{"op":11,"d":null}"#.to_vec(),
+            inflate(&mut decompress, &second)
+        );
+    }
+
+    #[test]
+    fn test_large() {
+        let payload = (0..10_000u32)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut deflater = Deflater::new();
+        let compressed = deflater.compress(payload.as_bytes());
+
+        let mut decompress = Decompress::new(true);
+        let mut output = Vec::with_capacity(payload.len() + 1);
+        decompress
+            .decompress_vec(&compressed, &mut output, FlushDecompress::Sync)
+            .unwrap();
+
+        assert_eq!(payload.as_bytes(), output.as_slice());
+    }
+
+    #[test]
+    fn test_payload() {
+        let payload = br#"{"op":0,"s":1,"t":"READY","d":{}}"#;
+        let compressed = compress_payload(payload);
+        assert_eq!(0x78, compressed[0]);
+
+        let mut decompress = Decompress::new(true);
+        let mut output = Vec::with_capacity(1024);
+        decompress
+            .decompress_vec(&compressed, &mut output, FlushDecompress::Finish)
+            .unwrap();
+
+        assert_eq!(payload.to_vec(), output);
+    }
+
+    #[test]
+    fn test_transport() {
+        assert_eq!(Transport::None, Transport::from_query("encoding=json&v=9"));
+        assert_eq!(
+            Transport::ZlibStream,
+            Transport::from_query("encoding=json&v=9&compress=zlib-stream")
+        );
+        assert_eq!(
+            Transport::ZstdStream,
+            Transport::from_query("compress=zstd-stream&v=9")
+        );
+    }
+
+    #[test]
+    fn test_zstd_stream() {
+        let mut deflater = ZstdDeflater::new().unwrap();
+        let mut decoder = Decoder::new().unwrap();
+
+        for payload in &[
+            &br#"{"op":10,"d":{"heartbeat_interval":41250}}"#[..],
+            &br#"{"op":11,"d":null}"#[..],
+        ] {
+            let compressed = deflater.compress(payload).unwrap();
+            let mut output = vec![0; 1024];
+            let status = decoder.run_on_buffers(&compressed, &mut output).unwrap();
+
+            assert_eq!(compressed.len(), status.bytes_read);
+            assert_eq!(*payload, &output[..status.bytes_written]);
+        }
+    }
+}
@@ -0,0 +1,855 @@
+//! Mock server for Discord's gateway.
+//!
+//! Start a [`MockGateway`] and pass its [`MockGateway::url`] to the
+//! `gateway_url` method of `twilight-gateway`'s shard or cluster builder. The
+//! server speaks the gateway protocol like Discord does:
+//!
+//! - New connections get a `HELLO` payload with the configured
+//!   [heartbeat interval];
+//! - Heartbeats are acknowledged, unless [acknowledgements are dropped];
+//! - An `IDENTIFY` starts a new session and is answered with a `READY`
+//!   dispatch event;
+//! - A `RESUME` of a known session is answered with a `RESUMED` dispatch
+//!   event, and a `RESUME` of an unknown session with a non-resumable
+//!   `INVALID_SESSION` payload.
+//!
+//! Connections requesting `zlib-stream` or `zstd-stream` compression get
+//! their payloads compressed with a shared context. Other connections get
+//! plain text payloads, with dispatch events compressed individually if
+//! payload compression was requested when identifying.
+//!
+//! Tests can script dispatch events, close codes, `RECONNECT` and
+//! `INVALID_SESSION` payloads, and assert on the payloads received from
+//! clients.
+//!
+//! [acknowledgements are dropped]: MockGateway::heartbeat_acks
+//! [heartbeat interval]: MockGateway::heartbeat_interval
+
+mod connection;
+mod deflater;
+mod payload;
+
+pub use self::payload::ReceivedPayload;
+
+use self::{
+    connection::{Command, Connection},
+    deflater::Transport,
+};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Duration,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{
+        mpsc::{self, UnboundedReceiver, UnboundedSender},
+        oneshot::{self, Sender},
+        Mutex as AsyncMutex,
+    },
+};
+use tokio_tungstenite::tungstenite::handshake::server::{
+    Callback, ErrorResponse, Request, Response,
+};
+
+/// Default interval between heartbeats, which is what Discord usually sends.
+const DEFAULT_HEARTBEAT_INTERVAL: u64 = 41_250;
+
+/// Starting a [`MockGateway`] failed.
+#[derive(Debug)]
+pub struct MockGatewayError {
+    kind: MockGatewayErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MockGatewayError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MockGatewayErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MockGatewayErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MockGatewayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            MockGatewayErrorType::Binding => f.write_str("binding to a local port failed"),
+        }
+    }
+}
+
+impl Error for MockGatewayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MockGatewayError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MockGatewayErrorType {
+    /// Binding to a local port failed.
+    Binding,
+}
+
+/// Scripted state of a mock gateway, shared with its connections.
+#[derive(Debug)]
+struct State {
+    connections: Mutex<Vec<UnboundedSender<Command>>>,
+    heartbeat_acks: AtomicBool,
+    heartbeat_interval: AtomicU64,
+    next_connection: AtomicU64,
+    next_session: AtomicU64,
+    payload_tx: UnboundedSender<ReceivedPayload>,
+    payloads: Mutex<Vec<ReceivedPayload>>,
+    /// Sequence number of each known session, by session ID.
+    sessions: Mutex<HashMap<String, u64>>,
+}
+
+impl State {
+    /// Send a command to all open connections.
+    fn broadcast(&self, command: &Command) {
+        let mut connections = self
+            .connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        connections.retain(|connection| connection.send(command.clone()).is_ok());
+    }
+}
+
+/// In-process websocket server that mocks Discord's gateway.
+///
+/// The server runs on a random local port until it's dropped. Refer to the
+/// [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Debug)]
+pub struct MockGateway {
+    addr: SocketAddr,
+    payload_rx: AsyncMutex<UnboundedReceiver<ReceivedPayload>>,
+    shutdown: Option<Sender<()>>,
+    state: Arc<State>,
+}
+
+impl MockGateway {
+    /// Start a mock gateway on a random local port.
+    ///
+    /// This must be called from within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MockGatewayErrorType::Binding`] error type if binding to a
+    /// local port failed.
+    pub async fn start() -> Result<Self, MockGatewayError> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .map_err(|source| MockGatewayError {
+                kind: MockGatewayErrorType::Binding,
+                source: Some(Box::new(source)),
+            })?;
+        let addr = listener.local_addr().map_err(|source| MockGatewayError {
+            kind: MockGatewayErrorType::Binding,
+            source: Some(Box::new(source)),
+        })?;
+
+        let (payload_tx, payload_rx) = mpsc::unbounded_channel();
+        let state = Arc::new(State {
+            connections: Mutex::new(Vec::new()),
+            heartbeat_acks: AtomicBool::new(true),
+            heartbeat_interval: AtomicU64::new(DEFAULT_HEARTBEAT_INTERVAL),
+            next_connection: AtomicU64::new(0),
+            next_session: AtomicU64::new(0),
+            payload_tx,
+            payloads: Mutex::new(Vec::new()),
+            sessions: Mutex::new(HashMap::new()),
+        });
+
+        let (shutdown, mut rx) = oneshot::channel();
+        let accept_state = Arc::clone(&state);
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut rx => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => {
+                            tokio::spawn(accept(stream, Arc::clone(&accept_state)));
+                        }
+                        Err(source) => {
+                            tracing::warn!("accepting a connection failed: {}", source);
+                        }
+                    },
+                }
+            }
+
+            // Dropping the senders ends the open connections.
+            accept_state
+                .connections
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clear();
+        });
+
+        tracing::debug!(%addr, "started mock gateway");
+
+        Ok(Self {
+            addr,
+            payload_rx: AsyncMutex::new(payload_rx),
+            shutdown: Some(shutdown),
+            state,
+        })
+    }
+
+    /// Address that the server listens on.
+    #[must_use = "retrieving the address has no effect if left unused"]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// URL of the server, to pass to `ShardBuilder::gateway_url`.
+    #[must_use = "retrieving the URL has no effect if left unused"]
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    /// Set the heartbeat interval sent in the `HELLO` payload of new
+    /// connections.
+    ///
+    /// The default is 41.25 seconds.
+    pub fn heartbeat_interval(&self, heartbeat_interval: Duration) -> &Self {
+        let millis = u64::try_from(heartbeat_interval.as_millis()).unwrap_or(u64::MAX);
+        self.state
+            .heartbeat_interval
+            .store(millis, Ordering::Relaxed);
+
+        self
+    }
+
+    /// Set whether heartbeats are acknowledged.
+    ///
+    /// Dropping acknowledgements makes the connections look like zombied
+    /// connections to clients. The default is `true`.
+    pub fn heartbeat_acks(&self, heartbeat_acks: bool) -> &Self {
+        self.state
+            .heartbeat_acks
+            .store(heartbeat_acks, Ordering::Relaxed);
+
+        self
+    }
+
+    /// Send a dispatch event to all connections with a session.
+    ///
+    /// Each session has its own sequence numbers, which carry over when a
+    /// session is resumed.
+    pub fn dispatch(&self, event_type: impl Into<String>, data: Value) -> &Self {
+        self.state.broadcast(&Command::Dispatch {
+            data,
+            event_type: event_type.into(),
+        });
+
+        self
+    }
+
+    /// Close all open connections with a close code, such as 4000 for an
+    /// unknown error or 4004 for an invalid token.
+    ///
+    /// Sessions are kept, so clients can resume them afterwards.
+    pub fn close(&self, code: u16, reason: impl Into<String>) -> &Self {
+        self.state.broadcast(&Command::Close {
+            code,
+            reason: reason.into(),
+        });
+
+        self
+    }
+
+    /// Send an `INVALID_SESSION` payload to all open connections.
+    ///
+    /// If the sessions aren't resumable then they are forgotten, and clients
+    /// need to identify again.
+    pub fn invalidate_session(&self, resumable: bool) -> &Self {
+        self.state
+            .broadcast(&Command::InvalidateSession { resumable });
+
+        self
+    }
+
+    /// Send a `RECONNECT` payload to all open connections.
+    pub fn reconnect(&self) -> &Self {
+        self.state.broadcast(&Command::Reconnect);
+
+        self
+    }
+
+    /// Number of connections that the server accepted.
+    #[must_use = "retrieving the number of connections has no effect if left unused"]
+    pub fn connections(&self) -> u64 {
+        self.state.next_connection.load(Ordering::Relaxed)
+    }
+
+    /// Payloads that the server received, in the order they were received.
+    #[must_use = "retrieving the payloads has no effect if left unused"]
+    pub fn payloads(&self) -> Vec<ReceivedPayload> {
+        self.state
+            .payloads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Wait for the next payload that the server receives.
+    ///
+    /// Each payload is returned once, in the order they were received,
+    /// including payloads received before this was first called.
+    pub async fn next_payload(&self) -> Option<ReceivedPayload> {
+        self.payload_rx.lock().await.recv().await
+    }
+}
+
+impl Drop for MockGateway {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _sent = shutdown.send(());
+        }
+    }
+}
+
+/// Websocket handshake callback recording the transport compression
+/// requested by the client.
+struct Handshake<'a>(&'a mut Transport);
+
+impl Callback for Handshake<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        *self.0 = request
+            .uri()
+            .query()
+            .map_or(Transport::None, Transport::from_query);
+
+        Ok(response)
+    }
+}
+
+/// Perform the websocket handshake of a new connection and run it.
+async fn accept(stream: TcpStream, state: Arc<State>) {
+    let mut transport = Transport::None;
+
+    let stream = match tokio_tungstenite::accept_hdr_async(stream, Handshake(&mut transport)).await
+    {
+        Ok(stream) => stream,
+        Err(source) => {
+            tracing::warn!("websocket handshake failed: {}", source);
+
+            return;
+        }
+    };
+
+    let id = state.next_connection.fetch_add(1, Ordering::Relaxed) + 1;
+    let (tx, rx) = mpsc::unbounded_channel();
+    state
+        .connections
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(tx);
+
+    tracing::debug!(
+        connection = id,
+        ?transport,
+        "accepted mock gateway connection"
+    );
+
+    match Connection::new(id, stream, transport, rx, state) {
+        Ok(connection) => connection.run().await,
+        Err(source) => tracing::warn!(
+            connection = id,
+            "creating the compressor failed: {}",
+            source
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockGateway, MockGatewayError, ReceivedPayload};
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::{json, Value};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::TryFrom,
+        env,
+        error::Error,
+        fmt::Debug,
+        fs,
+        future::Future,
+        path::PathBuf,
+        pin::Pin,
+        process,
+        sync::Arc,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use tokio::time;
+    use tokio_tungstenite::tungstenite::Message;
+    use twilight_gateway::{
+        cluster::ShardScheme,
+        queue::Queue,
+        shard::{Compression, FileSessionStore, SessionStore, StoredSession},
+        Cluster, Event, EventTypeFlags, Intents, Shard,
+    };
+
+    assert_impl_all!(MockGateway: Debug, Send, Sync);
+    assert_impl_all!(MockGatewayError: Debug, Error, Send, Sync);
+    assert_impl_all!(ReceivedPayload: Clone, Debug, Send, Sync);
+
+    /// Queue that lets shards identify right away.
+    #[derive(Debug)]
+    struct InstantQueue;
+
+    impl Queue for InstantQueue {
+        fn request<'a>(&'a self, _: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+            Box::pin(async {})
+        }
+    }
+
+    fn shard(gateway: &MockGateway) -> Shard {
+        Shard::builder("mock-token", Intents::empty())
+            .gateway_url(Some(gateway.url()))
+            .queue(Arc::new(Box::new(InstantQueue)))
+            .build()
+    }
+
+    /// Create an empty directory to store sessions in.
+    fn session_directory(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let directory = env::temp_dir().join(format!(
+            "twilight-mock-{}-{}-{}",
+            name,
+            process::id(),
+            nanos
+        ));
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
+    /// Wait for the next payload with an opcode, skipping others.
+    async fn next_op(gateway: &MockGateway, op: u8) -> ReceivedPayload {
+        time::timeout(Duration::from_secs(10), async {
+            loop {
+                let payload = gateway.next_payload().await.unwrap();
+
+                if payload.op == op {
+                    return payload;
+                }
+            }
+        })
+        .await
+        .unwrap()
+    }
+
+    async fn next_event(events: &mut (impl StreamExt<Item = Event> + Unpin)) -> Event {
+        time::timeout(Duration::from_secs(10), events.next())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    async fn next_text(
+        stream: &mut (impl StreamExt<Item = Result<Message, impl Debug>> + Unpin),
+    ) -> Value {
+        match stream.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_uncompressed() {
+        let gateway = MockGateway::start().await.unwrap();
+        let (mut stream, _) = tokio_tungstenite::connect_async(gateway.url())
+            .await
+            .unwrap();
+
+        let hello = next_text(&mut stream).await;
+        assert_eq!(10, hello["op"]);
+        assert_eq!(41_250, hello["d"]["heartbeat_interval"]);
+
+        let identify = json!({ "op": 2, "d": { "token": "mock-token" } });
+        stream
+            .send(Message::Text(identify.to_string()))
+            .await
+            .unwrap();
+
+        let ready = next_text(&mut stream).await;
+        assert_eq!("READY", ready["t"]);
+        assert_eq!(1, ready["s"]);
+        assert_eq!("mock-session-1", ready["d"]["session_id"]);
+
+        gateway.dispatch("CUSTOM", json!({ "foo": "bar" }));
+        let dispatch = next_text(&mut stream).await;
+        assert_eq!("CUSTOM", dispatch["t"]);
+        assert_eq!(2, dispatch["s"]);
+        assert_eq!("bar", dispatch["d"]["foo"]);
+
+        let payload = next_op(&gateway, 2).await;
+        assert_eq!(1, payload.connection);
+        assert_eq!("mock-token", payload.d["token"]);
+    }
+
+    #[tokio::test]
+    async fn test_identify_and_heartbeat() {
+        let gateway = MockGateway::start().await.unwrap();
+        gateway.heartbeat_interval(Duration::from_millis(100));
+
+        let mut shard = shard(&gateway);
+        let mut events = shard.some_events(EventTypeFlags::READY);
+        shard.start().await.unwrap();
+
+        let identify = next_op(&gateway, 2).await;
+        assert_eq!("mock-token", identify.d["token"]);
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+        assert_eq!(Some("mock-session-1"), shard.info().unwrap().session_id());
+
+        // The shard only sends a second heartbeat if the first one was
+        // acknowledged.
+        next_op(&gateway, 1).await;
+        next_op(&gateway, 1).await;
+        assert!(shard.info().unwrap().latency().heartbeats() >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_heartbeat_acks() {
+        let gateway = MockGateway::start().await.unwrap();
+        gateway
+            .heartbeat_interval(Duration::from_millis(50))
+            .heartbeat_acks(false);
+
+        let mut shard = shard(&gateway);
+        shard.start().await.unwrap();
+        next_op(&gateway, 1).await;
+        time::sleep(Duration::from_millis(500)).await;
+
+        let heartbeats = gateway
+            .payloads()
+            .iter()
+            .filter(|payload| payload.op == 1)
+            .count();
+
+        // The shard stops heartbeating once a heartbeat isn't acknowledged.
+        assert_eq!(1, heartbeats);
+        assert_eq!(0, shard.info().unwrap().latency().heartbeats());
+    }
+
+    #[tokio::test]
+    async fn test_resume_after_close() {
+        let gateway = MockGateway::start().await.unwrap();
+        let mut shard = shard(&gateway);
+        let mut events = shard.some_events(EventTypeFlags::READY | EventTypeFlags::RESUMED);
+        shard.start().await.unwrap();
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+
+        gateway.close(4000, "unknown error");
+
+        let resume = next_op(&gateway, 6).await;
+        assert_eq!(2, resume.connection);
+        assert_eq!("mock-session-1", resume.d["session_id"]);
+        assert!(matches!(next_event(&mut events).await, Event::Resumed));
+        assert_eq!(2, shard.info().unwrap().seq());
+    }
+
+    #[tokio::test]
+    async fn test_reconnect() {
+        let gateway = MockGateway::start().await.unwrap();
+        let mut shard = shard(&gateway);
+        let mut events = shard.some_events(EventTypeFlags::READY | EventTypeFlags::RESUMED);
+        shard.start().await.unwrap();
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+
+        gateway.reconnect();
+
+        let resume = next_op(&gateway, 6).await;
+        assert_eq!("mock-session-1", resume.d["session_id"]);
+        assert!(matches!(next_event(&mut events).await, Event::Resumed));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_session() {
+        let gateway = MockGateway::start().await.unwrap();
+        let mut shard = shard(&gateway);
+        let mut events = shard.some_events(EventTypeFlags::READY);
+        shard.start().await.unwrap();
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+        next_op(&gateway, 2).await;
+
+        gateway.invalidate_session(false);
+
+        let identify = next_op(&gateway, 2).await;
+        assert_eq!(2, identify.connection);
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+        assert_eq!(Some("mock-session-2"), shard.info().unwrap().session_id());
+    }
+
+    #[tokio::test]
+    async fn test_cluster_resume() {
+        let gateway = MockGateway::start().await.unwrap();
+        let cluster = Cluster::builder("mock-token", Intents::empty())
+            .gateway_url(Some(gateway.url()))
+            .queue(Arc::new(Box::new(InstantQueue)))
+            .shard_scheme(ShardScheme::try_from((0..=1, 2)).unwrap())
+            .build()
+            .await
+            .unwrap();
+        cluster.up().await;
+
+        next_op(&gateway, 2).await;
+        next_op(&gateway, 2).await;
+        time::timeout(Duration::from_secs(10), async {
+            while gateway.connections() < 2
+                || cluster
+                    .info()
+                    .values()
+                    .any(|info| info.session_id().is_none())
+            {
+                time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        gateway.close(4000, "unknown error");
+
+        let mut sessions = vec![
+            next_op(&gateway, 6).await.d["session_id"].clone(),
+            next_op(&gateway, 6).await.d["session_id"].clone(),
+        ];
+        sessions.sort_by_key(ToString::to_string);
+        assert_eq!(vec!["mock-session-1", "mock-session-2"], sessions);
+        assert_eq!(4, gateway.connections());
+    }
+
+    #[tokio::test]
+    async fn test_raw_dispatches() {
+        let gateway = MockGateway::start().await.unwrap();
+        let mut shard = shard(&gateway);
+        let mut events = shard.some_events(EventTypeFlags::READY);
+        let mut dispatches = shard.raw_dispatches(EventTypeFlags::MESSAGE_ACK);
+        shard.start().await.unwrap();
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+
+        gateway.dispatch(
+            "MESSAGE_ACK",
+            json!({ "channel_id": "1", "message_id": "2", "version": 3 }),
+        );
+
+        let dispatch = time::timeout(Duration::from_secs(10), dispatches.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("MESSAGE_ACK", dispatch.event_type());
+        assert_eq!(2, dispatch.seq());
+        assert_eq!("2", dispatch.deserialize::<Value>().unwrap()["message_id"]);
+        assert!(matches!(dispatch.event().unwrap(), Event::MessageAck(_)));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_dispatch() {
+        let gateway = MockGateway::start().await.unwrap();
+        let mut shard = shard(&gateway);
+        let mut events = shard.some_events(
+            EventTypeFlags::READY | EventTypeFlags::MESSAGE_ACK | EventTypeFlags::UNKNOWN,
+        );
+        shard.start().await.unwrap();
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+
+        gateway.dispatch("SESSIONS_REPLACE", json!([{ "status": "online" }]));
+        gateway.dispatch(
+            "MESSAGE_ACK",
+            json!({ "channel_id": "1", "message_id": "2", "version": 3 }),
+        );
+
+        match next_event(&mut events).await {
+            Event::Unknown { name, data } => {
+                assert_eq!("SESSIONS_REPLACE", name);
+                assert_eq!(r#"[{"status":"online"}]"#, data);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(matches!(
+            next_event(&mut events).await,
+            Event::MessageAck(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_compression() {
+        let modes = [
+            (Compression::None, false),
+            (Compression::None, true),
+            (Compression::ZlibStream, false),
+            (Compression::ZstdStream, false),
+            // Payload compression isn't requested along with transport
+            // compression.
+            (Compression::ZstdStream, true),
+        ];
+
+        for &(compression, identify_compress) in &modes {
+            let gateway = MockGateway::start().await.unwrap();
+            let mut shard = Shard::builder("mock-token", Intents::empty())
+                .compression(compression)
+                .gateway_url(Some(gateway.url()))
+                .identify_compress(identify_compress)
+                .queue(Arc::new(Box::new(InstantQueue)))
+                .build();
+            let mut events = shard.some_events(EventTypeFlags::READY | EventTypeFlags::MESSAGE_ACK);
+            shard.start().await.unwrap();
+
+            let identify = next_op(&gateway, 2).await;
+            assert_eq!(
+                identify_compress && compression == Compression::None,
+                identify.d["compress"],
+                "{:?}",
+                compression,
+            );
+            assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+
+            gateway.dispatch(
+                "MESSAGE_ACK",
+                json!({ "channel_id": "1", "message_id": "2", "version": 3 }),
+            );
+            assert!(matches!(
+                next_event(&mut events).await,
+                Event::MessageAck(_)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_store_resume() {
+        let gateway = MockGateway::start().await.unwrap();
+        let directory = session_directory("session-store-resume");
+        let store = FileSessionStore::new(&directory);
+
+        let mut shard = Shard::builder("mock-token", Intents::empty())
+            .gateway_url(Some(gateway.url()))
+            .queue(Arc::new(Box::new(InstantQueue)))
+            .session_store(Arc::new(Box::new(store.clone())))
+            .build();
+        let mut events = shard.some_events(EventTypeFlags::READY);
+        shard.start().await.unwrap();
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+        next_op(&gateway, 2).await;
+
+        let saved = shard.shutdown_and_save().await.unwrap().unwrap();
+        assert_eq!(gateway.url(), saved.gateway_url);
+        assert_eq!("mock-session-1", saved.session_id);
+        assert_eq!(Some(saved), store.load([0, 1]).await.unwrap());
+
+        // The shard of the next process connects to the stored gateway URL.
+        let mut shard = Shard::builder("mock-token", Intents::empty())
+            .queue(Arc::new(Box::new(InstantQueue)))
+            .session_store(Arc::new(Box::new(store)))
+            .build();
+        let mut events = shard.some_events(EventTypeFlags::RESUMED);
+        shard.start().await.unwrap();
+
+        let resume = next_op(&gateway, 6).await;
+        assert_eq!("mock-session-1", resume.d["session_id"]);
+        assert_eq!(1, resume.d["seq"]);
+        assert!(matches!(next_event(&mut events).await, Event::Resumed));
+        assert!(!gateway
+            .payloads()
+            .iter()
+            .skip(1)
+            .any(|payload| payload.op == 2));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_session_store_identify() {
+        let gateway = MockGateway::start().await.unwrap();
+        let directory = session_directory("session-store-identify");
+        let store = FileSessionStore::new(&directory);
+        store
+            .save(&StoredSession {
+                gateway_url: gateway.url(),
+                read_state_version: 12,
+                sequence: 345,
+                session_id: "expired-session".to_owned(),
+                shard: [0, 1],
+                user_guild_settings_version: 6,
+            })
+            .await
+            .unwrap();
+
+        let mut shard = Shard::builder("mock-token", Intents::empty())
+            .queue(Arc::new(Box::new(InstantQueue)))
+            .session_store(Arc::new(Box::new(store.clone())))
+            .build();
+        let mut events = shard.some_events(EventTypeFlags::READY);
+        shard.start().await.unwrap();
+
+        // Discord doesn't know the session, so the shard identifies with the
+        // configured client state to get a full `READY`, since nothing from
+        // the stored session is cached.
+        let resume = next_op(&gateway, 6).await;
+        assert_eq!("expired-session", resume.d["session_id"]);
+        let identify = next_op(&gateway, 2).await;
+        assert_eq!(0, identify.d["client_state"]["read_state_version"]);
+        assert_eq!(
+            -1,
+            identify.d["client_state"]["user_guild_settings_version"]
+        );
+        assert!(matches!(next_event(&mut events).await, Event::Ready(_)));
+
+        let saved = shard.shutdown_and_save().await.unwrap().unwrap();
+        assert_eq!("mock-session-1", saved.session_id);
+        assert_eq!(0, saved.read_state_version);
+
+        // Sessions of other shards are removed and not resumed.
+        let mut shard = Shard::builder("mock-token", Intents::empty())
+            .gateway_url(Some(gateway.url()))
+            .queue(Arc::new(Box::new(InstantQueue)))
+            .session_store(Arc::new(Box::new(store.clone())))
+            .shard(0, 2)
+            .unwrap()
+            .build();
+        shard.start().await.unwrap();
+        next_op(&gateway, 2).await;
+        assert!(store.load([0, 2]).await.unwrap().is_none());
+        assert_eq!(
+            1,
+            gateway
+                .payloads()
+                .iter()
+                .filter(|payload| payload.op == 6)
+                .count()
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+use serde_json::Value;
+
+/// Payload that a [`MockGateway`] received from a client.
+///
+/// [`MockGateway`]: super::MockGateway
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ReceivedPayload {
+    /// Number of the connection that the payload was received on, starting
+    /// at 1 for the first connection to the gateway.
+    pub connection: u64,
+    /// Data of the payload, or [`Value::Null`] if it has none.
+    pub d: Value,
+    /// Opcode of the payload, such as 1 for heartbeats and 2 for identifies.
+    pub op: u8,
+}
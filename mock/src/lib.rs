@@ -0,0 +1,68 @@
+//! # twilight-mock
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-mock` provides local mock Discord servers for testing code that
+//! uses the Twilight ecosystem, without hitting Discord and fully offline.
+//!
+//! The [`rest`] module contains a [`MockServer`] that serves the REST API.
+//! Point an HTTP client at it with [`ClientBuilder::base_url`], script the
+//! responses of routes, emulate ratelimits, and assert on the requests it
+//! received.
+//!
+//! The [`gateway`] module contains a [`MockGateway`] that speaks the gateway
+//! protocol, including `zlib-stream`, `zstd-stream` and payload compression.
+//! Point a shard or cluster at it with their `gateway_url` builder methods,
+//! script dispatch events, close codes and session invalidations, and verify
+//! how they identify, heartbeat, resume and reconnect.
+//!
+//! ## Examples
+//!
+//! Respond to a request for gateway information and check the request:
+//!
+//! ```rust
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! use twilight_http::{routing::Route, Client};
+//! use twilight_mock::rest::{MockResponse, MockServer};
+//!
+//! let server = MockServer::start().await?;
+//! server.mock(
+//!     Route::GetGateway,
+//!     MockResponse::raw(200, r#"{"url":"wss://gateway.discord.gg"}"#),
+//! );
+//!
+//! let client = Client::builder().base_url(server.url()).build();
+//! let info = client.gateway().await?;
+//!
+//! assert_eq!("wss://gateway.discord.gg", info.url);
+//! assert_eq!("gateway", server.requests()[0].path);
+//! # Ok(()) }
+//! ```
+//!
+//! [`ClientBuilder::base_url`]: twilight_http::client::ClientBuilder::base_url
+//! [`MockGateway`]: gateway::MockGateway
+//! [`MockServer`]: rest::MockServer
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.49+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions)]
+
+pub mod gateway;
+pub mod rest;
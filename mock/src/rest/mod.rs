@@ -0,0 +1,595 @@
+//! Mock server for Discord's REST API.
+//!
+//! Start a [`MockServer`], script the [`MockResponse`]s of the routes under
+//! test with [`MockServer::mock`], and point an HTTP client at
+//! [`MockServer::url`]. Every request that the server receives is recorded
+//! as a [`RecordedRequest`].
+//!
+//! Requests to routes without a mocked response get a `404 Not Found`
+//! response, like requests to unknown resources do on Discord.
+//!
+//! # Ratelimits
+//!
+//! The server can emulate Discord's ratelimits. Configure a bucket with
+//! [`MockServer::ratelimit`] and its paths get ratelimit headers, and a
+//! `429 Too Many Requests` response once the bucket is exhausted.
+//! [`MockServer::global_ratelimit`] makes the server respond to all requests
+//! with a global ratelimit for some time.
+
+mod ratelimit;
+mod request;
+mod response;
+
+pub use self::{request::RecordedRequest, response::MockResponse};
+
+use self::ratelimit::Ratelimits;
+use hyper::{
+    body,
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::{Infallible, TryFrom},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::SocketAddr,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+use tokio::sync::oneshot::{self, Sender};
+use twilight_http::{
+    request::Method,
+    routing::{Path, Route},
+};
+
+/// Starting a [`MockServer`] failed.
+#[derive(Debug)]
+pub struct MockServerError {
+    kind: MockServerErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MockServerError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MockServerErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MockServerErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MockServerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            MockServerErrorType::Binding => f.write_str("binding to a local port failed"),
+        }
+    }
+}
+
+impl Error for MockServerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MockServerError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MockServerErrorType {
+    /// Binding to a local port failed.
+    Binding,
+}
+
+/// Scripted state of a mock server, shared with its connections.
+#[derive(Debug, Default)]
+struct State {
+    mocks: Mutex<HashMap<(Method, String), VecDeque<MockResponse>>>,
+    ratelimits: Mutex<Ratelimits>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+/// In-process HTTP server that mocks Discord's REST API.
+///
+/// The server runs on a random local port until it's dropped. Refer to the
+/// [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    shutdown: Option<Sender<()>>,
+    state: Arc<State>,
+}
+
+impl MockServer {
+    /// Start a mock server on a random local port.
+    ///
+    /// This must be called from within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MockServerErrorType::Binding`] error type if binding to a
+    /// local port failed.
+    // Async so that it can only be called where a runtime is available to
+    // spawn the server on.
+    #[allow(clippy::unused_async)]
+    pub async fn start() -> Result<Self, MockServerError> {
+        let state = Arc::new(State::default());
+        let service_state = Arc::clone(&state);
+
+        let make_service = make_service_fn(move |_| {
+            let state = Arc::clone(&service_state);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(Arc::clone(&state), request)
+                }))
+            }
+        });
+
+        let builder =
+            Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0))).map_err(|source| {
+                MockServerError {
+                    kind: MockServerErrorType::Binding,
+                    source: Some(Box::new(source)),
+                }
+            })?;
+        let server = builder.serve(make_service);
+        let addr = server.local_addr();
+
+        let (shutdown, rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let server = server.with_graceful_shutdown(async {
+                let _shutdown = rx.await;
+            });
+
+            if let Err(source) = server.await {
+                tracing::warn!("mock server failed: {}", source);
+            }
+        });
+
+        tracing::debug!(%addr, "started mock server");
+
+        Ok(Self {
+            addr,
+            shutdown: Some(shutdown),
+            state,
+        })
+    }
+
+    /// Address that the server listens on.
+    #[must_use = "retrieving the address has no effect if left unused"]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Base URL of the server, to pass to [`ClientBuilder::base_url`].
+    ///
+    /// [`ClientBuilder::base_url`]: twilight_http::client::ClientBuilder::base_url
+    #[must_use = "retrieving the URL has no effect if left unused"]
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Queue a response for a route.
+    ///
+    /// Responses queued for the same route are sent in order, and the last
+    /// one is repeated for all further requests. Routes are matched by their
+    /// method and path, ignoring the query string.
+    pub fn mock(&self, route: Route, response: MockResponse) -> &Self {
+        let (method, _, path) = route.into_parts();
+        let path = strip_query(&path).0.to_owned();
+
+        self.state
+            .mocks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((method, path))
+            .or_default()
+            .push_back(response);
+
+        self
+    }
+
+    /// Emulate a ratelimit bucket shared by some paths.
+    ///
+    /// Responses for the paths get ratelimit headers with the bucket hash,
+    /// and once `limit` requests have been made within `reset_after` the
+    /// server responds with `429 Too Many Requests` until the bucket resets.
+    pub fn ratelimit(
+        &self,
+        hash: impl Into<String>,
+        paths: impl IntoIterator<Item = Path>,
+        limit: u64,
+        reset_after: Duration,
+    ) -> &Self {
+        self.state
+            .ratelimits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .add(hash.into(), paths, limit, reset_after);
+
+        self
+    }
+
+    /// Respond to all requests with a global ratelimit for some time.
+    pub fn global_ratelimit(&self, reset_after: Duration) -> &Self {
+        self.state
+            .ratelimits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .lock_global(reset_after);
+
+        self
+    }
+
+    /// Requests that the server received, in the order they were received.
+    #[must_use = "retrieving the requests has no effect if left unused"]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Forget the requests that the server received.
+    pub fn clear_requests(&self) {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _sent = shutdown.send(());
+        }
+    }
+}
+
+async fn handle(state: Arc<State>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = body::to_bytes(body)
+        .await
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default();
+
+    let method = match method(&parts.method) {
+        Some(method) => method,
+        None => return Ok(into_hyper(&MockResponse::new(405), &[])),
+    };
+
+    let path = parts.uri.path().trim_start_matches('/');
+    let path = strip_api_prefix(path).to_owned();
+    let query = parts.uri.query().map(ToOwned::to_owned);
+
+    tracing::debug!(?method, %path, "mock server received request");
+
+    state
+        .requests
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(RecordedRequest {
+            body,
+            headers: parts.headers,
+            method,
+            path: path.clone(),
+            query,
+        });
+
+    let bucket = Path::try_from((method, path.as_str())).ok();
+    let (headers, limited) = state
+        .ratelimits
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .check(bucket.as_ref());
+    let headers = ratelimit::headers(&headers, limited);
+
+    if limited {
+        let global = headers
+            .iter()
+            .any(|(name, _)| *name == "x-ratelimit-global");
+        let retry_after = headers
+            .iter()
+            .find(|(name, _)| *name == "retry-after")
+            .and_then(|(_, value)| value.parse::<f64>().ok())
+            .unwrap_or_default();
+        let body = serde_json::json!({
+            "global": global,
+            "message": "You are being rate limited.",
+            "retry_after": retry_after,
+        });
+
+        return Ok(into_hyper(
+            &MockResponse::raw(429, body.to_string()),
+            &headers,
+        ));
+    }
+
+    let response = {
+        let mut mocks = state.mocks.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match mocks.get_mut(&(method, path)) {
+            Some(responses) if responses.len() > 1 => responses.pop_front(),
+            Some(responses) => responses.front().cloned(),
+            None => None,
+        }
+    };
+
+    let response = response.unwrap_or_else(|| MockResponse::error(404, 0, "404: Not Found"));
+
+    Ok(into_hyper(&response, &headers))
+}
+
+fn into_hyper(response: &MockResponse, headers: &[(&'static str, String)]) -> Response<Body> {
+    let mut builder = Response::builder().status(response.status);
+
+    for (name, value) in headers {
+        builder = builder.header(*name, value.as_str());
+    }
+
+    for (name, value) in &response.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+
+    builder
+        .body(Body::from(response.body.clone()))
+        .unwrap_or_else(|source| {
+            tracing::warn!("mocked response is invalid: {}", source);
+
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = hyper::StatusCode::INTERNAL_SERVER_ERROR;
+
+            response
+        })
+}
+
+fn method(method: &hyper::Method) -> Option<Method> {
+    Some(match *method {
+        hyper::Method::DELETE => Method::Delete,
+        hyper::Method::GET => Method::Get,
+        hyper::Method::PATCH => Method::Patch,
+        hyper::Method::POST => Method::Post,
+        hyper::Method::PUT => Method::Put,
+        _ => return None,
+    })
+}
+
+/// Strip the `api/v{version}/` prefix of a path, if it has one.
+fn strip_api_prefix(path: &str) -> &str {
+    let mut segments = path.splitn(3, '/');
+
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("api"), Some(version), Some(rest)) if version.starts_with('v') => rest,
+        _ => path,
+    }
+}
+
+/// Split a path into the path and its query string.
+fn strip_query(path: &str) -> (&str, Option<&str>) {
+    let mut split = path.splitn(2, '?');
+
+    (split.next().unwrap_or_default(), split.next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockResponse, MockServer, MockServerError, RecordedRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use twilight_http::{
+        client::RetryPolicy, error::ErrorType, request::Method, routing::Path, routing::Route,
+        Client,
+    };
+    use twilight_model::id::{ChannelId, MessageId};
+
+    assert_impl_all!(MockServer: Debug, Send, Sync);
+    assert_impl_all!(MockServerError: Debug, Error, Send, Sync);
+    assert_impl_all!(RecordedRequest: Clone, Debug, Send, Sync);
+
+    fn client(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.url())
+            .token("Bot mock-token")
+            .ratelimiter(None)
+            .retry_policy(RetryPolicy::disabled())
+            .build()
+    }
+
+    #[test]
+    fn test_strip_api_prefix() {
+        assert_eq!("gateway", super::strip_api_prefix("api/v8/gateway"));
+        assert_eq!("channels/1", super::strip_api_prefix("api/v9/channels/1"));
+        assert_eq!("gateway", super::strip_api_prefix("gateway"));
+    }
+
+    #[tokio::test]
+    async fn test_mock_and_record() {
+        let server = MockServer::start().await.unwrap();
+        server
+            .mock(
+                Route::GetGateway,
+                MockResponse::raw(200, r#"{"url":"wss://first"}"#),
+            )
+            .mock(
+                Route::GetGateway,
+                MockResponse::raw(200, r#"{"url":"wss://second"}"#),
+            );
+        let client = client(&server);
+
+        assert_eq!("wss://first", client.gateway().await.unwrap().url);
+        assert_eq!("wss://second", client.gateway().await.unwrap().url);
+        assert_eq!("wss://second", client.gateway().await.unwrap().url);
+
+        let requests = server.requests();
+        assert_eq!(3, requests.len());
+        assert_eq!(Method::Get, requests[0].method);
+        assert_eq!("gateway", requests[0].path);
+        assert_eq!(Some("Bot mock-token"), requests[0].header("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let error = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.as_u16() == 404
+        ));
+        assert_eq!("channels/1/messages/2", server.requests()[0].path);
+    }
+
+    #[tokio::test]
+    async fn test_ratelimit() {
+        let server = MockServer::start().await.unwrap();
+        server
+            .mock(
+                Route::CreateTypingTrigger { channel_id: 1 },
+                MockResponse::empty(),
+            )
+            .ratelimit(
+                "typing",
+                vec![Path::ChannelsIdTyping(1)],
+                1,
+                Duration::from_secs(5),
+            );
+        let client = client(&server);
+
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+        let error = client
+            .create_typing_trigger(ChannelId(1))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.as_u16() == 429
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit() {
+        let server = MockServer::start().await.unwrap();
+        server
+            .mock(Route::GetGateway, MockResponse::raw(200, r#"{"url":"a"}"#))
+            .global_ratelimit(Duration::from_secs(5));
+        let client = client(&server);
+
+        let error = client.gateway().await.unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.as_u16() == 429
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_track_ack_token() {
+        let server = MockServer::start().await.unwrap();
+        let route = || Route::AckMessage {
+            channel_id: 1,
+            message_id: 2,
+        };
+        server
+            .mock(route(), MockResponse::raw(200, r#"{"token":"first"}"#))
+            .mock(route(), MockResponse::raw(200, r#"{"token":"second"}"#));
+        let client = Client::builder()
+            .base_url(server.url())
+            .token("mock-token")
+            .ratelimiter(None)
+            .track_ack_token(true)
+            .build();
+
+        // Created before the first ack completes, but sent after it.
+        let first = client.ack_message(ChannelId(1), MessageId(2), None);
+        let second = client.ack_message(ChannelId(1), MessageId(2), None);
+
+        assert_eq!("first", first.await.unwrap().token);
+        assert_eq!(Some("first".to_owned()), client.ack_token());
+        assert_eq!("second", second.await.unwrap().token);
+        assert_eq!(Some("second".to_owned()), client.ack_token());
+
+        let requests = server.requests();
+        assert_eq!(
+            serde_json::json!({ "token": null }),
+            requests[0].json::<serde_json::Value>().unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({ "token": "first" }),
+            requests[1].json::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_untracked_ack_token() {
+        let server = MockServer::start().await.unwrap();
+        server.mock(
+            Route::AckMessage {
+                channel_id: 1,
+                message_id: 2,
+            },
+            MockResponse::raw(200, r#"{"token":"first"}"#),
+        );
+        let client = client(&server);
+
+        client
+            .ack_message(ChannelId(1), MessageId(2), None)
+            .await
+            .unwrap();
+        client
+            .ack_message(ChannelId(1), MessageId(2), None)
+            .await
+            .unwrap();
+
+        assert!(client.ack_token().is_none());
+        assert_eq!(
+            serde_json::json!({ "token": null }),
+            server.requests()[1].json::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_global_ratelimit_holds_requests() {
+        let server = MockServer::start().await.unwrap();
+        server
+            .mock(Route::GetGateway, MockResponse::raw(200, r#"{"url":"a"}"#))
+            .mock(
+                Route::CreateTypingTrigger { channel_id: 1 },
+                MockResponse::empty(),
+            )
+            .global_ratelimit(Duration::from_millis(500));
+        let client = client(&server);
+
+        assert!(client.gateway().await.is_err());
+
+        // Another route is held until the global ratelimit resets instead of
+        // being sent and ratelimited too.
+        client.create_typing_trigger(ChannelId(1)).await.unwrap();
+        assert_eq!(2, server.requests().len());
+    }
+}
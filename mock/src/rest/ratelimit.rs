@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use twilight_http::{ratelimiting::RatelimitHeaders, routing::Path};
+
+/// State of an emulated bucket.
+#[derive(Debug)]
+struct Bucket {
+    limit: u64,
+    remaining: u64,
+    reset_after: Duration,
+    reset_at: Option<Instant>,
+}
+
+impl Bucket {
+    /// Take one of the remaining requests of the bucket, returning whether
+    /// there was one.
+    fn take(&mut self, now: Instant) -> bool {
+        if self.reset_at.map_or(false, |reset_at| reset_at <= now) {
+            self.remaining = self.limit;
+            self.reset_at = None;
+        }
+
+        if self.remaining == 0 {
+            return false;
+        }
+
+        self.remaining -= 1;
+        self.reset_at.get_or_insert(now + self.reset_after);
+
+        true
+    }
+
+    fn reset_after(&self, now: Instant) -> Duration {
+        self.reset_at.map_or(self.reset_after, |reset_at| {
+            reset_at.saturating_duration_since(now)
+        })
+    }
+}
+
+/// Emulated ratelimits of a mock server.
+#[derive(Debug, Default)]
+pub(super) struct Ratelimits {
+    buckets: HashMap<String, Bucket>,
+    global_until: Option<Instant>,
+    paths: HashMap<Path, String>,
+}
+
+impl Ratelimits {
+    pub fn add(
+        &mut self,
+        hash: String,
+        paths: impl IntoIterator<Item = Path>,
+        limit: u64,
+        reset_after: Duration,
+    ) {
+        for path in paths {
+            self.paths.insert(path, hash.clone());
+        }
+
+        self.buckets.insert(
+            hash,
+            Bucket {
+                limit,
+                remaining: limit,
+                reset_after,
+                reset_at: None,
+            },
+        );
+    }
+
+    pub fn lock_global(&mut self, reset_after: Duration) {
+        self.global_until = Some(Instant::now() + reset_after);
+    }
+
+    /// Check whether a request to a path may be made, returning its
+    /// ratelimit headers and whether it's ratelimited.
+    pub fn check(&mut self, path: Option<&Path>) -> (RatelimitHeaders, bool) {
+        let now = Instant::now();
+
+        if let Some(global_until) = self.global_until {
+            if global_until > now {
+                let reset_after = global_until.saturating_duration_since(now);
+                let secs = reset_after.as_secs() + u64::from(reset_after.subsec_nanos() > 0);
+
+                return (RatelimitHeaders::GlobalLimited { reset_after: secs }, true);
+            }
+
+            self.global_until = None;
+        }
+
+        let paths = &self.paths;
+
+        let hash = match path.and_then(|path| paths.get(path)) {
+            Some(hash) => hash,
+            None => return (RatelimitHeaders::None, false),
+        };
+        let bucket = match self.buckets.get_mut(hash) {
+            Some(bucket) => bucket,
+            None => return (RatelimitHeaders::None, false),
+        };
+
+        let allowed = bucket.take(now);
+        let reset_after = millis(bucket.reset_after(now));
+        let unix_now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, millis);
+
+        let headers = RatelimitHeaders::Present {
+            bucket: Some(hash.clone()),
+            global: false,
+            limit: bucket.limit,
+            remaining: bucket.remaining,
+            reset: unix_now + reset_after,
+            reset_after,
+        };
+
+        (headers, !allowed)
+    }
+}
+
+/// Format ratelimit headers like Discord does.
+pub(super) fn headers(headers: &RatelimitHeaders, limited: bool) -> Vec<(&'static str, String)> {
+    match headers {
+        RatelimitHeaders::GlobalLimited { reset_after } => vec![
+            ("retry-after", reset_after.to_string()),
+            ("x-ratelimit-global", "true".to_owned()),
+        ],
+        RatelimitHeaders::Present {
+            bucket,
+            limit,
+            remaining,
+            reset,
+            reset_after,
+            ..
+        } => {
+            let mut headers = vec![
+                ("x-ratelimit-limit", limit.to_string()),
+                ("x-ratelimit-remaining", remaining.to_string()),
+                ("x-ratelimit-reset", secs(*reset)),
+                ("x-ratelimit-reset-after", secs(*reset_after)),
+            ];
+
+            if let Some(bucket) = bucket {
+                headers.push(("x-ratelimit-bucket", bucket.clone()));
+            }
+
+            if limited {
+                headers.push(("retry-after", secs(*reset_after)));
+            }
+
+            headers
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+/// Format milliseconds as seconds with a fraction.
+fn secs(millis: u64) -> String {
+    format!("{}.{:03}", millis / 1000, millis % 1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{headers, Ratelimits};
+    use std::time::Duration;
+    use twilight_http::{ratelimiting::RatelimitHeaders, routing::Path};
+
+    #[test]
+    fn test_bucket() {
+        let mut ratelimits = Ratelimits::default();
+        let path = Path::ChannelsIdTyping(1);
+        ratelimits.add(
+            "abc".to_owned(),
+            vec![path.clone()],
+            2,
+            Duration::from_secs(5),
+        );
+
+        let (first, limited) = ratelimits.check(Some(&path));
+        assert!(!limited);
+        assert!(matches!(
+            first,
+            RatelimitHeaders::Present { remaining: 1, .. }
+        ));
+        assert!(!ratelimits.check(Some(&path)).1);
+
+        let (last, limited) = ratelimits.check(Some(&path));
+        assert!(limited);
+        assert!(matches!(
+            last,
+            RatelimitHeaders::Present { remaining: 0, .. }
+        ));
+        assert!(headers(&last, limited)
+            .iter()
+            .any(|(name, _)| *name == "retry-after"));
+
+        assert!(!ratelimits.check(Some(&Path::ChannelsIdTyping(2))).1);
+    }
+
+    #[test]
+    fn test_global() {
+        let mut ratelimits = Ratelimits::default();
+        ratelimits.lock_global(Duration::from_millis(1500));
+
+        let (global, limited) = ratelimits.check(None);
+        assert!(limited);
+        assert!(matches!(
+            global,
+            RatelimitHeaders::GlobalLimited { reset_after: 2 }
+        ));
+        assert_eq!(
+            vec![
+                ("retry-after", "2".to_owned()),
+                ("x-ratelimit-global", "true".to_owned())
+            ],
+            headers(&global, limited)
+        );
+    }
+
+    #[test]
+    fn test_secs() {
+        assert_eq!("1.005", super::secs(1005));
+        assert_eq!("0.250", super::secs(250));
+    }
+}
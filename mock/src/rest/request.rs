@@ -0,0 +1,39 @@
+use hyper::HeaderMap;
+use serde::de::DeserializeOwned;
+use twilight_http::request::Method;
+
+/// Request received by a [`MockServer`].
+///
+/// [`MockServer`]: super::MockServer
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RecordedRequest {
+    /// Body of the request.
+    pub body: Vec<u8>,
+    /// Headers of the request.
+    pub headers: HeaderMap,
+    /// Method of the request.
+    pub method: Method,
+    /// Path of the request, without the leading `/api/v{version}/`, such as
+    /// `channels/1/messages`.
+    pub path: String,
+    /// Query string of the request, if there is one.
+    pub query: Option<String>,
+}
+
+impl RecordedRequest {
+    /// Value of a header of the request, if it's present and valid UTF-8.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// Deserialize the JSON body of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body isn't valid JSON for the type.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
@@ -0,0 +1,94 @@
+use serde::Serialize;
+
+/// Response that a [`MockServer`] sends for a route.
+///
+/// [`MockServer`]: super::MockServer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MockResponse {
+    pub(super) body: Vec<u8>,
+    pub(super) headers: Vec<(String, String)>,
+    pub(super) status: u16,
+}
+
+impl MockResponse {
+    /// Create a response with a status code and no body.
+    #[must_use]
+    pub fn new(status: u16) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: Vec::new(),
+            status,
+        }
+    }
+
+    /// Create a `204 No Content` response.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::new(204)
+    }
+
+    /// Create a `200 OK` response with a value serialized as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value couldn't be serialized.
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Result<Self, serde_json::Error> {
+        Ok(Self::raw(200, serde_json::to_vec(value)?))
+    }
+
+    /// Create a response with a status code and a raw JSON body, such as a
+    /// fixture recorded from Discord.
+    #[must_use]
+    pub fn raw(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self::new(status)
+            .body(body)
+            .header("content-type", "application/json")
+    }
+
+    /// Set the body of the response.
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+
+        self
+    }
+
+    /// Add a header to the response.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// Create a response with a Discord API error.
+    pub(super) fn error(status: u16, code: u64, message: &str) -> Self {
+        let body = serde_json::json!({
+            "code": code,
+            "message": message,
+        });
+
+        Self::raw(status, body.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockResponse;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(MockResponse: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_json() {
+        let response = MockResponse::json(&[1, 2]).unwrap();
+
+        assert_eq!(200, response.status);
+        assert_eq!(b"[1,2]".to_vec(), response.body);
+        assert_eq!(
+            vec![("content-type".to_owned(), "application/json".to_owned())],
+            response.headers
+        );
+    }
+}
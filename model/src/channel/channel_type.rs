@@ -1,33 +1,89 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum ChannelType {
-    GuildText = 0,
-    Private = 1,
-    GuildVoice = 2,
-    Group = 3,
-    GuildCategory = 4,
-    GuildNews = 5,
-    GuildStore = 6,
-    GuildStageVoice = 13,
+    GuildText,
+    Private,
+    GuildVoice,
+    Group,
+    GuildCategory,
+    GuildNews,
+    GuildStore,
+    GuildNewsThread,
+    GuildPublicThread,
+    GuildPrivateThread,
+    GuildStageVoice,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for ChannelType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::GuildText,
+            1 => Self::Private,
+            2 => Self::GuildVoice,
+            3 => Self::Group,
+            4 => Self::GuildCategory,
+            5 => Self::GuildNews,
+            6 => Self::GuildStore,
+            10 => Self::GuildNewsThread,
+            11 => Self::GuildPublicThread,
+            12 => Self::GuildPrivateThread,
+            13 => Self::GuildStageVoice,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ChannelType> for u8 {
+    fn from(value: ChannelType) -> Self {
+        match value {
+            ChannelType::GuildText => 0,
+            ChannelType::Private => 1,
+            ChannelType::GuildVoice => 2,
+            ChannelType::Group => 3,
+            ChannelType::GuildCategory => 4,
+            ChannelType::GuildNews => 5,
+            ChannelType::GuildStore => 6,
+            ChannelType::GuildNewsThread => 10,
+            ChannelType::GuildPublicThread => 11,
+            ChannelType::GuildPrivateThread => 12,
+            ChannelType::GuildStageVoice => 13,
+            ChannelType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(ChannelType, u8);
+
 impl ChannelType {
     pub fn name(self) -> &'static str {
         match self {
             Self::Group => "Group",
             Self::GuildCategory => "GuildCategory",
             Self::GuildNews => "GuildNews",
+            Self::GuildNewsThread => "GuildNewsThread",
+            Self::GuildPrivateThread => "GuildPrivateThread",
+            Self::GuildPublicThread => "GuildPublicThread",
             Self::GuildStageVoice => "GuildStageVoice",
             Self::GuildStore => "GuildStore",
             Self::GuildText => "GuildText",
             Self::GuildVoice => "GuildVoice",
             Self::Private => "Private",
+            Self::Unknown(_) => "Unknown",
         }
     }
+
+    /// Whether the channel type is a thread.
+    pub const fn is_thread(self) -> bool {
+        matches!(
+            self,
+            Self::GuildNewsThread | Self::GuildPublicThread | Self::GuildPrivateThread
+        )
+    }
 }
 
 #[cfg(test)]
@@ -44,7 +100,11 @@ mod tests {
         serde_test::assert_tokens(&ChannelType::GuildCategory, &[Token::U8(4)]);
         serde_test::assert_tokens(&ChannelType::GuildNews, &[Token::U8(5)]);
         serde_test::assert_tokens(&ChannelType::GuildStore, &[Token::U8(6)]);
+        serde_test::assert_tokens(&ChannelType::GuildNewsThread, &[Token::U8(10)]);
+        serde_test::assert_tokens(&ChannelType::GuildPublicThread, &[Token::U8(11)]);
+        serde_test::assert_tokens(&ChannelType::GuildPrivateThread, &[Token::U8(12)]);
         serde_test::assert_tokens(&ChannelType::GuildStageVoice, &[Token::U8(13)]);
+        serde_test::assert_tokens(&ChannelType::Unknown(255), &[Token::U8(255)]);
     }
 
     #[test]
@@ -52,10 +112,28 @@ mod tests {
         assert_eq!("Group", ChannelType::Group.name());
         assert_eq!("GuildCategory", ChannelType::GuildCategory.name());
         assert_eq!("GuildNews", ChannelType::GuildNews.name());
+        assert_eq!("GuildNewsThread", ChannelType::GuildNewsThread.name());
+        assert_eq!("GuildPrivateThread", ChannelType::GuildPrivateThread.name());
+        assert_eq!("GuildPublicThread", ChannelType::GuildPublicThread.name());
         assert_eq!("GuildStageVoice", ChannelType::GuildStageVoice.name());
         assert_eq!("GuildStore", ChannelType::GuildStore.name());
         assert_eq!("GuildText", ChannelType::GuildText.name());
         assert_eq!("GuildVoice", ChannelType::GuildVoice.name());
         assert_eq!("Private", ChannelType::Private.name());
     }
+
+    #[test]
+    fn test_is_thread() {
+        assert!(ChannelType::GuildNewsThread.is_thread());
+        assert!(ChannelType::GuildPrivateThread.is_thread());
+        assert!(ChannelType::GuildPublicThread.is_thread());
+        assert!(!ChannelType::GuildText.is_thread());
+        assert!(!ChannelType::Unknown(11).is_thread());
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(ChannelType::GuildStore < ChannelType::Unknown(7));
+        assert!(ChannelType::Unknown(7) < ChannelType::GuildNewsThread);
+    }
 }
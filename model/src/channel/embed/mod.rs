@@ -11,6 +11,7 @@ pub use self::{
     provider::EmbedProvider, thumbnail::EmbedThumbnail, video::EmbedVideo,
 };
 
+use crate::datetime::Timestamp;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -34,7 +35,7 @@ pub struct Embed {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<EmbedThumbnail>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,6 +50,7 @@ mod tests {
         Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedProvider, EmbedThumbnail,
         EmbedVideo,
     };
+    use crate::datetime::Timestamp;
     use serde_test::Token;
 
     #[test]
@@ -63,7 +65,9 @@ mod tests {
             kind: "rich".to_owned(),
             provider: None,
             thumbnail: None,
-            timestamp: Some("a timestamp".to_owned()),
+            timestamp: Some(
+                Timestamp::parse("2021-08-10T12:18:37.000000+00:00").expect("valid timestamp"),
+            ),
             title: Some("a title".to_owned()),
             url: Some("https://example.com".to_owned()),
             video: None,
@@ -86,7 +90,7 @@ mod tests {
                 Token::Str("rich"),
                 Token::Str("timestamp"),
                 Token::Some,
-                Token::Str("a timestamp"),
+                Token::Str("2021-08-10T12:18:37.000000+00:00"),
                 Token::Str("title"),
                 Token::Some,
                 Token::Str("a title"),
@@ -137,7 +141,9 @@ mod tests {
                 url: Some("https://example.com/1.png".to_owned()),
                 width: Some(2560),
             }),
-            timestamp: Some("a timestamp".to_owned()),
+            timestamp: Some(
+                Timestamp::parse("2021-08-10T12:18:37.000000+00:00").expect("valid timestamp"),
+            ),
             title: Some("a title".to_owned()),
             url: Some("https://example.com".to_owned()),
             video: Some(EmbedVideo {
@@ -264,7 +270,7 @@ mod tests {
                 Token::StructEnd,
                 Token::Str("timestamp"),
                 Token::Some,
-                Token::Str("a timestamp"),
+                Token::Str("2021-08-10T12:18:37.000000+00:00"),
                 Token::Str("title"),
                 Token::Some,
                 Token::Str("a title"),
@@ -1,16 +1,43 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum MessageActivityType {
-    Join = 1,
-    Spectate = 2,
-    Listen = 3,
-    JoinRequest = 5,
+    Join,
+    Spectate,
+    Listen,
+    JoinRequest,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for MessageActivityType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Join,
+            2 => Self::Spectate,
+            3 => Self::Listen,
+            5 => Self::JoinRequest,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<MessageActivityType> for u8 {
+    fn from(value: MessageActivityType) -> Self {
+        match value {
+            MessageActivityType::Join => 1,
+            MessageActivityType::Spectate => 2,
+            MessageActivityType::Listen => 3,
+            MessageActivityType::JoinRequest => 5,
+            MessageActivityType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(MessageActivityType, u8);
+
 #[cfg(test)]
 mod tests {
     use super::MessageActivityType;
@@ -22,5 +49,6 @@ mod tests {
         serde_test::assert_tokens(&MessageActivityType::Spectate, &[Token::U8(2)]);
         serde_test::assert_tokens(&MessageActivityType::Listen, &[Token::U8(3)]);
         serde_test::assert_tokens(&MessageActivityType::JoinRequest, &[Token::U8(5)]);
+        serde_test::assert_tokens(&MessageActivityType::Unknown(255), &[Token::U8(255)]);
     }
 }
@@ -2,20 +2,68 @@ use crate::{
     id::{RoleId, UserId},
     util::is_false,
 };
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::fmt::{Formatter, Result as FmtResult};
 
 mod builder;
 
 pub use self::builder::AllowedMentionsBuilder;
 
 /// Parse types.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
-#[serde(rename_all = "lowercase")]
 pub enum ParseTypes {
     Everyone,
     Roles,
     Users,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl ParseTypes {
+    /// Name of the parse type as sent by Discord.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Everyone => "everyone",
+            Self::Roles => "roles",
+            Self::Users => "users",
+            Self::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+struct ParseTypesVisitor;
+
+impl<'de> Visitor<'de> for ParseTypesVisitor {
+    type Value = ParseTypes;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a mention parse type")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(match value {
+            "everyone" => ParseTypes::Everyone,
+            "roles" => ParseTypes::Roles,
+            "users" => ParseTypes::Users,
+            unknown => ParseTypes::Unknown(unknown.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseTypes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseTypesVisitor)
+    }
+}
+
+impl Serialize for ParseTypes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
 }
 
 /// Allowed mentions structure.
@@ -85,10 +133,7 @@ mod tests {
                 },
                 Token::Str("parse"),
                 Token::Seq { len: Some(1) },
-                Token::UnitVariant {
-                    name: "ParseTypes",
-                    variant: "everyone",
-                },
+                Token::Str("everyone"),
                 Token::SeqEnd,
                 Token::Str("users"),
                 Token::Seq { len: Some(1) },
@@ -106,4 +151,15 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_parse_types() {
+        serde_test::assert_tokens(&ParseTypes::Everyone, &[Token::Str("everyone")]);
+        serde_test::assert_tokens(&ParseTypes::Roles, &[Token::Str("roles")]);
+        serde_test::assert_tokens(&ParseTypes::Users, &[Token::Str("users")]);
+        serde_test::assert_tokens(
+            &ParseTypes::Unknown("channels".to_owned()),
+            &[Token::Str("channels")],
+        );
+    }
 }
@@ -1,70 +1,93 @@
-use crate::channel::ConversionError;
-use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::convert::TryFrom;
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum MessageType {
-    Regular = 0,
-    RecipientAdd = 1,
-    RecipientRemove = 2,
-    Call = 3,
-    ChannelNameChange = 4,
-    ChannelIconChange = 5,
-    ChannelMessagePinned = 6,
-    GuildMemberJoin = 7,
-    UserPremiumSub = 8,
-    UserPremiumSubTier1 = 9,
-    UserPremiumSubTier2 = 10,
-    UserPremiumSubTier3 = 11,
-    ChannelFollowAdd = 12,
-    GuildDiscoveryDisqualified = 14,
-    GuildDiscoveryRequalified = 15,
-    GuildDiscoveryGracePeriodInitialWarning = 16,
-    GuildDiscoveryGracePeriodFinalWarning = 17,
+    Regular,
+    RecipientAdd,
+    RecipientRemove,
+    Call,
+    ChannelNameChange,
+    ChannelIconChange,
+    ChannelMessagePinned,
+    GuildMemberJoin,
+    UserPremiumSub,
+    UserPremiumSubTier1,
+    UserPremiumSubTier2,
+    UserPremiumSubTier3,
+    ChannelFollowAdd,
+    GuildDiscoveryDisqualified,
+    GuildDiscoveryRequalified,
+    GuildDiscoveryGracePeriodInitialWarning,
+    GuildDiscoveryGracePeriodFinalWarning,
     /// Message is an inline reply.
-    Reply = 19,
-    GuildInviteReminder = 22,
+    Reply,
+    GuildInviteReminder,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
-impl TryFrom<u8> for MessageType {
-    type Error = ConversionError;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        let message_type = match value {
-            0 => MessageType::Regular,
-            1 => MessageType::RecipientAdd,
-            2 => MessageType::RecipientRemove,
-            3 => MessageType::Call,
-            4 => MessageType::ChannelNameChange,
-            5 => MessageType::ChannelIconChange,
-            6 => MessageType::ChannelMessagePinned,
-            7 => MessageType::GuildMemberJoin,
-            8 => MessageType::UserPremiumSub,
-            9 => MessageType::UserPremiumSubTier1,
-            10 => MessageType::UserPremiumSubTier2,
-            11 => MessageType::UserPremiumSubTier3,
-            12 => MessageType::ChannelFollowAdd,
-            14 => MessageType::GuildDiscoveryDisqualified,
-            15 => MessageType::GuildDiscoveryRequalified,
-            16 => MessageType::GuildDiscoveryGracePeriodInitialWarning,
-            17 => MessageType::GuildDiscoveryGracePeriodFinalWarning,
-            19 => MessageType::Reply,
-            22 => MessageType::GuildInviteReminder,
-            _ => return Err(ConversionError::MessageType(value)),
-        };
+impl From<u8> for MessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Regular,
+            1 => Self::RecipientAdd,
+            2 => Self::RecipientRemove,
+            3 => Self::Call,
+            4 => Self::ChannelNameChange,
+            5 => Self::ChannelIconChange,
+            6 => Self::ChannelMessagePinned,
+            7 => Self::GuildMemberJoin,
+            8 => Self::UserPremiumSub,
+            9 => Self::UserPremiumSubTier1,
+            10 => Self::UserPremiumSubTier2,
+            11 => Self::UserPremiumSubTier3,
+            12 => Self::ChannelFollowAdd,
+            14 => Self::GuildDiscoveryDisqualified,
+            15 => Self::GuildDiscoveryRequalified,
+            16 => Self::GuildDiscoveryGracePeriodInitialWarning,
+            17 => Self::GuildDiscoveryGracePeriodFinalWarning,
+            19 => Self::Reply,
+            22 => Self::GuildInviteReminder,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
 
-        Ok(message_type)
+impl From<MessageType> for u8 {
+    fn from(value: MessageType) -> Self {
+        match value {
+            MessageType::Regular => 0,
+            MessageType::RecipientAdd => 1,
+            MessageType::RecipientRemove => 2,
+            MessageType::Call => 3,
+            MessageType::ChannelNameChange => 4,
+            MessageType::ChannelIconChange => 5,
+            MessageType::ChannelMessagePinned => 6,
+            MessageType::GuildMemberJoin => 7,
+            MessageType::UserPremiumSub => 8,
+            MessageType::UserPremiumSubTier1 => 9,
+            MessageType::UserPremiumSubTier2 => 10,
+            MessageType::UserPremiumSubTier3 => 11,
+            MessageType::ChannelFollowAdd => 12,
+            MessageType::GuildDiscoveryDisqualified => 14,
+            MessageType::GuildDiscoveryRequalified => 15,
+            MessageType::GuildDiscoveryGracePeriodInitialWarning => 16,
+            MessageType::GuildDiscoveryGracePeriodFinalWarning => 17,
+            MessageType::Reply => 19,
+            MessageType::GuildInviteReminder => 22,
+            MessageType::Unknown(unknown) => unknown,
+        }
     }
 }
 
+impl_ord_by_value!(MessageType, u8);
+
 #[cfg(test)]
 mod tests {
-    use super::{ConversionError, MessageType};
+    use super::MessageType;
     use serde_test::Token;
-    use std::convert::TryFrom;
 
     #[test]
     fn test_variants() {
@@ -93,77 +116,43 @@ mod tests {
         );
         serde_test::assert_tokens(&MessageType::Reply, &[Token::U8(19)]);
         serde_test::assert_tokens(&MessageType::GuildInviteReminder, &[Token::U8(22)]);
+        serde_test::assert_tokens(&MessageType::Unknown(255), &[Token::U8(255)]);
     }
 
     #[test]
     fn test_conversions() {
-        assert_eq!(MessageType::try_from(0).unwrap(), MessageType::Regular);
-        assert_eq!(MessageType::try_from(1).unwrap(), MessageType::RecipientAdd);
-        assert_eq!(
-            MessageType::try_from(2).unwrap(),
-            MessageType::RecipientRemove
-        );
-        assert_eq!(MessageType::try_from(3).unwrap(), MessageType::Call);
-        assert_eq!(
-            MessageType::try_from(4).unwrap(),
-            MessageType::ChannelNameChange
-        );
-        assert_eq!(
-            MessageType::try_from(5).unwrap(),
-            MessageType::ChannelIconChange
-        );
-        assert_eq!(
-            MessageType::try_from(6).unwrap(),
-            MessageType::ChannelMessagePinned
-        );
-        assert_eq!(
-            MessageType::try_from(7).unwrap(),
-            MessageType::GuildMemberJoin
-        );
-        assert_eq!(
-            MessageType::try_from(8).unwrap(),
-            MessageType::UserPremiumSub
-        );
-        assert_eq!(
-            MessageType::try_from(9).unwrap(),
-            MessageType::UserPremiumSubTier1
-        );
-        assert_eq!(
-            MessageType::try_from(10).unwrap(),
-            MessageType::UserPremiumSubTier2
-        );
-        assert_eq!(
-            MessageType::try_from(11).unwrap(),
-            MessageType::UserPremiumSubTier3
-        );
-        assert_eq!(
-            MessageType::try_from(12).unwrap(),
-            MessageType::ChannelFollowAdd
-        );
-        assert_eq!(
-            MessageType::try_from(14).unwrap(),
+        assert_eq!(MessageType::from(0), MessageType::Regular);
+        assert_eq!(MessageType::from(1), MessageType::RecipientAdd);
+        assert_eq!(MessageType::from(2), MessageType::RecipientRemove);
+        assert_eq!(MessageType::from(3), MessageType::Call);
+        assert_eq!(MessageType::from(4), MessageType::ChannelNameChange);
+        assert_eq!(MessageType::from(5), MessageType::ChannelIconChange);
+        assert_eq!(MessageType::from(6), MessageType::ChannelMessagePinned);
+        assert_eq!(MessageType::from(7), MessageType::GuildMemberJoin);
+        assert_eq!(MessageType::from(8), MessageType::UserPremiumSub);
+        assert_eq!(MessageType::from(9), MessageType::UserPremiumSubTier1);
+        assert_eq!(MessageType::from(10), MessageType::UserPremiumSubTier2);
+        assert_eq!(MessageType::from(11), MessageType::UserPremiumSubTier3);
+        assert_eq!(MessageType::from(12), MessageType::ChannelFollowAdd);
+        assert_eq!(
+            MessageType::from(14),
             MessageType::GuildDiscoveryDisqualified
         );
         assert_eq!(
-            MessageType::try_from(15).unwrap(),
+            MessageType::from(15),
             MessageType::GuildDiscoveryRequalified
         );
         assert_eq!(
-            MessageType::try_from(16).unwrap(),
+            MessageType::from(16),
             MessageType::GuildDiscoveryGracePeriodInitialWarning
         );
         assert_eq!(
-            MessageType::try_from(17).unwrap(),
+            MessageType::from(17),
             MessageType::GuildDiscoveryGracePeriodFinalWarning
         );
-        assert_eq!(MessageType::try_from(19).unwrap(), MessageType::Reply);
-        assert_eq!(
-            MessageType::try_from(22).unwrap(),
-            MessageType::GuildInviteReminder
-        );
-        assert_eq!(
-            MessageType::try_from(250).unwrap_err(),
-            ConversionError::MessageType(250)
-        );
+        assert_eq!(MessageType::from(19), MessageType::Reply);
+        assert_eq!(MessageType::from(22), MessageType::GuildInviteReminder);
+        assert_eq!(MessageType::from(250), MessageType::Unknown(250));
+        assert_eq!(250, u8::from(MessageType::Unknown(250)));
     }
 }
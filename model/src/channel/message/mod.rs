@@ -19,6 +19,7 @@ pub use self::{
 
 use crate::{
     channel::{embed::Embed, Attachment, ChannelMention},
+    datetime::Timestamp,
     guild::PartialMember,
     id::{ChannelId, GuildId, MessageId, RoleId, WebhookId},
     user::User,
@@ -35,7 +36,7 @@ pub struct Message {
     pub author: User,
     pub channel_id: ChannelId,
     pub content: String,
-    pub edited_timestamp: Option<String>,
+    pub edited_timestamp: Option<Timestamp>,
     pub embeds: Vec<Embed>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<MessageFlags>,
@@ -66,7 +67,7 @@ pub struct Message {
     /// Stickers within the message.
     #[serde(default)]
     pub stickers: Vec<Sticker>,
-    pub timestamp: String,
+    pub timestamp: Timestamp,
     pub tts: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub webhook_id: Option<WebhookId>,
@@ -81,6 +82,7 @@ mod tests {
     };
     use crate::{
         channel::{ChannelType, ReactionType},
+        datetime::Timestamp,
         guild::PartialMember,
         id::{ApplicationId, ChannelId, GuildId, MessageId, UserId},
         user::User,
@@ -119,7 +121,9 @@ mod tests {
             kind: MessageType::Regular,
             member: Some(PartialMember {
                 deaf: false,
-                joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
+                joined_at: Some(
+                    Timestamp::parse("2020-01-01T00:00:00.000000+00:00").expect("valid timestamp"),
+                ),
                 mute: false,
                 nick: Some("member nick".to_owned()),
                 premium_since: None,
@@ -143,7 +147,8 @@ mod tests {
                 tags: Some("foo,bar,baz".to_owned()),
             }],
             referenced_message: None,
-            timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
+            timestamp: Timestamp::parse("2020-02-02T02:02:02.020000+00:00")
+                .expect("valid timestamp"),
             tts: false,
             webhook_id: None,
         };
@@ -299,7 +304,9 @@ mod tests {
             },
             channel_id: ChannelId(2),
             content: "ping".to_owned(),
-            edited_timestamp: Some("123".to_owned()),
+            edited_timestamp: Some(
+                Timestamp::parse("2021-08-10T12:18:37.000000+00:00").expect("valid timestamp"),
+            ),
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
             guild_id: Some(GuildId(1)),
@@ -307,7 +314,9 @@ mod tests {
             kind: MessageType::Regular,
             member: Some(PartialMember {
                 deaf: false,
-                joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
+                joined_at: Some(
+                    Timestamp::parse("2020-01-01T00:00:00.000000+00:00").expect("valid timestamp"),
+                ),
                 mute: false,
                 nick: Some("member nick".to_owned()),
                 premium_since: None,
@@ -347,7 +356,8 @@ mod tests {
                 tags: Some("foo,bar,baz".to_owned()),
             }],
             referenced_message: None,
-            timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
+            timestamp: Timestamp::parse("2020-02-02T02:02:02.020000+00:00")
+                .expect("valid timestamp"),
             tts: false,
             webhook_id: Some(WebhookId(1)),
         };
@@ -418,7 +428,7 @@ mod tests {
                 Token::Str("ping"),
                 Token::Str("edited_timestamp"),
                 Token::Some,
-                Token::Str("123"),
+                Token::Str("2021-08-10T12:18:37.000000+00:00"),
                 Token::Str("embeds"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
@@ -1,89 +1,61 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::{
-    convert::TryFrom,
-    error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
-};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
 /// Format type of a [Sticker][`super::Sticker`].
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum StickerFormatType {
     /// Sticker format is a PNG.
-    Png = 1,
+    Png,
     /// Sticker format is an APNG.
-    Apng = 2,
+    Apng,
     /// Sticker format is a LOTTIE.
-    Lottie = 3,
+    Lottie,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
-impl TryFrom<u8> for StickerFormatType {
-    type Error = StickerFormatTypeConversionError;
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        Ok(match value {
-            1 => StickerFormatType::Png,
-            2 => StickerFormatType::Apng,
-            3 => StickerFormatType::Lottie,
-            _ => return Err(StickerFormatTypeConversionError { value }),
-        })
-    }
-}
-
-/// Converting into a [`StickerFormatType`] failed.
-///
-/// This occurs only when the input value doesn't map to a sticker type variant.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct StickerFormatTypeConversionError {
-    value: u8,
-}
-
-impl<'a> StickerFormatTypeConversionError {
-    /// Retrieve a copy of the input value that couldn't be parsed.
-    pub fn value(&self) -> u8 {
-        self.value
+impl From<u8> for StickerFormatType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Png,
+            2 => Self::Apng,
+            3 => Self::Lottie,
+            unknown => Self::Unknown(unknown),
+        }
     }
 }
 
-impl Display for StickerFormatTypeConversionError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_fmt(format_args!(
-            "Value ({}) doesn't match a sticker type",
-            self.value,
-        ))
+impl From<StickerFormatType> for u8 {
+    fn from(value: StickerFormatType) -> Self {
+        match value {
+            StickerFormatType::Png => 1,
+            StickerFormatType::Apng => 2,
+            StickerFormatType::Lottie => 3,
+            StickerFormatType::Unknown(unknown) => unknown,
+        }
     }
 }
 
-impl Error for StickerFormatTypeConversionError {}
+impl_ord_by_value!(StickerFormatType, u8);
 
 #[cfg(test)]
 mod tests {
     use super::StickerFormatType;
     use serde_test::Token;
-    use std::convert::TryFrom;
 
     #[test]
     fn test_variants() {
         serde_test::assert_tokens(&StickerFormatType::Png, &[Token::U8(1)]);
         serde_test::assert_tokens(&StickerFormatType::Apng, &[Token::U8(2)]);
         serde_test::assert_tokens(&StickerFormatType::Lottie, &[Token::U8(3)]);
+        serde_test::assert_tokens(&StickerFormatType::Unknown(255), &[Token::U8(255)]);
     }
 
     #[test]
     fn test_conversions() {
-        assert_eq!(
-            StickerFormatType::try_from(1).unwrap(),
-            StickerFormatType::Png
-        );
-        assert_eq!(
-            StickerFormatType::try_from(2).unwrap(),
-            StickerFormatType::Apng
-        );
-        assert_eq!(
-            StickerFormatType::try_from(3).unwrap(),
-            StickerFormatType::Lottie
-        );
+        assert_eq!(StickerFormatType::from(1), StickerFormatType::Png);
+        assert_eq!(StickerFormatType::from(2), StickerFormatType::Apng);
+        assert_eq!(StickerFormatType::from(3), StickerFormatType::Lottie);
     }
 }
@@ -1,6 +1,7 @@
 pub mod embed;
 pub mod message;
 pub mod permission_overwrite;
+pub mod thread;
 
 mod attachment;
 mod category_channel;
@@ -12,6 +13,7 @@ mod private_channel;
 mod reaction;
 mod reaction_type;
 mod text_channel;
+mod thread_channel;
 mod video_quality_mode;
 mod voice_channel;
 mod webhook;
@@ -21,31 +23,19 @@ pub use self::{
     attachment::Attachment, category_channel::CategoryChannel, channel_mention::ChannelMention,
     channel_type::ChannelType, followed_channel::FollowedChannel, group::Group, message::Message,
     private_channel::PrivateChannel, reaction::Reaction, reaction_type::ReactionType,
-    text_channel::TextChannel, video_quality_mode::VideoQualityMode, voice_channel::VoiceChannel,
-    webhook::Webhook, webhook_type::WebhookType,
+    text_channel::TextChannel, thread_channel::ThreadChannel, video_quality_mode::VideoQualityMode,
+    voice_channel::VoiceChannel, webhook::Webhook, webhook_type::WebhookType,
 };
 
-use crate::id::{ChannelId, GuildId, MessageId};
+use crate::{
+    channel::thread::ThreadMember,
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
-use std::fmt::{self, Formatter, Result as FmtResult};
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum ConversionError {
-    MessageType(u8),
-}
-
-impl fmt::Display for ConversionError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            ConversionError::MessageType(num) => {
-                write!(f, "Could not convert {} into a valid MessageType!", num)
-            }
-        }
-    }
-}
+use std::fmt::{Formatter, Result as FmtResult};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -86,6 +76,7 @@ pub enum GuildChannel {
     Text(TextChannel),
     Voice(VoiceChannel),
     Stage(VoiceChannel),
+    Thread(ThreadChannel),
 }
 
 impl GuildChannel {
@@ -96,6 +87,7 @@ impl GuildChannel {
             Self::Text(text) => text.guild_id,
             Self::Voice(voice) => voice.guild_id,
             Self::Stage(stage) => stage.guild_id,
+            Self::Thread(thread) => thread.guild_id,
         }
     }
 
@@ -106,6 +98,7 @@ impl GuildChannel {
             Self::Text(text) => text.id,
             Self::Voice(voice) => voice.id,
             Self::Stage(stage) => stage.id,
+            Self::Thread(thread) => thread.id,
         }
     }
 
@@ -116,6 +109,36 @@ impl GuildChannel {
             Self::Text(text) => text.name.as_ref(),
             Self::Voice(voice) => voice.name.as_ref(),
             Self::Stage(stage) => stage.name.as_ref(),
+            Self::Thread(thread) => thread.name.as_ref(),
+        }
+    }
+
+    /// Return an immutable reference to the permission overwrites of the inner
+    /// guild channel.
+    ///
+    /// Threads don't have permission overwrites of their own, they use the
+    /// ones of their parent channel.
+    pub fn permission_overwrites(&self) -> &[permission_overwrite::PermissionOverwrite] {
+        match self {
+            Self::Category(category) => &category.permission_overwrites,
+            Self::Text(text) => &text.permission_overwrites,
+            Self::Voice(voice) => &voice.permission_overwrites,
+            Self::Stage(stage) => &stage.permission_overwrites,
+            Self::Thread(_) => &[],
+        }
+    }
+
+    /// Return the ID of the parent of the inner guild channel.
+    ///
+    /// This is the category of a channel, or the channel a thread was created
+    /// in.
+    pub fn parent_id(&self) -> Option<ChannelId> {
+        match self {
+            Self::Category(_) => None,
+            Self::Text(text) => text.parent_id,
+            Self::Voice(voice) => voice.parent_id,
+            Self::Stage(stage) => stage.parent_id,
+            Self::Thread(thread) => thread.parent_id,
         }
     }
 }
@@ -128,12 +151,17 @@ enum GuildChannelField {
     Id,
     LastMessageId,
     LastPinTimestamp,
+    Member,
+    MemberCount,
+    MessageCount,
     Name,
     Nsfw,
+    OwnerId,
     ParentId,
     PermissionOverwrites,
     Position,
     RateLimitPerUser,
+    ThreadMetadata,
     Topic,
     Type,
     UserLimit,
@@ -154,6 +182,10 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         const VARIANTS: &[&str] = &[
             "GuildCategory",
             "GuildNews",
+            "GuildNewsThread",
+            "GuildPrivateThread",
+            "GuildPublicThread",
+            "GuildStageVoice",
             "GuildStore",
             "GuildText",
             "GuildVoice",
@@ -165,12 +197,17 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let mut kind = None;
         let mut last_message_id: Option<Option<MessageId>> = None;
         let mut last_pin_timestamp: Option<Option<String>> = None;
+        let mut member: Option<Option<ThreadMember>> = None;
+        let mut member_count = None;
+        let mut message_count = None;
         let mut name = None;
         let mut nsfw = None;
+        let mut owner_id: Option<Option<UserId>> = None;
         let mut parent_id: Option<Option<ChannelId>> = None;
         let mut permission_overwrites = None;
         let mut position = None;
         let mut rate_limit_per_user = None;
+        let mut thread_metadata = None;
         let mut topic: Option<Option<String>> = None;
         let mut user_limit = None;
         let mut video_quality_mode = None;
@@ -242,6 +279,27 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     last_pin_timestamp = Some(map.next_value()?);
                 }
+                GuildChannelField::Member => {
+                    if member.is_some() {
+                        return Err(DeError::duplicate_field("member"));
+                    }
+
+                    member = Some(map.next_value()?);
+                }
+                GuildChannelField::MemberCount => {
+                    if member_count.is_some() {
+                        return Err(DeError::duplicate_field("member_count"));
+                    }
+
+                    member_count = Some(map.next_value()?);
+                }
+                GuildChannelField::MessageCount => {
+                    if message_count.is_some() {
+                        return Err(DeError::duplicate_field("message_count"));
+                    }
+
+                    message_count = Some(map.next_value()?);
+                }
                 GuildChannelField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -256,6 +314,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     nsfw = Some(map.next_value()?);
                 }
+                GuildChannelField::OwnerId => {
+                    if owner_id.is_some() {
+                        return Err(DeError::duplicate_field("owner_id"));
+                    }
+
+                    owner_id = Some(map.next_value()?);
+                }
                 GuildChannelField::ParentId => {
                     if parent_id.is_some() {
                         return Err(DeError::duplicate_field("parent_id"));
@@ -284,6 +349,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     rate_limit_per_user = map.next_value::<Option<u64>>()?;
                 }
+                GuildChannelField::ThreadMetadata => {
+                    if thread_metadata.is_some() {
+                        return Err(DeError::duplicate_field("thread_metadata"));
+                    }
+
+                    thread_metadata = Some(map.next_value()?);
+                }
                 GuildChannelField::Topic => {
                     if topic.is_some() {
                         return Err(DeError::duplicate_field("topic"));
@@ -311,14 +383,53 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         // Now let's get all of the fields required by every guild channel
         // type.
         let id = id.ok_or_else(|| DeError::missing_field("id"))?;
-        let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
+        let kind: ChannelType = kind.ok_or_else(|| DeError::missing_field("type"))?;
         let name = name.ok_or_else(|| DeError::missing_field("name"))?;
+        let parent_id = parent_id.unwrap_or_default();
+
+        // Threads don't have permission overwrites or a position, so handle
+        // them before requiring those.
+        if kind.is_thread() {
+            let last_message_id = last_message_id.unwrap_or_default();
+            let last_pin_timestamp = last_pin_timestamp.unwrap_or_default();
+            let member = member.unwrap_or_default();
+            let member_count = member_count.unwrap_or_default();
+            let message_count = message_count.unwrap_or_default();
+            let owner_id = owner_id.unwrap_or_default();
+            let thread_metadata =
+                thread_metadata.ok_or_else(|| DeError::missing_field("thread_metadata"))?;
+
+            tracing::trace!(
+                %id,
+                ?kind,
+                %name,
+                ?parent_id,
+                ?thread_metadata,
+                "handling thread channel"
+            );
+
+            return Ok(GuildChannel::Thread(ThreadChannel {
+                guild_id,
+                id,
+                kind,
+                last_message_id,
+                last_pin_timestamp,
+                member,
+                member_count,
+                message_count,
+                name,
+                owner_id,
+                parent_id,
+                rate_limit_per_user,
+                thread_metadata,
+            }));
+        }
+
         let permission_overwrites =
             permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
         let position = position.ok_or_else(|| DeError::missing_field("position"))?;
 
         let nsfw = nsfw.unwrap_or_default();
-        let parent_id = parent_id.unwrap_or_default();
 
         tracing::trace!(
             %id,
@@ -411,10 +522,14 @@ impl<'de> Deserialize<'de> for GuildChannel {
 mod tests {
     use super::{
         CategoryChannel, Channel, ChannelType, Group, GuildChannel, PrivateChannel, TextChannel,
-        VoiceChannel,
+        ThreadChannel, VoiceChannel,
     };
     use crate::{
-        channel::permission_overwrite::PermissionOverwrite,
+        channel::{
+            permission_overwrite::PermissionOverwrite,
+            thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+        },
+        datetime::Timestamp,
         id::{ChannelId, GuildId, MessageId, UserId},
     };
 
@@ -492,6 +607,31 @@ mod tests {
         }
     }
 
+    fn guild_thread() -> ThreadChannel {
+        ThreadChannel {
+            guild_id: Some(GuildId(321)),
+            id: ChannelId(654),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: 1,
+            message_count: 2,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(987)),
+            parent_id: Some(ChannelId(456)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                archive_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                    .expect("valid timestamp"),
+                invitable: None,
+                locked: false,
+            },
+        }
+    }
+
     fn private() -> PrivateChannel {
         PrivateChannel {
             id: ChannelId(234),
@@ -521,6 +661,10 @@ mod tests {
             Channel::Guild(GuildChannel::Stage(guild_stage())).id(),
             ChannelId(789)
         );
+        assert_eq!(
+            Channel::Guild(GuildChannel::Thread(guild_thread())).id(),
+            ChannelId(654)
+        );
         assert_eq!(Channel::Private(private()).id(), ChannelId(234));
     }
 
@@ -567,6 +711,10 @@ mod tests {
             GuildChannel::Stage(guild_stage()).guild_id(),
             Some(GuildId(321))
         );
+        assert_eq!(
+            GuildChannel::Thread(guild_thread()).guild_id(),
+            Some(GuildId(321))
+        );
     }
 
     #[test]
@@ -578,6 +726,7 @@ mod tests {
         assert_eq!(GuildChannel::Text(guild_text()).id(), ChannelId(456));
         assert_eq!(GuildChannel::Voice(guild_voice()).id(), ChannelId(789));
         assert_eq!(GuildChannel::Stage(guild_stage()).id(), ChannelId(789));
+        assert_eq!(GuildChannel::Thread(guild_thread()).id(), ChannelId(654));
     }
 
     #[test]
@@ -586,6 +735,22 @@ mod tests {
         assert_eq!(GuildChannel::Text(guild_text()).name(), "text");
         assert_eq!(GuildChannel::Voice(guild_voice()).name(), "voice");
         assert_eq!(GuildChannel::Stage(guild_stage()).name(), "stage");
+        assert_eq!(GuildChannel::Thread(guild_thread()).name(), "thread");
+    }
+
+    #[test]
+    fn test_guild_channel_parent_id() {
+        assert!(GuildChannel::Category(guild_category())
+            .parent_id()
+            .is_none());
+        assert!(GuildChannel::Text(guild_text()).parent_id().is_none());
+        assert_eq!(
+            GuildChannel::Thread(guild_thread()).parent_id(),
+            Some(ChannelId(456))
+        );
+        assert!(GuildChannel::Thread(guild_thread())
+            .permission_overwrites()
+            .is_empty());
     }
 
     // The deserializer for GuildChannel should skip over fields names that
@@ -734,4 +899,49 @@ mod tests {
             .unwrap()
         );
     }
+
+    #[test]
+    fn test_guild_thread_channel_deserialization() {
+        let mut thread = guild_thread();
+        thread.kind = ChannelType::GuildPrivateThread;
+        thread.member = Some(ThreadMember {
+            flags: 0,
+            id: Some(ChannelId(654)),
+            join_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                .expect("valid timestamp"),
+            user_id: Some(UserId(987)),
+        });
+        thread.thread_metadata.invitable = Some(true);
+        thread.rate_limit_per_user = Some(0);
+
+        assert_eq!(
+            GuildChannel::Thread(thread),
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "321",
+                "id": "654",
+                "last_message_id": null,
+                "member": {
+                    "flags": 0,
+                    "id": "654",
+                    "join_timestamp": "2021-07-01T00:00:00.000000+00:00",
+                    "user_id": "987",
+                },
+                "member_count": 1,
+                "message_count": 2,
+                "name": "thread",
+                "owner_id": "987",
+                "parent_id": "456",
+                "rate_limit_per_user": 0,
+                "thread_metadata": {
+                    "archived": false,
+                    "archive_timestamp": "2021-07-01T00:00:00.000000+00:00",
+                    "auto_archive_duration": 1440,
+                    "invitable": true,
+                    "locked": false,
+                },
+                "type": 12,
+            }))
+            .unwrap()
+        );
+    }
 }
@@ -2,8 +2,11 @@ use crate::{
     guild::Permissions,
     id::{RoleId, UserId},
 };
-use serde::{de::Deserializer, ser::SerializeStruct, Deserialize, Serialize, Serializer};
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde::{
+    de::{Deserializer, Error as DeError, Unexpected},
+    ser::SerializeStruct,
+    Deserialize, Serialize, Serializer,
+};
 
 pub(crate) mod integer {
     use serde::de::{Deserializer, Error as DeError, Visitor};
@@ -59,14 +62,35 @@ struct PermissionOverwriteData {
 }
 
 /// Type of a permission overwrite target.
-#[derive(Clone, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
-#[repr(u8)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum PermissionOverwriteTargetType {
     /// Permission overwrite targets an individual member.
-    Member = 1,
+    Member,
     /// Permission overwrite targets an individual role.
-    Role = 0,
+    Role,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for PermissionOverwriteTargetType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Member,
+            0 => Self::Role,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<PermissionOverwriteTargetType> for u8 {
+    fn from(value: PermissionOverwriteTargetType) -> Self {
+        match value {
+            PermissionOverwriteTargetType::Member => 1,
+            PermissionOverwriteTargetType::Role => 0,
+            PermissionOverwriteTargetType::Unknown(unknown) => unknown,
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for PermissionOverwrite {
@@ -89,6 +113,13 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
 
                 PermissionOverwriteType::Role(id)
             }
+            // Overwrites of unknown targets can't be represented.
+            PermissionOverwriteTargetType::Unknown(unknown) => {
+                return Err(DeError::invalid_value(
+                    Unexpected::Unsigned(u64::from(unknown)),
+                    &"a member or role target type",
+                ));
+            }
         };
 
         Ok(Self {
@@ -109,11 +140,11 @@ impl Serialize for PermissionOverwrite {
         match &self.kind {
             PermissionOverwriteType::Member(id) => {
                 state.serialize_field("id", &id.0.to_string())?;
-                state.serialize_field("type", &(PermissionOverwriteTargetType::Member as u8))?;
+                state.serialize_field("type", &u8::from(PermissionOverwriteTargetType::Member))?;
             }
             PermissionOverwriteType::Role(id) => {
                 state.serialize_field("id", &id.0.to_string())?;
-                state.serialize_field("type", &(PermissionOverwriteTargetType::Role as u8))?;
+                state.serialize_field("type", &u8::from(PermissionOverwriteTargetType::Role))?;
             }
         }
 
@@ -198,5 +229,16 @@ mod tests {
     fn test_overwrite_type_name() {
         serde_test::assert_tokens(&PermissionOverwriteTargetType::Member, &[Token::U8(1)]);
         serde_test::assert_tokens(&PermissionOverwriteTargetType::Role, &[Token::U8(0)]);
+        serde_test::assert_tokens(
+            &PermissionOverwriteTargetType::Unknown(255),
+            &[Token::U8(255)],
+        );
+    }
+
+    #[test]
+    fn test_unknown_target_type() {
+        let raw = r#"{"allow":"1","deny":"2","id":"3","type":255}"#;
+
+        assert!(serde_json::from_str::<PermissionOverwrite>(raw).is_err());
     }
 }
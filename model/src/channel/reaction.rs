@@ -162,6 +162,7 @@ impl<'de> Deserialize<'de> for Reaction {
 mod tests {
     use super::super::{Reaction, ReactionType};
     use crate::{
+        datetime::Timestamp,
         guild::Member,
         id::{ChannelId, GuildId, MessageId, RoleId, UserId},
         user::User,
@@ -181,7 +182,9 @@ mod tests {
                 deaf: false,
                 guild_id: GuildId(1),
                 hoisted_role: Some(RoleId(5)),
-                joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
+                joined_at: Some(
+                    Timestamp::parse("2020-01-01T00:00:00.000000+00:00").expect("valid timestamp"),
+                ),
                 mute: false,
                 nick: Some("typing".to_owned()),
                 pending: false,
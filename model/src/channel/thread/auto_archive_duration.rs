@@ -0,0 +1,80 @@
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
+
+/// Number of minutes of inactivity after which a thread is automatically
+/// archived.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u16", into = "u16")]
+pub enum AutoArchiveDuration {
+    /// One hour.
+    Hour,
+    /// One day.
+    Day,
+    /// Three days.
+    ThreeDays,
+    /// One week.
+    Week,
+    /// Variant value is unknown to the library.
+    Unknown(u16),
+}
+
+impl From<u16> for AutoArchiveDuration {
+    fn from(value: u16) -> Self {
+        match value {
+            60 => Self::Hour,
+            1440 => Self::Day,
+            4320 => Self::ThreeDays,
+            10080 => Self::Week,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<AutoArchiveDuration> for u16 {
+    fn from(value: AutoArchiveDuration) -> Self {
+        match value {
+            AutoArchiveDuration::Hour => 60,
+            AutoArchiveDuration::Day => 1440,
+            AutoArchiveDuration::ThreeDays => 4320,
+            AutoArchiveDuration::Week => 10080,
+            AutoArchiveDuration::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(AutoArchiveDuration, u16);
+
+impl AutoArchiveDuration {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Day => "Day",
+            Self::Hour => "Hour",
+            Self::ThreeDays => "ThreeDays",
+            Self::Unknown(_) => "Unknown",
+            Self::Week => "Week",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoArchiveDuration;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoArchiveDuration::Hour, &[Token::U16(60)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Day, &[Token::U16(1440)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::ThreeDays, &[Token::U16(4320)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Week, &[Token::U16(10080)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Unknown(30), &[Token::U16(30)]);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!("Day", AutoArchiveDuration::Day.name());
+        assert_eq!("Hour", AutoArchiveDuration::Hour.name());
+        assert_eq!("ThreeDays", AutoArchiveDuration::ThreeDays.name());
+        assert_eq!("Week", AutoArchiveDuration::Week.name());
+    }
+}
@@ -0,0 +1,17 @@
+use super::ThreadMember;
+use crate::channel::Channel;
+use serde::{Deserialize, Serialize};
+
+/// List of threads, along with the current user's membership in them.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadsListing {
+    /// Whether there are more threads that can be retrieved.
+    ///
+    /// Not present when listing the active threads of a guild.
+    #[serde(default)]
+    pub has_more: bool,
+    /// Thread members of the current user for the threads they joined.
+    pub members: Vec<ThreadMember>,
+    /// Threads of the listing.
+    pub threads: Vec<Channel>,
+}
@@ -0,0 +1,64 @@
+use crate::{
+    datetime::Timestamp,
+    id::{ChannelId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Membership of a user in a thread.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMember {
+    /// Notification settings of the member, only used by clients.
+    pub flags: u64,
+    /// ID of the thread.
+    ///
+    /// Not present when the member is part of a thread object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<ChannelId>,
+    /// When the user last joined the thread.
+    pub join_timestamp: Timestamp,
+    /// ID of the user.
+    ///
+    /// Not present when the member is part of a thread object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelId, ThreadMember, Timestamp, UserId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_member() {
+        let value = ThreadMember {
+            flags: 1,
+            id: Some(ChannelId(2)),
+            join_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                .expect("valid timestamp"),
+            user_id: Some(UserId(3)),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMember",
+                    len: 4,
+                },
+                Token::Str("flags"),
+                Token::U64(1),
+                Token::Str("id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("join_timestamp"),
+                Token::Str("2021-07-01T00:00:00.000000+00:00"),
+                Token::Str("user_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("3"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
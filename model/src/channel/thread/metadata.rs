@@ -0,0 +1,59 @@
+use super::AutoArchiveDuration;
+use crate::datetime::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Thread-specific state of a thread channel.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// Duration of inactivity after which the thread is archived.
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// When the thread's archive status was last changed.
+    pub archive_timestamp: Timestamp,
+    /// Whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only present on private threads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    /// Whether only moderators can unarchive the thread.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadMetadata, Timestamp};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_metadata() {
+        let value = ThreadMetadata {
+            archived: false,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            archive_timestamp: Timestamp::parse("2021-07-01T00:00:00.000000+00:00")
+                .expect("valid timestamp"),
+            invitable: None,
+            locked: true,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archived"),
+                Token::Bool(false),
+                Token::Str("auto_archive_duration"),
+                Token::U16(1440),
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-07-01T00:00:00.000000+00:00"),
+                Token::Str("locked"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
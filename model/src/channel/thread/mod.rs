@@ -0,0 +1,9 @@
+mod auto_archive_duration;
+mod listing;
+mod member;
+mod metadata;
+
+pub use self::{
+    auto_archive_duration::AutoArchiveDuration, listing::ThreadsListing, member::ThreadMember,
+    metadata::ThreadMetadata,
+};
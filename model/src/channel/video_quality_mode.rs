@@ -1,21 +1,45 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum VideoQualityMode {
     /// Discord chooses the quality for optimal performance.
-    Auto = 1,
+    Auto,
     /// 720p.
-    Full = 2,
+    Full,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for VideoQualityMode {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Auto,
+            2 => Self::Full,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<VideoQualityMode> for u8 {
+    fn from(value: VideoQualityMode) -> Self {
+        match value {
+            VideoQualityMode::Auto => 1,
+            VideoQualityMode::Full => 2,
+            VideoQualityMode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(VideoQualityMode, u8);
+
 impl VideoQualityMode {
     pub fn name(self) -> &'static str {
         match self {
             Self::Auto => "Auto",
             Self::Full => "Full",
+            Self::Unknown(_) => "Unknown",
         }
     }
 }
@@ -29,6 +53,7 @@ mod tests {
     fn test_variants() {
         serde_test::assert_tokens(&VideoQualityMode::Auto, &[Token::U8(1)]);
         serde_test::assert_tokens(&VideoQualityMode::Full, &[Token::U8(2)]);
+        serde_test::assert_tokens(&VideoQualityMode::Unknown(255), &[Token::U8(255)]);
     }
 
     #[test]
@@ -1,14 +1,37 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum WebhookType {
-    Incoming = 1,
-    ChannelFollower = 2,
+    Incoming,
+    ChannelFollower,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for WebhookType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Incoming,
+            2 => Self::ChannelFollower,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<WebhookType> for u8 {
+    fn from(value: WebhookType) -> Self {
+        match value {
+            WebhookType::Incoming => 1,
+            WebhookType::ChannelFollower => 2,
+            WebhookType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(WebhookType, u8);
+
 impl Default for WebhookType {
     fn default() -> Self {
         Self::Incoming
@@ -29,5 +52,6 @@ mod tests {
     fn test_variants() {
         serde_test::assert_tokens(&WebhookType::Incoming, &[Token::U8(1)]);
         serde_test::assert_tokens(&WebhookType::ChannelFollower, &[Token::U8(2)]);
+        serde_test::assert_tokens(&WebhookType::Unknown(255), &[Token::U8(255)]);
     }
 }
@@ -0,0 +1,6 @@
+//! Utilities for parsing and formatting the timestamps that Discord sends and
+//! accepts.
+
+mod timestamp;
+
+pub use self::timestamp::{Timestamp, TimestampParseError, TimestampParseErrorType};
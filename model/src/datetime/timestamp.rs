@@ -0,0 +1,582 @@
+use serde::{
+    de::{Deserialize, Deserializer, Error as DeError, Visitor},
+    ser::{Serialize, Serializer},
+};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// Number of days from the Unix epoch to 0000-01-01.
+const MIN_DAYS: i64 = -719_528;
+
+/// Number of days from the Unix epoch to 9999-12-31.
+const MAX_DAYS: i64 = 2_932_896;
+
+/// Parsing a [`Timestamp`] failed.
+#[derive(Debug)]
+pub struct TimestampParseError {
+    kind: TimestampParseErrorType,
+}
+
+impl TimestampParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub fn kind(&self) -> &TimestampParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        TimestampParseErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+
+    const FORMAT: Self = Self {
+        kind: TimestampParseErrorType::Format,
+    };
+
+    const RANGE: Self = Self {
+        kind: TimestampParseErrorType::Range,
+    };
+}
+
+impl Display for TimestampParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            TimestampParseErrorType::Format => {
+                f.write_str("provided value is not in an ISO 8601 format")
+            }
+            TimestampParseErrorType::Range => {
+                f.write_str("value of a field is not in an acceptable range")
+            }
+        }
+    }
+}
+
+impl Error for TimestampParseError {}
+
+/// Type of [`TimestampParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TimestampParseErrorType {
+    /// Format of the input string is invalid.
+    Format,
+    /// Value of a field is not in an acceptable range, such as a month of 13
+    /// or a year past 9999.
+    Range,
+}
+
+/// Point in time with microsecond precision, as sent by Discord.
+///
+/// Timestamps are parsed from and serialized into the ISO 8601 format that
+/// Discord uses, such as `2021-01-01T01:01:01.010000+00:00`. Offsets other
+/// than UTC are accepted and normalized to UTC. Fractional seconds beyond
+/// microseconds are truncated.
+///
+/// Timestamps between the years 0 and 9999, inclusive, are supported.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use twilight_model::datetime::Timestamp;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let timestamp = Timestamp::from_str("2021-01-01T01:01:01.010000+00:00")?;
+///
+/// assert_eq!(1_609_462_861, timestamp.as_secs());
+/// assert_eq!(1_609_462_861_010_000, timestamp.as_micros());
+/// assert_eq!("2021-01-01T01:01:01.010000+00:00", timestamp.to_string());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    /// Create a timestamp from the number of seconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the
+    /// timestamp isn't between the years 0 and 9999.
+    pub fn from_secs(secs: i64) -> Result<Self, TimestampParseError> {
+        secs.checked_mul(MICROS_PER_SECOND)
+            .ok_or(TimestampParseError::RANGE)
+            .and_then(Self::from_micros)
+    }
+
+    /// Create a timestamp from the number of microseconds since the Unix
+    /// epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the
+    /// timestamp isn't between the years 0 and 9999.
+    pub fn from_micros(micros: i64) -> Result<Self, TimestampParseError> {
+        let days = micros.div_euclid(MICROS_PER_DAY);
+
+        if (MIN_DAYS..=MAX_DAYS).contains(&days) {
+            Ok(Self(micros))
+        } else {
+            Err(TimestampParseError::RANGE)
+        }
+    }
+
+    /// Parse a timestamp from an ISO 8601 string.
+    ///
+    /// Accepted strings are in the format `YYYY-MM-DDTHH:MM:SS`, optionally
+    /// followed by fractional seconds, and followed by either `Z` or an offset
+    /// in the format `+HH:MM`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Format`] error type if the string
+    /// isn't in the accepted format.
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the value of
+    /// a field isn't in an acceptable range.
+    pub fn parse(input: &str) -> Result<Self, TimestampParseError> {
+        let bytes = input.as_bytes();
+
+        if bytes.len() < 20
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || bytes[10] != b'T'
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err(TimestampParseError::FORMAT);
+        }
+
+        let year = digits(&bytes[0..4])?;
+        let month = digits(&bytes[5..7])?;
+        let day = digits(&bytes[8..10])?;
+        let hour = digits(&bytes[11..13])?;
+        let minute = digits(&bytes[14..16])?;
+        let second = digits(&bytes[17..19])?;
+
+        let mut rest = &bytes[19..];
+        let mut micros = 0;
+
+        if rest[0] == b'.' {
+            let len = rest[1..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+
+            if len == 0 {
+                return Err(TimestampParseError::FORMAT);
+            }
+
+            // Pad or truncate the fraction to exactly six digits.
+            for idx in 0..6 {
+                micros *= 10;
+
+                if idx < len {
+                    micros += i64::from(rest[1 + idx] - b'0');
+                }
+            }
+
+            rest = &rest[1 + len..];
+        }
+
+        let offset = match rest {
+            [b'Z'] => 0,
+            [sign, h1, h2, b':', m1, m2] if *sign == b'+' || *sign == b'-' => {
+                let hours = digits(&[*h1, *h2])?;
+                let minutes = digits(&[*m1, *m2])?;
+
+                if hours > 23 || minutes > 59 {
+                    return Err(TimestampParseError::RANGE);
+                }
+
+                let offset = (hours * 3600 + minutes * 60) * MICROS_PER_SECOND;
+
+                if *sign == b'+' {
+                    offset
+                } else {
+                    -offset
+                }
+            }
+            _ => return Err(TimestampParseError::FORMAT),
+        };
+
+        if !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return Err(TimestampParseError::RANGE);
+        }
+
+        let days = days_from_civil(year, month, day);
+        let secs = hour * 3600 + minute * 60 + second;
+
+        Self::from_micros(days * MICROS_PER_DAY + secs * MICROS_PER_SECOND + micros - offset)
+    }
+
+    /// Number of seconds since the Unix epoch, rounded down.
+    pub fn as_secs(self) -> i64 {
+        self.0.div_euclid(MICROS_PER_SECOND)
+    }
+
+    /// Number of microseconds since the Unix epoch.
+    pub fn as_micros(self) -> i64 {
+        self.0
+    }
+}
+
+impl Display for Timestamp {
+    /// Format the timestamp in the ISO 8601 format Discord uses, such as
+    /// `2021-01-01T01:01:01.010000+00:00`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (year, month, day) = civil_from_days(self.0.div_euclid(MICROS_PER_DAY));
+        let time = self.0.rem_euclid(MICROS_PER_DAY);
+        let secs = time / MICROS_PER_SECOND;
+
+        f.write_fmt(format_args!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}+00:00",
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            time % MICROS_PER_SECOND,
+        ))
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Timestamp {
+    type Error = TimestampParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimestampVisitor;
+
+        impl<'de> Visitor<'de> for TimestampVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("iso 8601 timestamp")
+            }
+
+            fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+                Timestamp::parse(value).map_err(DeError::custom)
+            }
+        }
+
+        deserializer.deserialize_str(TimestampVisitor)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        use chrono::TimeZone;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nanos = timestamp.0.rem_euclid(MICROS_PER_SECOND) as u32 * 1000;
+
+        // Timestamps are always within chrono's supported range.
+        chrono::Utc
+            .timestamp_opt(timestamp.as_secs(), nanos)
+            .single()
+            .expect("timestamp in range")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for Timestamp {
+    type Error = TimestampParseError;
+
+    fn try_from(datetime: chrono::DateTime<Tz>) -> Result<Self, Self::Error> {
+        datetime
+            .timestamp()
+            .checked_mul(MICROS_PER_SECOND)
+            .and_then(|micros| micros.checked_add(i64::from(datetime.timestamp_subsec_micros())))
+            .ok_or(TimestampParseError::RANGE)
+            .and_then(Self::from_micros)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        Self::from_unix_timestamp_nanos(i128::from(timestamp.0) * 1000)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = TimestampParseError;
+
+    fn try_from(datetime: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let micros = datetime.unix_timestamp_nanos().div_euclid(1000);
+
+        i64::try_from(micros)
+            .map_err(|_| TimestampParseError::RANGE)
+            .and_then(Self::from_micros)
+    }
+}
+
+/// Parse a slice of ASCII digits into a number.
+fn digits(bytes: &[u8]) -> Result<i64, TimestampParseError> {
+    bytes.iter().try_fold(0, |acc, byte| {
+        if byte.is_ascii_digit() {
+            Ok(acc * 10 + i64::from(byte - b'0'))
+        } else {
+            Err(TimestampParseError::FORMAT)
+        }
+    })
+}
+
+const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since the Unix epoch of a date in the proleptic Gregorian
+/// calendar.
+// Algorithm from <http://howardhinnant.github.io/date_algorithms.html>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic Gregorian calendar of a number of days since the
+/// Unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Timestamp, TimestampParseError, TimestampParseErrorType};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::TryFrom,
+        error::Error,
+        fmt::{Debug, Display},
+        hash::Hash,
+        str::FromStr,
+    };
+
+    assert_impl_all!(
+        Timestamp: Clone,
+        Copy,
+        Debug,
+        Display,
+        Eq,
+        FromStr,
+        Hash,
+        Ord,
+        Send,
+        Sync,
+        TryFrom<&'static str>
+    );
+    assert_impl_all!(TimestampParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(TimestampParseError: Error, Send, Sync);
+
+    #[test]
+    fn test_parse_and_display() -> Result<(), TimestampParseError> {
+        let timestamp = Timestamp::parse("2021-01-01T01:01:01.010000+00:00")?;
+        assert_eq!(1_609_462_861_010_000, timestamp.as_micros());
+        assert_eq!("2021-01-01T01:01:01.010000+00:00", timestamp.to_string());
+
+        let timestamp = Timestamp::parse("2015-04-26T06:26:56.936000+00:00")?;
+        assert_eq!(1_430_029_616, timestamp.as_secs());
+        assert_eq!("2015-04-26T06:26:56.936000+00:00", timestamp.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_variants() -> Result<(), TimestampParseError> {
+        let expected = Timestamp::from_secs(1_609_462_861)?;
+
+        assert_eq!(expected, Timestamp::parse("2021-01-01T01:01:01+00:00")?);
+        assert_eq!(expected, Timestamp::parse("2021-01-01T01:01:01Z")?);
+        assert_eq!(expected, Timestamp::parse("2021-01-01T01:01:01.0Z")?);
+        assert_eq!(expected, Timestamp::parse("2021-01-01T03:31:01+02:30")?);
+        assert_eq!(expected, Timestamp::parse("2020-12-31T23:01:01-02:00")?);
+        assert_eq!(
+            Timestamp::from_micros(1_609_462_861_123_456)?,
+            Timestamp::parse("2021-01-01T01:01:01.123456789+00:00")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            Timestamp::parse("").unwrap_err().kind(),
+            TimestampParseErrorType::Format
+        ));
+        assert!(matches!(
+            Timestamp::parse("2021-01-01 01:01:01+00:00")
+                .unwrap_err()
+                .kind(),
+            TimestampParseErrorType::Format
+        ));
+        assert!(matches!(
+            Timestamp::parse("2021-01-01T01:01:01.+00:00")
+                .unwrap_err()
+                .kind(),
+            TimestampParseErrorType::Format
+        ));
+        assert!(matches!(
+            Timestamp::parse("2021-01-01T01:01:01").unwrap_err().kind(),
+            TimestampParseErrorType::Format
+        ));
+        assert!(matches!(
+            Timestamp::parse("2021-13-01T01:01:01+00:00")
+                .unwrap_err()
+                .kind(),
+            TimestampParseErrorType::Range
+        ));
+        assert!(matches!(
+            Timestamp::parse("2021-02-29T01:01:01+00:00")
+                .unwrap_err()
+                .kind(),
+            TimestampParseErrorType::Range
+        ));
+        assert!(Timestamp::parse("2020-02-29T01:01:01+00:00").is_ok());
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(
+            "0000-01-01T00:00:00.000000+00:00",
+            Timestamp::parse("0000-01-01T00:00:00+00:00")
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "9999-12-31T23:59:59.999999+00:00",
+            Timestamp::parse("9999-12-31T23:59:59.999999+00:00")
+                .unwrap()
+                .to_string()
+        );
+        assert!(matches!(
+            Timestamp::parse("0000-01-01T00:00:00+00:01")
+                .unwrap_err()
+                .kind(),
+            TimestampParseErrorType::Range
+        ));
+        assert!(Timestamp::from_secs(i64::MAX).is_err());
+        assert!(Timestamp::from_micros(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_ordering() -> Result<(), TimestampParseError> {
+        let earlier = Timestamp::parse("1969-12-31T23:59:59.999999+00:00")?;
+        let later = Timestamp::parse("1970-01-01T00:00:00+00:00")?;
+
+        assert!(earlier < later);
+        assert_eq!(-1, earlier.as_micros());
+        assert_eq!(-1, earlier.as_secs());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde() -> Result<(), TimestampParseError> {
+        let value = Timestamp::from_micros(1_609_462_861_010_000)?;
+
+        serde_test::assert_tokens(&value, &[Token::Str("2021-01-01T01:01:01.010000+00:00")]);
+        serde_test::assert_de_tokens(&value, &[Token::Str("2021-01-01T01:01:01.01Z")]);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() -> Result<(), TimestampParseError> {
+        let value = Timestamp::from_micros(1_609_462_861_010_000)?;
+        let datetime = chrono::DateTime::<chrono::Utc>::from(value);
+
+        assert_eq!(1_609_462_861, datetime.timestamp());
+        assert_eq!(10_000, datetime.timestamp_subsec_micros());
+        assert_eq!(value, Timestamp::try_from(datetime)?);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() -> Result<(), TimestampParseError> {
+        let value = Timestamp::from_micros(1_609_462_861_010_000)?;
+        let datetime = time::OffsetDateTime::from(value);
+
+        assert_eq!(1_609_462_861, datetime.unix_timestamp());
+        assert_eq!(value, Timestamp::try_from(datetime)?);
+
+        Ok(())
+    }
+}
@@ -1,45 +1,49 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::convert::TryFrom;
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::{
+    convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 
 /// Gateway close event codes.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[repr(u16)]
+#[serde(into = "u16")]
 pub enum CloseCode {
     /// An unknown error occurred.
-    UnknownError = 4000,
+    UnknownError,
     /// An invalid opcode or payload for an opcode was sent.
-    UnknownOpcode = 4001,
+    UnknownOpcode,
     /// An invalid payload was sent.
-    DecodeError = 4002,
+    DecodeError,
     /// A payload was sent prior to identifying.
-    NotAuthenticated = 4003,
+    NotAuthenticated,
     /// An invalid token was sent when identifying.
-    AuthenticationFailed = 4004,
+    AuthenticationFailed,
     /// Multiple identify payloads were sent.
-    AlreadyAuthenticated = 4005,
+    AlreadyAuthenticated,
     /// An invalid sequence was sent for resuming.
-    InvalidSequence = 4007,
+    InvalidSequence,
     /// Too many payloads were sent in a certain amount of time.
-    RateLimited = 4008,
+    RateLimited,
     /// The session timed out.
-    SessionTimedOut = 4009,
+    SessionTimedOut,
     /// An invalid shard was sent when identifying.
-    InvalidShard = 4010,
+    InvalidShard,
     /// Sharding is required because there are too many guilds.
-    ShardingRequired = 4011,
+    ShardingRequired,
     /// An invalid version for the gateway was sent.
-    InvalidApiVersion = 4012,
+    InvalidApiVersion,
     /// An invalid intent was sent.
-    InvalidIntents = 4013,
+    InvalidIntents,
     /// A disallowed intent was sent, may need allowlisting.
-    DisallowedIntents = 4014,
+    DisallowedIntents,
+    /// Variant value is unknown to the library.
+    ///
+    /// Close codes that are unknown to the library are kept in this variant
+    /// when deserializing, but can't be converted from with [`TryFrom`].
+    Unknown(u16),
 }
 
 #[derive(Debug, PartialEq)]
@@ -91,6 +95,38 @@ impl TryFrom<u16> for CloseCode {
     }
 }
 
+impl<'de> Deserialize<'de> for CloseCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = u16::deserialize(deserializer)?;
+
+        Ok(Self::try_from(code).unwrap_or(Self::Unknown(code)))
+    }
+}
+
+impl From<CloseCode> for u16 {
+    fn from(value: CloseCode) -> Self {
+        match value {
+            CloseCode::UnknownError => 4000,
+            CloseCode::UnknownOpcode => 4001,
+            CloseCode::DecodeError => 4002,
+            CloseCode::NotAuthenticated => 4003,
+            CloseCode::AuthenticationFailed => 4004,
+            CloseCode::AlreadyAuthenticated => 4005,
+            CloseCode::InvalidSequence => 4007,
+            CloseCode::RateLimited => 4008,
+            CloseCode::SessionTimedOut => 4009,
+            CloseCode::InvalidShard => 4010,
+            CloseCode::ShardingRequired => 4011,
+            CloseCode::InvalidApiVersion => 4012,
+            CloseCode::InvalidIntents => 4013,
+            CloseCode::DisallowedIntents => 4014,
+            CloseCode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(CloseCode, u16);
+
 #[cfg(test)]
 mod tests {
     use super::CloseCode;
@@ -113,6 +149,7 @@ mod tests {
         serde_test::assert_tokens(&CloseCode::InvalidApiVersion, &[Token::U16(4012)]);
         serde_test::assert_tokens(&CloseCode::InvalidIntents, &[Token::U16(4013)]);
         serde_test::assert_tokens(&CloseCode::DisallowedIntents, &[Token::U16(4014)]);
+        serde_test::assert_tokens(&CloseCode::Unknown(4999), &[Token::U16(4999)]);
     }
 
     #[test]
@@ -158,6 +195,13 @@ mod tests {
             CloseCode::try_from(4014).unwrap(),
             CloseCode::DisallowedIntents
         );
-        assert!(CloseCode::try_from(5000).is_err());
+        assert_eq!(5000, CloseCode::try_from(5000).unwrap_err().code());
+        assert_eq!(5000, u16::from(CloseCode::Unknown(5000)));
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(CloseCode::UnknownError < CloseCode::Unknown(4006));
+        assert!(CloseCode::Unknown(4006) < CloseCode::InvalidSequence);
     }
 }
@@ -46,12 +46,30 @@ pub enum DispatchEvent {
     ReactionRemoveAll(ReactionRemoveAll),
     ReactionRemoveEmoji(ReactionRemoveEmoji),
     Ready(Box<Ready>),
+    RelationshipAdd(Box<RelationshipAdd>),
+    RelationshipRemove(RelationshipRemove),
     Resumed,
     RoleCreate(RoleCreate),
     RoleDelete(RoleDelete),
     RoleUpdate(RoleUpdate),
+    ThreadCreate(ThreadCreate),
+    ThreadDelete(ThreadDelete),
+    ThreadListSync(ThreadListSync),
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    ThreadUpdate(ThreadUpdate),
     TypingStart(Box<TypingStart>),
     UnavailableGuild(UnavailableGuild),
+    /// Dispatch event unknown to the library.
+    ///
+    /// This can't be serialized or deserialized, since its data is kept as
+    /// the raw JSON it was received as.
+    #[serde(skip_serializing)]
+    Unknown {
+        name: String,
+        data: String,
+    },
+    UserGuildSettingsUpdate(Box<UserGuildSettingsUpdate>),
     UserUpdate(UserUpdate),
     VoiceServerUpdate(VoiceServerUpdate),
     VoiceStateUpdate(Box<VoiceStateUpdate>),
@@ -93,12 +111,22 @@ impl DispatchEvent {
             Self::ReactionRemoveAll(_) => EventType::ReactionRemoveAll,
             Self::ReactionRemoveEmoji(_) => EventType::ReactionRemoveEmoji,
             Self::Ready(_) => EventType::Ready,
+            Self::RelationshipAdd(_) => EventType::RelationshipAdd,
+            Self::RelationshipRemove(_) => EventType::RelationshipRemove,
             Self::Resumed => EventType::Resumed,
             Self::RoleCreate(_) => EventType::RoleCreate,
             Self::RoleDelete(_) => EventType::RoleDelete,
             Self::RoleUpdate(_) => EventType::RoleUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
+            Self::Unknown { name, .. } => EventType::Unknown(name.clone()),
+            Self::UserGuildSettingsUpdate(_) => EventType::UserGuildSettingsUpdate,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -142,12 +170,22 @@ impl TryFrom<Event> for DispatchEvent {
             Event::ReactionRemoveAll(v) => Self::ReactionRemoveAll(v),
             Event::ReactionRemoveEmoji(v) => Self::ReactionRemoveEmoji(v),
             Event::Ready(v) => Self::Ready(v),
+            Event::RelationshipAdd(v) => Self::RelationshipAdd(v),
+            Event::RelationshipRemove(v) => Self::RelationshipRemove(v),
             Event::Resumed => Self::Resumed,
             Event::RoleCreate(v) => Self::RoleCreate(v),
             Event::RoleDelete(v) => Self::RoleDelete(v),
             Event::RoleUpdate(v) => Self::RoleUpdate(v),
+            Event::ThreadCreate(v) => Self::ThreadCreate(v),
+            Event::ThreadDelete(v) => Self::ThreadDelete(v),
+            Event::ThreadListSync(v) => Self::ThreadListSync(v),
+            Event::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            Event::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            Event::ThreadUpdate(v) => Self::ThreadUpdate(v),
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
+            Event::Unknown { name, data } => Self::Unknown { name, data },
+            Event::UserGuildSettingsUpdate(v) => Self::UserGuildSettingsUpdate(v),
             Event::UserUpdate(v) => Self::UserUpdate(v),
             Event::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             Event::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
@@ -161,6 +199,8 @@ impl TryFrom<Event> for DispatchEvent {
 /// Deserialize into a [`DispatchEvent`] by knowing its event name.
 ///
 /// An event name is something like `"CHANNEL_CREATE"` or `"GUILD_MEMBER_ADD"`.
+/// Events with names unknown to the library can't be deserialized, since
+/// [`DispatchEvent::Unknown`] keeps the raw payload.
 #[derive(PartialEq, Eq)]
 pub struct DispatchEventWithTypeDeserializer<'a>(&'a str);
 
@@ -275,14 +315,41 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
                 DispatchEvent::PresencesReplace
             }
             "READY" => DispatchEvent::Ready(Box::new(Ready::deserialize(deserializer)?)),
+            "RELATIONSHIP_ADD" => DispatchEvent::RelationshipAdd(Box::new(
+                RelationshipAdd::deserialize(deserializer)?,
+            )),
+            "RELATIONSHIP_REMOVE" => {
+                DispatchEvent::RelationshipRemove(RelationshipRemove::deserialize(deserializer)?)
+            }
             "RESUMED" => {
                 deserializer.deserialize_ignored_any(IgnoredAny)?;
 
                 DispatchEvent::Resumed
             }
+            "THREAD_CREATE" => {
+                DispatchEvent::ThreadCreate(ThreadCreate::deserialize(deserializer)?)
+            }
+            "THREAD_DELETE" => {
+                DispatchEvent::ThreadDelete(ThreadDelete::deserialize(deserializer)?)
+            }
+            "THREAD_LIST_SYNC" => {
+                DispatchEvent::ThreadListSync(ThreadListSync::deserialize(deserializer)?)
+            }
+            "THREAD_MEMBER_UPDATE" => {
+                DispatchEvent::ThreadMemberUpdate(ThreadMemberUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_MEMBERS_UPDATE" => {
+                DispatchEvent::ThreadMembersUpdate(ThreadMembersUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_UPDATE" => {
+                DispatchEvent::ThreadUpdate(ThreadUpdate::deserialize(deserializer)?)
+            }
             "TYPING_START" => {
                 DispatchEvent::TypingStart(Box::new(TypingStart::deserialize(deserializer)?))
             }
+            "USER_GUILD_SETTINGS_UPDATE" => DispatchEvent::UserGuildSettingsUpdate(Box::new(
+                UserGuildSettingsUpdate::deserialize(deserializer)?,
+            )),
             "USER_UPDATE" => DispatchEvent::UserUpdate(UserUpdate::deserialize(deserializer)?),
             "VOICE_SERVER_UPDATE" => {
                 DispatchEvent::VoiceServerUpdate(VoiceServerUpdate::deserialize(deserializer)?)
@@ -300,7 +367,11 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::{DispatchEvent, DispatchEventWithTypeDeserializer};
+    use super::{DispatchEvent, DispatchEventWithTypeDeserializer, ThreadMembersUpdate};
+    use crate::{
+        gateway::event::EventType,
+        id::{ChannelId, GuildId, UserId},
+    };
     use serde::de::DeserializeSeed;
     use serde_json::Deserializer;
 
@@ -317,4 +388,49 @@ mod tests {
 
         assert_eq!(event, DispatchEvent::GiftCodeUpdate);
     }
+
+    #[test]
+    fn test_unknown() {
+        let input = r#"{"count":1}"#;
+
+        let deserializer = DispatchEventWithTypeDeserializer::new("SESSIONS_REPLACE");
+        let mut json_deserializer = Deserializer::from_str(input);
+        assert!(deserializer.deserialize(&mut json_deserializer).is_err());
+
+        let event = DispatchEvent::Unknown {
+            name: "SESSIONS_REPLACE".to_owned(),
+            data: input.to_owned(),
+        };
+        assert_eq!(
+            EventType::Unknown("SESSIONS_REPLACE".to_owned()),
+            event.kind()
+        );
+        assert!(serde_json::to_string(&event).is_err());
+    }
+
+    #[test]
+    fn test_thread_members_update() {
+        let input = r#"{
+            "guild_id": "1",
+            "id": "2",
+            "member_count": 3,
+            "removed_member_ids": ["4"]
+        }"#;
+
+        let deserializer = DispatchEventWithTypeDeserializer::new("THREAD_MEMBERS_UPDATE");
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        assert_eq!(
+            DispatchEvent::ThreadMembersUpdate(ThreadMembersUpdate {
+                added_members: Vec::new(),
+                guild_id: GuildId(1),
+                id: ChannelId(2),
+                member_count: 3,
+                removed_member_ids: vec![UserId(4)],
+            }),
+            event
+        );
+        assert_eq!(EventType::ThreadMembersUpdate, event.kind());
+    }
 }
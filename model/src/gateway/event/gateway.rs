@@ -3,8 +3,7 @@ use super::{
 };
 use serde::{
     de::{
-        value::U8Deserializer, DeserializeSeed, Deserializer, Error as DeError, IgnoredAny,
-        IntoDeserializer, MapAccess, Unexpected, Visitor,
+        DeserializeSeed, Deserializer, Error as DeError, IgnoredAny, MapAccess, Unexpected, Visitor,
     },
     ser::{SerializeStruct, Serializer},
     Deserialize, Serialize,
@@ -310,16 +309,7 @@ impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
         let _span_enter = span.enter();
         tracing::trace!(event_type=?self.2, op=self.0, seq=?self.1);
 
-        let op_deser: U8Deserializer<V::Error> = self.0.into_deserializer();
-
-        let op = OpCode::deserialize(op_deser).ok().ok_or_else(|| {
-            tracing::trace!(op = self.0, "unknown opcode");
-            let unexpected = Unexpected::Unsigned(u64::from(self.0));
-
-            DeError::invalid_value(unexpected, &"an opcode")
-        })?;
-
-        Ok(match op {
+        Ok(match OpCode::from(self.0) {
             OpCode::Event => {
                 let t = self
                     .2
@@ -408,6 +398,9 @@ impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
 
                 GatewayEvent::InvalidateSession(invalidate)
             }
+            OpCode::GuildSubscribe => {
+                return Err(DeError::unknown_variant("GuildSubscribe", VALID_OPCODES))
+            }
             OpCode::Identify => return Err(DeError::unknown_variant("Identify", VALID_OPCODES)),
             OpCode::Reconnect => {
                 Self::ignore_all(&mut map)?;
@@ -430,6 +423,12 @@ impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
             OpCode::VoiceStateUpdate => {
                 return Err(DeError::unknown_variant("VoiceStateUpdate", VALID_OPCODES))
             }
+            OpCode::Unknown(_) => {
+                tracing::trace!(op = self.0, "unknown opcode");
+                let unexpected = Unexpected::Unsigned(u64::from(self.0));
+
+                return Err(DeError::invalid_value(unexpected, &"an opcode"));
+            }
         })
     }
 }
@@ -843,14 +842,11 @@ mod tests {
                     len: 4,
                 },
                 Token::Str("t"),
-                Token::UnitVariant {
-                    name: "EventType",
-                    variant: "GUILD_ROLE_DELETE",
-                },
+                Token::Str("GUILD_ROLE_DELETE"),
                 Token::Str("s"),
                 Token::U64(2_048),
                 Token::Str("op"),
-                Token::U8(OpCode::Event as u8),
+                Token::U8(OpCode::Event.into()),
                 Token::Str("d"),
                 Token::Struct {
                     name: "RoleDelete",
@@ -882,7 +878,7 @@ mod tests {
                 Token::Str("s"),
                 Token::None,
                 Token::Str("op"),
-                Token::U8(OpCode::Heartbeat as u8),
+                Token::U8(OpCode::Heartbeat.into()),
                 Token::Str("d"),
                 Token::U64(1024),
                 Token::StructEnd,
@@ -904,7 +900,7 @@ mod tests {
                 Token::Str("s"),
                 Token::None,
                 Token::Str("op"),
-                Token::U8(OpCode::HeartbeatAck as u8),
+                Token::U8(OpCode::HeartbeatAck.into()),
                 Token::Str("d"),
                 Token::None,
                 Token::StructEnd,
@@ -926,7 +922,7 @@ mod tests {
                 Token::Str("s"),
                 Token::None,
                 Token::Str("op"),
-                Token::U8(OpCode::Hello as u8),
+                Token::U8(OpCode::Hello.into()),
                 Token::Str("d"),
                 Token::Struct {
                     name: "Hello",
@@ -956,7 +952,7 @@ mod tests {
                 Token::Str("s"),
                 Token::None,
                 Token::Str("op"),
-                Token::U8(OpCode::InvalidSession as u8),
+                Token::U8(OpCode::InvalidSession.into()),
                 Token::Str("d"),
                 Token::Bool(true),
                 Token::StructEnd,
@@ -978,7 +974,7 @@ mod tests {
                 Token::Str("s"),
                 Token::None,
                 Token::Str("op"),
-                Token::U8(OpCode::Reconnect as u8),
+                Token::U8(OpCode::Reconnect.into()),
                 Token::Str("d"),
                 Token::None,
                 Token::StructEnd,
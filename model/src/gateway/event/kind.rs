@@ -1,13 +1,16 @@
-use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    convert::TryFrom,
+    fmt::{Formatter, Result as FmtResult},
+};
 
 /// The type of an event.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum EventType {
-    #[serde(rename = "GUILD_BAN_ADD")]
     BanAdd,
-    #[serde(rename = "GUILD_BAN_REMOVE")]
     BanRemove,
     ChannelCreate,
     ChannelDelete,
@@ -26,15 +29,10 @@ pub enum EventType {
     GuildUpdate,
     InviteCreate,
     InviteDelete,
-    #[serde(rename = "GUILD_MEMBER_ADD")]
     MemberAdd,
-    #[serde(rename = "GUILD_MEMBER_LIST_UPDATE")]
     MemberListUpdate,
-    #[serde(rename = "GUILD_MEMBER_REMOVE")]
     MemberRemove,
-    #[serde(rename = "GUILD_MEMBER_UPDATE")]
     MemberUpdate,
-    #[serde(rename = "GUILD_MEMBERS_CHUNK")]
     MemberChunk,
     MessageAck,
     MessageCreate,
@@ -43,21 +41,16 @@ pub enum EventType {
     MessageUpdate,
     PresenceUpdate,
     PresencesReplace,
-    #[serde(rename = "MESSAGE_REACTION_ADD")]
     ReactionAdd,
-    #[serde(rename = "MESSAGE_REACTION_REMOVE")]
     ReactionRemove,
-    #[serde(rename = "MESSAGE_REACTION_REMOVE_ALL")]
     ReactionRemoveAll,
-    #[serde(rename = "MESSAGE_REACTION_REMOVE_EMOJI")]
     ReactionRemoveEmoji,
     Ready,
+    RelationshipAdd,
+    RelationshipRemove,
     Resumed,
-    #[serde(rename = "GUILD_ROLE_CREATE")]
     RoleCreate,
-    #[serde(rename = "GUILD_ROLE_DELETE")]
     RoleDelete,
-    #[serde(rename = "GUILD_ROLE_UPDATE")]
     RoleUpdate,
     ShardConnected,
     ShardConnecting,
@@ -66,58 +59,30 @@ pub enum EventType {
     ShardReconnecting,
     ShardPayload,
     ShardResuming,
+    ThreadCreate,
+    ThreadDelete,
+    ThreadListSync,
+    ThreadMemberUpdate,
+    ThreadMembersUpdate,
+    ThreadUpdate,
     TypingStart,
     UnavailableGuild,
+    UserGuildSettingsUpdate,
     UserUpdate,
     VoiceServerUpdate,
     VoiceStateUpdate,
     WebhooksUpdate,
+    /// Variant value is unknown to the library.
+    Unknown(String),
 }
 
 impl EventType {
-    pub fn name(self) -> Option<&'static str> {
+    /// Name of the dispatch event as sent by Discord.
+    ///
+    /// Returns `None` for events that are emitted by the gateway or shard
+    /// rather than dispatched by Discord.
+    pub fn name(&self) -> Option<&str> {
         match self {
-            Self::BanAdd => Some("GUILD_BAN_ADD"),
-            Self::BanRemove => Some("GUILD_BAN_REMOVE"),
-            Self::ChannelCreate => Some("CHANNEL_CREATE"),
-            Self::ChannelDelete => Some("CHANNEL_DELETE"),
-            Self::ChannelPinsUpdate => Some("CHANNEL_PINS_UPDATE"),
-            Self::ChannelUpdate => Some("CHANNEL_UPDATE"),
-            Self::GiftCodeUpdate => Some("GIFT_CODE_UPDATE"),
-            Self::GuildCreate => Some("GUILD_CREATE"),
-            Self::GuildDelete => Some("GUILD_DELETE"),
-            Self::GuildEmojisUpdate => Some("GUILD_EMOJIS_UPDATE"),
-            Self::GuildIntegrationsUpdate => Some("GUILD_INTEGRATIONS_UPDATE"),
-            Self::GuildUpdate => Some("GUILD_UPDATE"),
-            Self::InviteCreate => Some("INVITE_CREATE"),
-            Self::InviteDelete => Some("INVITE_DELETE"),
-            Self::MemberAdd => Some("GUILD_MEMBER_ADD"),
-            Self::MemberListUpdate => Some("GUILD_MEMBER_LIST_UPDATE"),
-            Self::MemberRemove => Some("GUILD_MEMBER_REMOVE"),
-            Self::MemberUpdate => Some("GUILD_MEMBER_UPDATE"),
-            Self::MemberChunk => Some("GUILD_MEMBERS_CHUNK"),
-            Self::MessageAck => Some("MESSAGE_ACK"),
-            Self::MessageCreate => Some("MESSAGE_CREATE"),
-            Self::MessageDelete => Some("MESSAGE_DELETE"),
-            Self::MessageDeleteBulk => Some("MESSAGE_DELETE_BULK"),
-            Self::MessageUpdate => Some("MESSAGE_UPDATE"),
-            Self::PresenceUpdate => Some("PRESENCE_UPDATE"),
-            Self::PresencesReplace => Some("PRESENCES_REPLACE"),
-            Self::ReactionAdd => Some("MESSAGE_REACTION_ADD"),
-            Self::ReactionRemove => Some("MESSAGE_REACTION_REMOVE"),
-            Self::ReactionRemoveAll => Some("MESSAGE_REACTION_REMOVE_ALL"),
-            Self::ReactionRemoveEmoji => Some("MESSAGE_REACTION_REMOVE_EMOJI"),
-            Self::Ready => Some("READY"),
-            Self::Resumed => Some("RESUMED"),
-            Self::RoleCreate => Some("GUILD_ROLE_CREATE"),
-            Self::RoleDelete => Some("GUILD_ROLE_DELETE"),
-            Self::RoleUpdate => Some("GUILD_ROLE_UPDATE"),
-            Self::TypingStart => Some("TYPING_START"),
-            Self::UnavailableGuild => Some("UNAVAILABLE_GUILD"),
-            Self::UserUpdate => Some("USER_UPDATE"),
-            Self::VoiceServerUpdate => Some("VOICE_SERVER_UPDATE"),
-            Self::VoiceStateUpdate => Some("VOICE_STATE_UPDATE"),
-            Self::WebhooksUpdate => Some("WEBHOOKS_UPDATE"),
             Self::GatewayHeartbeat
             | Self::GatewayHeartbeatAck
             | Self::GatewayHello
@@ -130,6 +95,75 @@ impl EventType {
             | Self::ShardReconnecting
             | Self::ShardPayload
             | Self::ShardResuming => None,
+            _ => Some(self.serialized_name()),
+        }
+    }
+
+    fn serialized_name(&self) -> &str {
+        match self {
+            Self::BanAdd => "GUILD_BAN_ADD",
+            Self::BanRemove => "GUILD_BAN_REMOVE",
+            Self::ChannelCreate => "CHANNEL_CREATE",
+            Self::ChannelDelete => "CHANNEL_DELETE",
+            Self::ChannelPinsUpdate => "CHANNEL_PINS_UPDATE",
+            Self::ChannelUpdate => "CHANNEL_UPDATE",
+            Self::GatewayHeartbeat => "GATEWAY_HEARTBEAT",
+            Self::GatewayHeartbeatAck => "GATEWAY_HEARTBEAT_ACK",
+            Self::GatewayHello => "GATEWAY_HELLO",
+            Self::GatewayInvalidateSession => "GATEWAY_INVALIDATE_SESSION",
+            Self::GatewayReconnect => "GATEWAY_RECONNECT",
+            Self::GiftCodeUpdate => "GIFT_CODE_UPDATE",
+            Self::GuildCreate => "GUILD_CREATE",
+            Self::GuildDelete => "GUILD_DELETE",
+            Self::GuildEmojisUpdate => "GUILD_EMOJIS_UPDATE",
+            Self::GuildIntegrationsUpdate => "GUILD_INTEGRATIONS_UPDATE",
+            Self::GuildUpdate => "GUILD_UPDATE",
+            Self::InviteCreate => "INVITE_CREATE",
+            Self::InviteDelete => "INVITE_DELETE",
+            Self::MemberAdd => "GUILD_MEMBER_ADD",
+            Self::MemberListUpdate => "GUILD_MEMBER_LIST_UPDATE",
+            Self::MemberRemove => "GUILD_MEMBER_REMOVE",
+            Self::MemberUpdate => "GUILD_MEMBER_UPDATE",
+            Self::MemberChunk => "GUILD_MEMBERS_CHUNK",
+            Self::MessageAck => "MESSAGE_ACK",
+            Self::MessageCreate => "MESSAGE_CREATE",
+            Self::MessageDelete => "MESSAGE_DELETE",
+            Self::MessageDeleteBulk => "MESSAGE_DELETE_BULK",
+            Self::MessageUpdate => "MESSAGE_UPDATE",
+            Self::PresenceUpdate => "PRESENCE_UPDATE",
+            Self::PresencesReplace => "PRESENCES_REPLACE",
+            Self::ReactionAdd => "MESSAGE_REACTION_ADD",
+            Self::ReactionRemove => "MESSAGE_REACTION_REMOVE",
+            Self::ReactionRemoveAll => "MESSAGE_REACTION_REMOVE_ALL",
+            Self::ReactionRemoveEmoji => "MESSAGE_REACTION_REMOVE_EMOJI",
+            Self::Ready => "READY",
+            Self::RelationshipAdd => "RELATIONSHIP_ADD",
+            Self::RelationshipRemove => "RELATIONSHIP_REMOVE",
+            Self::Resumed => "RESUMED",
+            Self::RoleCreate => "GUILD_ROLE_CREATE",
+            Self::RoleDelete => "GUILD_ROLE_DELETE",
+            Self::RoleUpdate => "GUILD_ROLE_UPDATE",
+            Self::ShardConnected => "SHARD_CONNECTED",
+            Self::ShardConnecting => "SHARD_CONNECTING",
+            Self::ShardDisconnected => "SHARD_DISCONNECTED",
+            Self::ShardIdentifying => "SHARD_IDENTIFYING",
+            Self::ShardReconnecting => "SHARD_RECONNECTING",
+            Self::ShardPayload => "SHARD_PAYLOAD",
+            Self::ShardResuming => "SHARD_RESUMING",
+            Self::ThreadCreate => "THREAD_CREATE",
+            Self::ThreadDelete => "THREAD_DELETE",
+            Self::ThreadListSync => "THREAD_LIST_SYNC",
+            Self::ThreadMemberUpdate => "THREAD_MEMBER_UPDATE",
+            Self::ThreadMembersUpdate => "THREAD_MEMBERS_UPDATE",
+            Self::ThreadUpdate => "THREAD_UPDATE",
+            Self::TypingStart => "TYPING_START",
+            Self::UnavailableGuild => "UNAVAILABLE_GUILD",
+            Self::UserGuildSettingsUpdate => "USER_GUILD_SETTINGS_UPDATE",
+            Self::UserUpdate => "USER_UPDATE",
+            Self::VoiceServerUpdate => "VOICE_SERVER_UPDATE",
+            Self::VoiceStateUpdate => "VOICE_STATE_UPDATE",
+            Self::WebhooksUpdate => "WEBHOOKS_UPDATE",
+            Self::Unknown(unknown) => unknown,
         }
     }
 }
@@ -170,12 +204,21 @@ impl<'a> TryFrom<&'a str> for EventType {
             "MESSAGE_REACTION_REMOVE_ALL" => Ok(Self::ReactionRemoveAll),
             "MESSAGE_REACTION_REMOVE_EMOJI" => Ok(Self::ReactionRemoveEmoji),
             "READY" => Ok(Self::Ready),
+            "RELATIONSHIP_ADD" => Ok(Self::RelationshipAdd),
+            "RELATIONSHIP_REMOVE" => Ok(Self::RelationshipRemove),
             "RESUMED" => Ok(Self::Resumed),
             "GUILD_ROLE_CREATE" => Ok(Self::RoleCreate),
             "GUILD_ROLE_DELETE" => Ok(Self::RoleDelete),
             "GUILD_ROLE_UPDATE" => Ok(Self::RoleUpdate),
+            "THREAD_CREATE" => Ok(Self::ThreadCreate),
+            "THREAD_DELETE" => Ok(Self::ThreadDelete),
+            "THREAD_LIST_SYNC" => Ok(Self::ThreadListSync),
+            "THREAD_MEMBER_UPDATE" => Ok(Self::ThreadMemberUpdate),
+            "THREAD_MEMBERS_UPDATE" => Ok(Self::ThreadMembersUpdate),
+            "THREAD_UPDATE" => Ok(Self::ThreadUpdate),
             "TYPING_START" => Ok(Self::TypingStart),
             "UNAVAILABLE_GUILD" => Ok(Self::UnavailableGuild),
+            "USER_GUILD_SETTINGS_UPDATE" => Ok(Self::UserGuildSettingsUpdate),
             "USER_UPDATE" => Ok(Self::UserUpdate),
             "VOICE_SERVER_UPDATE" => Ok(Self::VoiceServerUpdate),
             "VOICE_STATE_UPDATE" => Ok(Self::VoiceStateUpdate),
@@ -185,82 +228,159 @@ impl<'a> TryFrom<&'a str> for EventType {
     }
 }
 
+struct EventTypeVisitor;
+
+impl<'de> Visitor<'de> for EventTypeVisitor {
+    type Value = EventType;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an event name")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        if let Ok(kind) = EventType::try_from(value) {
+            return Ok(kind);
+        }
+
+        Ok(match value {
+            "GATEWAY_HEARTBEAT" => EventType::GatewayHeartbeat,
+            "GATEWAY_HEARTBEAT_ACK" => EventType::GatewayHeartbeatAck,
+            "GATEWAY_HELLO" => EventType::GatewayHello,
+            "GATEWAY_INVALIDATE_SESSION" => EventType::GatewayInvalidateSession,
+            "GATEWAY_RECONNECT" => EventType::GatewayReconnect,
+            "SHARD_CONNECTED" => EventType::ShardConnected,
+            "SHARD_CONNECTING" => EventType::ShardConnecting,
+            "SHARD_DISCONNECTED" => EventType::ShardDisconnected,
+            "SHARD_IDENTIFYING" => EventType::ShardIdentifying,
+            "SHARD_RECONNECTING" => EventType::ShardReconnecting,
+            "SHARD_PAYLOAD" => EventType::ShardPayload,
+            "SHARD_RESUMING" => EventType::ShardResuming,
+            unknown => EventType::Unknown(unknown.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(EventTypeVisitor)
+    }
+}
+
+impl Serialize for EventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.serialized_name())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::EventType;
     use serde_test::Token;
+    use std::convert::TryFrom;
 
-    fn assert_variant(kind: EventType, name: &'static str) {
-        serde_test::assert_tokens(
-            &kind,
-            &[Token::UnitVariant {
-                name: "EventType",
-                variant: name,
-            }],
-        );
+    fn assert_variant(kind: &EventType, name: &'static str) {
+        serde_test::assert_tokens(kind, &[Token::Str(name)]);
     }
 
     #[test]
     fn test_variants() {
-        assert_variant(EventType::BanAdd, "GUILD_BAN_ADD");
-        assert_variant(EventType::BanRemove, "GUILD_BAN_REMOVE");
-        assert_variant(EventType::ChannelCreate, "CHANNEL_CREATE");
-        assert_variant(EventType::ChannelDelete, "CHANNEL_DELETE");
-        assert_variant(EventType::ChannelPinsUpdate, "CHANNEL_PINS_UPDATE");
-        assert_variant(EventType::ChannelUpdate, "CHANNEL_UPDATE");
-        assert_variant(EventType::GatewayHeartbeat, "GATEWAY_HEARTBEAT");
-        assert_variant(EventType::GatewayHeartbeatAck, "GATEWAY_HEARTBEAT_ACK");
-        assert_variant(EventType::GatewayHello, "GATEWAY_HELLO");
+        assert_variant(&EventType::BanAdd, "GUILD_BAN_ADD");
+        assert_variant(&EventType::BanRemove, "GUILD_BAN_REMOVE");
+        assert_variant(&EventType::ChannelCreate, "CHANNEL_CREATE");
+        assert_variant(&EventType::ChannelDelete, "CHANNEL_DELETE");
+        assert_variant(&EventType::ChannelPinsUpdate, "CHANNEL_PINS_UPDATE");
+        assert_variant(&EventType::ChannelUpdate, "CHANNEL_UPDATE");
+        assert_variant(&EventType::GatewayHeartbeat, "GATEWAY_HEARTBEAT");
+        assert_variant(&EventType::GatewayHeartbeatAck, "GATEWAY_HEARTBEAT_ACK");
+        assert_variant(&EventType::GatewayHello, "GATEWAY_HELLO");
         assert_variant(
-            EventType::GatewayInvalidateSession,
+            &EventType::GatewayInvalidateSession,
             "GATEWAY_INVALIDATE_SESSION",
         );
-        assert_variant(EventType::GatewayReconnect, "GATEWAY_RECONNECT");
-        assert_variant(EventType::GiftCodeUpdate, "GIFT_CODE_UPDATE");
-        assert_variant(EventType::GuildCreate, "GUILD_CREATE");
-        assert_variant(EventType::GuildDelete, "GUILD_DELETE");
-        assert_variant(EventType::GuildEmojisUpdate, "GUILD_EMOJIS_UPDATE");
+        assert_variant(&EventType::GatewayReconnect, "GATEWAY_RECONNECT");
+        assert_variant(&EventType::GiftCodeUpdate, "GIFT_CODE_UPDATE");
+        assert_variant(&EventType::GuildCreate, "GUILD_CREATE");
+        assert_variant(&EventType::GuildDelete, "GUILD_DELETE");
+        assert_variant(&EventType::GuildEmojisUpdate, "GUILD_EMOJIS_UPDATE");
         assert_variant(
-            EventType::GuildIntegrationsUpdate,
+            &EventType::GuildIntegrationsUpdate,
             "GUILD_INTEGRATIONS_UPDATE",
         );
-        assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
-        assert_variant(EventType::InviteCreate, "INVITE_CREATE");
-        assert_variant(EventType::InviteDelete, "INVITE_DELETE");
-        assert_variant(EventType::MemberAdd, "GUILD_MEMBER_ADD");
-        assert_variant(EventType::MemberRemove, "GUILD_MEMBER_REMOVE");
-        assert_variant(EventType::MemberUpdate, "GUILD_MEMBER_UPDATE");
-        assert_variant(EventType::MemberChunk, "GUILD_MEMBERS_CHUNK");
-        assert_variant(EventType::MessageCreate, "MESSAGE_CREATE");
-        assert_variant(EventType::MessageDelete, "MESSAGE_DELETE");
-        assert_variant(EventType::MessageDeleteBulk, "MESSAGE_DELETE_BULK");
-        assert_variant(EventType::MessageUpdate, "MESSAGE_UPDATE");
-        assert_variant(EventType::PresenceUpdate, "PRESENCE_UPDATE");
-        assert_variant(EventType::PresencesReplace, "PRESENCES_REPLACE");
-        assert_variant(EventType::ReactionAdd, "MESSAGE_REACTION_ADD");
-        assert_variant(EventType::ReactionRemove, "MESSAGE_REACTION_REMOVE");
-        assert_variant(EventType::ReactionRemoveAll, "MESSAGE_REACTION_REMOVE_ALL");
+        assert_variant(&EventType::GuildUpdate, "GUILD_UPDATE");
+        assert_variant(&EventType::InviteCreate, "INVITE_CREATE");
+        assert_variant(&EventType::InviteDelete, "INVITE_DELETE");
+        assert_variant(&EventType::MemberAdd, "GUILD_MEMBER_ADD");
+        assert_variant(&EventType::MemberRemove, "GUILD_MEMBER_REMOVE");
+        assert_variant(&EventType::MemberUpdate, "GUILD_MEMBER_UPDATE");
+        assert_variant(&EventType::MemberChunk, "GUILD_MEMBERS_CHUNK");
+        assert_variant(&EventType::MessageCreate, "MESSAGE_CREATE");
+        assert_variant(&EventType::MessageDelete, "MESSAGE_DELETE");
+        assert_variant(&EventType::MessageDeleteBulk, "MESSAGE_DELETE_BULK");
+        assert_variant(&EventType::MessageUpdate, "MESSAGE_UPDATE");
+        assert_variant(&EventType::PresenceUpdate, "PRESENCE_UPDATE");
+        assert_variant(&EventType::PresencesReplace, "PRESENCES_REPLACE");
+        assert_variant(&EventType::ReactionAdd, "MESSAGE_REACTION_ADD");
+        assert_variant(&EventType::ReactionRemove, "MESSAGE_REACTION_REMOVE");
+        assert_variant(&EventType::ReactionRemoveAll, "MESSAGE_REACTION_REMOVE_ALL");
         assert_variant(
-            EventType::ReactionRemoveEmoji,
+            &EventType::ReactionRemoveEmoji,
             "MESSAGE_REACTION_REMOVE_EMOJI",
         );
-        assert_variant(EventType::Ready, "READY");
-        assert_variant(EventType::Resumed, "RESUMED");
-        assert_variant(EventType::RoleCreate, "GUILD_ROLE_CREATE");
-        assert_variant(EventType::RoleDelete, "GUILD_ROLE_DELETE");
-        assert_variant(EventType::RoleUpdate, "GUILD_ROLE_UPDATE");
-        assert_variant(EventType::ShardConnected, "SHARD_CONNECTED");
-        assert_variant(EventType::ShardConnecting, "SHARD_CONNECTING");
-        assert_variant(EventType::ShardDisconnected, "SHARD_DISCONNECTED");
-        assert_variant(EventType::ShardIdentifying, "SHARD_IDENTIFYING");
-        assert_variant(EventType::ShardPayload, "SHARD_PAYLOAD");
-        assert_variant(EventType::ShardReconnecting, "SHARD_RECONNECTING");
-        assert_variant(EventType::ShardResuming, "SHARD_RESUMING");
-        assert_variant(EventType::TypingStart, "TYPING_START");
-        assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
-        assert_variant(EventType::UserUpdate, "USER_UPDATE");
-        assert_variant(EventType::VoiceServerUpdate, "VOICE_SERVER_UPDATE");
-        assert_variant(EventType::VoiceStateUpdate, "VOICE_STATE_UPDATE");
-        assert_variant(EventType::WebhooksUpdate, "WEBHOOKS_UPDATE");
+        assert_variant(&EventType::Ready, "READY");
+        assert_variant(&EventType::RelationshipAdd, "RELATIONSHIP_ADD");
+        assert_variant(&EventType::RelationshipRemove, "RELATIONSHIP_REMOVE");
+        assert_variant(&EventType::Resumed, "RESUMED");
+        assert_variant(&EventType::RoleCreate, "GUILD_ROLE_CREATE");
+        assert_variant(&EventType::RoleDelete, "GUILD_ROLE_DELETE");
+        assert_variant(&EventType::RoleUpdate, "GUILD_ROLE_UPDATE");
+        assert_variant(&EventType::ShardConnected, "SHARD_CONNECTED");
+        assert_variant(&EventType::ShardConnecting, "SHARD_CONNECTING");
+        assert_variant(&EventType::ShardDisconnected, "SHARD_DISCONNECTED");
+        assert_variant(&EventType::ShardIdentifying, "SHARD_IDENTIFYING");
+        assert_variant(&EventType::ShardPayload, "SHARD_PAYLOAD");
+        assert_variant(&EventType::ShardReconnecting, "SHARD_RECONNECTING");
+        assert_variant(&EventType::ShardResuming, "SHARD_RESUMING");
+        assert_variant(&EventType::ThreadCreate, "THREAD_CREATE");
+        assert_variant(&EventType::ThreadDelete, "THREAD_DELETE");
+        assert_variant(&EventType::ThreadListSync, "THREAD_LIST_SYNC");
+        assert_variant(&EventType::ThreadMemberUpdate, "THREAD_MEMBER_UPDATE");
+        assert_variant(&EventType::ThreadMembersUpdate, "THREAD_MEMBERS_UPDATE");
+        assert_variant(&EventType::ThreadUpdate, "THREAD_UPDATE");
+        assert_variant(&EventType::TypingStart, "TYPING_START");
+        assert_variant(&EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
+        assert_variant(
+            &EventType::UserGuildSettingsUpdate,
+            "USER_GUILD_SETTINGS_UPDATE",
+        );
+        assert_variant(&EventType::UserUpdate, "USER_UPDATE");
+        assert_variant(&EventType::VoiceServerUpdate, "VOICE_SERVER_UPDATE");
+        assert_variant(&EventType::VoiceStateUpdate, "VOICE_STATE_UPDATE");
+        assert_variant(&EventType::WebhooksUpdate, "WEBHOOKS_UPDATE");
+        assert_variant(
+            &EventType::Unknown("SESSIONS_REPLACE".to_owned()),
+            "SESSIONS_REPLACE",
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(Some("GUILD_BAN_ADD"), EventType::BanAdd.name());
+        assert!(EventType::GatewayHello.name().is_none());
+        assert!(EventType::ShardPayload.name().is_none());
+        assert_eq!(
+            Some("SESSIONS_REPLACE"),
+            EventType::Unknown("SESSIONS_REPLACE".to_owned()).name()
+        );
+        assert!(EventType::try_from("SESSIONS_REPLACE").is_err());
+    }
+
+    #[test]
+    fn test_unknown_long_name() {
+        let name = "A".repeat(100);
+        let kind = EventType::Unknown(name.clone());
+        let json = serde_json::to_string(&kind).unwrap();
+
+        assert_eq!(kind, serde_json::from_str::<EventType>(&json).unwrap());
+        assert_eq!(Some(name.as_str()), kind.name());
     }
 }
@@ -100,6 +100,11 @@ pub enum Event {
     ReactionRemoveEmoji(ReactionRemoveEmoji),
     /// A shard is now "ready" and fully connected.
     Ready(Box<Ready>),
+    /// A relationship with another user was added or changed, such as a new
+    /// friend request or a block.
+    RelationshipAdd(Box<RelationshipAdd>),
+    /// A relationship with another user was removed.
+    RelationshipRemove(RelationshipRemove),
     /// A shard has successfully resumed.
     Resumed,
     /// A role was created in a guild.
@@ -125,10 +130,34 @@ pub enum Event {
     ShardPayload(Payload),
     /// A shard is now in a Resuming stage after a disconnect.
     ShardResuming(Resuming),
+    /// A thread was created or the current user was added to a private thread.
+    ThreadCreate(ThreadCreate),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDelete),
+    /// The active threads of a guild or some of its channels were synced.
+    ThreadListSync(ThreadListSync),
+    /// The thread member of the current user was updated.
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    /// Users were added to or removed from a thread.
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    /// A thread was updated.
+    ThreadUpdate(ThreadUpdate),
     /// A user started typing in a channel.
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
     UnavailableGuild(UnavailableGuild),
+    /// A dispatch event unknown to the library was received.
+    Unknown {
+        /// Name of the event, such as `SESSIONS_REPLACE`.
+        name: String,
+        /// Data of the event, as the raw JSON it was received as.
+        ///
+        /// Data received in Erlang's external term format is converted to
+        /// JSON.
+        data: String,
+    },
+    /// The current user's notification settings for a guild were updated.
+    UserGuildSettingsUpdate(Box<UserGuildSettingsUpdate>),
     /// The current user was updated.
     UserUpdate(UserUpdate),
     /// A voice server update was sent.
@@ -178,6 +207,8 @@ impl Event {
             Self::ReactionRemoveAll(_) => EventType::ReactionRemoveAll,
             Self::ReactionRemoveEmoji(_) => EventType::ReactionRemoveEmoji,
             Self::Ready(_) => EventType::Ready,
+            Self::RelationshipAdd(_) => EventType::RelationshipAdd,
+            Self::RelationshipRemove(_) => EventType::RelationshipRemove,
             Self::Resumed => EventType::Resumed,
             Self::RoleCreate(_) => EventType::RoleCreate,
             Self::RoleDelete(_) => EventType::RoleDelete,
@@ -189,8 +220,16 @@ impl Event {
             Self::ShardReconnecting(_) => EventType::ShardReconnecting,
             Self::ShardPayload(_) => EventType::ShardPayload,
             Self::ShardResuming(_) => EventType::ShardResuming,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
+            Self::Unknown { name, .. } => EventType::Unknown(name.clone()),
+            Self::UserGuildSettingsUpdate(_) => EventType::UserGuildSettingsUpdate,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceServerUpdate(_) => EventType::VoiceServerUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -236,9 +275,19 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::ReactionRemoveAll(v) => Self::ReactionRemoveAll(v),
             DispatchEvent::ReactionRemoveEmoji(v) => Self::ReactionRemoveEmoji(v),
             DispatchEvent::Ready(v) => Self::Ready(v),
+            DispatchEvent::RelationshipAdd(v) => Self::RelationshipAdd(v),
+            DispatchEvent::RelationshipRemove(v) => Self::RelationshipRemove(v),
             DispatchEvent::Resumed => Self::Resumed,
+            DispatchEvent::ThreadCreate(v) => Self::ThreadCreate(v),
+            DispatchEvent::ThreadDelete(v) => Self::ThreadDelete(v),
+            DispatchEvent::ThreadListSync(v) => Self::ThreadListSync(v),
+            DispatchEvent::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            DispatchEvent::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
+            DispatchEvent::Unknown { name, data } => Self::Unknown { name, data },
+            DispatchEvent::UserGuildSettingsUpdate(v) => Self::UserGuildSettingsUpdate(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
             DispatchEvent::VoiceServerUpdate(v) => Self::VoiceServerUpdate(v),
             DispatchEvent::VoiceStateUpdate(v) => Self::VoiceStateUpdate(v),
@@ -1,37 +1,84 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
 /// Gateway opcodes.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum OpCode {
     /// An event was received.
-    Event = 0,
+    Event,
     /// Fired periodically to keep connection alive.
-    Heartbeat = 1,
+    Heartbeat,
     /// Start a new session.
-    Identify = 2,
+    Identify,
     /// Update the client's presence information.
-    StatusUpdate = 3,
+    StatusUpdate,
     /// Join, leave or move between voice channels.
-    VoiceStateUpdate = 4,
+    VoiceStateUpdate,
     /// Voice ping checking. This opcode is deprecated.
-    VoiceServerPing = 5,
+    VoiceServerPing,
     /// Resume a previously disconnected session.
-    Resume = 6,
+    Resume,
     /// Received to indicate a reconnect is required.
-    Reconnect = 7,
+    Reconnect,
     /// Request a list of members for a guild.
-    RequestGuildMembers = 8,
+    RequestGuildMembers,
     /// Received when the session is invalidated.
-    InvalidSession = 9,
+    InvalidSession,
     /// Received after connecting, contains heartbeat interval.
-    Hello = 10,
+    Hello,
     /// Received in response to a heartbeat.
-    HeartbeatAck = 11,
+    HeartbeatAck,
+    /// Subscribe to a guild's typing, activities, threads and member list.
+    GuildSubscribe,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for OpCode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Event,
+            1 => Self::Heartbeat,
+            2 => Self::Identify,
+            3 => Self::StatusUpdate,
+            4 => Self::VoiceStateUpdate,
+            5 => Self::VoiceServerPing,
+            6 => Self::Resume,
+            7 => Self::Reconnect,
+            8 => Self::RequestGuildMembers,
+            9 => Self::InvalidSession,
+            10 => Self::Hello,
+            11 => Self::HeartbeatAck,
+            14 => Self::GuildSubscribe,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<OpCode> for u8 {
+    fn from(value: OpCode) -> Self {
+        match value {
+            OpCode::Event => 0,
+            OpCode::Heartbeat => 1,
+            OpCode::Identify => 2,
+            OpCode::StatusUpdate => 3,
+            OpCode::VoiceStateUpdate => 4,
+            OpCode::VoiceServerPing => 5,
+            OpCode::Resume => 6,
+            OpCode::Reconnect => 7,
+            OpCode::RequestGuildMembers => 8,
+            OpCode::InvalidSession => 9,
+            OpCode::Hello => 10,
+            OpCode::HeartbeatAck => 11,
+            OpCode::GuildSubscribe => 14,
+            OpCode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(OpCode, u8);
+
 #[cfg(test)]
 mod tests {
     use super::OpCode;
@@ -51,5 +98,7 @@ mod tests {
         serde_test::assert_tokens(&OpCode::InvalidSession, &[Token::U8(9)]);
         serde_test::assert_tokens(&OpCode::Hello, &[Token::U8(10)]);
         serde_test::assert_tokens(&OpCode::HeartbeatAck, &[Token::U8(11)]);
+        serde_test::assert_tokens(&OpCode::GuildSubscribe, &[Token::U8(14)]);
+        serde_test::assert_tokens(&OpCode::Unknown(255), &[Token::U8(255)]);
     }
 }
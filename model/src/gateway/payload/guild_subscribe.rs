@@ -0,0 +1,259 @@
+use crate::{
+    gateway::opcode::OpCode,
+    id::{ChannelId, GuildId, UserId},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Subscribe to a guild's lazy-loaded events and member list ranges.
+///
+/// Discord only sends `GUILD_MEMBER_LIST_UPDATE`, typing, activity, and
+/// thread events for guilds that a user session has subscribed to.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuildSubscribe {
+    pub d: GuildSubscribeInfo,
+    pub op: OpCode,
+}
+
+impl GuildSubscribe {
+    pub fn new(info: GuildSubscribeInfo) -> Self {
+        Self {
+            d: info,
+            op: OpCode::GuildSubscribe,
+        }
+    }
+
+    /// Create a new builder to configure a guild subscription.
+    ///
+    /// This is an alias to [`GuildSubscribeBuilder::new`]. Refer to its
+    /// documentation for more information.
+    pub fn builder(guild_id: GuildId) -> GuildSubscribeBuilder {
+        GuildSubscribeBuilder::new(guild_id)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuildSubscribeInfo {
+    pub guild_id: GuildId,
+    /// Whether to receive activity events for the guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activities: Option<bool>,
+    /// Member list ranges to subscribe to, keyed by the channel whose member
+    /// list is requested.
+    ///
+    /// Each range is an inclusive pair of list indices, such as `(0, 99)`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub channels: HashMap<ChannelId, Vec<(u32, u32)>>,
+    /// Members whose presences and updates to receive.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<UserId>,
+    /// Whether to receive thread events for the guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threads: Option<bool>,
+    /// Whether to receive typing events for the guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typing: Option<bool>,
+}
+
+impl GuildSubscribeInfo {
+    /// Create an empty subscription for a guild.
+    pub fn new(guild_id: GuildId) -> Self {
+        Self {
+            guild_id,
+            activities: None,
+            channels: HashMap::new(),
+            members: Vec::new(),
+            threads: None,
+            typing: None,
+        }
+    }
+
+    /// Merge a newer subscription for the same guild into this one.
+    ///
+    /// Toggles that are set in `other` replace the current ones, ranges
+    /// replace the ranges of the same channel, and members are added if
+    /// they aren't already subscribed to.
+    pub fn merge(&mut self, other: GuildSubscribeInfo) {
+        if other.activities.is_some() {
+            self.activities = other.activities;
+        }
+
+        if other.threads.is_some() {
+            self.threads = other.threads;
+        }
+
+        if other.typing.is_some() {
+            self.typing = other.typing;
+        }
+
+        self.channels.extend(other.channels);
+
+        for member in other.members {
+            if !self.members.contains(&member) {
+                self.members.push(member);
+            }
+        }
+    }
+}
+
+/// Builder to configure and construct a [`GuildSubscribe`].
+#[derive(Debug)]
+pub struct GuildSubscribeBuilder(GuildSubscribeInfo);
+
+impl GuildSubscribeBuilder {
+    /// Create a new builder to configure and construct a [`GuildSubscribe`].
+    pub fn new(guild_id: GuildId) -> Self {
+        Self(GuildSubscribeInfo::new(guild_id))
+    }
+
+    /// Consume the builder, creating a guild subscription command.
+    ///
+    /// # Examples
+    ///
+    /// Subscribe to typing events and the first 100 members of a channel's
+    /// member list:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     gateway::payload::GuildSubscribe,
+    ///     id::{ChannelId, GuildId},
+    /// };
+    ///
+    /// let subscribe = GuildSubscribe::builder(GuildId(1))
+    ///     .typing(true)
+    ///     .channel(ChannelId(2), vec![(0, 99)])
+    ///     .build();
+    ///
+    /// assert_eq!(Some(true), subscribe.d.typing);
+    /// assert_eq!(Some(&vec![(0, 99)]), subscribe.d.channels.get(&ChannelId(2)));
+    /// ```
+    pub fn build(self) -> GuildSubscribe {
+        GuildSubscribe::new(self.0)
+    }
+
+    /// Set whether to receive activity events.
+    ///
+    /// By default, this uses Discord's default.
+    pub fn activities(mut self, activities: bool) -> Self {
+        self.0.activities.replace(activities);
+
+        self
+    }
+
+    /// Set the member list ranges to subscribe to for a channel.
+    ///
+    /// Calling this again for the same channel replaces its ranges.
+    pub fn channel(mut self, channel_id: ChannelId, ranges: Vec<(u32, u32)>) -> Self {
+        self.0.channels.insert(channel_id, ranges);
+
+        self
+    }
+
+    /// Set the members to receive updates for.
+    pub fn members(mut self, members: Vec<UserId>) -> Self {
+        self.0.members = members;
+
+        self
+    }
+
+    /// Set whether to receive thread events.
+    ///
+    /// By default, this uses Discord's default.
+    pub fn threads(mut self, threads: bool) -> Self {
+        self.0.threads.replace(threads);
+
+        self
+    }
+
+    /// Set whether to receive typing events.
+    ///
+    /// By default, this uses Discord's default.
+    pub fn typing(mut self, typing: bool) -> Self {
+        self.0.typing.replace(typing);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildSubscribe, GuildSubscribeInfo};
+    use crate::{
+        gateway::OpCode,
+        id::{ChannelId, GuildId, UserId},
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_subscribe() {
+        let value = GuildSubscribe::builder(GuildId(1))
+            .typing(true)
+            .channel(ChannelId(2), vec![(0, 99)])
+            .build();
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildSubscribe",
+                    len: 2,
+                },
+                Token::Str("d"),
+                Token::Struct {
+                    name: "GuildSubscribeInfo",
+                    len: 3,
+                },
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("channels"),
+                Token::Map { len: Some(1) },
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Seq { len: Some(1) },
+                Token::Tuple { len: 2 },
+                Token::U32(0),
+                Token::U32(99),
+                Token::TupleEnd,
+                Token::SeqEnd,
+                Token::MapEnd,
+                Token::Str("typing"),
+                Token::Some,
+                Token::Bool(true),
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::U8(u8::from(OpCode::GuildSubscribe)),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut info = GuildSubscribe::builder(GuildId(1))
+            .typing(true)
+            .channel(ChannelId(2), vec![(0, 99)])
+            .members(vec![UserId(3)])
+            .build()
+            .d;
+
+        let mut other = GuildSubscribeInfo::new(GuildId(1));
+        other.activities = Some(false);
+        other
+            .channels
+            .insert(ChannelId(2), vec![(0, 99), (100, 199)]);
+        other.channels.insert(ChannelId(4), vec![(0, 99)]);
+        other.members = vec![UserId(3), UserId(5)];
+        info.merge(other);
+
+        assert_eq!(Some(false), info.activities);
+        assert_eq!(Some(true), info.typing);
+        assert_eq!(None, info.threads);
+        assert_eq!(2, info.channels.len());
+        assert_eq!(
+            Some(&vec![(0, 99), (100, 199)]),
+            info.channels.get(&ChannelId(2))
+        );
+        assert_eq!(vec![UserId(3), UserId(5)], info.members);
+    }
+}
@@ -48,16 +48,24 @@ impl IdentifyInfo {
     }
 }
 
+/// State of the client's local cache, sent when identifying.
+///
+/// Discord uses this to only send the parts of the `READY` payload that have
+/// changed since the state was last received.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct IdentifyClientState {
+    /// Hashes of guilds' metadata, channels and roles, keyed by guild ID.
     pub guild_hashes: HashMap<String, String>,
+    /// Highest ID of the last message in any channel.
     pub highest_last_message_id: MessageId,
+    /// Version of the read states.
     pub read_state_version: u64,
+    /// Version of the user guild settings, or `-1` if there are none.
     pub user_guild_settings_version: i64,
 }
 
-impl IdentifyClientState {
-    pub fn default() -> Self {
+impl Default for IdentifyClientState {
+    fn default() -> Self {
         Self {
             guild_hashes: HashMap::new(),
             highest_last_message_id: MessageId(0),
@@ -67,23 +75,25 @@ impl IdentifyClientState {
     }
 }
 
+/// Properties of the client that are sent when identifying.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct IdentifyProperties {
-    os: String,
-    browser: String,
-    release_channel: String,
-    client_version: String,
-    os_version: String,
-    os_arch: String,
-    system_locale: String,
-    client_build_number: u64,
-    client_event_source: Option<String>,
+    pub os: String,
+    pub browser: String,
+    pub release_channel: String,
+    pub client_version: String,
+    pub os_version: String,
+    pub os_arch: String,
+    pub system_locale: String,
+    pub client_build_number: u64,
+    pub client_event_source: Option<String>,
 }
 
 impl IdentifyProperties {
+    /// Properties of the stable desktop client on 64-bit Windows 10.
     pub fn default_windows_desktop() -> Self {
         Self {
-            os: "Mac OS X".into(),
+            os: "Windows".into(),
             browser: "Discord Client".into(),
             release_channel: "stable".into(),
             client_version: "1.0.9001".into(),
@@ -95,3 +105,50 @@ impl IdentifyProperties {
         }
     }
 }
+
+impl Default for IdentifyProperties {
+    fn default() -> Self {
+        Self::default_windows_desktop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IdentifyClientState, IdentifyInfo, IdentifyProperties};
+    use crate::id::MessageId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_client_state_default() {
+        serde_test::assert_tokens(
+            &IdentifyClientState::default(),
+            &[
+                Token::Struct {
+                    name: "IdentifyClientState",
+                    len: 4,
+                },
+                Token::Str("guild_hashes"),
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+                Token::Str("highest_last_message_id"),
+                Token::NewtypeStruct { name: "MessageId" },
+                Token::Str("0"),
+                Token::Str("read_state_version"),
+                Token::U64(0),
+                Token::Str("user_guild_settings_version"),
+                Token::I64(-1),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_identify_info_new() {
+        let info = IdentifyInfo::new("token", IdentifyProperties::default());
+
+        assert_eq!("Windows", info.properties.os);
+        assert_eq!(61, info.capabilities);
+        assert!(!info.compress);
+        assert_eq!(MessageId(0), info.client_state.highest_last_message_id);
+    }
+}
@@ -193,6 +193,7 @@ impl<'de> Deserialize<'de> for MemberChunk {
 mod tests {
     use super::super::MemberChunk;
     use crate::{
+        datetime::Timestamp,
         gateway::presence::{ClientStatus, Presence, Status, UserOrId},
         guild::Member,
         id::{GuildId, RoleId, UserId},
@@ -308,7 +309,10 @@ mod tests {
                     deaf: false,
                     guild_id: GuildId(1),
                     hoisted_role: Some(RoleId(6)),
-                    joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
+                    joined_at: Some(
+                        Timestamp::parse("2020-04-04T04:04:04.000000+00:00")
+                            .expect("valid timestamp"),
+                    ),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     pending: false,
@@ -334,7 +338,10 @@ mod tests {
                     deaf: false,
                     guild_id: GuildId(1),
                     hoisted_role: Some(RoleId(6)),
-                    joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
+                    joined_at: Some(
+                        Timestamp::parse("2020-04-04T04:04:04.000000+00:00")
+                            .expect("valid timestamp"),
+                    ),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     pending: false,
@@ -360,7 +367,10 @@ mod tests {
                     deaf: false,
                     guild_id: GuildId(1),
                     hoisted_role: Some(RoleId(6)),
-                    joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
+                    joined_at: Some(
+                        Timestamp::parse("2020-04-04T04:04:04.000000+00:00")
+                            .expect("valid timestamp"),
+                    ),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     pending: true,
@@ -386,7 +396,10 @@ mod tests {
                     deaf: false,
                     guild_id: GuildId(1),
                     hoisted_role: Some(RoleId(6)),
-                    joined_at: Some("2020-04-04T04:04:04.000000+00:00".to_owned()),
+                    joined_at: Some(
+                        Timestamp::parse("2020-04-04T04:04:04.000000+00:00")
+                            .expect("valid timestamp"),
+                    ),
                     mute: false,
                     nick: Some("chunk".to_owned()),
                     pending: false,
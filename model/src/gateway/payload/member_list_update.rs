@@ -1,5 +1,6 @@
 use crate::{
     channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    datetime::Timestamp,
     gateway::presence::Presence,
     guild::Permissions,
     id::{GuildId, RoleId, UserId},
@@ -55,7 +56,7 @@ pub struct MemberListMember {
     pub roles: Vec<RoleId>,
     pub presence: Presence,
     pub mute: bool,
-    pub joined_at: String,
+    pub joined_at: Timestamp,
     pub hoisted_role: Option<RoleId>,
     pub deaf: bool,
 }
@@ -1,4 +1,5 @@
 use crate::{
+    datetime::Timestamp,
     id::{GuildId, RoleId},
     user::User,
 };
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 pub struct MemberUpdate {
     pub guild_id: GuildId,
     pub deaf: Option<bool>,
-    pub joined_at: String,
+    pub joined_at: Timestamp,
     pub mute: Option<bool>,
     pub nick: Option<String>,
     /// Whether the user has yet to pass the guild's [Membership Screening]
@@ -30,7 +31,7 @@ pub struct MemberUpdate {
 #[cfg(test)]
 mod tests {
     use super::MemberUpdate;
-    use crate::user::User;
+    use crate::{datetime::Timestamp, user::User};
     use serde_test::Token;
 
     #[test]
@@ -55,7 +56,8 @@ mod tests {
             premium_since: None,
             pending: false,
             nick: Some("Twilight".to_string()),
-            joined_at: "2017-02-27T22:21:50.121000+00:00".to_string(),
+            joined_at: Timestamp::parse("2017-02-27T22:21:50.121000+00:00")
+                .expect("valid timestamp"),
             guild_id: 1_234.into(),
             deaf: Some(false),
             mute: Some(false),
@@ -1,3 +1,4 @@
+pub mod guild_subscribe;
 pub mod identify;
 pub mod reaction_remove_emoji;
 pub mod request_guild_members;
@@ -33,12 +34,21 @@ mod reaction_add;
 mod reaction_remove;
 mod reaction_remove_all;
 mod ready;
+mod relationship_add;
+mod relationship_remove;
 mod role_create;
 mod role_delete;
 mod role_update;
+mod thread_create;
+mod thread_delete;
+mod thread_list_sync;
+mod thread_member_update;
+mod thread_members_update;
+mod thread_update;
 mod typing_start;
 mod unavailable_guild;
 mod update_voice_state;
+mod user_guild_settings_update;
 mod user_update;
 mod voice_server_update;
 mod voice_state_update;
@@ -55,6 +65,7 @@ pub use self::{
     guild_delete::GuildDelete,
     guild_emojis_update::GuildEmojisUpdate,
     guild_integrations_update::GuildIntegrationsUpdate,
+    guild_subscribe::GuildSubscribe,
     guild_update::GuildUpdate,
     heartbeat::Heartbeat,
     invite_create::InviteCreate,
@@ -63,7 +74,7 @@ pub use self::{
     member_chunk::MemberChunk,
     member_list_update::{
         GroupId, MemberListGroup, MemberListId, MemberListItem, MemberListMember, MemberListUpdate,
-        MemberListUpdateOp, PermissionsHash,
+        MemberListUpdateOp, PartialUser, PermissionsHash,
     },
     member_remove::MemberRemove,
     member_update::MemberUpdate,
@@ -77,15 +88,24 @@ pub use self::{
     reaction_remove::ReactionRemove,
     reaction_remove_all::ReactionRemoveAll,
     reaction_remove_emoji::ReactionRemoveEmoji,
-    ready::{ReadState, ReadStateWrapper, Ready},
+    ready::{PartialRelationship, ReadState, ReadStateWrapper, Ready, UserGuildSettingsWrapper},
+    relationship_add::RelationshipAdd,
+    relationship_remove::RelationshipRemove,
     request_guild_members::RequestGuildMembers,
     role_create::RoleCreate,
     role_delete::RoleDelete,
     role_update::RoleUpdate,
+    thread_create::ThreadCreate,
+    thread_delete::ThreadDelete,
+    thread_list_sync::ThreadListSync,
+    thread_member_update::ThreadMemberUpdate,
+    thread_members_update::ThreadMembersUpdate,
+    thread_update::ThreadUpdate,
     typing_start::TypingStart,
     unavailable_guild::UnavailableGuild,
     update_status::UpdateStatus,
     update_voice_state::UpdateVoiceState,
+    user_guild_settings_update::UserGuildSettingsUpdate,
     user_update::UserUpdate,
     voice_server_update::VoiceServerUpdate,
     voice_state_update::VoiceStateUpdate,
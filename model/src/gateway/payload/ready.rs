@@ -1,7 +1,7 @@
 use crate::channel::ChannelType;
 use crate::guild::PartialMember;
 use crate::id::UserId;
-use crate::user::User;
+use crate::user::{RelationshipType, User, UserGuildSettings};
 use crate::{
     guild::GuildStatus,
     id::{ChannelId, MessageId},
@@ -24,6 +24,15 @@ pub struct ReadStateWrapper {
     pub entries: Vec<ReadState>,
 }
 
+/// Notification settings of the current user for all guilds and private
+/// channels.
+#[derive(Clone, Default, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct UserGuildSettingsWrapper {
+    pub version: u64,
+    pub partial: bool,
+    pub entries: Vec<UserGuildSettings>,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct PartialPrivateChannel {
     pub id: ChannelId,
@@ -36,28 +45,47 @@ pub struct PartialPrivateChannel {
     pub recipient_ids: Vec<UserId>,
 }
 
+/// Relationship sent in the `READY` payload.
+///
+/// The other user is included in [`Ready::users`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PartialRelationship {
+    /// ID of the other user.
+    pub id: UserId,
+    #[serde(rename = "type")]
+    pub kind: RelationshipType,
+    /// Nickname the current user has given the other user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    pub user_id: UserId,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Ready {
     pub users: Vec<User>,
     pub guilds: Vec<GuildStatus>,
     pub private_channels: Vec<PartialPrivateChannel>,
     pub merged_members: Vec<Vec<PartialMember>>,
+    #[serde(default)]
+    pub relationships: Vec<PartialRelationship>,
     pub session_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shard: Option<[u64; 2]>,
     pub user: CurrentUser,
     pub read_state: ReadStateWrapper,
+    #[serde(default)]
+    pub user_guild_settings: UserGuildSettingsWrapper,
     #[serde(rename = "v")]
     pub version: u64,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ReadStateWrapper, Ready};
+    use super::{PartialRelationship, ReadStateWrapper, Ready, UserGuildSettingsWrapper};
     use crate::{
         guild::{GuildStatus, UnavailableGuild},
         id::{GuildId, UserId},
-        user::CurrentUser,
+        user::{CurrentUser, RelationshipType},
     };
     use serde_test::Token;
 
@@ -80,6 +108,12 @@ mod tests {
             guilds,
             private_channels: vec![],
             merged_members: vec![],
+            relationships: vec![PartialRelationship {
+                id: UserId(5),
+                kind: RelationshipType::Friend,
+                nickname: None,
+                user_id: UserId(5),
+            }],
             session_id: "foo".to_owned(),
             shard: Some([4, 7]),
             user: CurrentUser {
@@ -101,6 +135,11 @@ mod tests {
                 partial: false,
                 entries: vec![],
             },
+            user_guild_settings: UserGuildSettingsWrapper {
+                version: 2,
+                partial: false,
+                entries: vec![],
+            },
             version: 8,
         };
 
@@ -109,7 +148,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Ready",
-                    len: 9,
+                    len: 11,
                 },
                 Token::Str("users"),
                 Token::Seq { len: Some(0) },
@@ -143,6 +182,22 @@ mod tests {
                 Token::Str("merged_members"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
+                Token::Str("relationships"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "PartialRelationship",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("5"),
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("5"),
+                Token::StructEnd,
+                Token::SeqEnd,
                 Token::Str("session_id"),
                 Token::Str("foo"),
                 Token::Str("shard"),
@@ -183,6 +238,19 @@ mod tests {
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
                 Token::StructEnd,
+                Token::Str("user_guild_settings"),
+                Token::Struct {
+                    name: "UserGuildSettingsWrapper",
+                    len: 3,
+                },
+                Token::Str("version"),
+                Token::U64(2),
+                Token::Str("partial"),
+                Token::Bool(false),
+                Token::Str("entries"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
                 Token::Str("v"),
                 Token::U64(8),
                 Token::StructEnd,
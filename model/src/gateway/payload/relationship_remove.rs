@@ -0,0 +1,42 @@
+use crate::{id::UserId, user::RelationshipType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RelationshipRemove {
+    /// ID of the other user.
+    pub id: UserId,
+    /// Type of the relationship that was removed.
+    #[serde(rename = "type")]
+    pub kind: RelationshipType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RelationshipRemove, RelationshipType};
+    use crate::id::UserId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_relationship_remove() {
+        let value = RelationshipRemove {
+            id: UserId(1),
+            kind: RelationshipType::Blocked,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RelationshipRemove",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
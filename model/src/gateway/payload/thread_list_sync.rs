@@ -0,0 +1,23 @@
+use crate::{
+    channel::{thread::ThreadMember, Channel},
+    id::{ChannelId, GuildId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Active threads of a guild or some of its channels, sent when the current
+/// user gains access to them.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadListSync {
+    /// IDs of the parent channels whose threads are being synced.
+    ///
+    /// Empty when the threads of the entire guild are synced.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    pub guild_id: GuildId,
+    /// Thread members of the current user for the synced threads they
+    /// joined.
+    #[serde(default)]
+    pub members: Vec<ThreadMember>,
+    /// All active threads in the synced channels.
+    pub threads: Vec<Channel>,
+}
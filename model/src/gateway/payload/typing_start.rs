@@ -150,6 +150,7 @@ impl<'de> Deserialize<'de> for TypingStart {
 mod tests {
     use super::super::TypingStart;
     use crate::{
+        datetime::Timestamp,
         guild::Member,
         id::{ChannelId, GuildId, RoleId, UserId},
         user::User,
@@ -166,7 +167,9 @@ mod tests {
                 deaf: false,
                 guild_id: GuildId(1),
                 hoisted_role: Some(RoleId(4)),
-                joined_at: Some("2020-01-01T00:00:00.000000+00:00".to_owned()),
+                joined_at: Some(
+                    Timestamp::parse("2020-01-01T00:00:00.000000+00:00").expect("valid timestamp"),
+                ),
                 mute: false,
                 nick: Some("typing".to_owned()),
                 pending: false,
@@ -8,6 +8,7 @@ pub struct VoiceStateUpdate(pub VoiceState);
 mod tests {
     use super::{VoiceState, VoiceStateUpdate};
     use crate::{
+        datetime::Timestamp,
         guild::Member,
         id::{GuildId, RoleId, UserId},
         user::User,
@@ -155,7 +156,9 @@ mod tests {
                 deaf: false,
                 guild_id: GuildId(999_999),
                 hoisted_role: Some(RoleId(123)),
-                joined_at: Some("2016-12-08T18:41:21.954000+00:00".to_string()),
+                joined_at: Some(
+                    Timestamp::parse("2016-12-08T18:41:21.954000+00:00").expect("valid timestamp"),
+                ),
                 mute: false,
                 nick: Some("Twilight".to_string()),
                 pending: false,
@@ -1,18 +1,49 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum ActivityType {
-    Playing = 0,
-    Streaming = 1,
-    Listening = 2,
-    Watching = 3,
-    Custom = 4,
-    Competing = 5,
+    Playing,
+    Streaming,
+    Listening,
+    Watching,
+    Custom,
+    Competing,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for ActivityType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Playing,
+            1 => Self::Streaming,
+            2 => Self::Listening,
+            3 => Self::Watching,
+            4 => Self::Custom,
+            5 => Self::Competing,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ActivityType> for u8 {
+    fn from(value: ActivityType) -> Self {
+        match value {
+            ActivityType::Playing => 0,
+            ActivityType::Streaming => 1,
+            ActivityType::Listening => 2,
+            ActivityType::Watching => 3,
+            ActivityType::Custom => 4,
+            ActivityType::Competing => 5,
+            ActivityType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(ActivityType, u8);
+
 impl Default for ActivityType {
     fn default() -> Self {
         Self::Playing
@@ -37,5 +68,6 @@ mod tests {
         serde_test::assert_tokens(&ActivityType::Watching, &[Token::U8(3)]);
         serde_test::assert_tokens(&ActivityType::Custom, &[Token::U8(4)]);
         serde_test::assert_tokens(&ActivityType::Competing, &[Token::U8(5)]);
+        serde_test::assert_tokens(&ActivityType::Unknown(255), &[Token::U8(255)]);
     }
 }
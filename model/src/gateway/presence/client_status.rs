@@ -33,19 +33,13 @@ mod tests {
                 },
                 Token::Str("desktop"),
                 Token::Some,
-                Token::Enum { name: "Status" },
                 Token::Str("idle"),
-                Token::Unit,
                 Token::Str("mobile"),
                 Token::Some,
-                Token::Enum { name: "Status" },
                 Token::Str("online"),
-                Token::Unit,
                 Token::Str("web"),
                 Token::Some,
-                Token::Enum { name: "Status" },
                 Token::Str("dnd"),
-                Token::Unit,
                 Token::StructEnd,
             ],
         );
@@ -207,9 +207,7 @@ mod tests {
                 Token::NewtypeStruct { name: "GuildId" },
                 Token::Str("2"),
                 Token::Str("status"),
-                Token::Enum { name: "Status" },
                 Token::Str("online"),
-                Token::Unit,
                 Token::Str("client_status"),
                 Token::Struct {
                     name: "ClientStatus",
@@ -217,9 +215,7 @@ mod tests {
                 },
                 Token::Str("desktop"),
                 Token::Some,
-                Token::Enum { name: "Status" },
                 Token::Str("online"),
-                Token::Unit,
                 Token::Str("mobile"),
                 Token::None,
                 Token::Str("web"),
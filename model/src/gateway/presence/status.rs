@@ -1,17 +1,80 @@
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    cmp::Ordering,
+    fmt::{Formatter, Result as FmtResult},
+};
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Status {
-    #[serde(rename = "dnd")]
     DoNotDisturb,
-    #[serde(rename = "idle")]
     Idle,
-    #[serde(rename = "invisible")]
     Invisible,
-    #[serde(rename = "offline")]
     Offline,
-    #[serde(rename = "online")]
     Online,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl Status {
+    /// Name of the status as sent by Discord.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::DoNotDisturb => "dnd",
+            Self::Idle => "idle",
+            Self::Invisible => "invisible",
+            Self::Offline => "offline",
+            Self::Online => "online",
+            Self::Unknown(unknown) => unknown.as_str(),
+        }
+    }
+}
+
+impl Ord for Status {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
+impl PartialOrd for Status {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct StatusVisitor;
+
+impl<'de> Visitor<'de> for StatusVisitor {
+    type Value = Status;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a status name")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(match value {
+            "dnd" => Status::DoNotDisturb,
+            "idle" => Status::Idle,
+            "invisible" => Status::Invisible,
+            "offline" => Status::Offline,
+            "online" => Status::Online,
+            unknown => Status::Unknown(unknown.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StatusVisitor)
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
 }
 
 #[cfg(test)]
@@ -21,40 +84,28 @@ mod tests {
 
     #[test]
     fn test_variants() {
+        serde_test::assert_tokens(&Status::DoNotDisturb, &[Token::Str("dnd")]);
+        serde_test::assert_tokens(&Status::Idle, &[Token::Str("idle")]);
+        serde_test::assert_tokens(&Status::Invisible, &[Token::Str("invisible")]);
+        serde_test::assert_tokens(&Status::Offline, &[Token::Str("offline")]);
+        serde_test::assert_tokens(&Status::Online, &[Token::Str("online")]);
         serde_test::assert_tokens(
-            &Status::DoNotDisturb,
-            &[Token::UnitVariant {
-                name: "Status",
-                variant: "dnd",
-            }],
-        );
-        serde_test::assert_tokens(
-            &Status::Idle,
-            &[Token::UnitVariant {
-                name: "Status",
-                variant: "idle",
-            }],
-        );
-        serde_test::assert_tokens(
-            &Status::Invisible,
-            &[Token::UnitVariant {
-                name: "Status",
-                variant: "invisible",
-            }],
-        );
-        serde_test::assert_tokens(
-            &Status::Offline,
-            &[Token::UnitVariant {
-                name: "Status",
-                variant: "offline",
-            }],
-        );
-        serde_test::assert_tokens(
-            &Status::Online,
-            &[Token::UnitVariant {
-                name: "Status",
-                variant: "online",
-            }],
+            &Status::Unknown("streaming".to_owned()),
+            &[Token::Str("streaming")],
         );
     }
+
+    #[test]
+    fn test_ord() {
+        assert!(Status::DoNotDisturb < Status::Online);
+        assert!(Status::Unknown("away".to_owned()) < Status::DoNotDisturb);
+    }
+
+    #[test]
+    fn test_unknown_long_name() {
+        let status = Status::Unknown("a".repeat(100));
+        let json = serde_json::to_string(&status).unwrap();
+
+        assert_eq!(status, serde_json::from_str::<Status>(&json).unwrap());
+    }
 }
@@ -1,8 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::fmt::{Formatter, Result as FmtResult};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[non_exhaustive]
-#[serde(rename_all = "snake_case")]
 pub enum AuditLogChangeKey {
     AfkChannelId,
     AfkTimeout,
@@ -43,9 +46,7 @@ pub enum AuditLogChangeKey {
     PruneDeleteDays,
     PublicUpdatesChannelId,
     RateLimitPerUser,
-    #[serde(rename = "$add")]
     RoleAdded,
-    #[serde(rename = "$remove")]
     RoleRemoved,
     Region,
     RulesChannelId,
@@ -60,4 +61,180 @@ pub enum AuditLogChangeKey {
     VerificationLevel,
     WidgetChannelId,
     WidgetEnabled,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl AuditLogChangeKey {
+    /// Name of the changed key as sent by Discord.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::AfkChannelId => "afk_channel_id",
+            Self::AfkTimeout => "afk_timeout",
+            Self::Allow => "allow",
+            Self::ApplicationId => "application_id",
+            Self::AvatarHash => "avatar_hash",
+            Self::BannerHash => "banner_hash",
+            Self::Bitrate => "bitrate",
+            Self::ChannelId => "channel_id",
+            Self::Code => "code",
+            Self::Color => "color",
+            Self::Deaf => "deaf",
+            Self::DefaultMessageNotifications => "default_message_notifications",
+            Self::Deny => "deny",
+            Self::Description => "description",
+            Self::DiscoverySplashHash => "discovery_splash_hash",
+            Self::EnableEmoticons => "enable_emoticons",
+            Self::ExpireBehavior => "expire_behavior",
+            Self::ExpireGracePeriod => "expire_grace_period",
+            Self::ExplicitContentFilter => "explicit_content_filter",
+            Self::Hoist => "hoist",
+            Self::IconHash => "icon_hash",
+            Self::Id => "id",
+            Self::InviterId => "inviter_id",
+            Self::MaxAge => "max_age",
+            Self::MaxUses => "max_uses",
+            Self::Mentionable => "mentionable",
+            Self::MfaLevel => "mfa_level",
+            Self::Mute => "mute",
+            Self::Name => "name",
+            Self::Nick => "nick",
+            Self::Nsfw => "nsfw",
+            Self::OwnerId => "owner_id",
+            Self::PermissionOverwrites => "permission_overwrites",
+            Self::Permissions => "permissions",
+            Self::Position => "position",
+            Self::PreferredLocale => "preferred_locale",
+            Self::PruneDeleteDays => "prune_delete_days",
+            Self::PublicUpdatesChannelId => "public_updates_channel_id",
+            Self::RateLimitPerUser => "rate_limit_per_user",
+            Self::RoleAdded => "$add",
+            Self::RoleRemoved => "$remove",
+            Self::Region => "region",
+            Self::RulesChannelId => "rules_channel_id",
+            Self::SplashHash => "splash_hash",
+            Self::SystemChannelId => "system_channel_id",
+            Self::Temporary => "temporary",
+            Self::Topic => "topic",
+            Self::Type => "type",
+            Self::Uses => "uses",
+            Self::UserLimit => "user_limit",
+            Self::VanityUrlCode => "vanity_url_code",
+            Self::VerificationLevel => "verification_level",
+            Self::WidgetChannelId => "widget_channel_id",
+            Self::WidgetEnabled => "widget_enabled",
+            Self::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+struct AuditLogChangeKeyVisitor;
+
+impl<'de> Visitor<'de> for AuditLogChangeKeyVisitor {
+    type Value = AuditLogChangeKey;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an audit log change key")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(match value {
+            "afk_channel_id" => AuditLogChangeKey::AfkChannelId,
+            "afk_timeout" => AuditLogChangeKey::AfkTimeout,
+            "allow" => AuditLogChangeKey::Allow,
+            "application_id" => AuditLogChangeKey::ApplicationId,
+            "avatar_hash" => AuditLogChangeKey::AvatarHash,
+            "banner_hash" => AuditLogChangeKey::BannerHash,
+            "bitrate" => AuditLogChangeKey::Bitrate,
+            "channel_id" => AuditLogChangeKey::ChannelId,
+            "code" => AuditLogChangeKey::Code,
+            "color" => AuditLogChangeKey::Color,
+            "deaf" => AuditLogChangeKey::Deaf,
+            "default_message_notifications" => AuditLogChangeKey::DefaultMessageNotifications,
+            "deny" => AuditLogChangeKey::Deny,
+            "description" => AuditLogChangeKey::Description,
+            "discovery_splash_hash" => AuditLogChangeKey::DiscoverySplashHash,
+            "enable_emoticons" => AuditLogChangeKey::EnableEmoticons,
+            "expire_behavior" => AuditLogChangeKey::ExpireBehavior,
+            "expire_grace_period" => AuditLogChangeKey::ExpireGracePeriod,
+            "explicit_content_filter" => AuditLogChangeKey::ExplicitContentFilter,
+            "hoist" => AuditLogChangeKey::Hoist,
+            "icon_hash" => AuditLogChangeKey::IconHash,
+            "id" => AuditLogChangeKey::Id,
+            "inviter_id" => AuditLogChangeKey::InviterId,
+            "max_age" => AuditLogChangeKey::MaxAge,
+            "max_uses" => AuditLogChangeKey::MaxUses,
+            "mentionable" => AuditLogChangeKey::Mentionable,
+            "mfa_level" => AuditLogChangeKey::MfaLevel,
+            "mute" => AuditLogChangeKey::Mute,
+            "name" => AuditLogChangeKey::Name,
+            "nick" => AuditLogChangeKey::Nick,
+            "nsfw" => AuditLogChangeKey::Nsfw,
+            "owner_id" => AuditLogChangeKey::OwnerId,
+            "permission_overwrites" => AuditLogChangeKey::PermissionOverwrites,
+            "permissions" => AuditLogChangeKey::Permissions,
+            "position" => AuditLogChangeKey::Position,
+            "preferred_locale" => AuditLogChangeKey::PreferredLocale,
+            "prune_delete_days" => AuditLogChangeKey::PruneDeleteDays,
+            "public_updates_channel_id" => AuditLogChangeKey::PublicUpdatesChannelId,
+            "rate_limit_per_user" => AuditLogChangeKey::RateLimitPerUser,
+            "$add" => AuditLogChangeKey::RoleAdded,
+            "$remove" => AuditLogChangeKey::RoleRemoved,
+            "region" => AuditLogChangeKey::Region,
+            "rules_channel_id" => AuditLogChangeKey::RulesChannelId,
+            "splash_hash" => AuditLogChangeKey::SplashHash,
+            "system_channel_id" => AuditLogChangeKey::SystemChannelId,
+            "temporary" => AuditLogChangeKey::Temporary,
+            "topic" => AuditLogChangeKey::Topic,
+            "type" => AuditLogChangeKey::Type,
+            "uses" => AuditLogChangeKey::Uses,
+            "user_limit" => AuditLogChangeKey::UserLimit,
+            "vanity_url_code" => AuditLogChangeKey::VanityUrlCode,
+            "verification_level" => AuditLogChangeKey::VerificationLevel,
+            "widget_channel_id" => AuditLogChangeKey::WidgetChannelId,
+            "widget_enabled" => AuditLogChangeKey::WidgetEnabled,
+            unknown => AuditLogChangeKey::Unknown(unknown.to_owned()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for AuditLogChangeKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(AuditLogChangeKeyVisitor)
+    }
+}
+
+impl Serialize for AuditLogChangeKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuditLogChangeKey;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::AfkChannelId,
+            &[Token::Str("afk_channel_id")],
+        );
+        serde_test::assert_tokens(&AuditLogChangeKey::RoleAdded, &[Token::Str("$add")]);
+        serde_test::assert_tokens(&AuditLogChangeKey::RoleRemoved, &[Token::Str("$remove")]);
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::Unknown("rtc_region".to_owned()),
+            &[Token::Str("rtc_region")],
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!("vanity_url_code", AuditLogChangeKey::VanityUrlCode.name());
+        assert_eq!(
+            "rtc_region",
+            AuditLogChangeKey::Unknown("rtc_region".to_owned()).name()
+        );
+    }
 }
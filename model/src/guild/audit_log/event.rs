@@ -1,43 +1,178 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum AuditLogEvent {
-    GuildCreate = 1,
-    ChannelCreate = 10,
-    ChannelUpdate = 11,
-    ChannelDelete = 12,
-    ChannelOverwriteCreate = 13,
-    ChannelOverwriteUpdate = 14,
-    ChannelOverwriteDelete = 15,
-    MemberKick = 20,
-    MemberPrune = 21,
-    MemberBanAdd = 22,
-    MemberBanRemove = 23,
-    MemberUpdate = 24,
-    MemberRoleUpdate = 25,
-    MemberMove = 26,
-    MemberDisconnect = 27,
-    BotAdd = 28,
-    RoleCreate = 30,
-    RoleUpdate = 31,
-    RoleDelete = 32,
-    InviteCreate = 40,
-    InviteUpdate = 41,
-    InviteDelete = 42,
-    WebhookCreate = 50,
-    WebhookUpdate = 51,
-    WebhookDelete = 52,
-    EmojiCreate = 60,
-    EmojiUpdate = 61,
-    EmojiDelete = 62,
-    MessageDelete = 72,
-    MessageBulkDelete = 73,
-    MessagePin = 74,
-    MessageUnpin = 75,
-    IntegrationCreate = 80,
-    IntegrationUpdate = 81,
-    IntegrationDelete = 82,
+    GuildCreate,
+    ChannelCreate,
+    ChannelUpdate,
+    ChannelDelete,
+    ChannelOverwriteCreate,
+    ChannelOverwriteUpdate,
+    ChannelOverwriteDelete,
+    MemberKick,
+    MemberPrune,
+    MemberBanAdd,
+    MemberBanRemove,
+    MemberUpdate,
+    MemberRoleUpdate,
+    MemberMove,
+    MemberDisconnect,
+    BotAdd,
+    RoleCreate,
+    RoleUpdate,
+    RoleDelete,
+    InviteCreate,
+    InviteUpdate,
+    InviteDelete,
+    WebhookCreate,
+    WebhookUpdate,
+    WebhookDelete,
+    EmojiCreate,
+    EmojiUpdate,
+    EmojiDelete,
+    MessageDelete,
+    MessageBulkDelete,
+    MessagePin,
+    MessageUnpin,
+    IntegrationCreate,
+    IntegrationUpdate,
+    IntegrationDelete,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for AuditLogEvent {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::GuildCreate,
+            10 => Self::ChannelCreate,
+            11 => Self::ChannelUpdate,
+            12 => Self::ChannelDelete,
+            13 => Self::ChannelOverwriteCreate,
+            14 => Self::ChannelOverwriteUpdate,
+            15 => Self::ChannelOverwriteDelete,
+            20 => Self::MemberKick,
+            21 => Self::MemberPrune,
+            22 => Self::MemberBanAdd,
+            23 => Self::MemberBanRemove,
+            24 => Self::MemberUpdate,
+            25 => Self::MemberRoleUpdate,
+            26 => Self::MemberMove,
+            27 => Self::MemberDisconnect,
+            28 => Self::BotAdd,
+            30 => Self::RoleCreate,
+            31 => Self::RoleUpdate,
+            32 => Self::RoleDelete,
+            40 => Self::InviteCreate,
+            41 => Self::InviteUpdate,
+            42 => Self::InviteDelete,
+            50 => Self::WebhookCreate,
+            51 => Self::WebhookUpdate,
+            52 => Self::WebhookDelete,
+            60 => Self::EmojiCreate,
+            61 => Self::EmojiUpdate,
+            62 => Self::EmojiDelete,
+            72 => Self::MessageDelete,
+            73 => Self::MessageBulkDelete,
+            74 => Self::MessagePin,
+            75 => Self::MessageUnpin,
+            80 => Self::IntegrationCreate,
+            81 => Self::IntegrationUpdate,
+            82 => Self::IntegrationDelete,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<AuditLogEvent> for u8 {
+    fn from(value: AuditLogEvent) -> Self {
+        match value {
+            AuditLogEvent::GuildCreate => 1,
+            AuditLogEvent::ChannelCreate => 10,
+            AuditLogEvent::ChannelUpdate => 11,
+            AuditLogEvent::ChannelDelete => 12,
+            AuditLogEvent::ChannelOverwriteCreate => 13,
+            AuditLogEvent::ChannelOverwriteUpdate => 14,
+            AuditLogEvent::ChannelOverwriteDelete => 15,
+            AuditLogEvent::MemberKick => 20,
+            AuditLogEvent::MemberPrune => 21,
+            AuditLogEvent::MemberBanAdd => 22,
+            AuditLogEvent::MemberBanRemove => 23,
+            AuditLogEvent::MemberUpdate => 24,
+            AuditLogEvent::MemberRoleUpdate => 25,
+            AuditLogEvent::MemberMove => 26,
+            AuditLogEvent::MemberDisconnect => 27,
+            AuditLogEvent::BotAdd => 28,
+            AuditLogEvent::RoleCreate => 30,
+            AuditLogEvent::RoleUpdate => 31,
+            AuditLogEvent::RoleDelete => 32,
+            AuditLogEvent::InviteCreate => 40,
+            AuditLogEvent::InviteUpdate => 41,
+            AuditLogEvent::InviteDelete => 42,
+            AuditLogEvent::WebhookCreate => 50,
+            AuditLogEvent::WebhookUpdate => 51,
+            AuditLogEvent::WebhookDelete => 52,
+            AuditLogEvent::EmojiCreate => 60,
+            AuditLogEvent::EmojiUpdate => 61,
+            AuditLogEvent::EmojiDelete => 62,
+            AuditLogEvent::MessageDelete => 72,
+            AuditLogEvent::MessageBulkDelete => 73,
+            AuditLogEvent::MessagePin => 74,
+            AuditLogEvent::MessageUnpin => 75,
+            AuditLogEvent::IntegrationCreate => 80,
+            AuditLogEvent::IntegrationUpdate => 81,
+            AuditLogEvent::IntegrationDelete => 82,
+            AuditLogEvent::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(AuditLogEvent, u8);
+
+#[cfg(test)]
+mod tests {
+    use super::AuditLogEvent;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AuditLogEvent::GuildCreate, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelCreate, &[Token::U8(10)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelUpdate, &[Token::U8(11)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelDelete, &[Token::U8(12)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelOverwriteCreate, &[Token::U8(13)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelOverwriteUpdate, &[Token::U8(14)]);
+        serde_test::assert_tokens(&AuditLogEvent::ChannelOverwriteDelete, &[Token::U8(15)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberKick, &[Token::U8(20)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberPrune, &[Token::U8(21)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberBanAdd, &[Token::U8(22)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberBanRemove, &[Token::U8(23)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberUpdate, &[Token::U8(24)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberRoleUpdate, &[Token::U8(25)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberMove, &[Token::U8(26)]);
+        serde_test::assert_tokens(&AuditLogEvent::MemberDisconnect, &[Token::U8(27)]);
+        serde_test::assert_tokens(&AuditLogEvent::BotAdd, &[Token::U8(28)]);
+        serde_test::assert_tokens(&AuditLogEvent::RoleCreate, &[Token::U8(30)]);
+        serde_test::assert_tokens(&AuditLogEvent::RoleUpdate, &[Token::U8(31)]);
+        serde_test::assert_tokens(&AuditLogEvent::RoleDelete, &[Token::U8(32)]);
+        serde_test::assert_tokens(&AuditLogEvent::InviteCreate, &[Token::U8(40)]);
+        serde_test::assert_tokens(&AuditLogEvent::InviteUpdate, &[Token::U8(41)]);
+        serde_test::assert_tokens(&AuditLogEvent::InviteDelete, &[Token::U8(42)]);
+        serde_test::assert_tokens(&AuditLogEvent::WebhookCreate, &[Token::U8(50)]);
+        serde_test::assert_tokens(&AuditLogEvent::WebhookUpdate, &[Token::U8(51)]);
+        serde_test::assert_tokens(&AuditLogEvent::WebhookDelete, &[Token::U8(52)]);
+        serde_test::assert_tokens(&AuditLogEvent::EmojiCreate, &[Token::U8(60)]);
+        serde_test::assert_tokens(&AuditLogEvent::EmojiUpdate, &[Token::U8(61)]);
+        serde_test::assert_tokens(&AuditLogEvent::EmojiDelete, &[Token::U8(62)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessageDelete, &[Token::U8(72)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessageBulkDelete, &[Token::U8(73)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessagePin, &[Token::U8(74)]);
+        serde_test::assert_tokens(&AuditLogEvent::MessageUnpin, &[Token::U8(75)]);
+        serde_test::assert_tokens(&AuditLogEvent::IntegrationCreate, &[Token::U8(80)]);
+        serde_test::assert_tokens(&AuditLogEvent::IntegrationUpdate, &[Token::U8(81)]);
+        serde_test::assert_tokens(&AuditLogEvent::IntegrationDelete, &[Token::U8(82)]);
+        serde_test::assert_tokens(&AuditLogEvent::Unknown(255), &[Token::U8(255)]);
+    }
 }
@@ -1,14 +1,37 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum DefaultMessageNotificationLevel {
-    All = 0,
-    Mentions = 1,
+    All,
+    Mentions,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for DefaultMessageNotificationLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::All,
+            1 => Self::Mentions,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<DefaultMessageNotificationLevel> for u8 {
+    fn from(value: DefaultMessageNotificationLevel) -> Self {
+        match value {
+            DefaultMessageNotificationLevel::All => 0,
+            DefaultMessageNotificationLevel::Mentions => 1,
+            DefaultMessageNotificationLevel::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(DefaultMessageNotificationLevel, u8);
+
 #[cfg(test)]
 mod tests {
     use super::DefaultMessageNotificationLevel;
@@ -18,5 +41,9 @@ mod tests {
     fn test_variants() {
         serde_test::assert_tokens(&DefaultMessageNotificationLevel::All, &[Token::U8(0)]);
         serde_test::assert_tokens(&DefaultMessageNotificationLevel::Mentions, &[Token::U8(1)]);
+        serde_test::assert_tokens(
+            &DefaultMessageNotificationLevel::Unknown(255),
+            &[Token::U8(255)],
+        );
     }
 }
@@ -1,15 +1,40 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum ExplicitContentFilter {
-    None = 0,
-    MembersWithoutRole = 1,
-    AllMembers = 2,
+    None,
+    MembersWithoutRole,
+    AllMembers,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for ExplicitContentFilter {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::MembersWithoutRole,
+            2 => Self::AllMembers,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ExplicitContentFilter> for u8 {
+    fn from(value: ExplicitContentFilter) -> Self {
+        match value {
+            ExplicitContentFilter::None => 0,
+            ExplicitContentFilter::MembersWithoutRole => 1,
+            ExplicitContentFilter::AllMembers => 2,
+            ExplicitContentFilter::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(ExplicitContentFilter, u8);
+
 #[cfg(test)]
 mod tests {
     use super::ExplicitContentFilter;
@@ -20,5 +45,6 @@ mod tests {
         serde_test::assert_tokens(&ExplicitContentFilter::None, &[Token::U8(0)]);
         serde_test::assert_tokens(&ExplicitContentFilter::MembersWithoutRole, &[Token::U8(1)]);
         serde_test::assert_tokens(&ExplicitContentFilter::AllMembers, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ExplicitContentFilter::Unknown(255), &[Token::U8(255)]);
     }
 }
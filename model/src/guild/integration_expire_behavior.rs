@@ -1,17 +1,40 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
 /// Behavior to perform when the user's integration expires.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum IntegrationExpireBehavior {
     /// Remove the role when the integration expires.
-    RemoveRole = 0,
+    RemoveRole,
     /// Kick the user when the integration expires.
-    Kick = 1,
+    Kick,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for IntegrationExpireBehavior {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::RemoveRole,
+            1 => Self::Kick,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<IntegrationExpireBehavior> for u8 {
+    fn from(value: IntegrationExpireBehavior) -> Self {
+        match value {
+            IntegrationExpireBehavior::RemoveRole => 0,
+            IntegrationExpireBehavior::Kick => 1,
+            IntegrationExpireBehavior::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(IntegrationExpireBehavior, u8);
+
 #[cfg(test)]
 mod tests {
     use super::IntegrationExpireBehavior;
@@ -21,5 +44,6 @@ mod tests {
     fn test_integration_expire_behavior() {
         serde_test::assert_tokens(&IntegrationExpireBehavior::RemoveRole, &[Token::U8(0)]);
         serde_test::assert_tokens(&IntegrationExpireBehavior::Kick, &[Token::U8(1)]);
+        serde_test::assert_tokens(&IntegrationExpireBehavior::Unknown(255), &[Token::U8(255)]);
     }
 }
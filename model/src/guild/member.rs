@@ -1,4 +1,5 @@
 use crate::{
+    datetime::Timestamp,
     id::{GuildId, RoleId},
     user::User,
 };
@@ -17,7 +18,7 @@ pub struct Member {
     pub deaf: bool,
     pub guild_id: GuildId,
     pub hoisted_role: Option<RoleId>,
-    pub joined_at: Option<String>,
+    pub joined_at: Option<Timestamp>,
     pub mute: bool,
     pub nick: Option<String>,
     /// Whether the user has yet to pass the guild's [Membership Screening]
@@ -34,7 +35,7 @@ pub struct Member {
 pub(crate) struct MemberIntermediary {
     pub deaf: bool,
     pub hoisted_role: Option<RoleId>,
-    pub joined_at: Option<String>,
+    pub joined_at: Option<Timestamp>,
     pub mute: bool,
     pub nick: Option<String>,
     #[serde(default)]
@@ -176,6 +177,7 @@ impl<'de> DeserializeSeed<'de> for MemberListDeserializer {
 mod tests {
     use super::Member;
     use crate::{
+        datetime::Timestamp,
         id::{GuildId, RoleId, UserId},
         user::User,
     };
@@ -187,7 +189,9 @@ mod tests {
             deaf: false,
             guild_id: GuildId(1),
             hoisted_role: Some(RoleId(2)),
-            joined_at: Some("timestamp".to_owned()),
+            joined_at: Some(
+                Timestamp::parse("2021-08-10T12:18:37.000000+00:00").expect("valid timestamp"),
+            ),
             mute: true,
             nick: Some("twilight".to_owned()),
             pending: false,
@@ -228,7 +232,7 @@ mod tests {
                 Token::Str("2"),
                 Token::Str("joined_at"),
                 Token::Some,
-                Token::Str("timestamp"),
+                Token::Str("2021-08-10T12:18:37.000000+00:00"),
                 Token::Str("mute"),
                 Token::Bool(true),
                 Token::Str("nick"),
@@ -1,14 +1,37 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum MfaLevel {
-    None = 0,
-    Elevated = 1,
+    None,
+    Elevated,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for MfaLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Elevated,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<MfaLevel> for u8 {
+    fn from(value: MfaLevel) -> Self {
+        match value {
+            MfaLevel::None => 0,
+            MfaLevel::Elevated => 1,
+            MfaLevel::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(MfaLevel, u8);
+
 #[cfg(test)]
 mod tests {
     use super::MfaLevel;
@@ -18,5 +41,6 @@ mod tests {
     fn test_variants() {
         serde_test::assert_tokens(&MfaLevel::None, &[Token::U8(0)]);
         serde_test::assert_tokens(&MfaLevel::Elevated, &[Token::U8(1)]);
+        serde_test::assert_tokens(&MfaLevel::Unknown(255), &[Token::U8(255)]);
     }
 }
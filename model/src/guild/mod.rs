@@ -105,6 +105,13 @@ pub struct Guild {
     pub splash: Option<String>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
+    /// Active threads of the guild that the current user can see.
+    ///
+    /// Only present in [`GuildCreate`] events.
+    ///
+    /// [`GuildCreate`]: crate::gateway::payload::GuildCreate
+    #[serde(default)]
+    pub threads: Vec<GuildChannel>,
     #[serde(default)]
     pub unavailable: bool,
     pub vanity_url_code: Option<String>,
@@ -161,6 +168,7 @@ impl<'de> Deserialize<'de> for Guild {
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
+            Threads,
             Unavailable,
             VerificationLevel,
             VoiceStates,
@@ -218,6 +226,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
+                let mut threads = None::<Vec<GuildChannel>>;
                 let mut unavailable = None;
                 let mut verification_level = None;
                 let mut voice_states = None::<Vec<VoiceState>>;
@@ -522,6 +531,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             rules_channel_id = Some(map.next_value()?);
                         }
+                        Field::Threads => {
+                            if threads.is_some() {
+                                return Err(DeError::duplicate_field("threads"));
+                            }
+
+                            threads = Some(map.next_value()?);
+                        }
                         Field::Unavailable => {
                             if unavailable.is_some() {
                                 return Err(DeError::duplicate_field("unavailable"));
@@ -613,6 +629,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let rules_channel_id = rules_channel_id.unwrap_or_default();
                 let splash = splash.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
+                let mut threads = threads.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
                 let mut voice_states = voice_states.unwrap_or_default();
@@ -661,6 +678,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?splash,
                     ?system_channel_flags,
                     ?system_channel_id,
+                    ?threads,
                     ?unavailable,
                     ?vanity_url_code,
                     ?voice_states,
@@ -680,6 +698,15 @@ impl<'de> Deserialize<'de> for Guild {
                         GuildChannel::Voice(c) | GuildChannel::Stage(c) => {
                             c.guild_id.replace(id);
                         }
+                        GuildChannel::Thread(c) => {
+                            c.guild_id.replace(id);
+                        }
+                    }
+                }
+
+                for thread in &mut threads {
+                    if let GuildChannel::Thread(c) = thread {
+                        c.guild_id.replace(id);
                     }
                 }
 
@@ -734,6 +761,7 @@ impl<'de> Deserialize<'de> for Guild {
                     splash,
                     system_channel_flags,
                     system_channel_id,
+                    threads,
                     unavailable,
                     vanity_url_code,
                     verification_level,
@@ -783,6 +811,7 @@ impl<'de> Deserialize<'de> for Guild {
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
+            "threads",
             "unavailable",
             "verification_level",
             "voice_states",
@@ -845,6 +874,7 @@ mod tests {
             splash: Some("splash hash".to_owned()),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
             system_channel_id: Some(ChannelId(7)),
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
@@ -858,7 +888,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Guild",
-                    len: 44,
+                    len: 45,
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
@@ -970,6 +1000,9 @@ mod tests {
                 Token::Some,
                 Token::NewtypeStruct { name: "ChannelId" },
                 Token::Str("7"),
+                Token::Str("threads"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("unavailable"),
                 Token::Bool(false),
                 Token::Str("vanity_url_code"),
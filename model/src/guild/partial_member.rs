@@ -1,10 +1,10 @@
-use crate::id::RoleId;
+use crate::{datetime::Timestamp, id::RoleId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct PartialMember {
     pub deaf: bool,
-    pub joined_at: Option<String>,
+    pub joined_at: Option<Timestamp>,
     pub mute: bool,
     pub nick: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -14,14 +14,16 @@ pub struct PartialMember {
 
 #[cfg(test)]
 mod tests {
-    use super::{PartialMember, RoleId};
+    use super::{PartialMember, RoleId, Timestamp};
     use serde_test::Token;
 
     #[test]
     fn test_partial_member() {
         let value = PartialMember {
             deaf: false,
-            joined_at: Some("timestamp".to_owned()),
+            joined_at: Some(
+                Timestamp::parse("2021-08-10T12:18:37.000000+00:00").expect("valid timestamp"),
+            ),
             mute: true,
             nick: Some("a nickname".to_owned()),
             premium_since: None,
@@ -39,7 +41,7 @@ mod tests {
                 Token::Bool(false),
                 Token::Str("joined_at"),
                 Token::Some,
-                Token::Str("timestamp"),
+                Token::Str("2021-08-10T12:18:37.000000+00:00"),
                 Token::Str("mute"),
                 Token::Bool(true),
                 Token::Str("nick"),
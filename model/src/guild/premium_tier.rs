@@ -1,16 +1,43 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum PremiumTier {
-    None = 0,
-    Tier1 = 1,
-    Tier2 = 2,
-    Tier3 = 3,
+    None,
+    Tier1,
+    Tier2,
+    Tier3,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for PremiumTier {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Tier1,
+            2 => Self::Tier2,
+            3 => Self::Tier3,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<PremiumTier> for u8 {
+    fn from(value: PremiumTier) -> Self {
+        match value {
+            PremiumTier::None => 0,
+            PremiumTier::Tier1 => 1,
+            PremiumTier::Tier2 => 2,
+            PremiumTier::Tier3 => 3,
+            PremiumTier::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(PremiumTier, u8);
+
 impl Default for PremiumTier {
     fn default() -> Self {
         Self::None
@@ -28,5 +55,6 @@ mod tests {
         serde_test::assert_tokens(&PremiumTier::Tier1, &[Token::U8(1)]);
         serde_test::assert_tokens(&PremiumTier::Tier2, &[Token::U8(2)]);
         serde_test::assert_tokens(&PremiumTier::Tier3, &[Token::U8(3)]);
+        serde_test::assert_tokens(&PremiumTier::Unknown(255), &[Token::U8(255)]);
     }
 }
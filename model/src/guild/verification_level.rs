@@ -1,17 +1,46 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum VerificationLevel {
-    None = 0,
-    Low = 1,
-    Medium = 2,
-    High = 3,
-    VeryHigh = 4,
+    None,
+    Low,
+    Medium,
+    High,
+    VeryHigh,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for VerificationLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Low,
+            2 => Self::Medium,
+            3 => Self::High,
+            4 => Self::VeryHigh,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<VerificationLevel> for u8 {
+    fn from(value: VerificationLevel) -> Self {
+        match value {
+            VerificationLevel::None => 0,
+            VerificationLevel::Low => 1,
+            VerificationLevel::Medium => 2,
+            VerificationLevel::High => 3,
+            VerificationLevel::VeryHigh => 4,
+            VerificationLevel::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(VerificationLevel, u8);
+
 #[cfg(test)]
 mod tests {
     use super::VerificationLevel;
@@ -24,5 +53,6 @@ mod tests {
         serde_test::assert_tokens(&VerificationLevel::Medium, &[Token::U8(2)]);
         serde_test::assert_tokens(&VerificationLevel::High, &[Token::U8(3)]);
         serde_test::assert_tokens(&VerificationLevel::VeryHigh, &[Token::U8(4)]);
+        serde_test::assert_tokens(&VerificationLevel::Unknown(255), &[Token::U8(255)]);
     }
 }
@@ -1,13 +1,34 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum TargetUserType {
-    Stream = 1,
+    Stream,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for TargetUserType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Stream,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<TargetUserType> for u8 {
+    fn from(value: TargetUserType) -> Self {
+        match value {
+            TargetUserType::Stream => 1,
+            TargetUserType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(TargetUserType, u8);
+
 #[cfg(test)]
 mod tests {
     use super::TargetUserType;
@@ -16,5 +37,6 @@ mod tests {
     #[test]
     fn test_variants() {
         serde_test::assert_tokens(&TargetUserType::Stream, &[Token::U8(1)]);
+        serde_test::assert_tokens(&TargetUserType::Unknown(255), &[Token::U8(255)]);
     }
 }
@@ -21,6 +21,16 @@
 //! resource category. These types may be directly returned by, built on top of,
 //! or extended by other crates.
 //!
+//! ## Features
+//!
+//! ### `chrono`
+//!
+//! Conversions between [`datetime::Timestamp`] and `chrono`'s `DateTime`.
+//!
+//! ### `time`
+//!
+//! Conversions between [`datetime::Timestamp`] and `time`'s `OffsetDateTime`.
+//!
 //! ## License
 //!
 //! [ISC][LICENSE.md]
@@ -48,6 +58,7 @@
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
 pub mod channel;
+pub mod datetime;
 pub mod gateway;
 pub mod guild;
 pub mod id;
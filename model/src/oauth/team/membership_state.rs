@@ -1,14 +1,37 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum TeamMembershipState {
-    Invited = 1,
-    Accepted = 2,
+    Invited,
+    Accepted,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for TeamMembershipState {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Invited,
+            2 => Self::Accepted,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<TeamMembershipState> for u8 {
+    fn from(value: TeamMembershipState) -> Self {
+        match value {
+            TeamMembershipState::Invited => 1,
+            TeamMembershipState::Accepted => 2,
+            TeamMembershipState::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(TeamMembershipState, u8);
+
 #[cfg(test)]
 mod tests {
     use super::TeamMembershipState;
@@ -18,5 +41,6 @@ mod tests {
     fn test_variants() {
         serde_test::assert_tokens(&TeamMembershipState::Invited, &[Token::U8(1)]);
         serde_test::assert_tokens(&TeamMembershipState::Accepted, &[Token::U8(2)]);
+        serde_test::assert_tokens(&TeamMembershipState::Unknown(255), &[Token::U8(255)]);
     }
 }
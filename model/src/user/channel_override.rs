@@ -0,0 +1,16 @@
+use super::{MessageNotificationLevel, MuteConfig};
+use crate::id::ChannelId;
+use serde::{Deserialize, Serialize};
+
+/// Notification settings of the current user for a channel, overriding the
+/// settings of its guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ChannelOverride {
+    pub channel_id: ChannelId,
+    /// Whether the channel is collapsed, if it's a category.
+    #[serde(default)]
+    pub collapsed: bool,
+    pub message_notifications: MessageNotificationLevel,
+    pub mute_config: Option<MuteConfig>,
+    pub muted: bool,
+}
@@ -1,14 +1,37 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum ConnectionVisibility {
-    None = 0,
-    Everyone = 1,
+    None,
+    Everyone,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for ConnectionVisibility {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Everyone,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ConnectionVisibility> for u8 {
+    fn from(value: ConnectionVisibility) -> Self {
+        match value {
+            ConnectionVisibility::None => 0,
+            ConnectionVisibility::Everyone => 1,
+            ConnectionVisibility::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(ConnectionVisibility, u8);
+
 #[cfg(test)]
 mod tests {
     use super::ConnectionVisibility;
@@ -18,5 +41,6 @@ mod tests {
     fn test_variants() {
         serde_test::assert_tokens(&ConnectionVisibility::None, &[Token::U8(0)]);
         serde_test::assert_tokens(&ConnectionVisibility::Everyone, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ConnectionVisibility::Unknown(255), &[Token::U8(255)]);
     }
 }
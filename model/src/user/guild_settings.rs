@@ -0,0 +1,193 @@
+use super::{ChannelOverride, MessageNotificationLevel, MuteConfig};
+use crate::id::{ChannelId, GuildId};
+use serde::{Deserialize, Serialize};
+
+/// Notification settings of the current user for a guild.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct UserGuildSettings {
+    #[serde(default)]
+    pub channel_overrides: Vec<ChannelOverride>,
+    /// ID of the guild, or `None` for the settings of private channels.
+    pub guild_id: Option<GuildId>,
+    #[serde(default)]
+    pub hide_muted_channels: bool,
+    pub message_notifications: MessageNotificationLevel,
+    #[serde(default)]
+    pub mobile_push: bool,
+    pub mute_config: Option<MuteConfig>,
+    pub muted: bool,
+    /// Whether to suppress `@everyone` and `@here` mentions.
+    pub suppress_everyone: bool,
+    /// Whether to suppress role mentions.
+    #[serde(default)]
+    pub suppress_roles: bool,
+    #[serde(default)]
+    pub version: u64,
+}
+
+impl UserGuildSettings {
+    /// Get the override of a channel, if there is one.
+    pub fn channel_override(&self, channel_id: ChannelId) -> Option<&ChannelOverride> {
+        self.channel_overrides
+            .iter()
+            .find(|channel_override| channel_override.channel_id == channel_id)
+    }
+
+    /// Whether a channel is muted, either by its own override or by a muted
+    /// parent category.
+    ///
+    /// The guild's own mute isn't taken into account.
+    pub fn channel_muted(&self, channel_id: ChannelId, parent_id: Option<ChannelId>) -> bool {
+        let muted = |id| self.channel_override(id).map_or(false, |o| o.muted);
+
+        muted(channel_id) || parent_id.map_or(false, muted)
+    }
+
+    /// Notification level of a channel, resolving inherited levels from the
+    /// parent category and the guild.
+    ///
+    /// Returns [`MessageNotificationLevel::Inherit`] if neither the channel,
+    /// its parent, nor the guild set a level, in which case the guild's
+    /// default notification level applies.
+    pub fn channel_notifications(
+        &self,
+        channel_id: ChannelId,
+        parent_id: Option<ChannelId>,
+    ) -> MessageNotificationLevel {
+        let level = |id| {
+            self.channel_override(id)
+                .map(|o| o.message_notifications)
+                .filter(|level| *level != MessageNotificationLevel::Inherit)
+        };
+
+        level(channel_id)
+            .or_else(|| parent_id.and_then(level))
+            .unwrap_or(self.message_notifications)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelOverride, MessageNotificationLevel, MuteConfig, UserGuildSettings};
+    use crate::id::{ChannelId, GuildId};
+    use serde_test::Token;
+
+    fn settings() -> UserGuildSettings {
+        UserGuildSettings {
+            channel_overrides: vec![
+                ChannelOverride {
+                    channel_id: ChannelId(2),
+                    collapsed: false,
+                    message_notifications: MessageNotificationLevel::Nothing,
+                    mute_config: None,
+                    muted: true,
+                },
+                ChannelOverride {
+                    channel_id: ChannelId(3),
+                    collapsed: false,
+                    message_notifications: MessageNotificationLevel::Inherit,
+                    mute_config: None,
+                    muted: false,
+                },
+            ],
+            guild_id: Some(GuildId(1)),
+            hide_muted_channels: true,
+            message_notifications: MessageNotificationLevel::OnlyMentions,
+            mobile_push: true,
+            mute_config: Some(MuteConfig {
+                end_time: None,
+                selected_time_window: Some(-1),
+            }),
+            muted: false,
+            suppress_everyone: true,
+            suppress_roles: false,
+            version: 5,
+        }
+    }
+
+    #[test]
+    fn test_channel_resolution() {
+        let settings = settings();
+
+        assert!(settings.channel_muted(ChannelId(2), None));
+        assert!(settings.channel_muted(ChannelId(4), Some(ChannelId(2))));
+        assert!(!settings.channel_muted(ChannelId(3), None));
+
+        assert_eq!(
+            MessageNotificationLevel::Nothing,
+            settings.channel_notifications(ChannelId(3), Some(ChannelId(2)))
+        );
+        assert_eq!(
+            MessageNotificationLevel::OnlyMentions,
+            settings.channel_notifications(ChannelId(3), None)
+        );
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_user_guild_settings() {
+        let mut value = settings();
+        value.channel_overrides.truncate(1);
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "UserGuildSettings",
+                    len: 10,
+                },
+                Token::Str("channel_overrides"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ChannelOverride",
+                    len: 5,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("collapsed"),
+                Token::Bool(false),
+                Token::Str("message_notifications"),
+                Token::U8(2),
+                Token::Str("mute_config"),
+                Token::None,
+                Token::Str("muted"),
+                Token::Bool(true),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("hide_muted_channels"),
+                Token::Bool(true),
+                Token::Str("message_notifications"),
+                Token::U8(1),
+                Token::Str("mobile_push"),
+                Token::Bool(true),
+                Token::Str("mute_config"),
+                Token::Some,
+                Token::Struct {
+                    name: "MuteConfig",
+                    len: 2,
+                },
+                Token::Str("end_time"),
+                Token::None,
+                Token::Str("selected_time_window"),
+                Token::Some,
+                Token::I64(-1),
+                Token::StructEnd,
+                Token::Str("muted"),
+                Token::Bool(false),
+                Token::Str("suppress_everyone"),
+                Token::Bool(true),
+                Token::Str("suppress_roles"),
+                Token::Bool(false),
+                Token::Str("version"),
+                Token::U64(5),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
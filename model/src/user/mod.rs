@@ -1,15 +1,24 @@
+mod channel_override;
 mod connection;
 mod connection_visibility;
 mod current_user;
 mod current_user_guild;
 mod flags;
+mod guild_settings;
+mod mute_config;
+mod notification_level;
 mod premium_type;
 mod profile;
+mod relationship;
+mod relationship_type;
 
 pub use self::{
-    connection::Connection, connection_visibility::ConnectionVisibility, current_user::CurrentUser,
-    current_user_guild::CurrentUserGuild, flags::UserFlags, premium_type::PremiumType,
-    profile::UserProfile,
+    channel_override::ChannelOverride, connection::Connection,
+    connection_visibility::ConnectionVisibility, current_user::CurrentUser,
+    current_user_guild::CurrentUserGuild, flags::UserFlags, guild_settings::UserGuildSettings,
+    mute_config::MuteConfig, notification_level::MessageNotificationLevel,
+    premium_type::PremiumType, profile::UserProfile, relationship::Relationship,
+    relationship_type::RelationshipType,
 };
 
 use crate::id::UserId;
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Duration of a guild or channel mute.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MuteConfig {
+    /// ISO 8601 timestamp of when the mute ends, if it isn't indefinite.
+    pub end_time: Option<String>,
+    /// Length of the mute in seconds that the user selected, `-1` for an
+    /// indefinite mute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_time_window: Option<i64>,
+}
@@ -0,0 +1,59 @@
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
+
+/// Which messages the current user is notified about in a guild or channel.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum MessageNotificationLevel {
+    /// Notify about all messages.
+    AllMessages,
+    /// Notify only about messages that mention the current user.
+    OnlyMentions,
+    /// Don't notify about any messages.
+    Nothing,
+    /// Use the level of the guild, or the guild's default level.
+    Inherit,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for MessageNotificationLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::AllMessages,
+            1 => Self::OnlyMentions,
+            2 => Self::Nothing,
+            3 => Self::Inherit,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<MessageNotificationLevel> for u8 {
+    fn from(value: MessageNotificationLevel) -> Self {
+        match value {
+            MessageNotificationLevel::AllMessages => 0,
+            MessageNotificationLevel::OnlyMentions => 1,
+            MessageNotificationLevel::Nothing => 2,
+            MessageNotificationLevel::Inherit => 3,
+            MessageNotificationLevel::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(MessageNotificationLevel, u8);
+
+#[cfg(test)]
+mod tests {
+    use super::MessageNotificationLevel;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&MessageNotificationLevel::AllMessages, &[Token::U8(0)]);
+        serde_test::assert_tokens(&MessageNotificationLevel::OnlyMentions, &[Token::U8(1)]);
+        serde_test::assert_tokens(&MessageNotificationLevel::Nothing, &[Token::U8(2)]);
+        serde_test::assert_tokens(&MessageNotificationLevel::Inherit, &[Token::U8(3)]);
+        serde_test::assert_tokens(&MessageNotificationLevel::Unknown(255), &[Token::U8(255)]);
+    }
+}
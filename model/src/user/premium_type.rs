@@ -1,14 +1,40 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
-#[repr(u8)]
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum PremiumType {
-    None = 0,
-    NitroClassic = 1,
-    Nitro = 2,
+    None,
+    NitroClassic,
+    Nitro,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for PremiumType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::NitroClassic,
+            2 => Self::Nitro,
+            unknown => Self::Unknown(unknown),
+        }
+    }
 }
 
+impl From<PremiumType> for u8 {
+    fn from(value: PremiumType) -> Self {
+        match value {
+            PremiumType::None => 0,
+            PremiumType::NitroClassic => 1,
+            PremiumType::Nitro => 2,
+            PremiumType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(PremiumType, u8);
+
 #[cfg(test)]
 mod tests {
     use super::PremiumType;
@@ -19,5 +45,6 @@ mod tests {
         serde_test::assert_tokens(&PremiumType::None, &[Token::U8(0)]);
         serde_test::assert_tokens(&PremiumType::NitroClassic, &[Token::U8(1)]);
         serde_test::assert_tokens(&PremiumType::Nitro, &[Token::U8(2)]);
+        serde_test::assert_tokens(&PremiumType::Unknown(255), &[Token::U8(255)]);
     }
 }
@@ -0,0 +1,81 @@
+use super::{RelationshipType, User};
+use crate::id::UserId;
+use serde::{Deserialize, Serialize};
+
+/// Relationship between the current user and another user, such as a friend,
+/// a blocked user or a pending friend request.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Relationship {
+    /// ID of the other user.
+    pub id: UserId,
+    #[serde(rename = "type")]
+    pub kind: RelationshipType,
+    /// Nickname the current user has given the other user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    pub user: User,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Relationship, RelationshipType, User};
+    use crate::id::UserId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_relationship() {
+        let value = Relationship {
+            id: UserId(1),
+            kind: RelationshipType::PendingIncoming,
+            nickname: None,
+            user: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(1),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Relationship",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("user"),
+                Token::Struct {
+                    name: "User",
+                    len: 5,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("bot"),
+                Token::Bool(false),
+                Token::Str("discriminator"),
+                Token::Str("0001"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("1"),
+                Token::Str("username"),
+                Token::Str("test"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
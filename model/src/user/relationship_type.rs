@@ -0,0 +1,65 @@
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
+
+/// Type of a relationship between the current user and another user.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum RelationshipType {
+    /// Users are friends.
+    Friend,
+    /// Current user has blocked the other user.
+    Blocked,
+    /// Other user has sent the current user a friend request.
+    PendingIncoming,
+    /// Current user has sent the other user a friend request.
+    PendingOutgoing,
+    /// Users aren't friends but have interacted, such as playing a game
+    /// together.
+    Implicit,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RelationshipType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Friend,
+            2 => Self::Blocked,
+            3 => Self::PendingIncoming,
+            4 => Self::PendingOutgoing,
+            5 => Self::Implicit,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RelationshipType> for u8 {
+    fn from(value: RelationshipType) -> Self {
+        match value {
+            RelationshipType::Friend => 1,
+            RelationshipType::Blocked => 2,
+            RelationshipType::PendingIncoming => 3,
+            RelationshipType::PendingOutgoing => 4,
+            RelationshipType::Implicit => 5,
+            RelationshipType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(RelationshipType, u8);
+
+#[cfg(test)]
+mod tests {
+    use super::RelationshipType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&RelationshipType::Friend, &[Token::U8(1)]);
+        serde_test::assert_tokens(&RelationshipType::Blocked, &[Token::U8(2)]);
+        serde_test::assert_tokens(&RelationshipType::PendingIncoming, &[Token::U8(3)]);
+        serde_test::assert_tokens(&RelationshipType::PendingOutgoing, &[Token::U8(4)]);
+        serde_test::assert_tokens(&RelationshipType::Implicit, &[Token::U8(5)]);
+        serde_test::assert_tokens(&RelationshipType::Unknown(255), &[Token::U8(255)]);
+    }
+}
@@ -2,3 +2,23 @@
 pub fn is_false(value: &bool) -> bool {
     !value
 }
+
+/// Implement [`Ord`] and [`PartialOrd`] for an enum with an unknown variant by
+/// comparing the integer values of its variants.
+macro_rules! impl_ord_by_value {
+    ($name:ident, $value:ty) => {
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                <$value>::from(*self).cmp(&<$value>::from(*other))
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_ord_by_value;
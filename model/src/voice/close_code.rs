@@ -1,41 +1,45 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::convert::TryFrom;
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::{
+    convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 
 /// Voice gateway close event codes.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[repr(u16)]
+#[serde(into = "u16")]
 pub enum CloseCode {
     /// An invalid opcode was sent.
-    UnknownOpcode = 4001,
+    UnknownOpcode,
     /// An invalid payload was sent.
-    DecodeError = 4002,
+    DecodeError,
     /// A payload was sent prior to identifying.
-    NotAuthenticated = 4003,
+    NotAuthenticated,
     /// An invalid token was sent when identifying.
-    AuthenticationFailed = 4004,
+    AuthenticationFailed,
     /// Multiple identify payloads were sent.
-    AlreadyAuthenticated = 4005,
+    AlreadyAuthenticated,
     /// The session was invalidated.
-    SessionNoLongerValid = 4006,
+    SessionNoLongerValid,
     /// The session timed out.
-    SessionTimedOut = 4009,
+    SessionTimedOut,
     /// The specified voice server was not found.
-    ServerNotFound = 4011,
+    ServerNotFound,
     /// An unknown protocol was sent.
-    UnknownProtocol = 4012,
+    UnknownProtocol,
     /// Disconnected from the voice channel.
-    Disconnected = 4014,
+    Disconnected,
     /// The voice server crashed.
-    VoiceServerCrashed = 4015,
+    VoiceServerCrashed,
     /// The encryption could not be recognised.
-    UnknownEncryptionMode = 4016,
+    UnknownEncryptionMode,
+    /// Variant value is unknown to the library.
+    ///
+    /// Close codes that are unknown to the library are kept in this variant
+    /// when deserializing, but can't be converted from with [`TryFrom`].
+    Unknown(u16),
 }
 
 #[derive(Debug, PartialEq)]
@@ -85,6 +89,36 @@ impl TryFrom<u16> for CloseCode {
     }
 }
 
+impl<'de> Deserialize<'de> for CloseCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = u16::deserialize(deserializer)?;
+
+        Ok(Self::try_from(code).unwrap_or(Self::Unknown(code)))
+    }
+}
+
+impl From<CloseCode> for u16 {
+    fn from(value: CloseCode) -> Self {
+        match value {
+            CloseCode::UnknownOpcode => 4001,
+            CloseCode::DecodeError => 4002,
+            CloseCode::NotAuthenticated => 4003,
+            CloseCode::AuthenticationFailed => 4004,
+            CloseCode::AlreadyAuthenticated => 4005,
+            CloseCode::SessionNoLongerValid => 4006,
+            CloseCode::SessionTimedOut => 4009,
+            CloseCode::ServerNotFound => 4011,
+            CloseCode::UnknownProtocol => 4012,
+            CloseCode::Disconnected => 4014,
+            CloseCode::VoiceServerCrashed => 4015,
+            CloseCode::UnknownEncryptionMode => 4016,
+            CloseCode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(CloseCode, u16);
+
 #[cfg(test)]
 mod tests {
     use super::CloseCode;
@@ -104,6 +138,7 @@ mod tests {
         serde_test::assert_tokens(&CloseCode::Disconnected, &[Token::U16(4014)]);
         serde_test::assert_tokens(&CloseCode::VoiceServerCrashed, &[Token::U16(4015)]);
         serde_test::assert_tokens(&CloseCode::UnknownEncryptionMode, &[Token::U16(4016)]);
+        serde_test::assert_tokens(&CloseCode::Unknown(4999), &[Token::U16(4999)]);
     }
 
     #[test]
@@ -143,6 +178,7 @@ mod tests {
             CloseCode::try_from(4016).unwrap(),
             CloseCode::UnknownEncryptionMode
         );
-        assert!(CloseCode::try_from(5000).is_err());
+        assert_eq!(5000, CloseCode::try_from(5000).unwrap_err().code());
+        assert_eq!(5000, u16::from(CloseCode::Unknown(5000)));
     }
 }
@@ -1,36 +1,77 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::util::impl_ord_by_value;
+use serde::{Deserialize, Serialize};
 
 // Voice gateway opcodes.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
 pub enum OpCode {
     /// Start a voice websocket connection.
-    Identify = 0,
+    Identify,
     /// Select the protocol to use.
-    SelectProtocol = 1,
+    SelectProtocol,
     /// Received to indicate completion of handshake.
-    Ready = 2,
+    Ready,
     /// Fired periodically to keep connection alive.
-    Heartbeat = 3,
+    Heartbeat,
     /// Received to indicate session description.
-    SessionDescription = 4,
+    SessionDescription,
     /// Sent and received to indicate speaking status.
-    Speaking = 5,
+    Speaking,
     /// Received in response to a heartbeat.
-    HeartbeatAck = 6,
+    HeartbeatAck,
     /// Resume a previously disconnected session.
-    Resume = 7,
+    Resume,
     /// Received after connecting, contains heartbeat interval.
-    Hello = 8,
+    Hello,
     /// Received to indicate a successful resume.
-    Resumed = 9,
+    Resumed,
     /// Received to indicate someone was disconnected.
-    ClientDisconnect = 13,
+    ClientDisconnect,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
 }
 
+impl From<u8> for OpCode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Identify,
+            1 => Self::SelectProtocol,
+            2 => Self::Ready,
+            3 => Self::Heartbeat,
+            4 => Self::SessionDescription,
+            5 => Self::Speaking,
+            6 => Self::HeartbeatAck,
+            7 => Self::Resume,
+            8 => Self::Hello,
+            9 => Self::Resumed,
+            13 => Self::ClientDisconnect,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<OpCode> for u8 {
+    fn from(value: OpCode) -> Self {
+        match value {
+            OpCode::Identify => 0,
+            OpCode::SelectProtocol => 1,
+            OpCode::Ready => 2,
+            OpCode::Heartbeat => 3,
+            OpCode::SessionDescription => 4,
+            OpCode::Speaking => 5,
+            OpCode::HeartbeatAck => 6,
+            OpCode::Resume => 7,
+            OpCode::Hello => 8,
+            OpCode::Resumed => 9,
+            OpCode::ClientDisconnect => 13,
+            OpCode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl_ord_by_value!(OpCode, u8);
+
 #[cfg(test)]
 mod tests {
     use super::OpCode;
@@ -49,5 +90,6 @@ mod tests {
         serde_test::assert_tokens(&OpCode::Hello, &[Token::U8(8)]);
         serde_test::assert_tokens(&OpCode::Resumed, &[Token::U8(9)]);
         serde_test::assert_tokens(&OpCode::ClientDisconnect, &[Token::U8(13)]);
+        serde_test::assert_tokens(&OpCode::Unknown(255), &[Token::U8(255)]);
     }
 }
@@ -265,7 +265,7 @@ impl<'de> Deserialize<'de> for VoiceState {
 #[cfg(test)]
 mod tests {
     use super::{ChannelId, GuildId, Member, UserId, VoiceState};
-    use crate::{id::RoleId, user::User};
+    use crate::{datetime::Timestamp, id::RoleId, user::User};
     use serde_test::Token;
 
     #[test]
@@ -336,7 +336,9 @@ mod tests {
                 deaf: false,
                 guild_id: GuildId(2),
                 hoisted_role: Some(RoleId(2)),
-                joined_at: Some("timestamp".to_owned()),
+                joined_at: Some(
+                    Timestamp::parse("2021-08-10T12:18:37.000000+00:00").expect("valid timestamp"),
+                ),
                 mute: true,
                 nick: Some("twilight".to_owned()),
                 pending: false,
@@ -403,7 +405,7 @@ mod tests {
                 Token::Str("2"),
                 Token::Str("joined_at"),
                 Token::Some,
-                Token::Str("timestamp"),
+                Token::Str("2021-08-10T12:18:37.000000+00:00"),
                 Token::Str("mute"),
                 Token::Bool(true),
                 Token::Str("nick"),
@@ -828,6 +828,8 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::ReactionRemoveAll(e) => e.guild_id,
         Event::ReactionRemoveEmoji(e) => Some(e.guild_id),
         Event::Ready(_) => None,
+        Event::RelationshipAdd(_) => None,
+        Event::RelationshipRemove(_) => None,
         Event::Resumed => None,
         Event::RoleCreate(e) => Some(e.guild_id),
         Event::RoleDelete(e) => Some(e.guild_id),
@@ -839,8 +841,16 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::ShardPayload(_) => None,
         Event::ShardReconnecting(_) => None,
         Event::ShardResuming(_) => None,
+        Event::ThreadCreate(e) => channel_guild_id(e),
+        Event::ThreadDelete(e) => Some(e.guild_id),
+        Event::ThreadListSync(e) => Some(e.guild_id),
+        Event::ThreadMemberUpdate(_) => None,
+        Event::ThreadMembersUpdate(e) => Some(e.guild_id),
+        Event::ThreadUpdate(e) => channel_guild_id(e),
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
+        Event::Unknown { .. } => None,
+        Event::UserGuildSettingsUpdate(e) => e.guild_id,
         Event::UserUpdate(_) => None,
         Event::VoiceServerUpdate(e) => e.guild_id,
         Event::VoiceStateUpdate(e) => e.0.guild_id,
@@ -866,9 +876,13 @@ mod tests {
             message::{Message, MessageType},
             Reaction, ReactionType,
         },
+        datetime::Timestamp,
         gateway::{
             event::{Event, EventType},
-            payload::{MessageCreate, ReactionAdd, ReadState, ReadStateWrapper, Ready, RoleDelete},
+            payload::{
+                MessageCreate, ReactionAdd, ReadState, ReadStateWrapper, Ready, RoleDelete,
+                UserGuildSettingsWrapper,
+            },
         },
         id::{ChannelId, GuildId, MessageId, RoleId, UserId},
         user::{CurrentUser, User},
@@ -915,7 +929,7 @@ mod tests {
             reference: None,
             stickers: Vec::new(),
             referenced_message: None,
-            timestamp: String::new(),
+            timestamp: Timestamp::from_secs(1_632_072_645).expect("valid timestamp"),
             tts: false,
             webhook_id: None,
         }
@@ -1001,6 +1015,7 @@ mod tests {
             guilds: Vec::new(),
             private_channels: Vec::new(),
             merged_members: vec![],
+            relationships: vec![],
             session_id: String::new(),
             shard: Some([5, 7]),
             users: vec![],
@@ -1027,6 +1042,7 @@ mod tests {
                     id: ChannelId(1),
                 }],
             },
+            user_guild_settings: UserGuildSettingsWrapper::default(),
             version: 6,
         };
         let event = Event::Ready(Box::new(ready));
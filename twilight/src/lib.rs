@@ -119,6 +119,12 @@
 //! in any other crate. Currently, it contains a trait to make extracting data
 //! from Discord identifiers (Snowflakes) easier.
 //!
+//! ### [`twilight-mock`]
+//!
+//! Local mock Discord servers for testing code that uses the Twilight
+//! ecosystem offline, such as a REST API server to point the HTTP client at and
+//! a gateway server to point shards and clusters at.
+//!
 //! ### [`twilight-gateway-queue`]
 //!
 //! A trait and some implementations that are used by the gateway to ratelimit
@@ -236,6 +242,7 @@
 //! [`twilight-http`]: https://twilight.rs/chapter_1_crates/section_2_http.html
 //! [`twilight-lavalink`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_3_lavalink.html
 //! [`twilight-mention`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_2_mention.html
+//! [`twilight-mock`]: https://github.com/twilight-rs/twilight/tree/main/mock
 //! [`twilight-model`]: https://twilight.rs/chapter_1_crates/section_1_model.html
 //! [`twilight-standby`]: https://twilight.rs/chapter_1_crates/section_6_standby.html
 //! [`twilight-util`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_4_util.html
@@ -0,0 +1,331 @@
+//! Calculate the permissions of a member in a guild or in a channel.
+//!
+//! Permissions are calculated in the same order as Discord does:
+//!
+//! 1. The owner of a guild has all permissions;
+//! 2. The permissions of the `@everyone` role are combined with the
+//!    permissions of all of the member's roles;
+//! 3. A member with the [`ADMINISTRATOR`] permission has all permissions;
+//! 4. In a channel, the overwrite for the `@everyone` role is applied, then the
+//!    overwrites of the member's roles, and lastly the overwrite for the member.
+//!
+//! # Examples
+//!
+//! Calculate the permissions of a member in a channel where the `@everyone`
+//! role can't send messages, but one of the member's roles can:
+//!
+//! ```rust
+//! use twilight_model::{
+//!     channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+//!     guild::Permissions,
+//!     id::{GuildId, RoleId, UserId},
+//! };
+//! use twilight_util::permission_calculator::PermissionCalculator;
+//!
+//! let guild_id = GuildId(1);
+//! let everyone_role = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+//! let member_roles = &[(RoleId(2), Permissions::empty())];
+//!
+//! let overwrites = &[
+//!     PermissionOverwrite {
+//!         allow: Permissions::empty(),
+//!         deny: Permissions::SEND_MESSAGES,
+//!         kind: PermissionOverwriteType::Role(RoleId(1)),
+//!     },
+//!     PermissionOverwrite {
+//!         allow: Permissions::SEND_MESSAGES,
+//!         deny: Permissions::empty(),
+//!         kind: PermissionOverwriteType::Role(RoleId(2)),
+//!     },
+//! ];
+//!
+//! let calculator = PermissionCalculator::new(guild_id, UserId(3), everyone_role, member_roles);
+//! let permissions = calculator.in_channel(overwrites);
+//!
+//! assert!(permissions.contains(Permissions::SEND_MESSAGES));
+//! ```
+//!
+//! [`ADMINISTRATOR`]: twilight_model::guild::Permissions::ADMINISTRATOR
+
+use twilight_model::{
+    channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    guild::Permissions,
+    id::{GuildId, RoleId, UserId},
+};
+
+/// Permissions that are implicitly denied when [`SEND_MESSAGES`] is denied in
+/// a channel.
+///
+/// [`SEND_MESSAGES`]: Permissions::SEND_MESSAGES
+const SEND_MESSAGES_DEPENDENTS: Permissions = Permissions::from_bits_truncate(
+    Permissions::ATTACH_FILES.bits()
+        | Permissions::EMBED_LINKS.bits()
+        | Permissions::MENTION_EVERYONE.bits()
+        | Permissions::SEND_TTS_MESSAGES.bits(),
+);
+
+/// Calculator for a member's permissions in a guild or in one of its channels.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "calculators aren't useful unless permissions are calculated"]
+pub struct PermissionCalculator<'a> {
+    everyone_role: Permissions,
+    guild_id: GuildId,
+    member_roles: &'a [(RoleId, Permissions)],
+    owner_id: Option<UserId>,
+    user_id: UserId,
+}
+
+impl<'a> PermissionCalculator<'a> {
+    /// Create a new permission calculator for a member of a guild.
+    ///
+    /// `everyone_role` is the permissions of the `@everyone` role, which has
+    /// the same ID as the guild. `member_roles` is the ID and permissions of
+    /// each of the member's other roles.
+    pub fn new(
+        guild_id: GuildId,
+        user_id: UserId,
+        everyone_role: Permissions,
+        member_roles: &'a [(RoleId, Permissions)],
+    ) -> Self {
+        Self {
+            everyone_role,
+            guild_id,
+            member_roles,
+            owner_id: None,
+            user_id,
+        }
+    }
+
+    /// Set the ID of the owner of the guild.
+    ///
+    /// If the member is the owner then they have all permissions.
+    pub fn owner_id(mut self, owner_id: UserId) -> Self {
+        self.owner_id = Some(owner_id);
+
+        self
+    }
+
+    /// Calculate the guild-level permissions of the member, without taking
+    /// any channel into account.
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub fn root(&self) -> Permissions {
+        if self.is_owner() {
+            return Permissions::all();
+        }
+
+        let permissions = self
+            .member_roles
+            .iter()
+            .fold(self.everyone_role, |permissions, (_, role)| {
+                permissions | *role
+            });
+
+        if permissions.contains(Permissions::ADMINISTRATOR) {
+            return Permissions::all();
+        }
+
+        permissions
+    }
+
+    /// Calculate the permissions of the member in a channel with the provided
+    /// permission overwrites.
+    ///
+    /// If the member can't view the channel then no permissions are returned,
+    /// and if the member can't send messages then the permissions that depend
+    /// on sending messages are removed, just like Discord does.
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub fn in_channel(&self, overwrites: &[PermissionOverwrite]) -> Permissions {
+        let mut permissions = self.root();
+
+        // Administrators and the owner bypass all overwrites.
+        if permissions == Permissions::all() {
+            return permissions;
+        }
+
+        let everyone_role_id = RoleId(self.guild_id.0);
+        let mut role_allow = Permissions::empty();
+        let mut role_deny = Permissions::empty();
+        let mut member_overwrite = None;
+
+        for overwrite in overwrites {
+            match overwrite.kind {
+                PermissionOverwriteType::Role(role_id) if role_id == everyone_role_id => {
+                    permissions.remove(overwrite.deny);
+                    permissions.insert(overwrite.allow);
+                }
+                PermissionOverwriteType::Role(role_id) => {
+                    if self.member_roles.iter().any(|(id, _)| *id == role_id) {
+                        role_allow.insert(overwrite.allow);
+                        role_deny.insert(overwrite.deny);
+                    }
+                }
+                PermissionOverwriteType::Member(user_id) => {
+                    if user_id == self.user_id {
+                        member_overwrite = Some(overwrite);
+                    }
+                }
+            }
+        }
+
+        permissions.remove(role_deny);
+        permissions.insert(role_allow);
+
+        if let Some(overwrite) = member_overwrite {
+            permissions.remove(overwrite.deny);
+            permissions.insert(overwrite.allow);
+        }
+
+        if !permissions.contains(Permissions::VIEW_CHANNEL) {
+            return Permissions::empty();
+        }
+
+        if !permissions.contains(Permissions::SEND_MESSAGES) {
+            permissions.remove(SEND_MESSAGES_DEPENDENTS);
+        }
+
+        permissions
+    }
+
+    fn is_owner(&self) -> bool {
+        self.owner_id == Some(self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PermissionCalculator;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::Permissions,
+        id::{GuildId, RoleId, UserId},
+    };
+
+    assert_impl_all!(PermissionCalculator<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    const GUILD_ID: GuildId = GuildId(1);
+    const USER_ID: UserId = UserId(2);
+
+    fn overwrite(
+        kind: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite { allow, deny, kind }
+    }
+
+    #[test]
+    fn test_owner_has_all() {
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, Permissions::empty(), &[])
+            .owner_id(USER_ID);
+
+        assert_eq!(Permissions::all(), calculator.root());
+        assert_eq!(
+            Permissions::all(),
+            calculator.in_channel(&[overwrite(
+                PermissionOverwriteType::Member(USER_ID),
+                Permissions::empty(),
+                Permissions::VIEW_CHANNEL,
+            )])
+        );
+    }
+
+    #[test]
+    fn test_administrator_has_all() {
+        let member_roles = &[(RoleId(3), Permissions::ADMINISTRATOR)];
+        let calculator =
+            PermissionCalculator::new(GUILD_ID, USER_ID, Permissions::empty(), member_roles);
+
+        assert_eq!(Permissions::all(), calculator.root());
+    }
+
+    #[test]
+    fn test_root_combines_roles() {
+        let member_roles = &[
+            (RoleId(3), Permissions::SEND_MESSAGES),
+            (RoleId(4), Permissions::KICK_MEMBERS),
+        ];
+        let calculator =
+            PermissionCalculator::new(GUILD_ID, USER_ID, Permissions::VIEW_CHANNEL, member_roles);
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::KICK_MEMBERS,
+            calculator.root()
+        );
+    }
+
+    #[test]
+    fn test_overwrite_order() {
+        let member_roles = &[(RoleId(3), Permissions::empty())];
+        let everyone =
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS;
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, member_roles);
+
+        let overwrites = &[
+            overwrite(
+                PermissionOverwriteType::Role(RoleId(GUILD_ID.0)),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+            ),
+            overwrite(
+                PermissionOverwriteType::Role(RoleId(3)),
+                Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+                Permissions::empty(),
+            ),
+            // Overwrites for roles the member doesn't have are ignored.
+            overwrite(
+                PermissionOverwriteType::Role(RoleId(4)),
+                Permissions::empty(),
+                Permissions::VIEW_CHANNEL,
+            ),
+            overwrite(
+                PermissionOverwriteType::Member(USER_ID),
+                Permissions::empty(),
+                Permissions::ADD_REACTIONS,
+            ),
+        ];
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
+            calculator.in_channel(overwrites)
+        );
+    }
+
+    #[test]
+    fn test_view_channel_denied() {
+        let calculator = PermissionCalculator::new(
+            GUILD_ID,
+            USER_ID,
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
+            &[],
+        );
+        let overwrites = &[overwrite(
+            PermissionOverwriteType::Member(USER_ID),
+            Permissions::empty(),
+            Permissions::VIEW_CHANNEL,
+        )];
+
+        assert_eq!(Permissions::empty(), calculator.in_channel(overwrites));
+    }
+
+    #[test]
+    fn test_send_messages_denied() {
+        let everyone = Permissions::VIEW_CHANNEL
+            | Permissions::SEND_MESSAGES
+            | Permissions::EMBED_LINKS
+            | Permissions::MENTION_EVERYONE;
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]);
+        let overwrites = &[overwrite(
+            PermissionOverwriteType::Role(RoleId(GUILD_ID.0)),
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        )];
+
+        assert_eq!(Permissions::VIEW_CHANNEL, calculator.in_channel(overwrites));
+    }
+}